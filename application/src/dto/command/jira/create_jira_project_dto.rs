@@ -1,3 +1,8 @@
+use domain::entity::jira::JiraProject;
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey, JiraProjectName};
+
+use crate::error::command::jira::{JiraProjectField, JiraProjectFieldError};
+
 /// DTO for creating a Jira project.
 #[derive(Debug, Clone)]
 pub struct CreateJiraProjectDto {
@@ -5,3 +10,24 @@ pub struct CreateJiraProjectDto {
     pub key: String,
     pub name: String,
 }
+
+impl CreateJiraProjectDto {
+    /// Validates the input and builds the project.
+    ///
+    /// Every field is validated before failing so callers see all problems at once; the
+    /// errors are in id, key, name order.
+    pub fn into_project(self) -> Result<JiraProject, Vec<JiraProjectFieldError>> {
+        let mut errors = Vec::new();
+        let id = JiraProjectId::of(self.id)
+            .map_err(|e| errors.push(JiraProjectFieldError::new(JiraProjectField::Id, e)));
+        let key = JiraProjectKey::of(self.key)
+            .map_err(|e| errors.push(JiraProjectFieldError::new(JiraProjectField::Key, e)));
+        let name = JiraProjectName::of(self.name)
+            .map_err(|e| errors.push(JiraProjectFieldError::new(JiraProjectField::Name, e)));
+
+        match (id, key, name) {
+            (Ok(id), Ok(key), Ok(name)) => Ok(JiraProject::new(id, key, name)),
+            _ => Err(errors),
+        }
+    }
+}
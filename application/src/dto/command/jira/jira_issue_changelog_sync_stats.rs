@@ -0,0 +1,10 @@
+/// Outcome of a Jira issue changelog sync.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueChangelogSyncStats {
+    /// Number of issues whose changelog was fetched.
+    pub issues: i32,
+    /// Number of changelog entries fetched from Jira.
+    pub fetched: i32,
+    /// Number of fetched entries that were not stored before.
+    pub stored: u64,
+}
@@ -0,0 +1,208 @@
+use chrono::{DateTime, Duration, Utc};
+
+use domain::value_object::SyncWindow;
+use domain::value_object::jira::JiraProjectKey;
+
+use crate::error::command::jira::JiraIssueSyncParamsError;
+
+/// Settings of a single Jira issue sync run.
+///
+/// Only `since` is required; every other setting is off until set with its `with_*`
+/// method. Run [`validate`](Self::validate) before syncing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssueSyncParams {
+    /// Only issues updated at or after this time are synced.
+    pub since: DateTime<Utc>,
+    /// Issues updated at or after this time are left out.
+    pub until: Option<DateTime<Utc>>,
+    /// Projects to sync instead of every stored project.
+    pub project_keys: Option<Vec<JiraProjectKey>>,
+    /// Splits the range into windows of this length, synced oldest first.
+    pub window: Option<Duration>,
+    /// Most issues written per repository call; by default one call per API page.
+    pub persist_batch_size: Option<usize>,
+    /// Stops once this many fetched issues have been handed to the repository.
+    pub max_issues: Option<usize>,
+    /// Fetches and counts issues without writing anything.
+    pub dry_run: bool,
+}
+
+impl JiraIssueSyncParams {
+    pub fn new(since: DateTime<Utc>) -> Self {
+        Self {
+            since,
+            until: None,
+            project_keys: None,
+            window: None,
+            persist_batch_size: None,
+            max_issues: None,
+            dry_run: false,
+        }
+    }
+
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn with_project_keys(mut self, project_keys: Vec<JiraProjectKey>) -> Self {
+        self.project_keys = Some(project_keys);
+        self
+    }
+
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    pub fn with_persist_batch_size(mut self, persist_batch_size: usize) -> Self {
+        self.persist_batch_size = Some(persist_batch_size);
+        self
+    }
+
+    pub fn with_max_issues(mut self, max_issues: usize) -> Self {
+        self.max_issues = Some(max_issues);
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Checks that the settings describe a sync that can run.
+    pub fn validate(&self) -> Result<(), JiraIssueSyncParamsError> {
+        if let Some(until) = self.until
+            && SyncWindow::of(self.since, until).is_err()
+        {
+            return Err(JiraIssueSyncParamsError::UntilBeforeSince {
+                since: self.since,
+                until,
+            });
+        }
+        if self.project_keys.as_ref().is_some_and(Vec::is_empty) {
+            return Err(JiraIssueSyncParamsError::NoProjectKeys);
+        }
+        if self.window.is_some_and(|window| window <= Duration::zero()) {
+            return Err(JiraIssueSyncParamsError::NonPositiveWindow);
+        }
+        if self.persist_batch_size == Some(0) {
+            return Err(JiraIssueSyncParamsError::ZeroPersistBatchSize);
+        }
+        if self.max_issues == Some(0) {
+            return Err(JiraIssueSyncParamsError::ZeroMaxIssues);
+        }
+        Ok(())
+    }
+
+    /// Returns the `(since, until)` ranges to sync in order.
+    ///
+    /// Without a window this is the whole range. With one, the range is cut into
+    /// consecutive windows ending at `until`, or at `now` when no `until` is set; the
+    /// last window is then left open so issues updated during the run are not missed.
+    pub fn windows(&self, now: DateTime<Utc>) -> Vec<(DateTime<Utc>, Option<DateTime<Utc>>)> {
+        let chunks = self.window.and_then(|window| {
+            SyncWindow::of(self.since, self.until.unwrap_or(now))
+                .ok()?
+                .chunks(window)
+                .ok()
+        });
+        let Some(chunks) = chunks else {
+            return vec![(self.since, self.until)];
+        };
+
+        let mut windows: Vec<_> = chunks
+            .map(|chunk| (chunk.since(), Some(chunk.until())))
+            .collect();
+        if self.until.is_none()
+            && let Some(last) = windows.last_mut()
+        {
+            last.1 = None;
+        }
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_validate_accepts_defaults_and_empty_range() {
+        assert!(JiraIssueSyncParams::new(at(0)).validate().is_ok());
+        assert!(
+            JiraIssueSyncParams::new(at(3))
+                .with_until(at(3))
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_until_before_since() {
+        let result = JiraIssueSyncParams::new(at(3)).with_until(at(1)).validate();
+
+        assert!(matches!(
+            result,
+            Err(JiraIssueSyncParamsError::UntilBeforeSince { since, until })
+                if since == at(3) && until == at(1)
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_or_zero_settings() {
+        let params = JiraIssueSyncParams::new(at(0));
+
+        assert!(matches!(
+            params.clone().with_project_keys(vec![]).validate(),
+            Err(JiraIssueSyncParamsError::NoProjectKeys)
+        ));
+        assert!(matches!(
+            params.clone().with_window(Duration::zero()).validate(),
+            Err(JiraIssueSyncParamsError::NonPositiveWindow)
+        ));
+        assert!(matches!(
+            params.clone().with_persist_batch_size(0).validate(),
+            Err(JiraIssueSyncParamsError::ZeroPersistBatchSize)
+        ));
+        assert!(matches!(
+            params.with_max_issues(0).validate(),
+            Err(JiraIssueSyncParamsError::ZeroMaxIssues)
+        ));
+    }
+
+    #[test]
+    fn test_windows_cut_the_range_up_to_until() {
+        let params = JiraIssueSyncParams::new(at(0))
+            .with_until(at(5))
+            .with_window(Duration::hours(2));
+
+        assert_eq!(
+            params.windows(at(9)),
+            vec![
+                (at(0), Some(at(2))),
+                (at(2), Some(at(4))),
+                (at(4), Some(at(5))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_windows_leave_the_last_window_open_without_until() {
+        let params = JiraIssueSyncParams::new(at(0)).with_window(Duration::hours(2));
+
+        assert_eq!(
+            params.windows(at(3)),
+            vec![(at(0), Some(at(2))), (at(2), None)]
+        );
+        assert_eq!(
+            JiraIssueSyncParams::new(at(0)).windows(at(3)),
+            vec![(at(0), None)]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use domain::value_object::jira::JiraProjectKey;
+
+/// Summary of a Jira issue sync run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueSyncReport {
+    /// Number of issues persisted, or that would have been in a dry run.
+    pub synced: i32,
+    /// Number of fetched issues the repository reported as not written.
+    pub skipped: i32,
+    /// Number of issues Jira returned that could not be converted and were not synced.
+    pub rejected: i32,
+    /// Number of API pages fetched, including empty ones.
+    pub pages_fetched: i32,
+    /// Latest `updated_at` among the persisted issues; `None` when nothing was persisted.
+    pub watermark: Option<DateTime<Utc>>,
+    /// Wall-clock time the run took.
+    pub duration: Duration,
+    /// Issues each project would contribute, in sync order; only filled on dry runs.
+    pub per_project: Vec<(JiraProjectKey, i32)>,
+}
+
+impl JiraIssueSyncReport {
+    /// Returns the number of issues persisted.
+    pub fn synced(&self) -> i32 {
+        self.synced
+    }
+
+    /// Adds the counts and duration of `other`, keeping the later watermark.
+    pub fn merge(&mut self, other: &Self) {
+        self.synced += other.synced;
+        self.skipped += other.skipped;
+        self.rejected += other.rejected;
+        self.pages_fetched += other.pages_fetched;
+        self.watermark = self.watermark.max(other.watermark);
+        self.duration += other.duration;
+        for (project_key, count) in &other.per_project {
+            self.add_project_count(project_key, *count);
+        }
+    }
+
+    /// Adds `count` issues to the per-project count of `project_key`.
+    pub fn add_project_count(&mut self, project_key: &JiraProjectKey, count: i32) {
+        match self
+            .per_project
+            .iter_mut()
+            .find(|(key, _)| key == project_key)
+        {
+            Some((_, total)) => *total += count,
+            None => self.per_project.push((project_key.clone(), count)),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::JiraIssueSyncReport;
+
+/// Outcome of a cancellable Jira issue sync.
+///
+/// Both variants carry the report collected so far; a cancelled sync has only
+/// committed whole batches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JiraIssueSyncStatus {
+    Completed(JiraIssueSyncReport),
+    Cancelled(JiraIssueSyncReport),
+}
+
+impl JiraIssueSyncStatus {
+    /// Returns the report collected during the sync.
+    pub fn report(&self) -> &JiraIssueSyncReport {
+        match self {
+            Self::Completed(report) | Self::Cancelled(report) => report,
+        }
+    }
+
+    /// Consumes the status and returns its report.
+    pub fn into_report(self) -> JiraIssueSyncReport {
+        match self {
+            Self::Completed(report) | Self::Cancelled(report) => report,
+        }
+    }
+
+    /// Returns the number of issues persisted.
+    pub fn synced(&self) -> i32 {
+        self.report().synced
+    }
+}
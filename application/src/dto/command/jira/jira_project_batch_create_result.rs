@@ -0,0 +1,12 @@
+use domain::entity::jira::JiraProject;
+
+use crate::error::command::jira::JiraProjectInputRejection;
+
+/// Outcome of creating a batch of Jira projects.
+#[derive(Debug, Default)]
+pub struct JiraProjectBatchCreateResult {
+    /// Projects created, in batch order.
+    pub created: Vec<JiraProject>,
+    /// Inputs that failed validation and were skipped, in batch order.
+    pub rejected: Vec<JiraProjectInputRejection>,
+}
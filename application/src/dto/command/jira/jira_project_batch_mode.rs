@@ -0,0 +1,9 @@
+/// How a batch of project inputs is handled when some of them are invalid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JiraProjectBatchMode {
+    /// Creates the valid inputs and reports the invalid ones.
+    #[default]
+    PartialSuccess,
+    /// Creates nothing if any input is invalid.
+    AllOrNothing,
+}
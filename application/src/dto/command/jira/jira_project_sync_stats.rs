@@ -0,0 +1,21 @@
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey};
+
+/// Fetched projects that shared an id, e.g. after a project key was renamed and the
+/// old key re-created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraProjectIdConflict {
+    pub id: JiraProjectId,
+    /// Key of the project that was persisted; the one that sorts first.
+    pub kept: JiraProjectKey,
+    /// Keys of the projects that were skipped, in sorted order.
+    pub dropped: Vec<JiraProjectKey>,
+}
+
+/// Outcome of a Jira project sync.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraProjectSyncStats {
+    /// Number of projects persisted.
+    pub synced: i32,
+    /// Ids shared by more than one fetched project.
+    pub id_conflicts: Vec<JiraProjectIdConflict>,
+}
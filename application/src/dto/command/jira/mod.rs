@@ -1,5 +1,19 @@
 mod create_jira_project_dto;
+mod jira_issue_changelog_sync_stats;
+mod jira_issue_sync_params;
+mod jira_issue_sync_report;
+mod jira_issue_sync_status;
+mod jira_project_batch_create_result;
+mod jira_project_batch_mode;
+mod jira_project_sync_stats;
 mod update_jira_project_dto;
 
 pub use create_jira_project_dto::CreateJiraProjectDto;
+pub use jira_issue_changelog_sync_stats::JiraIssueChangelogSyncStats;
+pub use jira_issue_sync_params::JiraIssueSyncParams;
+pub use jira_issue_sync_report::JiraIssueSyncReport;
+pub use jira_issue_sync_status::JiraIssueSyncStatus;
+pub use jira_project_batch_create_result::JiraProjectBatchCreateResult;
+pub use jira_project_batch_mode::JiraProjectBatchMode;
+pub use jira_project_sync_stats::{JiraProjectIdConflict, JiraProjectSyncStats};
 pub use update_jira_project_dto::UpdateJiraProjectDto;
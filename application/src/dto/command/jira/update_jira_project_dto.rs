@@ -1,7 +1,13 @@
+use chrono::{DateTime, Utc};
+
 /// DTO for updating a Jira project.
+/// Fields left as `None` keep their current value.
 #[derive(Debug, Clone)]
 pub struct UpdateJiraProjectDto {
     pub id: String,
-    pub key: String,
-    pub name: String,
+    pub key: Option<String>,
+    pub name: Option<String>,
+    /// The `updated_at` the client last read; the update is rejected if the project has
+    /// changed since. `None` updates whatever version is stored.
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
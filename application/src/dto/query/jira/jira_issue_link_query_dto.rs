@@ -0,0 +1,18 @@
+/// DTO for a link between two Jira issues, read from `source_key` to `target_key`.
+/// This is a read-only data structure optimized for queries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JiraIssueLinkQueryDto {
+    pub source_key: String,
+    pub target_key: String,
+    pub link_type: String,
+}
+
+impl JiraIssueLinkQueryDto {
+    pub fn new(source_key: String, target_key: String, link_type: String) -> Self {
+        Self {
+            source_key,
+            target_key,
+            link_type,
+        }
+    }
+}
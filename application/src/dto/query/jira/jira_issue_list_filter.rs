@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+
+use domain::value_object::jira::JiraIssueFilter;
+
+/// Ordering applied when listing Jira issues.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JiraIssueListSort {
+    /// Most recently updated first.
+    #[default]
+    UpdatedAt,
+    /// Most watched first; issues without a watcher count come last.
+    Watches,
+}
+
+impl JiraIssueListSort {
+    /// Returns the direction the sort key is ordered in.
+    pub fn direction(&self) -> JiraSortDirection {
+        match self {
+            Self::UpdatedAt | Self::Watches => JiraSortDirection::Descending,
+        }
+    }
+}
+
+/// Direction of a list ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraSortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Optional filters applied when listing Jira issues.
+/// Unset fields do not restrict the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueListFilter {
+    /// Only include issues reported by this Atlassian account ID.
+    pub reported_by: Option<String>,
+    /// Only include unresolved issues whose due date has passed.
+    pub overdue: bool,
+    /// Only include issues last synced before this time.
+    pub synced_before: Option<DateTime<Utc>>,
+    /// Ordering of the result.
+    pub sort: JiraIssueListSort,
+}
+
+impl JiraIssueListFilter {
+    pub fn new(reported_by: Option<String>) -> Self {
+        Self {
+            reported_by,
+            overdue: false,
+            synced_before: None,
+            sort: JiraIssueListSort::default(),
+        }
+    }
+
+    /// Restricts the result to unresolved issues due before today.
+    pub fn with_overdue(mut self, overdue: bool) -> Self {
+        self.overdue = overdue;
+        self
+    }
+
+    /// Restricts the result to issues no sync has written since `synced_before`.
+    pub fn with_synced_before(mut self, synced_before: Option<DateTime<Utc>>) -> Self {
+        self.synced_before = synced_before;
+        self
+    }
+
+    /// Sets the ordering of the result.
+    pub fn with_sort(mut self, sort: JiraIssueListSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Returns the criteria an issue itself has to meet. `synced_before` is about the
+    /// stored copy rather than the issue, so it is left to the repository.
+    pub fn issue_filter(&self) -> JiraIssueFilter {
+        let mut filter = JiraIssueFilter::new();
+        if let Some(reported_by) = &self.reported_by {
+            filter = filter.with_reporter(reported_by.clone());
+        }
+        if self.overdue {
+            filter = filter.with_overdue();
+        }
+        filter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_filter_of_empty_list_filter_matches_everything() {
+        assert!(JiraIssueListFilter::new(None).issue_filter().is_empty());
+    }
+
+    #[test]
+    fn test_issue_filter_carries_reporter_and_overdue() {
+        let filter = JiraIssueListFilter::new(Some("account-1".to_string()))
+            .with_overdue(true)
+            .with_synced_before(Some(Utc::now()));
+
+        assert_eq!(
+            filter.issue_filter(),
+            JiraIssueFilter::new()
+                .with_reporter("account-1")
+                .with_overdue()
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use domain::value_object::Page;
+
+use super::{JiraIssueListFilter, JiraIssueListSort, JiraIssueQueryDto, JiraSortDirection};
+
+/// The ordering and filters a Jira issue list was produced with, after defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssueListQueryMeta {
+    pub sort_by: JiraIssueListSort,
+    pub direction: JiraSortDirection,
+    /// Every filter passed to the repository, including the ones left unset.
+    pub filters: JiraIssueListFilter,
+}
+
+impl JiraIssueListQueryMeta {
+    /// Describes the list produced by `filter`.
+    pub fn from_filter(filter: &JiraIssueListFilter) -> Self {
+        Self {
+            sort_by: filter.sort,
+            direction: filter.sort.direction(),
+            filters: filter.clone(),
+        }
+    }
+}
+
+/// A page of Jira issues together with how it was produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssueListQueryResult {
+    pub page: Page<JiraIssueQueryDto>,
+    pub meta: JiraIssueListQueryMeta,
+}
@@ -0,0 +1,25 @@
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+
+/// Number of issues of one type in a filtered issue list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JiraIssueTypeCount {
+    pub issue_type: JiraIssueType,
+    pub count: i32,
+}
+
+/// Number of issues of one priority in a filtered issue list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JiraIssuePriorityCount {
+    /// None counts the issues without a priority.
+    pub priority: Option<JiraIssuePriority>,
+    pub count: i32,
+}
+
+/// Issue counts of a filtered issue list across all of its pages.
+///
+/// Each breakdown lists only the values that occur, most frequent first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueListSummary {
+    pub by_type: Vec<JiraIssueTypeCount>,
+    pub by_priority: Vec<JiraIssuePriorityCount>,
+}
@@ -1,20 +1,33 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 
-use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType, JiraStatusCategory, JiraUser};
 
 /// DTO for Jira issue query results.
 /// This is a read-only data structure optimized for queries,
 /// using domain enums for type safety.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct JiraIssueQueryDto {
     pub id: i64,
     pub key: String,
     pub summary: String,
     pub description: Option<String>,
     pub issue_type: JiraIssueType,
-    pub priority: JiraIssuePriority,
+    /// None for issues without a priority.
+    pub priority: Option<JiraIssuePriority>,
+    pub reporter: Option<JiraUser>,
+    pub watches: Option<i32>,
+    pub votes: Option<i32>,
+    pub status_category: Option<JiraStatusCategory>,
+    pub original_estimate_seconds: Option<i64>,
+    pub remaining_estimate_seconds: Option<i64>,
+    pub time_spent_seconds: Option<i64>,
+    pub resolution: Option<String>,
+    pub due_date: Option<NaiveDate>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the issue was last written by a sync, as opposed to updated in Jira.
+    pub synced_at: Option<DateTime<Utc>>,
 }
 
 impl JiraIssueQueryDto {
@@ -25,7 +38,8 @@ impl JiraIssueQueryDto {
         summary: String,
         description: Option<String>,
         issue_type: JiraIssueType,
-        priority: JiraIssuePriority,
+        priority: Option<JiraIssuePriority>,
+        reporter: Option<JiraUser>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -36,8 +50,286 @@ impl JiraIssueQueryDto {
             description,
             issue_type,
             priority,
+            reporter,
+            watches: None,
+            votes: None,
+            status_category: None,
+            original_estimate_seconds: None,
+            remaining_estimate_seconds: None,
+            time_spent_seconds: None,
+            resolution: None,
+            due_date: None,
             created_at,
             updated_at,
+            synced_at: None,
         }
     }
+
+    /// Returns a builder with every field defaulted.
+    pub fn builder() -> JiraIssueQueryDtoBuilder {
+        JiraIssueQueryDtoBuilder::default()
+    }
+
+    /// Attaches the watcher count.
+    pub fn with_watches(mut self, watches: Option<i32>) -> Self {
+        self.watches = watches;
+        self
+    }
+
+    /// Attaches the vote count.
+    pub fn with_votes(mut self, votes: Option<i32>) -> Self {
+        self.votes = votes;
+        self
+    }
+
+    /// Attaches the status category.
+    pub fn with_status_category(mut self, status_category: Option<JiraStatusCategory>) -> Self {
+        self.status_category = status_category;
+        self
+    }
+
+    /// Attaches the original estimate, remaining estimate and time spent, in seconds.
+    pub fn with_time_tracking(
+        mut self,
+        original_estimate_seconds: Option<i64>,
+        remaining_estimate_seconds: Option<i64>,
+        time_spent_seconds: Option<i64>,
+    ) -> Self {
+        self.original_estimate_seconds = original_estimate_seconds;
+        self.remaining_estimate_seconds = remaining_estimate_seconds;
+        self.time_spent_seconds = time_spent_seconds;
+        self
+    }
+
+    /// Attaches the resolution name.
+    pub fn with_resolution(mut self, resolution: Option<String>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Attaches the due date.
+    pub fn with_due_date(mut self, due_date: Option<NaiveDate>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
+    /// Attaches the time the issue was last synced.
+    pub fn with_synced_at(mut self, synced_at: Option<DateTime<Utc>>) -> Self {
+        self.synced_at = synced_at;
+        self
+    }
+}
+
+/// Builder for JiraIssueQueryDto that lets callers set only the fields they need.
+///
+/// Unset fields keep their defaults: empty strings, `Task`, no priority, description,
+/// reporter, aggregates, status category, time tracking, resolution, due date or sync
+/// time, and the Unix epoch for both timestamps.
+#[derive(Debug, Default)]
+pub struct JiraIssueQueryDtoBuilder {
+    id: i64,
+    key: String,
+    summary: String,
+    description: Option<String>,
+    issue_type: JiraIssueType,
+    priority: Option<JiraIssuePriority>,
+    reporter: Option<JiraUser>,
+    watches: Option<i32>,
+    votes: Option<i32>,
+    status_category: Option<JiraStatusCategory>,
+    original_estimate_seconds: Option<i64>,
+    remaining_estimate_seconds: Option<i64>,
+    time_spent_seconds: Option<i64>,
+    resolution: Option<String>,
+    due_date: Option<NaiveDate>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    synced_at: Option<DateTime<Utc>>,
+}
+
+impl JiraIssueQueryDtoBuilder {
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = summary.into();
+        self
+    }
+
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn issue_type(mut self, issue_type: JiraIssueType) -> Self {
+        self.issue_type = issue_type;
+        self
+    }
+
+    pub fn priority(mut self, priority: JiraIssuePriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn reporter(mut self, reporter: Option<JiraUser>) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
+    pub fn watches(mut self, watches: Option<i32>) -> Self {
+        self.watches = watches;
+        self
+    }
+
+    pub fn votes(mut self, votes: Option<i32>) -> Self {
+        self.votes = votes;
+        self
+    }
+
+    pub fn status_category(mut self, status_category: Option<JiraStatusCategory>) -> Self {
+        self.status_category = status_category;
+        self
+    }
+
+    pub fn original_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.original_estimate_seconds = seconds;
+        self
+    }
+
+    pub fn remaining_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.remaining_estimate_seconds = seconds;
+        self
+    }
+
+    pub fn time_spent_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.time_spent_seconds = seconds;
+        self
+    }
+
+    pub fn resolution(mut self, resolution: Option<String>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn due_date(mut self, due_date: Option<NaiveDate>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
+    pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    pub fn updated_at(mut self, updated_at: DateTime<Utc>) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn synced_at(mut self, synced_at: Option<DateTime<Utc>>) -> Self {
+        self.synced_at = synced_at;
+        self
+    }
+
+    pub fn build(self) -> JiraIssueQueryDto {
+        JiraIssueQueryDto {
+            id: self.id,
+            key: self.key,
+            summary: self.summary,
+            description: self.description,
+            issue_type: self.issue_type,
+            priority: self.priority,
+            reporter: self.reporter,
+            watches: self.watches,
+            votes: self.votes,
+            status_category: self.status_category,
+            original_estimate_seconds: self.original_estimate_seconds,
+            remaining_estimate_seconds: self.remaining_estimate_seconds,
+            time_spent_seconds: self.time_spent_seconds,
+            resolution: self.resolution,
+            due_date: self.due_date,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            synced_at: self.synced_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_builder_defaults_unset_fields() {
+        let dto = JiraIssueQueryDto::builder().id(1).key("PROJ-1").build();
+
+        assert_eq!(dto.id, 1);
+        assert_eq!(dto.key, "PROJ-1");
+        assert_eq!(dto.summary, "");
+        assert_eq!(dto.description, None);
+        assert_eq!(dto.issue_type, JiraIssueType::Task);
+        assert_eq!(dto.priority, None);
+        assert_eq!(dto.reporter, None);
+        assert_eq!(dto.watches, None);
+        assert_eq!(dto.votes, None);
+        assert_eq!(dto.original_estimate_seconds, None);
+        assert_eq!(dto.remaining_estimate_seconds, None);
+        assert_eq!(dto.time_spent_seconds, None);
+        assert_eq!(dto.created_at, DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(dto.updated_at, DateTime::<Utc>::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn test_builder_matches_new() {
+        let now = Utc::now();
+        let built = JiraIssueQueryDto::builder()
+            .id(2)
+            .key("PROJ-2")
+            .summary("Summary")
+            .issue_type(JiraIssueType::Bug)
+            .priority(JiraIssuePriority::High)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+
+        assert_eq!(
+            built,
+            JiraIssueQueryDto::new(
+                2,
+                "PROJ-2".to_string(),
+                "Summary".to_string(),
+                None,
+                JiraIssueType::Bug,
+                Some(JiraIssuePriority::High),
+                None,
+                now,
+                now,
+            )
+        );
+    }
+
+    #[test]
+    fn test_equal_dtos_compare_and_hash_equal() {
+        let dto = JiraIssueQueryDto::builder()
+            .id(1)
+            .key("PROJ-1")
+            .build()
+            .with_watches(Some(2));
+        let same = dto.clone();
+        let other = dto.clone().with_watches(Some(3));
+
+        assert_eq!(dto, same);
+        assert_ne!(dto, other);
+
+        let unique: HashSet<JiraIssueQueryDto> = [dto, same, other].into_iter().collect();
+        assert_eq!(unique.len(), 2);
+    }
 }
@@ -0,0 +1,10 @@
+use super::{JiraIssueQueryDto, JiraProjectQueryDto};
+
+/// A project together with all of its issues, as stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct JiraProjectDumpDto {
+    pub project: JiraProjectQueryDto,
+    /// Issues of the project, ordered by id.
+    pub issues: Vec<JiraIssueQueryDto>,
+}
@@ -1,14 +1,92 @@
+use chrono::{DateTime, Utc};
+
 /// DTO for Jira project query results.
 /// This is a read-only data structure optimized for queries.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct JiraProjectQueryDto {
     pub id: i64,
     pub key: String,
     pub name: String,
+    /// Version of the stored project; clients send it back to update the project safely.
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 impl JiraProjectQueryDto {
     pub fn new(id: i64, key: String, name: String) -> Self {
-        Self { id, key, name }
+        Self {
+            id,
+            key,
+            name,
+            updated_at: None,
+        }
+    }
+
+    pub fn with_updated_at(mut self, updated_at: DateTime<Utc>) -> Self {
+        self.updated_at = Some(updated_at);
+        self
+    }
+
+    /// Returns a builder with every field defaulted.
+    pub fn builder() -> JiraProjectQueryDtoBuilder {
+        JiraProjectQueryDtoBuilder::default()
+    }
+}
+
+/// Builder for JiraProjectQueryDto; unset fields default to `0` and empty strings.
+#[derive(Debug, Default)]
+pub struct JiraProjectQueryDtoBuilder {
+    id: i64,
+    key: String,
+    name: String,
+}
+
+impl JiraProjectQueryDtoBuilder {
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> JiraProjectQueryDto {
+        JiraProjectQueryDto::new(self.id, self.key, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_builder_defaults_name() {
+        let dto = JiraProjectQueryDto::builder().id(1).key("PROJ").build();
+
+        assert_eq!(
+            dto,
+            JiraProjectQueryDto::new(1, "PROJ".to_string(), String::new())
+        );
+    }
+
+    #[test]
+    fn test_dtos_dedup_in_hash_set() {
+        let proj = JiraProjectQueryDto::new(1, "PROJ".to_string(), "Project".to_string());
+        let renamed = JiraProjectQueryDto::new(1, "PROJ".to_string(), "Renamed".to_string());
+
+        assert_eq!(proj, proj.clone());
+        assert_ne!(proj, renamed);
+
+        let unique: HashSet<JiraProjectQueryDto> =
+            [proj.clone(), renamed, proj].into_iter().collect();
+        assert_eq!(unique.len(), 2);
     }
 }
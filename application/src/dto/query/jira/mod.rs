@@ -1,5 +1,17 @@
+mod jira_issue_link_query_dto;
+mod jira_issue_list_filter;
+mod jira_issue_list_query_result;
+mod jira_issue_list_summary;
 mod jira_issue_query_dto;
+mod jira_project_dump_dto;
 mod jira_project_query_dto;
 
-pub use jira_issue_query_dto::JiraIssueQueryDto;
-pub use jira_project_query_dto::JiraProjectQueryDto;
+pub use jira_issue_link_query_dto::JiraIssueLinkQueryDto;
+pub use jira_issue_list_filter::{JiraIssueListFilter, JiraIssueListSort, JiraSortDirection};
+pub use jira_issue_list_query_result::{JiraIssueListQueryMeta, JiraIssueListQueryResult};
+pub use jira_issue_list_summary::{
+    JiraIssueListSummary, JiraIssuePriorityCount, JiraIssueTypeCount,
+};
+pub use jira_issue_query_dto::{JiraIssueQueryDto, JiraIssueQueryDtoBuilder};
+pub use jira_project_dump_dto::JiraProjectDumpDto;
+pub use jira_project_query_dto::{JiraProjectQueryDto, JiraProjectQueryDtoBuilder};
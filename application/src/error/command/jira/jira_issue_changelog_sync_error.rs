@@ -0,0 +1,32 @@
+use domain::error::JiraError;
+use domain::value_object::jira::JiraIssueKey;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when syncing the changelogs of Jira issues.
+#[derive(Debug, Error)]
+pub enum JiraIssueChangelogSyncError {
+    #[error("Failed to fetch changelog of {0}: {1}")]
+    ChangelogFetchFailed(JiraIssueKey, #[source] JiraError),
+
+    #[error("Failed to persist changelog: {0}")]
+    ChangelogPersistFailed(#[source] JiraError),
+
+    #[error("Another changelog sync is already running")]
+    AlreadyRunning,
+
+    #[error("Failed to acquire the changelog sync lock: {0}")]
+    LockFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraIssueChangelogSyncError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ChangelogFetchFailed(..) => "JIRA_ISSUE_CHANGELOG_FETCH_FAILED",
+            Self::ChangelogPersistFailed(_) => "JIRA_ISSUE_CHANGELOG_PERSIST_FAILED",
+            Self::AlreadyRunning => "JIRA_ISSUE_CHANGELOG_SYNC_ALREADY_RUNNING",
+            Self::LockFailed(_) => "JIRA_ISSUE_CHANGELOG_SYNC_LOCK_FAILED",
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use domain::error::JiraError;
 use thiserror::Error;
 
+use super::JiraIssueSyncParamsError;
 use crate::error::ApplicationError;
 
 /// Represents errors that can occur when syncing Jira issues from external API.
 #[derive(Debug, Error)]
 pub enum JiraIssueSyncError {
+    #[error("Invalid sync parameters: {0}")]
+    InvalidParams(#[source] JiraIssueSyncParamsError),
+
     #[error("Failed to fetch project keys: {0}")]
     ProjectKeyFetchFailed(#[source] JiraError),
 
@@ -14,6 +18,27 @@ pub enum JiraIssueSyncError {
 
     #[error("Failed to persist issues: {0}")]
     IssuePersistFailed(#[source] JiraError),
+
+    #[error("Failed to persist issue links: {0}")]
+    LinkPersistFailed(#[source] JiraError),
+
+    #[error("Another issue sync is already running")]
+    AlreadyRunning,
+
+    #[error("Failed to acquire the issue sync lock: {0}")]
+    LockFailed(#[source] JiraError),
 }
 
-impl ApplicationError for JiraIssueSyncError {}
+impl ApplicationError for JiraIssueSyncError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidParams(_) => "JIRA_ISSUE_SYNC_INVALID_PARAMS",
+            Self::ProjectKeyFetchFailed(_) => "JIRA_PROJECT_KEY_FETCH_FAILED",
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+            Self::IssuePersistFailed(_) => "JIRA_ISSUE_PERSIST_FAILED",
+            Self::LinkPersistFailed(_) => "JIRA_ISSUE_LINK_PERSIST_FAILED",
+            Self::AlreadyRunning => "JIRA_ISSUE_SYNC_ALREADY_RUNNING",
+            Self::LockFailed(_) => "JIRA_ISSUE_SYNC_LOCK_FAILED",
+        }
+    }
+}
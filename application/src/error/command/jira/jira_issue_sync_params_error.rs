@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+/// Reasons a set of issue sync parameters is rejected before syncing.
+#[derive(Debug, Error)]
+pub enum JiraIssueSyncParamsError {
+    #[error("until ({until}) is before since ({since})")]
+    UntilBeforeSince {
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    },
+
+    #[error("the project key list is empty")]
+    NoProjectKeys,
+
+    #[error("the sync window must be longer than zero")]
+    NonPositiveWindow,
+
+    #[error("the persist batch size must be at least 1")]
+    ZeroPersistBatchSize,
+
+    #[error("the issue limit must be at least 1")]
+    ZeroMaxIssues,
+}
@@ -0,0 +1,44 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+use super::JiraProjectFieldError;
+use super::jira_project_field_error::join_field_errors;
+use crate::error::ApplicationError;
+
+/// An input of a batch that failed validation, with every invalid field.
+#[derive(Debug, Error)]
+#[error("input {index}: {}", join_field_errors(.errors))]
+pub struct JiraProjectInputRejection {
+    /// Position of the input in the batch, starting at 0.
+    pub index: usize,
+    /// Every invalid field, in id, key, name order.
+    pub errors: Vec<JiraProjectFieldError>,
+}
+
+/// Represents errors that can occur when creating a batch of Jira projects.
+#[derive(Debug, Error)]
+pub enum JiraProjectBatchCreateError {
+    /// Every rejected input, in batch order; only returned in all-or-nothing mode.
+    #[error("Validation error: {}", join_rejections(.0))]
+    ValidationFailed(Vec<JiraProjectInputRejection>),
+
+    #[error("Failed to create projects: {0}")]
+    CreationFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraProjectBatchCreateError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ValidationFailed(_) => "JIRA_PROJECT_VALIDATION_FAILED",
+            Self::CreationFailed(_) => "JIRA_PROJECT_CREATION_FAILED",
+        }
+    }
+}
+
+fn join_rejections(rejections: &[JiraProjectInputRejection]) -> String {
+    rejections
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
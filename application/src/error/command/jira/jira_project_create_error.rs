@@ -1,16 +1,39 @@
 use domain::error::JiraError;
+use domain::value_object::jira::JiraProjectKey;
 use thiserror::Error;
 
+use super::JiraProjectFieldError;
+use super::jira_project_field_error::join_field_errors;
 use crate::error::ApplicationError;
 
 /// Represents errors that can occur when creating a Jira project.
 #[derive(Debug, Error)]
 pub enum JiraProjectCreateError {
-    #[error("Validation error: {0}")]
-    ValidationFailed(#[source] JiraError),
+    /// Every invalid input field, in id, key, name order.
+    #[error("Validation error: {}", join_field_errors(.0))]
+    ValidationFailed(Vec<JiraProjectFieldError>),
 
     #[error("Failed to create project: {0}")]
     CreationFailed(#[source] JiraError),
+
+    #[error("Project not found in Jira: {0}")]
+    NotFoundInJira(JiraProjectKey),
+
+    #[error("Failed to fetch project: {0}")]
+    ProjectFetchFailed(#[source] JiraError),
+
+    #[error("Jira API is not configured")]
+    JiraNotConfigured,
 }
 
-impl ApplicationError for JiraProjectCreateError {}
+impl ApplicationError for JiraProjectCreateError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ValidationFailed(_) => "JIRA_PROJECT_VALIDATION_FAILED",
+            Self::CreationFailed(_) => "JIRA_PROJECT_CREATION_FAILED",
+            Self::NotFoundInJira(_) => "JIRA_PROJECT_NOT_FOUND",
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::JiraNotConfigured => "JIRA_API_NOT_CONFIGURED",
+        }
+    }
+}
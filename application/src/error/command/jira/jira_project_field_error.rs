@@ -0,0 +1,50 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+/// An input field of a Jira project command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JiraProjectField {
+    Id,
+    Key,
+    Name,
+}
+
+impl JiraProjectField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Key => "key",
+            Self::Name => "name",
+        }
+    }
+}
+
+impl std::fmt::Display for JiraProjectField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A project input field that failed validation, with the reason.
+#[derive(Debug, Error)]
+#[error("{field}: {source}")]
+pub struct JiraProjectFieldError {
+    pub field: JiraProjectField,
+    #[source]
+    pub source: JiraError,
+}
+
+impl JiraProjectFieldError {
+    pub fn new(field: JiraProjectField, source: JiraError) -> Self {
+        Self { field, source }
+    }
+}
+
+/// Joins field errors into one message, separated by semicolons.
+pub(super) fn join_field_errors(errors: &[JiraProjectFieldError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,42 @@
+use domain::error::JiraError;
+use domain::value_object::jira::JiraProjectKey;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when syncing a single Jira project.
+#[derive(Debug, Error)]
+pub enum JiraProjectSingleSyncError {
+    #[error("Validation error: {0}")]
+    ValidationFailed(#[source] JiraError),
+
+    #[error("Project not found in Jira: {0}")]
+    NotFound(JiraProjectKey),
+
+    #[error("Failed to fetch project: {0}")]
+    ProjectFetchFailed(#[source] JiraError),
+
+    #[error("Failed to persist project: {0}")]
+    ProjectPersistFailed(#[source] JiraError),
+}
+
+impl JiraProjectSingleSyncError {
+    /// Returns true if syncing the same project again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ValidationFailed(_) | Self::NotFound(_) => false,
+            Self::ProjectFetchFailed(e) | Self::ProjectPersistFailed(e) => e.is_retryable(),
+        }
+    }
+}
+
+impl ApplicationError for JiraProjectSingleSyncError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ValidationFailed(_) => "JIRA_PROJECT_VALIDATION_FAILED",
+            Self::NotFound(_) => "JIRA_PROJECT_NOT_FOUND",
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::ProjectPersistFailed(_) => "JIRA_PROJECT_PERSIST_FAILED",
+        }
+    }
+}
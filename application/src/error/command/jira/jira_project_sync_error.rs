@@ -11,6 +11,25 @@ pub enum JiraProjectSyncError {
 
     #[error("Failed to persist projects: {0}")]
     ProjectPersistFailed(#[source] JiraError),
+
+    #[error("Jira API returned no projects; check JIRA_BASE_URL and credentials")]
+    NoProjects,
+
+    #[error("Another project sync is already running")]
+    AlreadyRunning,
+
+    #[error("Failed to acquire the project sync lock: {0}")]
+    LockFailed(#[source] JiraError),
 }
 
-impl ApplicationError for JiraProjectSyncError {}
+impl ApplicationError for JiraProjectSyncError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::ProjectPersistFailed(_) => "JIRA_PROJECT_PERSIST_FAILED",
+            Self::NoProjects => "JIRA_PROJECT_SYNC_NO_PROJECTS",
+            Self::AlreadyRunning => "JIRA_PROJECT_SYNC_ALREADY_RUNNING",
+            Self::LockFailed(_) => "JIRA_PROJECT_SYNC_LOCK_FAILED",
+        }
+    }
+}
@@ -18,6 +18,19 @@ pub enum JiraProjectUpdateError {
 
     #[error("Failed to update project: {0}")]
     UpdateFailed(#[source] JiraError),
+
+    #[error("Project was modified concurrently: {0}")]
+    Conflict(#[source] JiraError),
 }
 
-impl ApplicationError for JiraProjectUpdateError {}
+impl ApplicationError for JiraProjectUpdateError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ValidationFailed(_) => "JIRA_PROJECT_VALIDATION_FAILED",
+            Self::NotFound(_) => "JIRA_PROJECT_NOT_FOUND",
+            Self::FindFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::UpdateFailed(_) => "JIRA_PROJECT_UPDATE_FAILED",
+            Self::Conflict(_) => "JIRA_PROJECT_CONFLICT",
+        }
+    }
+}
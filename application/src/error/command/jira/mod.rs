@@ -1,9 +1,19 @@
+mod jira_issue_changelog_sync_error;
 mod jira_issue_sync_error;
+mod jira_issue_sync_params_error;
+mod jira_project_batch_create_error;
 mod jira_project_create_error;
+mod jira_project_field_error;
+mod jira_project_single_sync_error;
 mod jira_project_sync_error;
 mod jira_project_update_error;
 
+pub use jira_issue_changelog_sync_error::JiraIssueChangelogSyncError;
 pub use jira_issue_sync_error::JiraIssueSyncError;
+pub use jira_issue_sync_params_error::JiraIssueSyncParamsError;
+pub use jira_project_batch_create_error::{JiraProjectBatchCreateError, JiraProjectInputRejection};
 pub use jira_project_create_error::JiraProjectCreateError;
+pub use jira_project_field_error::{JiraProjectField, JiraProjectFieldError};
+pub use jira_project_single_sync_error::JiraProjectSingleSyncError;
 pub use jira_project_sync_error::JiraProjectSyncError;
 pub use jira_project_update_error::JiraProjectUpdateError;
@@ -7,4 +7,254 @@ pub use transaction_error::TransactionError;
 use std::error::Error;
 
 /// Base trait for all application-level errors.
-pub trait ApplicationError: Error + Send + Sync + 'static {}
+pub trait ApplicationError: Error + Send + Sync + 'static {
+    /// Machine-readable code of the failure, such as `JIRA_PROJECT_NOT_FOUND`, for logs
+    /// and API error bodies.
+    ///
+    /// Codes are part of the API: variants meaning the same thing share a code across
+    /// use cases, and a published code is never changed.
+    fn code(&self) -> &'static str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::command::jira::*;
+    use super::query::jira::*;
+    use super::*;
+    use domain::error::{JiraError, PageNumberError, PageSizeError};
+    use domain::value_object::jira::{JiraIssueKey, JiraProjectId, JiraProjectKey};
+    use std::collections::HashSet;
+
+    fn jira() -> JiraError {
+        JiraError::api_error("failed")
+    }
+
+    fn page_number() -> PageNumberError {
+        PageNumberError::BelowMinimum { value: 0 }
+    }
+
+    fn page_size() -> PageSizeError {
+        PageSizeError::BelowMinimum { value: 0 }
+    }
+
+    /// Every variant of every application error, grouped by error type.
+    fn all_errors() -> Vec<Vec<Box<dyn ApplicationError>>> {
+        vec![
+            vec![Box::new(TransactionError::execution_failed("failed"))],
+            vec![
+                Box::new(JiraIssueChangelogSyncError::ChangelogFetchFailed(
+                    JiraIssueKey::new("PROJ-1"),
+                    jira(),
+                )),
+                Box::new(JiraIssueChangelogSyncError::ChangelogPersistFailed(jira())),
+                Box::new(JiraIssueChangelogSyncError::AlreadyRunning),
+                Box::new(JiraIssueChangelogSyncError::LockFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraIssueSyncError::InvalidParams(
+                    JiraIssueSyncParamsError::NoProjectKeys,
+                )),
+                Box::new(JiraIssueSyncError::ProjectKeyFetchFailed(jira())),
+                Box::new(JiraIssueSyncError::IssueFetchFailed(jira())),
+                Box::new(JiraIssueSyncError::IssuePersistFailed(jira())),
+                Box::new(JiraIssueSyncError::LinkPersistFailed(jira())),
+                Box::new(JiraIssueSyncError::AlreadyRunning),
+                Box::new(JiraIssueSyncError::LockFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraProjectBatchCreateError::ValidationFailed(vec![])),
+                Box::new(JiraProjectBatchCreateError::CreationFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraProjectCreateError::ValidationFailed(vec![])),
+                Box::new(JiraProjectCreateError::CreationFailed(jira())),
+                Box::new(JiraProjectCreateError::NotFoundInJira(JiraProjectKey::new(
+                    "PROJ",
+                ))),
+                Box::new(JiraProjectCreateError::ProjectFetchFailed(jira())),
+                Box::new(JiraProjectCreateError::JiraNotConfigured),
+            ],
+            vec![
+                Box::new(JiraProjectSingleSyncError::ValidationFailed(jira())),
+                Box::new(JiraProjectSingleSyncError::NotFound(JiraProjectKey::new(
+                    "PROJ",
+                ))),
+                Box::new(JiraProjectSingleSyncError::ProjectFetchFailed(jira())),
+                Box::new(JiraProjectSingleSyncError::ProjectPersistFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraProjectSyncError::ProjectFetchFailed(jira())),
+                Box::new(JiraProjectSyncError::ProjectPersistFailed(jira())),
+                Box::new(JiraProjectSyncError::NoProjects),
+                Box::new(JiraProjectSyncError::AlreadyRunning),
+                Box::new(JiraProjectSyncError::LockFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraProjectUpdateError::ValidationFailed(jira())),
+                Box::new(JiraProjectUpdateError::NotFound(JiraProjectId::new(1))),
+                Box::new(JiraProjectUpdateError::FindFailed(jira())),
+                Box::new(JiraProjectUpdateError::UpdateFailed(jira())),
+                Box::new(JiraProjectUpdateError::Conflict(jira())),
+            ],
+            vec![
+                Box::new(JiraAuthCheckQueryError::InvalidCredentials(jira())),
+                Box::new(JiraAuthCheckQueryError::CheckFailed(jira())),
+            ],
+            vec![
+                Box::new(JiraIssueExportQueryError::InvalidBatchSize(page_size())),
+                Box::new(JiraIssueExportQueryError::IssueFetchFailed(jira())),
+            ],
+            vec![Box::new(JiraIssueFindByIdQueryError::IssueFetchFailed(
+                jira(),
+            ))],
+            vec![Box::new(
+                JiraIssueLinkFindByKeysQueryError::LinkFetchFailed(jira()),
+            )],
+            vec![
+                Box::new(JiraIssueListQueryError::InvalidPageNumber(page_number())),
+                Box::new(JiraIssueListQueryError::InvalidPageSize(page_size())),
+                Box::new(JiraIssueListQueryError::IssueFetchFailed(jira())),
+                Box::new(JiraIssueListQueryError::RepositoryTimeout(jira())),
+            ],
+            vec![Box::new(JiraIssueResolveIdsQueryError::IssueFetchFailed(
+                jira(),
+            ))],
+            vec![
+                Box::new(JiraProjectDumpQueryError::InvalidProjectKey(jira())),
+                Box::new(JiraProjectDumpQueryError::ProjectNotFound(
+                    "PROJ".to_string(),
+                )),
+                Box::new(JiraProjectDumpQueryError::ProjectFetchFailed(jira())),
+                Box::new(JiraProjectDumpQueryError::IssueFetchFailed(jira())),
+            ],
+            vec![Box::new(JiraProjectFindByIdQueryError::ProjectFetchFailed(
+                jira(),
+            ))],
+            vec![
+                Box::new(JiraProjectListQueryError::InvalidPageNumber(page_number())),
+                Box::new(JiraProjectListQueryError::InvalidPageSize(page_size())),
+                Box::new(JiraProjectListQueryError::ProjectFetchFailed(jira())),
+                Box::new(JiraProjectListQueryError::RepositoryTimeout(jira())),
+            ],
+            vec![
+                Box::new(JiraProjectSearchQueryError::EmptyQuery),
+                Box::new(JiraProjectSearchQueryError::InvalidPageNumber(page_number())),
+                Box::new(JiraProjectSearchQueryError::InvalidPageSize(page_size())),
+                Box::new(JiraProjectSearchQueryError::ProjectFetchFailed(jira())),
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_codes_are_stable() {
+        let codes: Vec<Vec<&str>> = all_errors()
+            .iter()
+            .map(|errors| errors.iter().map(|e| e.code()).collect())
+            .collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                vec!["TRANSACTION_FAILED"],
+                vec![
+                    "JIRA_ISSUE_CHANGELOG_FETCH_FAILED",
+                    "JIRA_ISSUE_CHANGELOG_PERSIST_FAILED",
+                    "JIRA_ISSUE_CHANGELOG_SYNC_ALREADY_RUNNING",
+                    "JIRA_ISSUE_CHANGELOG_SYNC_LOCK_FAILED",
+                ],
+                vec![
+                    "JIRA_ISSUE_SYNC_INVALID_PARAMS",
+                    "JIRA_PROJECT_KEY_FETCH_FAILED",
+                    "JIRA_ISSUE_FETCH_FAILED",
+                    "JIRA_ISSUE_PERSIST_FAILED",
+                    "JIRA_ISSUE_LINK_PERSIST_FAILED",
+                    "JIRA_ISSUE_SYNC_ALREADY_RUNNING",
+                    "JIRA_ISSUE_SYNC_LOCK_FAILED",
+                ],
+                vec![
+                    "JIRA_PROJECT_VALIDATION_FAILED",
+                    "JIRA_PROJECT_CREATION_FAILED"
+                ],
+                vec![
+                    "JIRA_PROJECT_VALIDATION_FAILED",
+                    "JIRA_PROJECT_CREATION_FAILED",
+                    "JIRA_PROJECT_NOT_FOUND",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "JIRA_API_NOT_CONFIGURED",
+                ],
+                vec![
+                    "JIRA_PROJECT_VALIDATION_FAILED",
+                    "JIRA_PROJECT_NOT_FOUND",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "JIRA_PROJECT_PERSIST_FAILED",
+                ],
+                vec![
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "JIRA_PROJECT_PERSIST_FAILED",
+                    "JIRA_PROJECT_SYNC_NO_PROJECTS",
+                    "JIRA_PROJECT_SYNC_ALREADY_RUNNING",
+                    "JIRA_PROJECT_SYNC_LOCK_FAILED",
+                ],
+                vec![
+                    "JIRA_PROJECT_VALIDATION_FAILED",
+                    "JIRA_PROJECT_NOT_FOUND",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "JIRA_PROJECT_UPDATE_FAILED",
+                    "JIRA_PROJECT_CONFLICT",
+                ],
+                vec!["JIRA_AUTH_INVALID_CREDENTIALS", "JIRA_AUTH_CHECK_FAILED"],
+                vec!["INVALID_BATCH_SIZE", "JIRA_ISSUE_FETCH_FAILED"],
+                vec!["JIRA_ISSUE_FETCH_FAILED"],
+                vec!["JIRA_ISSUE_LINK_FETCH_FAILED"],
+                vec![
+                    "INVALID_PAGE_NUMBER",
+                    "INVALID_PAGE_SIZE",
+                    "JIRA_ISSUE_FETCH_FAILED",
+                    "REPOSITORY_TIMEOUT",
+                ],
+                vec!["JIRA_ISSUE_FETCH_FAILED"],
+                vec![
+                    "JIRA_PROJECT_INVALID_KEY",
+                    "JIRA_PROJECT_NOT_FOUND",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "JIRA_ISSUE_FETCH_FAILED",
+                ],
+                vec!["JIRA_PROJECT_FETCH_FAILED"],
+                vec![
+                    "INVALID_PAGE_NUMBER",
+                    "INVALID_PAGE_SIZE",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                    "REPOSITORY_TIMEOUT",
+                ],
+                vec![
+                    "EMPTY_SEARCH_QUERY",
+                    "INVALID_PAGE_NUMBER",
+                    "INVALID_PAGE_SIZE",
+                    "JIRA_PROJECT_FETCH_FAILED",
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_codes_are_distinct_within_each_error() {
+        for errors in all_errors() {
+            let codes: HashSet<&str> = errors.iter().map(|e| e.code()).collect();
+
+            assert_eq!(codes.len(), errors.len(), "{:?}", codes);
+        }
+    }
+
+    #[test]
+    fn test_codes_are_screaming_snake_case() {
+        for error in all_errors().iter().flatten() {
+            let code = error.code();
+
+            assert!(
+                code.chars().all(|c| c.is_ascii_uppercase() || c == '_'),
+                "{}",
+                code
+            );
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when checking Jira credentials.
+#[derive(Debug, Error)]
+pub enum JiraAuthCheckQueryError {
+    #[error("Jira rejected the credentials: {0}")]
+    InvalidCredentials(#[source] JiraError),
+
+    #[error("Failed to check Jira credentials: {0}")]
+    CheckFailed(#[source] JiraError),
+}
+
+impl From<JiraError> for JiraAuthCheckQueryError {
+    /// Maps an adapter error, keeping rejected credentials distinct from other failures.
+    fn from(error: JiraError) -> Self {
+        if error.is_unauthorized() {
+            Self::InvalidCredentials(error)
+        } else {
+            Self::CheckFailed(error)
+        }
+    }
+}
+
+impl ApplicationError for JiraAuthCheckQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidCredentials(_) => "JIRA_AUTH_INVALID_CREDENTIALS",
+            Self::CheckFailed(_) => "JIRA_AUTH_CHECK_FAILED",
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use domain::error::{JiraError, PageSizeError};
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when exporting Jira issues.
+#[derive(Debug, Error)]
+pub enum JiraIssueExportQueryError {
+    #[error("Invalid batch size: {0}")]
+    InvalidBatchSize(#[source] PageSizeError),
+
+    #[error("Failed to fetch issues: {0}")]
+    IssueFetchFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraIssueExportQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidBatchSize(_) => "INVALID_BATCH_SIZE",
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+        }
+    }
+}
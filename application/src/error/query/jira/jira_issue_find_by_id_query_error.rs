@@ -10,4 +10,10 @@ pub enum JiraIssueFindByIdQueryError {
     IssueFetchFailed(#[source] JiraError),
 }
 
-impl ApplicationError for JiraIssueFindByIdQueryError {}
+impl ApplicationError for JiraIssueFindByIdQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when finding the links of Jira issues.
+#[derive(Debug, Error)]
+pub enum JiraIssueLinkFindByKeysQueryError {
+    #[error("Failed to fetch issue links: {0}")]
+    LinkFetchFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraIssueLinkFindByKeysQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::LinkFetchFailed(_) => "JIRA_ISSUE_LINK_FETCH_FAILED",
+        }
+    }
+}
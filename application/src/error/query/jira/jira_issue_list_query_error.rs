@@ -14,6 +14,29 @@ pub enum JiraIssueListQueryError {
 
     #[error("Failed to fetch issues: {0}")]
     IssueFetchFailed(#[source] JiraError),
+
+    #[error("Timed out fetching issues: {0}")]
+    RepositoryTimeout(#[source] JiraError),
+}
+
+impl From<JiraError> for JiraIssueListQueryError {
+    /// Maps a repository error, keeping timeouts distinct so clients can retry them.
+    fn from(error: JiraError) -> Self {
+        if error.is_timeout() {
+            Self::RepositoryTimeout(error)
+        } else {
+            Self::IssueFetchFailed(error)
+        }
+    }
 }
 
-impl ApplicationError for JiraIssueListQueryError {}
+impl ApplicationError for JiraIssueListQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidPageNumber(_) => "INVALID_PAGE_NUMBER",
+            Self::InvalidPageSize(_) => "INVALID_PAGE_SIZE",
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+            Self::RepositoryTimeout(_) => "REPOSITORY_TIMEOUT",
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when resolving Jira issue keys to IDs.
+#[derive(Debug, Error)]
+pub enum JiraIssueResolveIdsQueryError {
+    #[error("Failed to resolve issue IDs: {0}")]
+    IssueFetchFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraIssueResolveIdsQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+        }
+    }
+}
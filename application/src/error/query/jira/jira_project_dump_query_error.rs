@@ -0,0 +1,31 @@
+use domain::error::JiraError;
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when dumping a Jira project.
+#[derive(Debug, Error)]
+pub enum JiraProjectDumpQueryError {
+    #[error("Invalid project key: {0}")]
+    InvalidProjectKey(#[source] JiraError),
+
+    #[error("Project not found: {0}")]
+    ProjectNotFound(String),
+
+    #[error("Failed to fetch project: {0}")]
+    ProjectFetchFailed(#[source] JiraError),
+
+    #[error("Failed to fetch issues: {0}")]
+    IssueFetchFailed(#[source] JiraError),
+}
+
+impl ApplicationError for JiraProjectDumpQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidProjectKey(_) => "JIRA_PROJECT_INVALID_KEY",
+            Self::ProjectNotFound(_) => "JIRA_PROJECT_NOT_FOUND",
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::IssueFetchFailed(_) => "JIRA_ISSUE_FETCH_FAILED",
+        }
+    }
+}
@@ -10,4 +10,10 @@ pub enum JiraProjectFindByIdQueryError {
     ProjectFetchFailed(#[source] JiraError),
 }
 
-impl ApplicationError for JiraProjectFindByIdQueryError {}
+impl ApplicationError for JiraProjectFindByIdQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+        }
+    }
+}
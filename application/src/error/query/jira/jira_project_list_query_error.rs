@@ -14,6 +14,29 @@ pub enum JiraProjectListQueryError {
 
     #[error("Failed to fetch projects: {0}")]
     ProjectFetchFailed(#[source] JiraError),
+
+    #[error("Timed out fetching projects: {0}")]
+    RepositoryTimeout(#[source] JiraError),
+}
+
+impl From<JiraError> for JiraProjectListQueryError {
+    /// Maps a repository error, keeping timeouts distinct so clients can retry them.
+    fn from(error: JiraError) -> Self {
+        if error.is_timeout() {
+            Self::RepositoryTimeout(error)
+        } else {
+            Self::ProjectFetchFailed(error)
+        }
+    }
 }
 
-impl ApplicationError for JiraProjectListQueryError {}
+impl ApplicationError for JiraProjectListQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidPageNumber(_) => "INVALID_PAGE_NUMBER",
+            Self::InvalidPageSize(_) => "INVALID_PAGE_SIZE",
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::RepositoryTimeout(_) => "REPOSITORY_TIMEOUT",
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use domain::error::{JiraError, PageNumberError, PageSizeError};
+use thiserror::Error;
+
+use crate::error::ApplicationError;
+
+/// Represents errors that can occur when searching Jira projects.
+#[derive(Debug, Error)]
+pub enum JiraProjectSearchQueryError {
+    #[error("Search query must not be empty")]
+    EmptyQuery,
+
+    #[error("Invalid page number: {0}")]
+    InvalidPageNumber(#[source] PageNumberError),
+
+    #[error("Invalid page size: {0}")]
+    InvalidPageSize(#[source] PageSizeError),
+
+    #[error("Failed to search projects: {0}")]
+    ProjectFetchFailed(#[source] JiraError),
+
+    #[error("Timed out searching projects: {0}")]
+    RepositoryTimeout(#[source] JiraError),
+}
+
+impl From<JiraError> for JiraProjectSearchQueryError {
+    /// Maps a repository error, keeping timeouts distinct so clients can retry them.
+    fn from(error: JiraError) -> Self {
+        if error.is_timeout() {
+            Self::RepositoryTimeout(error)
+        } else {
+            Self::ProjectFetchFailed(error)
+        }
+    }
+}
+
+impl ApplicationError for JiraProjectSearchQueryError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::EmptyQuery => "EMPTY_SEARCH_QUERY",
+            Self::InvalidPageNumber(_) => "INVALID_PAGE_NUMBER",
+            Self::InvalidPageSize(_) => "INVALID_PAGE_SIZE",
+            Self::ProjectFetchFailed(_) => "JIRA_PROJECT_FETCH_FAILED",
+            Self::RepositoryTimeout(_) => "REPOSITORY_TIMEOUT",
+        }
+    }
+}
@@ -1,9 +1,21 @@
+mod jira_auth_check_query_error;
+mod jira_issue_export_query_error;
 mod jira_issue_find_by_id_query_error;
+mod jira_issue_link_find_by_keys_query_error;
 mod jira_issue_list_query_error;
+mod jira_issue_resolve_ids_query_error;
+mod jira_project_dump_query_error;
 mod jira_project_find_by_id_query_error;
 mod jira_project_list_query_error;
+mod jira_project_search_query_error;
 
+pub use jira_auth_check_query_error::JiraAuthCheckQueryError;
+pub use jira_issue_export_query_error::JiraIssueExportQueryError;
 pub use jira_issue_find_by_id_query_error::JiraIssueFindByIdQueryError;
+pub use jira_issue_link_find_by_keys_query_error::JiraIssueLinkFindByKeysQueryError;
 pub use jira_issue_list_query_error::JiraIssueListQueryError;
+pub use jira_issue_resolve_ids_query_error::JiraIssueResolveIdsQueryError;
+pub use jira_project_dump_query_error::JiraProjectDumpQueryError;
 pub use jira_project_find_by_id_query_error::JiraProjectFindByIdQueryError;
 pub use jira_project_list_query_error::JiraProjectListQueryError;
+pub use jira_project_search_query_error::JiraProjectSearchQueryError;
@@ -32,4 +32,10 @@ impl TransactionError {
     }
 }
 
-impl ApplicationError for TransactionError {}
+impl ApplicationError for TransactionError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ExecutionFailed { .. } => "TRANSACTION_FAILED",
+        }
+    }
+}
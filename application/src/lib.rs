@@ -1,4 +1,6 @@
 pub mod dto;
 pub mod error;
 pub mod repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod usecase;
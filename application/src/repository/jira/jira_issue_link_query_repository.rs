@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use domain::error::JiraError;
+use domain::value_object::jira::JiraIssueKey;
+
+use crate::dto::query::jira::JiraIssueLinkQueryDto;
+
+/// Repository interface for Jira issue link queries.
+#[async_trait]
+pub trait JiraIssueLinkQueryRepository: Send + Sync {
+    /// Finds the links whose source or target is one of the given issue keys.
+    async fn find_by_issue_keys(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraError>;
+}
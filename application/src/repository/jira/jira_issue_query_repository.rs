@@ -1,23 +1,137 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use domain::error::JiraError;
-use domain::value_object::jira::JiraIssueId;
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey, JiraProjectId};
 use domain::value_object::{Page, PageNumber, PageSize};
 
-use crate::dto::query::jira::JiraIssueQueryDto;
+use crate::dto::query::jira::{JiraIssueListFilter, JiraIssueListSummary, JiraIssueQueryDto};
 
 /// Repository interface for Jira issue queries.
 /// Returns DTOs optimized for read operations.
 #[async_trait]
 pub trait JiraIssueQueryRepository: Send + Sync {
     /// Finds issues by their IDs.
+    ///
+    /// Results are in ascending id order, one per distinct id, whatever order `ids` is in;
+    /// ids with no matching issue are omitted. Use [`reorder_to_input`] to get them in the
+    /// requested order instead.
     async fn find_by_ids(&self, ids: Vec<JiraIssueId>)
     -> Result<Vec<JiraIssueQueryDto>, JiraError>;
 
-    /// Lists issues with pagination.
+    /// Resolves issue keys to their IDs. Keys with no matching issue are absent from the map.
+    async fn find_ids_by_keys(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraError>;
+
+    /// Lists issues matching the filter with pagination.
     async fn list(
         &self,
         page_number: PageNumber,
         page_size: PageSize,
+        filter: &JiraIssueListFilter,
     ) -> Result<Page<JiraIssueQueryDto>, JiraError>;
+
+    /// Counts the issues matching the filter by type and by priority, across every page
+    /// `list` would return for it. The sort of the filter does not apply.
+    async fn summarize(
+        &self,
+        filter: &JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraError>;
+
+    /// Lists issue IDs with pagination, ordered by id.
+    /// A lighter alternative to `list` for callers that only need to scan or count issues.
+    async fn list_ids_only(
+        &self,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraIssueId>, JiraError>;
+
+    /// Lists every issue of a project, ordered by id.
+    async fn list_by_project(
+        &self,
+        project_id: JiraProjectId,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError>;
+
+    /// Lists the most recently updated issues of each project, at most
+    /// `per_project_limit` per project, newest first.
+    /// Projects without issues are absent from the map.
+    async fn list_grouped_by_project(
+        &self,
+        project_ids: Vec<JiraProjectId>,
+        per_project_limit: PageSize,
+    ) -> Result<HashMap<JiraProjectId, Vec<JiraIssueQueryDto>>, JiraError>;
+
+    /// Lists up to `limit` issues with an id greater than `cursor`, ordered by id.
+    /// Keyset pagination keeps iteration stable under concurrent writes.
+    async fn list_after(
+        &self,
+        cursor: Option<JiraIssueId>,
+        limit: PageSize,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError>;
+}
+
+/// Reorders `find_by_ids` results to follow `input_ids`.
+///
+/// Ids without a result are skipped, and an id requested more than once is placed at
+/// its first occurrence only. Results whose id was not requested are dropped.
+pub fn reorder_to_input(
+    results: Vec<JiraIssueQueryDto>,
+    input_ids: &[JiraIssueId],
+) -> Vec<JiraIssueQueryDto> {
+    let mut by_id: HashMap<i64, JiraIssueQueryDto> =
+        results.into_iter().map(|dto| (dto.id, dto)).collect();
+    input_ids
+        .iter()
+        .filter_map(|id| by_id.remove(&id.value()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(ids: &[i64]) -> Vec<JiraIssueQueryDto> {
+        ids.iter()
+            .map(|&id| JiraIssueQueryDto::builder().id(id).build())
+            .collect()
+    }
+
+    fn request(ids: &[i64]) -> Vec<JiraIssueId> {
+        ids.iter().map(|&id| JiraIssueId::new(id)).collect()
+    }
+
+    fn ids_of(results: &[JiraIssueQueryDto]) -> Vec<i64> {
+        results.iter().map(|dto| dto.id).collect()
+    }
+
+    #[test]
+    fn test_reorder_to_input_follows_requested_order() {
+        let reordered = reorder_to_input(issues(&[1, 2, 3]), &request(&[3, 1, 2]));
+
+        assert_eq!(ids_of(&reordered), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_reorder_to_input_skips_missing_ids() {
+        let reordered = reorder_to_input(issues(&[2, 5]), &request(&[5, 4, 2, 9]));
+
+        assert_eq!(ids_of(&reordered), vec![5, 2]);
+    }
+
+    #[test]
+    fn test_reorder_to_input_places_duplicates_at_first_occurrence() {
+        let reordered = reorder_to_input(issues(&[1, 3]), &request(&[3, 1, 3, 1]));
+
+        assert_eq!(ids_of(&reordered), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_reorder_to_input_drops_unrequested_results() {
+        let reordered = reorder_to_input(issues(&[1, 2, 3]), &request(&[2]));
+
+        assert_eq!(ids_of(&reordered), vec![2]);
+    }
 }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 use domain::error::JiraError;
-use domain::value_object::jira::JiraProjectId;
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey};
 use domain::value_object::{Page, PageNumber, PageSize};
 
 use crate::dto::query::jira::JiraProjectQueryDto;
@@ -16,10 +16,24 @@ pub trait JiraProjectQueryRepository: Send + Sync {
         ids: Vec<JiraProjectId>,
     ) -> Result<Vec<JiraProjectQueryDto>, JiraError>;
 
+    /// Finds a project by its key.
+    async fn find_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProjectQueryDto>, JiraError>;
+
     /// Lists projects with pagination.
     async fn list(
         &self,
         page_number: PageNumber,
         page_size: PageSize,
     ) -> Result<Page<JiraProjectQueryDto>, JiraError>;
+
+    /// Searches projects whose name or key contains `query` (case-insensitive), with pagination.
+    async fn search(
+        &self,
+        query: &str,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraError>;
 }
@@ -1,5 +1,7 @@
+mod jira_issue_link_query_repository;
 mod jira_issue_query_repository;
 mod jira_project_query_repository;
 
+pub use jira_issue_link_query_repository::*;
 pub use jira_issue_query_repository::*;
 pub use jira_project_query_repository::*;
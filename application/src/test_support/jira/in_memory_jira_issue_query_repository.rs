@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use domain::error::JiraError;
+use domain::test_support::ProgrammedFailures;
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey, JiraProjectId};
+use domain::value_object::{Page, PageNumber, PageSize};
+
+use crate::dto::query::jira::{
+    JiraIssueListFilter, JiraIssueListSummary, JiraIssuePriorityCount, JiraIssueQueryDto,
+    JiraIssueTypeCount,
+};
+use crate::repository::jira::JiraIssueQueryRepository;
+
+/// A method of [`JiraIssueQueryRepository`], used to record calls and program failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraIssueQueryRepositoryMethod {
+    FindByIds,
+    FindIdsByKeys,
+    List,
+    Summarize,
+    ListIdsOnly,
+    ListByProject,
+    ListGroupedByProject,
+    ListAfter,
+}
+
+/// In-memory [`JiraIssueQueryRepository`] over a fixed set of issues, kept in id order.
+///
+/// The list filters are recorded but not applied: `list` and `summarize` cover every
+/// stored issue, and `list` pages through them in id order whatever the sort.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraIssueQueryRepository {
+    issues: Vec<(JiraProjectId, JiraIssueQueryDto)>,
+    calls: Mutex<Vec<JiraIssueQueryRepositoryMethod>>,
+    filters: Mutex<Vec<JiraIssueListFilter>>,
+    failures: ProgrammedFailures<JiraIssueQueryRepositoryMethod>,
+}
+
+impl InMemoryJiraIssueQueryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `issues` as issues of the project `project_id`.
+    pub fn with_project_issues(
+        mut self,
+        project_id: JiraProjectId,
+        issues: Vec<JiraIssueQueryDto>,
+    ) -> Self {
+        self.issues
+            .extend(issues.into_iter().map(|issue| (project_id, issue)));
+        self.issues.sort_by_key(|(_, issue)| issue.id);
+        self
+    }
+
+    /// Makes the next call of `method` return `error`.
+    pub fn fail_next(self, method: JiraIssueQueryRepositoryMethod, error: JiraError) -> Self {
+        self.failures.push(method, error);
+        self
+    }
+
+    /// Returns the methods called so far, in call order.
+    pub fn calls(&self) -> Vec<JiraIssueQueryRepositoryMethod> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Returns the filters `list` and `summarize` were called with, in call order.
+    pub fn filters(&self) -> Vec<JiraIssueListFilter> {
+        self.filters.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: JiraIssueQueryRepositoryMethod) -> Result<(), JiraError> {
+        self.calls.lock().unwrap().push(method);
+        match self.failures.take(method) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn dtos(&self) -> impl Iterator<Item = &JiraIssueQueryDto> {
+        self.issues.iter().map(|(_, issue)| issue)
+    }
+}
+
+/// Returns the page of `items` that `page_number` and `page_size` select.
+fn paginate<T: Clone>(items: &[T], page_number: PageNumber, page_size: PageSize) -> Page<T> {
+    let offset = page_number.offset(page_size) as usize;
+    let page_items = items
+        .iter()
+        .skip(offset)
+        .take(page_size.value() as usize)
+        .cloned()
+        .collect();
+    Page::new(items.len() as i32, page_items).with_pagination(page_number, page_size)
+}
+
+/// Counts `values` most frequent first, ties in order of first occurrence.
+fn count_by<T: PartialEq>(values: impl Iterator<Item = T>) -> Vec<(T, i32)> {
+    let mut counts: Vec<(T, i32)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(counted, _)| *counted == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    counts.sort_by_key(|(_, count)| -count);
+    counts
+}
+
+#[async_trait]
+impl JiraIssueQueryRepository for InMemoryJiraIssueQueryRepository {
+    async fn find_by_ids(
+        &self,
+        ids: Vec<JiraIssueId>,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::FindByIds)?;
+        Ok(self
+            .dtos()
+            .filter(|issue| ids.contains(&JiraIssueId::new(issue.id)))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_ids_by_keys(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::FindIdsByKeys)?;
+        Ok(self
+            .dtos()
+            .map(|issue| (JiraIssueKey::new(issue.key.clone()), issue.id))
+            .filter(|(key, _)| keys.contains(key))
+            .map(|(key, id)| (key, JiraIssueId::new(id)))
+            .collect())
+    }
+
+    async fn list(
+        &self,
+        page_number: PageNumber,
+        page_size: PageSize,
+        filter: &JiraIssueListFilter,
+    ) -> Result<Page<JiraIssueQueryDto>, JiraError> {
+        self.filters.lock().unwrap().push(filter.clone());
+        self.record(JiraIssueQueryRepositoryMethod::List)?;
+        let issues: Vec<JiraIssueQueryDto> = self.dtos().cloned().collect();
+        Ok(paginate(&issues, page_number, page_size))
+    }
+
+    async fn summarize(
+        &self,
+        filter: &JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraError> {
+        self.filters.lock().unwrap().push(filter.clone());
+        self.record(JiraIssueQueryRepositoryMethod::Summarize)?;
+        Ok(JiraIssueListSummary {
+            by_type: count_by(self.dtos().map(|issue| issue.issue_type))
+                .into_iter()
+                .map(|(issue_type, count)| JiraIssueTypeCount { issue_type, count })
+                .collect(),
+            by_priority: count_by(self.dtos().map(|issue| issue.priority))
+                .into_iter()
+                .map(|(priority, count)| JiraIssuePriorityCount { priority, count })
+                .collect(),
+        })
+    }
+
+    async fn list_ids_only(
+        &self,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraIssueId>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::ListIdsOnly)?;
+        let ids: Vec<JiraIssueId> = self
+            .dtos()
+            .map(|issue| JiraIssueId::new(issue.id))
+            .collect();
+        Ok(paginate(&ids, page_number, page_size))
+    }
+
+    async fn list_by_project(
+        &self,
+        project_id: JiraProjectId,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::ListByProject)?;
+        Ok(self
+            .issues
+            .iter()
+            .filter(|(issue_project_id, _)| *issue_project_id == project_id)
+            .map(|(_, issue)| issue.clone())
+            .collect())
+    }
+
+    async fn list_grouped_by_project(
+        &self,
+        project_ids: Vec<JiraProjectId>,
+        per_project_limit: PageSize,
+    ) -> Result<HashMap<JiraProjectId, Vec<JiraIssueQueryDto>>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::ListGroupedByProject)?;
+        let mut grouped: HashMap<JiraProjectId, Vec<JiraIssueQueryDto>> = HashMap::new();
+        for (project_id, issue) in &self.issues {
+            if project_ids.contains(project_id) {
+                grouped.entry(*project_id).or_default().push(issue.clone());
+            }
+        }
+        for issues in grouped.values_mut() {
+            issues.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.id.cmp(&b.id)));
+            issues.truncate(per_project_limit.value() as usize);
+        }
+        Ok(grouped)
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<JiraIssueId>,
+        limit: PageSize,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
+        self.record(JiraIssueQueryRepositoryMethod::ListAfter)?;
+        let after = cursor.map(|id| id.value()).unwrap_or(i64::MIN);
+        Ok(self
+            .dtos()
+            .filter(|issue| issue.id > after)
+            .take(limit.value() as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::value_object::jira::JiraIssueType;
+
+    fn issue(id: i64, issue_type: JiraIssueType, updated_hour: u32) -> JiraIssueQueryDto {
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("PROJ-{}", id))
+            .issue_type(issue_type)
+            .updated_at(
+                Utc.with_ymd_and_hms(2024, 1, 1, updated_hour, 0, 0)
+                    .unwrap(),
+            )
+            .build()
+    }
+
+    fn ids(issues: &[JiraIssueQueryDto]) -> Vec<i64> {
+        issues.iter().map(|issue| issue.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_reads_keep_id_order_and_project_membership() {
+        let repository = InMemoryJiraIssueQueryRepository::new()
+            .with_project_issues(
+                JiraProjectId::new(1),
+                vec![
+                    issue(3, JiraIssueType::Bug, 1),
+                    issue(1, JiraIssueType::Task, 2),
+                ],
+            )
+            .with_project_issues(JiraProjectId::new(2), vec![issue(2, JiraIssueType::Bug, 3)]);
+
+        let found = repository
+            .find_by_ids(vec![JiraIssueId::new(3), JiraIssueId::new(2)])
+            .await
+            .unwrap();
+        let of_project = repository
+            .list_by_project(JiraProjectId::new(1))
+            .await
+            .unwrap();
+        let grouped = repository
+            .list_grouped_by_project(vec![JiraProjectId::new(1)], PageSize::of(1).unwrap())
+            .await
+            .unwrap();
+        let summary = repository
+            .summarize(&JiraIssueListFilter::default())
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&found), vec![2, 3]);
+        assert_eq!(ids(&of_project), vec![1, 3]);
+        assert_eq!(ids(&grouped[&JiraProjectId::new(1)]), vec![1]);
+        assert_eq!(
+            summary.by_type,
+            vec![
+                JiraIssueTypeCount {
+                    issue_type: JiraIssueType::Bug,
+                    count: 2
+                },
+                JiraIssueTypeCount {
+                    issue_type: JiraIssueType::Task,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fail_next_fails_only_the_programmed_call_and_records_filters() {
+        let repository = InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::List,
+            JiraError::database_error("Connection failed"),
+        );
+        let filter = JiraIssueListFilter::new(Some("account-1".to_string()));
+        let page_number = PageNumber::of(1).unwrap();
+        let page_size = PageSize::of(10).unwrap();
+
+        assert!(
+            repository
+                .list(page_number, page_size, &filter)
+                .await
+                .is_err()
+        );
+        assert!(
+            repository
+                .list(page_number, page_size, &filter)
+                .await
+                .is_ok()
+        );
+        assert_eq!(repository.filters(), vec![filter.clone(), filter]);
+        assert_eq!(
+            repository.calls(),
+            vec![
+                JiraIssueQueryRepositoryMethod::List,
+                JiraIssueQueryRepositoryMethod::List
+            ]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use domain::error::JiraError;
+use domain::test_support::ProgrammedFailures;
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey};
+use domain::value_object::{Page, PageNumber, PageSize};
+
+use crate::dto::query::jira::JiraProjectQueryDto;
+use crate::repository::jira::JiraProjectQueryRepository;
+
+/// A method of [`JiraProjectQueryRepository`], used to record calls and program failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraProjectQueryRepositoryMethod {
+    FindByIds,
+    FindByKey,
+    List,
+    Search,
+}
+
+/// In-memory [`JiraProjectQueryRepository`] over a fixed set of projects.
+///
+/// Like the database, `find_by_ids` returns projects in id order and `list` and
+/// `search` in key order.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraProjectQueryRepository {
+    projects: Vec<JiraProjectQueryDto>,
+    calls: Mutex<Vec<JiraProjectQueryRepositoryMethod>>,
+    searches: Mutex<Vec<String>>,
+    failures: ProgrammedFailures<JiraProjectQueryRepositoryMethod>,
+}
+
+impl InMemoryJiraProjectQueryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a repository holding `projects`.
+    pub fn with_projects(mut projects: Vec<JiraProjectQueryDto>) -> Self {
+        projects.sort_by(|a, b| a.key.cmp(&b.key).then(a.id.cmp(&b.id)));
+        Self {
+            projects,
+            ..Self::default()
+        }
+    }
+
+    /// Makes the next call of `method` return `error`.
+    pub fn fail_next(self, method: JiraProjectQueryRepositoryMethod, error: JiraError) -> Self {
+        self.failures.push(method, error);
+        self
+    }
+
+    /// Returns the methods called so far, in call order.
+    pub fn calls(&self) -> Vec<JiraProjectQueryRepositoryMethod> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Returns the queries `search` was called with, in call order.
+    pub fn searches(&self) -> Vec<String> {
+        self.searches.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: JiraProjectQueryRepositoryMethod) -> Result<(), JiraError> {
+        self.calls.lock().unwrap().push(method);
+        match self.failures.take(method) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn page(
+        projects: Vec<JiraProjectQueryDto>,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Page<JiraProjectQueryDto> {
+        let total_count = projects.len() as i32;
+        let items = projects
+            .into_iter()
+            .skip(page_number.offset(page_size) as usize)
+            .take(page_size.value() as usize)
+            .collect();
+        Page::new(total_count, items).with_pagination(page_number, page_size)
+    }
+}
+
+#[async_trait]
+impl JiraProjectQueryRepository for InMemoryJiraProjectQueryRepository {
+    async fn find_by_ids(
+        &self,
+        ids: Vec<JiraProjectId>,
+    ) -> Result<Vec<JiraProjectQueryDto>, JiraError> {
+        self.record(JiraProjectQueryRepositoryMethod::FindByIds)?;
+        let mut found: Vec<JiraProjectQueryDto> = self
+            .projects
+            .iter()
+            .filter(|project| ids.contains(&JiraProjectId::new(project.id)))
+            .cloned()
+            .collect();
+        found.sort_by_key(|project| project.id);
+        Ok(found)
+    }
+
+    async fn find_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProjectQueryDto>, JiraError> {
+        self.record(JiraProjectQueryRepositoryMethod::FindByKey)?;
+        Ok(self
+            .projects
+            .iter()
+            .find(|project| project.key == key.value())
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
+        self.record(JiraProjectQueryRepositoryMethod::List)?;
+        Ok(Self::page(self.projects.clone(), page_number, page_size))
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
+        self.searches.lock().unwrap().push(query.to_string());
+        self.record(JiraProjectQueryRepositoryMethod::Search)?;
+        let query = query.to_lowercase();
+        let matching = self
+            .projects
+            .iter()
+            .filter(|project| {
+                project.name.to_lowercase().contains(&query)
+                    || project.key.to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect();
+        Ok(Self::page(matching, page_number, page_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: i64, key: &str, name: &str) -> JiraProjectQueryDto {
+        JiraProjectQueryDto::new(id, key.to_string(), name.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_search_matches_name_or_key_case_insensitively_in_key_order() {
+        let repository = InMemoryJiraProjectQueryRepository::with_projects(vec![
+            project(1, "ZETA", "Billing"),
+            project(2, "ALPHA", "Zeta platform"),
+            project(3, "BETA", "Other"),
+        ]);
+
+        let page = repository
+            .search(
+                "zeta",
+                PageNumber::of(1).unwrap(),
+                PageSize::of(10).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, 2);
+        assert_eq!(
+            page.items,
+            vec![
+                project(2, "ALPHA", "Zeta platform"),
+                project(1, "ZETA", "Billing")
+            ]
+        );
+        assert_eq!(repository.searches(), vec!["zeta".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_fail_next_fails_only_the_programmed_call() {
+        let repository =
+            InMemoryJiraProjectQueryRepository::with_projects(vec![project(1, "PROJ", "Project")])
+                .fail_next(
+                    JiraProjectQueryRepositoryMethod::FindByIds,
+                    JiraError::database_error("Connection failed"),
+                );
+
+        assert!(
+            repository
+                .find_by_ids(vec![JiraProjectId::new(1)])
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            repository
+                .find_by_ids(vec![JiraProjectId::new(1)])
+                .await
+                .unwrap(),
+            vec![project(1, "PROJ", "Project")]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod in_memory_jira_issue_query_repository;
+mod in_memory_jira_project_query_repository;
+
+pub use in_memory_jira_issue_query_repository::*;
+pub use in_memory_jira_project_query_repository::*;
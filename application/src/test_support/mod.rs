@@ -0,0 +1,6 @@
+//! Configurable in-memory test doubles for the application's query repositories.
+//!
+//! Available to this crate's tests and, through the `test-support` feature, to
+//! downstream crates' tests, alongside the domain doubles in `domain::test_support`.
+
+pub mod jira;
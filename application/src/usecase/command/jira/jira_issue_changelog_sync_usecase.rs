@@ -0,0 +1,263 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::port::jira::{JiraIssuePort, JiraSyncKind, JiraSyncLockPort};
+use domain::repository::jira::JiraChangelogRepository;
+use domain::value_object::jira::JiraIssueKey;
+
+use crate::dto::command::jira::JiraIssueChangelogSyncStats;
+use crate::error::command::jira::JiraIssueChangelogSyncError;
+
+use super::sync_lock::{acquire_sync_lock, release_sync_lock};
+
+/// Use case for syncing the change history of Jira issues from the external API.
+#[async_trait]
+pub trait JiraIssueChangelogSyncUseCase: Send + Sync {
+    /// Fetches the changelog of each issue in `issue_keys` and stores the entries not
+    /// stored yet.
+    ///
+    /// Issues are synced one at a time, so the changelogs of the issues before a failed
+    /// one stay stored. When built with a sync lock, fails with `AlreadyRunning` while
+    /// another changelog sync holds it.
+    async fn execute(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+    ) -> Result<JiraIssueChangelogSyncStats, JiraIssueChangelogSyncError>;
+}
+
+/// Implementation of JiraIssueChangelogSyncUseCase.
+pub struct JiraIssueChangelogSyncUseCaseImpl<P, R>
+where
+    P: JiraIssuePort,
+    R: JiraChangelogRepository,
+{
+    jira_issue_port: Arc<P>,
+    jira_changelog_repository: Arc<R>,
+    sync_lock_port: Option<Arc<dyn JiraSyncLockPort>>,
+}
+
+impl<P, R> JiraIssueChangelogSyncUseCaseImpl<P, R>
+where
+    P: JiraIssuePort,
+    R: JiraChangelogRepository,
+{
+    pub fn new(jira_issue_port: Arc<P>, jira_changelog_repository: Arc<R>) -> Self {
+        Self {
+            jira_issue_port,
+            jira_changelog_repository,
+            sync_lock_port: None,
+        }
+    }
+
+    /// Holds the changelog sync lock while a sync runs, so that no other instance syncs
+    /// changelogs at the same time.
+    pub fn with_sync_lock(mut self, sync_lock_port: Arc<dyn JiraSyncLockPort>) -> Self {
+        self.sync_lock_port = Some(sync_lock_port);
+        self
+    }
+
+    async fn sync(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+    ) -> Result<JiraIssueChangelogSyncStats, JiraIssueChangelogSyncError> {
+        let mut stats = JiraIssueChangelogSyncStats::default();
+
+        for key in issue_keys {
+            let entries = self
+                .jira_issue_port
+                .fetch_issue_changelog(&key)
+                .await
+                .map_err(|e| JiraIssueChangelogSyncError::ChangelogFetchFailed(key.clone(), e))?;
+
+            stats.issues += 1;
+            stats.fetched += entries.len() as i32;
+            if entries.is_empty() {
+                continue;
+            }
+
+            stats.stored += self
+                .jira_changelog_repository
+                .bulk_insert(entries)
+                .await
+                .map_err(JiraIssueChangelogSyncError::ChangelogPersistFailed)?;
+        }
+
+        Ok(stats)
+    }
+}
+
+#[async_trait]
+impl<P, R> JiraIssueChangelogSyncUseCase for JiraIssueChangelogSyncUseCaseImpl<P, R>
+where
+    P: JiraIssuePort,
+    R: JiraChangelogRepository,
+{
+    async fn execute(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+    ) -> Result<JiraIssueChangelogSyncStats, JiraIssueChangelogSyncError> {
+        let lock = acquire_sync_lock(
+            self.sync_lock_port.as_ref(),
+            JiraSyncKind::Changelogs,
+            JiraIssueChangelogSyncError::AlreadyRunning,
+            JiraIssueChangelogSyncError::LockFailed,
+        )
+        .await?;
+        let result = self.sync(issue_keys).await;
+        release_sync_lock(lock).await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::entity::jira::JiraChangelogEntry;
+    use domain::error::JiraError;
+    use domain::port::jira::JiraSyncKind;
+    use domain::test_support::jira::{
+        InMemoryJiraChangelogRepository, InMemoryJiraIssuePort, InMemoryJiraSyncLockPort,
+        JiraIssuePortMethod,
+    };
+
+    fn entry(key: &str, history_id: i64, to: &str) -> JiraChangelogEntry {
+        JiraChangelogEntry::new(
+            JiraIssueKey::new(key),
+            history_id,
+            0,
+            "status",
+            None,
+            Some(to.to_string()),
+            None,
+            Utc.with_ymd_and_hms(2024, 1, 2, 9, 30, 0).unwrap(),
+        )
+    }
+
+    fn keys(keys: &[&str]) -> Vec<JiraIssueKey> {
+        keys.iter().map(|key| JiraIssueKey::new(*key)).collect()
+    }
+
+    #[tokio::test]
+    async fn execute_should_store_changelog_of_every_issue() {
+        let port = Arc::new(
+            InMemoryJiraIssuePort::new()
+                .with_changelog("PROJ-1", vec![entry("PROJ-1", 1, "In Progress")])
+                .with_changelog(
+                    "PROJ-2",
+                    vec![
+                        entry("PROJ-2", 2, "In Progress"),
+                        entry("PROJ-2", 3, "Done"),
+                    ],
+                ),
+        );
+        let repo = Arc::new(InMemoryJiraChangelogRepository::new());
+        let usecase = JiraIssueChangelogSyncUseCaseImpl::new(port, repo.clone());
+
+        let stats = usecase
+            .execute(keys(&["PROJ-1", "PROJ-2", "PROJ-3"]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            stats,
+            JiraIssueChangelogSyncStats {
+                issues: 3,
+                fetched: 3,
+                stored: 3,
+            }
+        );
+        assert_eq!(repo.entries().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn execute_should_count_only_new_entries_as_stored_when_synced_again() {
+        let port = Arc::new(
+            InMemoryJiraIssuePort::new()
+                .with_changelog("PROJ-1", vec![entry("PROJ-1", 1, "In Progress")]),
+        );
+        let repo = Arc::new(InMemoryJiraChangelogRepository::new());
+        let usecase = JiraIssueChangelogSyncUseCaseImpl::new(port, repo.clone());
+        usecase.execute(keys(&["PROJ-1"])).await.unwrap();
+
+        let stats = usecase.execute(keys(&["PROJ-1"])).await.unwrap();
+
+        assert_eq!((stats.fetched, stats.stored), (1, 0));
+        assert_eq!(repo.entries().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_fetch_failed_with_key_when_port_fails() {
+        let port = Arc::new(InMemoryJiraIssuePort::new().fail_next(
+            JiraIssuePortMethod::FetchIssueChangelog,
+            JiraError::api_error("Connection failed"),
+        ));
+        let repo = Arc::new(InMemoryJiraChangelogRepository::new());
+        let usecase = JiraIssueChangelogSyncUseCaseImpl::new(port, repo.clone());
+
+        let result = usecase.execute(keys(&["PROJ-1"])).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueChangelogSyncError::ChangelogFetchFailed(key, _) if key.value() == "PROJ-1"
+        ));
+        assert!(repo.entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_persist_failed_when_repository_fails() {
+        let port = Arc::new(
+            InMemoryJiraIssuePort::new()
+                .with_changelog("PROJ-1", vec![entry("PROJ-1", 1, "In Progress")]),
+        );
+        let repo = Arc::new(
+            InMemoryJiraChangelogRepository::new()
+                .fail_next(JiraError::database_error("Insert failed")),
+        );
+        let usecase = JiraIssueChangelogSyncUseCaseImpl::new(port, repo);
+
+        let result = usecase.execute(keys(&["PROJ-1"])).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueChangelogSyncError::ChangelogPersistFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_release_sync_lock_when_fetch_fails() {
+        let port = Arc::new(InMemoryJiraIssuePort::new().fail_next(
+            JiraIssuePortMethod::FetchIssueChangelog,
+            JiraError::api_error("Connection failed"),
+        ));
+        let repo = Arc::new(InMemoryJiraChangelogRepository::new());
+        let lock_port = Arc::new(InMemoryJiraSyncLockPort::new());
+        let usecase =
+            JiraIssueChangelogSyncUseCaseImpl::new(port, repo).with_sync_lock(lock_port.clone());
+
+        let result = usecase.execute(keys(&["PROJ-1"])).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueChangelogSyncError::ChangelogFetchFailed(..)
+        ));
+        assert!(!lock_port.is_held(JiraSyncKind::Changelogs));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_already_running_when_sync_lock_is_held() {
+        let port = Arc::new(InMemoryJiraIssuePort::new());
+        let repo = Arc::new(InMemoryJiraChangelogRepository::new());
+        let lock_port =
+            Arc::new(InMemoryJiraSyncLockPort::new().held_elsewhere(JiraSyncKind::Changelogs));
+        let usecase = JiraIssueChangelogSyncUseCaseImpl::new(port, repo).with_sync_lock(lock_port);
+
+        let result = usecase.execute(keys(&["PROJ-1"])).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueChangelogSyncError::AlreadyRunning
+        ));
+    }
+}
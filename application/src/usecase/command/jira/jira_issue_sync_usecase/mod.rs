@@ -0,0 +1,360 @@
+mod pipeline;
+mod sync_run;
+#[cfg(test)]
+mod test_mocks;
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use futures::future::{self, BoxFuture, Either};
+use futures::stream::BoxStream;
+
+use domain::entity::jira::JiraIssue;
+use domain::error::JiraError;
+use domain::port::jira::{JiraIssuePage, JiraIssuePort, JiraSyncKind, JiraSyncLockPort};
+use domain::repository::jira::{
+    JiraIssueLinkRepository, JiraIssueRepository, JiraProjectRepository,
+};
+use domain::value_object::jira::JiraProjectKey;
+
+use crate::dto::command::jira::{JiraIssueSyncParams, JiraIssueSyncReport, JiraIssueSyncStatus};
+use crate::error::command::jira::JiraIssueSyncError;
+
+use super::sync_lock::{acquire_sync_lock, release_sync_lock};
+use sync_run::{ChunkEnd, SyncRun};
+
+/// Use case for syncing Jira issues from external API.
+#[async_trait]
+pub trait JiraIssueSyncUseCase: Send + Sync {
+    /// Syncs Jira issues from the external API.
+    ///
+    /// Shorthand for [`execute_with_params`](Self::execute_with_params) with only `since`
+    /// set and no cancellation: fetches issues of every stored project updated since the
+    /// given time, and persists them in batches within transactions.
+    ///
+    /// # Arguments
+    /// * `since` - Only fetch issues updated after this time
+    ///
+    /// # Returns
+    /// A report of the issues synced and skipped, pages fetched, watermark and duration,
+    /// or an error
+    async fn execute(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<JiraIssueSyncReport, JiraIssueSyncError>;
+
+    /// Syncs Jira issues as configured by `params`, stopping early once `cancel` resolves.
+    ///
+    /// The params are validated before anything is fetched. Windows are synced oldest
+    /// first, each one chunk of project keys at a time, and the sync completes early once
+    /// `max_issues` is reached. Without explicit project keys every stored project is
+    /// synced. When built with a sync lock, fails with `AlreadyRunning` while another
+    /// issue sync holds it.
+    ///
+    /// Cancellation is only observed between batches: a batch whose persist has started
+    /// is always awaited to completion, so a cancelled sync never leaves a partial batch.
+    ///
+    /// # Arguments
+    /// * `params` - The range, projects, batching and limits of this run
+    /// * `cancel` - Resolves when the sync should stop
+    ///
+    /// # Returns
+    /// Whether the sync completed or was cancelled, with the report collected so far,
+    /// or `InvalidParams` if the params were rejected
+    async fn execute_with_params(
+        &self,
+        params: JiraIssueSyncParams,
+        cancel: BoxFuture<'static, ()>,
+    ) -> Result<JiraIssueSyncStatus, JiraIssueSyncError>;
+}
+
+/// Default number of project keys combined into a single JQL query.
+pub const DEFAULT_PROJECT_KEY_CHUNK_SIZE: usize = 100;
+
+/// Implementation of JiraIssueSyncUseCase.
+pub struct JiraIssueSyncUseCaseImpl<P, I, T>
+where
+    P: JiraProjectRepository,
+    I: JiraIssueRepository,
+    T: JiraIssuePort,
+{
+    jira_project_repository: Arc<P>,
+    jira_issue_repository: Arc<I>,
+    jira_issue_port: Arc<T>,
+    project_key_chunk_size: usize,
+    max_in_flight_issues: Option<usize>,
+    jira_issue_link_repository: Option<Arc<dyn JiraIssueLinkRepository>>,
+    sync_lock_port: Option<Arc<dyn JiraSyncLockPort>>,
+}
+
+impl<P, I, T> JiraIssueSyncUseCaseImpl<P, I, T>
+where
+    P: JiraProjectRepository,
+    I: JiraIssueRepository,
+    T: JiraIssuePort,
+{
+    pub fn new(
+        jira_project_repository: Arc<P>,
+        jira_issue_repository: Arc<I>,
+        jira_issue_port: Arc<T>,
+    ) -> Self {
+        Self {
+            jira_project_repository,
+            jira_issue_repository,
+            jira_issue_port,
+            project_key_chunk_size: DEFAULT_PROJECT_KEY_CHUNK_SIZE,
+            max_in_flight_issues: None,
+            jira_issue_link_repository: None,
+            sync_lock_port: None,
+        }
+    }
+
+    /// Sets how many project keys are combined into one JQL query.
+    /// Large `project in (...)` clauses are rejected by Jira, so keys are fetched in chunks.
+    pub fn with_project_key_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.project_key_chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Keeps fetching pages while earlier batches persist, with at most `max` fetched
+    /// issues queued or persisting at once. Without it each page is persisted before
+    /// the next one is fetched.
+    pub fn with_max_in_flight_issues(mut self, max: usize) -> Self {
+        self.max_in_flight_issues = Some(max.max(1));
+        self
+    }
+
+    /// Also stores the links of every synced issue, replacing the links stored for it.
+    /// Without it links fetched with the issues are ignored.
+    pub fn with_issue_links(
+        mut self,
+        jira_issue_link_repository: Arc<dyn JiraIssueLinkRepository>,
+    ) -> Self {
+        self.jira_issue_link_repository = Some(jira_issue_link_repository);
+        self
+    }
+
+    /// Holds the issue sync lock while a sync runs, so that no other instance, nor a
+    /// scheduled and a manual run, sync issues at the same time.
+    pub fn with_sync_lock(mut self, sync_lock_port: Arc<dyn JiraSyncLockPort>) -> Self {
+        self.sync_lock_port = Some(sync_lock_port);
+        self
+    }
+
+    /// Runs a sync as described by `execute_with_params`, without taking the lock.
+    async fn sync(
+        &self,
+        params: JiraIssueSyncParams,
+        mut cancel: BoxFuture<'static, ()>,
+    ) -> Result<JiraIssueSyncStatus, JiraIssueSyncError> {
+        let started_at = Instant::now();
+        params
+            .validate()
+            .map_err(JiraIssueSyncError::InvalidParams)?;
+
+        // 1. Fetch all project keys (unless an explicit list was given)
+        let project_keys = match &params.project_keys {
+            Some(project_keys) => project_keys.clone(),
+            None => self.project_keys().await?,
+        };
+
+        let mut run = SyncRun::new(&params);
+        let mut report = JiraIssueSyncReport::default();
+        if params.dry_run {
+            // Projects without issues still show up in the breakdown
+            for project_key in &project_keys {
+                report.add_project_count(project_key, 0);
+            }
+        }
+
+        // 2. Fetch issues from Jira API as a stream, window by window and one JQL query
+        //    per chunk of project keys
+        'windows: for window in params.windows(Utc::now()) {
+            for project_key_chunk in project_keys.chunks(self.project_key_chunk_size) {
+                let project_keys = project_key_chunk.to_vec();
+                let end = match self.max_in_flight_issues {
+                    Some(max_in_flight) => {
+                        self.sync_chunk_pipelined(
+                            project_keys,
+                            window,
+                            max_in_flight,
+                            &mut run,
+                            &mut cancel,
+                            &mut report,
+                        )
+                        .await?
+                    }
+                    None => {
+                        self.sync_chunk(project_keys, window, &mut run, &mut cancel, &mut report)
+                            .await?
+                    }
+                };
+
+                match end {
+                    ChunkEnd::Exhausted => {}
+                    ChunkEnd::LimitReached => break 'windows,
+                    ChunkEnd::Cancelled => {
+                        report.duration = started_at.elapsed();
+                        return Ok(JiraIssueSyncStatus::Cancelled(report));
+                    }
+                }
+            }
+        }
+
+        report.duration = started_at.elapsed();
+        Ok(JiraIssueSyncStatus::Completed(report))
+    }
+
+    /// Returns all project keys from the repository.
+    async fn project_keys(&self) -> Result<Vec<JiraProjectKey>, JiraIssueSyncError> {
+        self.jira_project_repository
+            .find_all_project_keys()
+            .await
+            .map_err(JiraIssueSyncError::ProjectKeyFetchFailed)
+    }
+
+    /// Fetches the issues of `project_keys` updated in the given range.
+    fn fetch(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        match until {
+            Some(until) => self
+                .jira_issue_port
+                .fetch_issues_until(project_keys, since, until),
+            None => self.jira_issue_port.fetch_issues(project_keys, since),
+        }
+    }
+
+    /// Fetches and persists one chunk of projects page by page.
+    async fn sync_chunk(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        (since, until): (DateTime<Utc>, Option<DateTime<Utc>>),
+        run: &mut SyncRun,
+        cancel: &mut BoxFuture<'static, ()>,
+        report: &mut JiraIssueSyncReport,
+    ) -> Result<ChunkEnd, JiraIssueSyncError> {
+        let mut issue_stream = self.fetch(project_keys, since, until);
+
+        loop {
+            // Only the wait for the next batch races cancellation; `select` polls `cancel` first
+            let result = match future::select(&mut *cancel, issue_stream.next()).await {
+                Either::Left(((), _)) => return Ok(ChunkEnd::Cancelled),
+                Either::Right((None, _)) => return Ok(ChunkEnd::Exhausted),
+                Either::Right((Some(result), _)) => result,
+            };
+
+            // Each stream item is one API page
+            let page = result.map_err(JiraIssueSyncError::IssueFetchFailed)?;
+            report.pages_fetched += 1;
+            report.rejected += page.rejected as i32;
+
+            if page.issues.is_empty() {
+                continue;
+            }
+            for batch in run.take(page.issues) {
+                self.persist_batch(batch, run.dry_run, report).await?;
+            }
+            if run.limit_reached() {
+                return Ok(ChunkEnd::LimitReached);
+            }
+        }
+    }
+
+    /// Persists one batch and adds the outcome to `report`.
+    /// In a dry run nothing is written, every issue counts as synced and is added to
+    /// the per-project counts.
+    async fn persist_batch(
+        &self,
+        issues: Vec<JiraIssue>,
+        dry_run: bool,
+        report: &mut JiraIssueSyncReport,
+    ) -> Result<(), JiraIssueSyncError> {
+        let batch_size = issues.len() as i32;
+
+        let written = if dry_run {
+            for issue in &issues {
+                if let Ok(project_key) = issue.key.project_key() {
+                    report.add_project_count(&project_key, 1);
+                }
+            }
+            issues
+        } else {
+            // 3. Persist issues (transaction is handled within bulk_upsert)
+            let written = self
+                .jira_issue_repository
+                .bulk_upsert(issues)
+                .await
+                .map_err(JiraIssueSyncError::IssuePersistFailed)?;
+
+            if let Some(link_repository) = &self.jira_issue_link_repository {
+                let issue_keys = written.iter().map(|issue| issue.key.clone()).collect();
+                let links = written
+                    .iter()
+                    .flat_map(|issue| issue.links.iter().cloned())
+                    .collect();
+                link_repository
+                    .replace_for_issues(issue_keys, links)
+                    .await
+                    .map_err(JiraIssueSyncError::LinkPersistFailed)?;
+            }
+            written
+        };
+
+        report.synced += written.len() as i32;
+        report.skipped += batch_size - written.len() as i32;
+        report.watermark = written
+            .iter()
+            .map(|issue| issue.updated_at)
+            .chain(report.watermark)
+            .max();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P, I, T> JiraIssueSyncUseCase for JiraIssueSyncUseCaseImpl<P, I, T>
+where
+    P: JiraProjectRepository,
+    I: JiraIssueRepository,
+    T: JiraIssuePort,
+{
+    async fn execute(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<JiraIssueSyncReport, JiraIssueSyncError> {
+        self.execute_with_params(JiraIssueSyncParams::new(since), Box::pin(future::pending()))
+            .await
+            .map(JiraIssueSyncStatus::into_report)
+    }
+
+    async fn execute_with_params(
+        &self,
+        params: JiraIssueSyncParams,
+        cancel: BoxFuture<'static, ()>,
+    ) -> Result<JiraIssueSyncStatus, JiraIssueSyncError> {
+        // A dry run writes nothing, so it may overlap with a real sync
+        let lock = if params.dry_run {
+            None
+        } else {
+            acquire_sync_lock(
+                self.sync_lock_port.as_ref(),
+                JiraSyncKind::Issues,
+                JiraIssueSyncError::AlreadyRunning,
+                JiraIssueSyncError::LockFailed,
+            )
+            .await?
+        };
+        let result = self.sync(params, cancel).await;
+        release_sync_lock(lock).await;
+        result
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use futures::channel::mpsc;
+use futures::future::{self, BoxFuture, Either};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use domain::entity::jira::JiraIssue;
+use domain::port::jira::JiraIssuePort;
+use domain::repository::jira::{JiraIssueRepository, JiraProjectRepository};
+use domain::value_object::jira::JiraProjectKey;
+
+use crate::dto::command::jira::JiraIssueSyncReport;
+use crate::error::command::jira::JiraIssueSyncError;
+
+use super::JiraIssueSyncUseCaseImpl;
+use super::sync_run::{ChunkEnd, SyncRun};
+
+impl<P, I, T> JiraIssueSyncUseCaseImpl<P, I, T>
+where
+    P: JiraProjectRepository,
+    I: JiraIssueRepository,
+    T: JiraIssuePort,
+{
+    /// Like [`sync_chunk`](Self::sync_chunk), but keeps fetching pages while earlier
+    /// batches persist.
+    ///
+    /// A fetched page is queued only once its issues fit under `max_in_flight` together
+    /// with the issues already queued or persisting, so at most one more page waits
+    /// in memory while the database is slow. Batches are persisted in fetch order; on
+    /// cancellation or a fetch failure the batch being persisted completes and queued
+    /// ones are dropped.
+    pub(super) async fn sync_chunk_pipelined(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        (since, until): (DateTime<Utc>, Option<DateTime<Utc>>),
+        max_in_flight: usize,
+        run: &mut SyncRun,
+        cancel: &mut BoxFuture<'static, ()>,
+        report: &mut JiraIssueSyncReport,
+    ) -> Result<ChunkEnd, JiraIssueSyncError> {
+        let in_flight = Semaphore::new(max_in_flight);
+        let (batch_tx, mut batch_rx) = mpsc::unbounded::<(Vec<JiraIssue>, SemaphorePermit<'_>)>();
+        let cancelled = AtomicBool::new(false);
+        // Set on cancellation or a fetch or persist failure; queued batches are then dropped
+        let aborted = AtomicBool::new(false);
+        let mut pages_fetched = 0;
+        let mut rejected = 0;
+        let mut limit_reached = false;
+        let dry_run = run.dry_run;
+
+        let produce = async {
+            let batch_tx = batch_tx;
+            let mut issue_stream = self.fetch(project_keys, since, until);
+
+            'pages: loop {
+                let result = match future::select(&mut *cancel, issue_stream.next()).await {
+                    Either::Left(((), _)) => {
+                        cancelled.store(true, Ordering::SeqCst);
+                        aborted.store(true, Ordering::SeqCst);
+                        break Ok(());
+                    }
+                    Either::Right((None, _)) => break Ok(()),
+                    Either::Right((Some(result), _)) => result,
+                };
+
+                let page = match result {
+                    Ok(page) => page,
+                    Err(e) => {
+                        aborted.store(true, Ordering::SeqCst);
+                        break Err(JiraIssueSyncError::IssueFetchFailed(e));
+                    }
+                };
+                pages_fetched += 1;
+                rejected += page.rejected as i32;
+
+                if page.issues.is_empty() {
+                    continue;
+                }
+
+                for batch in run.take(page.issues) {
+                    // A batch larger than the cap takes every permit and is persisted on its own
+                    let wanted = batch.len().min(max_in_flight) as u32;
+                    let acquire = Box::pin(in_flight.acquire_many(wanted));
+                    let permit = match future::select(&mut *cancel, acquire).await {
+                        Either::Left(((), _)) => {
+                            cancelled.store(true, Ordering::SeqCst);
+                            aborted.store(true, Ordering::SeqCst);
+                            break 'pages Ok(());
+                        }
+                        Either::Right((Ok(permit), _)) => permit,
+                        // Closed only once persisting has stopped on an error
+                        Either::Right((Err(_), _)) => break 'pages Ok(()),
+                    };
+
+                    if batch_tx.unbounded_send((batch, permit)).is_err() {
+                        break 'pages Ok(());
+                    }
+                }
+
+                if run.limit_reached() {
+                    limit_reached = true;
+                    break Ok(());
+                }
+            }
+        };
+
+        let consume = async {
+            while let Some((issues, permit)) = batch_rx.next().await {
+                if aborted.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Err(e) = self.persist_batch(issues, dry_run, report).await {
+                    // Stop the producer, which may be waiting for a permit or about to send
+                    aborted.store(true, Ordering::SeqCst);
+                    in_flight.close();
+                    batch_rx.close();
+                    return Err(e);
+                }
+                drop(permit);
+            }
+            Ok(())
+        };
+
+        let (produced, consumed) = future::join(produce, consume).await;
+        report.pages_fetched += pages_fetched;
+        report.rejected += rejected;
+        produced?;
+        consumed?;
+
+        if cancelled.load(Ordering::SeqCst) {
+            Ok(ChunkEnd::Cancelled)
+        } else if limit_reached {
+            Ok(ChunkEnd::LimitReached)
+        } else {
+            Ok(ChunkEnd::Exhausted)
+        }
+    }
+}
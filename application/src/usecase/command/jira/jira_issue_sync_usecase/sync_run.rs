@@ -0,0 +1,52 @@
+use domain::entity::jira::JiraIssue;
+
+use crate::dto::command::jira::JiraIssueSyncParams;
+
+/// State of one sync run shared by all of its chunks.
+pub(super) struct SyncRun {
+    persist_batch_size: Option<usize>,
+    pub(super) dry_run: bool,
+    /// Issues still allowed under `max_issues`; `None` when unlimited.
+    remaining: Option<usize>,
+}
+
+impl SyncRun {
+    pub(super) fn new(params: &JiraIssueSyncParams) -> Self {
+        Self {
+            persist_batch_size: params.persist_batch_size,
+            dry_run: params.dry_run,
+            remaining: params.max_issues,
+        }
+    }
+
+    /// Cuts a fetched page to what is left of the issue limit and splits it into
+    /// persist batches.
+    pub(super) fn take(&mut self, mut issues: Vec<JiraIssue>) -> Vec<Vec<JiraIssue>> {
+        if let Some(remaining) = &mut self.remaining {
+            issues.truncate(*remaining);
+            *remaining -= issues.len();
+        }
+        let Some(batch_size) = self.persist_batch_size else {
+            return vec![issues];
+        };
+
+        let mut batches = Vec::with_capacity(issues.len().div_ceil(batch_size));
+        while issues.len() > batch_size {
+            let rest = issues.split_off(batch_size);
+            batches.push(std::mem::replace(&mut issues, rest));
+        }
+        batches.push(issues);
+        batches
+    }
+
+    pub(super) fn limit_reached(&self) -> bool {
+        self.remaining == Some(0)
+    }
+}
+
+/// How syncing one chunk of projects ended.
+pub(super) enum ChunkEnd {
+    Exhausted,
+    Cancelled,
+    LimitReached,
+}
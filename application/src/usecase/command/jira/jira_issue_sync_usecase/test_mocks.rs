@@ -0,0 +1,184 @@
+//! Test doubles for the issue sync use case tests.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use futures::channel::oneshot;
+use futures::stream::{self, BoxStream};
+
+use domain::entity::jira::{JiraChangelogEntry, JiraIssue};
+use domain::error::JiraError;
+use domain::port::jira::{JiraIssuePage, JiraIssuePort};
+use domain::repository::jira::JiraIssueRepository;
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey, JiraProjectKey};
+
+/// Records each committed batch and fires `cancel` after the first commit.
+pub(super) struct CancellingJiraIssueRepository {
+    pub(super) committed: Mutex<Vec<Vec<JiraIssueId>>>,
+    pub(super) cancel: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+#[async_trait]
+impl JiraIssueRepository for CancellingJiraIssueRepository {
+    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
+        self.committed
+            .lock()
+            .unwrap()
+            .push(issues.iter().map(|issue| issue.id).collect());
+        if let Some(cancel) = self.cancel.lock().unwrap().take() {
+            let _ = cancel.send(());
+        }
+        Ok(issues)
+    }
+}
+
+/// Takes a few milliseconds per batch and leaves the given issues unwritten.
+pub(super) struct SkippingJiraIssueRepository {
+    pub(super) skip: Vec<JiraIssueId>,
+}
+
+#[async_trait]
+impl JiraIssueRepository for SkippingJiraIssueRepository {
+    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        Ok(issues
+            .into_iter()
+            .filter(|issue| !self.skip.contains(&issue.id))
+            .collect())
+    }
+}
+
+/// Counts issues handed out by [`CountingJiraIssuePort`] and written by
+/// [`SlowJiraIssueRepository`].
+#[derive(Default)]
+pub(super) struct InFlightCounter {
+    pub(super) fetched: AtomicUsize,
+    pub(super) persisted: AtomicUsize,
+    pub(super) max_unpersisted: AtomicUsize,
+    pub(super) persisted_ids: Mutex<Vec<JiraIssueId>>,
+}
+
+/// Takes a second per batch to write.
+pub(super) struct SlowJiraIssueRepository {
+    pub(super) counter: Arc<InFlightCounter>,
+}
+
+#[async_trait]
+impl JiraIssueRepository for SlowJiraIssueRepository {
+    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        self.counter
+            .persisted_ids
+            .lock()
+            .unwrap()
+            .extend(issues.iter().map(|issue| issue.id));
+        self.counter
+            .persisted
+            .fetch_add(issues.len(), Ordering::SeqCst);
+        Ok(issues)
+    }
+}
+
+/// Records, whenever the next page is requested, how many earlier issues are not
+/// yet persisted.
+pub(super) struct CountingJiraIssuePort {
+    pub(super) pages: Vec<Vec<JiraIssue>>,
+    pub(super) counter: Arc<InFlightCounter>,
+}
+
+#[async_trait]
+impl JiraIssuePort for CountingJiraIssuePort {
+    fn fetch_issues(
+        &self,
+        _project_keys: Vec<JiraProjectKey>,
+        _since: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        let counter = self.counter.clone();
+        Box::pin(stream::iter(self.pages.clone()).map(move |page| {
+            let unpersisted =
+                counter.fetched.load(Ordering::SeqCst) - counter.persisted.load(Ordering::SeqCst);
+            counter
+                .max_unpersisted
+                .fetch_max(unpersisted, Ordering::SeqCst);
+            counter.fetched.fetch_add(page.len(), Ordering::SeqCst);
+            Ok(JiraIssuePage::new(page))
+        }))
+    }
+
+    async fn fetch_issue_changelog(
+        &self,
+        _key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError> {
+        unreachable!("the issue sync never fetches changelogs")
+    }
+}
+
+/// The project keys and range of one search.
+type Search = (Vec<JiraProjectKey>, DateTime<Utc>, Option<DateTime<Utc>>);
+
+/// Answers each search with one page of the issues updated in the searched range.
+pub(super) struct WindowedJiraIssuePort {
+    issues: Vec<JiraIssue>,
+    pub(super) searches: Mutex<Vec<Search>>,
+}
+
+impl WindowedJiraIssuePort {
+    pub(super) fn new(issues: Vec<JiraIssue>) -> Self {
+        Self {
+            issues,
+            searches: Mutex::new(vec![]),
+        }
+    }
+
+    fn search(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.searches
+            .lock()
+            .unwrap()
+            .push((project_keys, since, until));
+        let page: Vec<JiraIssue> = self
+            .issues
+            .iter()
+            .filter(|issue| {
+                issue.updated_at >= since && until.is_none_or(|until| issue.updated_at < until)
+            })
+            .cloned()
+            .collect();
+        Box::pin(stream::iter([Ok(JiraIssuePage::new(page))]))
+    }
+}
+
+#[async_trait]
+impl JiraIssuePort for WindowedJiraIssuePort {
+    fn fetch_issues(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.search(project_keys, since, None)
+    }
+
+    fn fetch_issues_until(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.search(project_keys, since, Some(until))
+    }
+
+    async fn fetch_issue_changelog(
+        &self,
+        _key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError> {
+        unreachable!("the issue sync never fetches changelogs")
+    }
+}
@@ -0,0 +1,751 @@
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::channel::oneshot;
+use futures::future;
+
+use domain::entity::jira::{JiraIssue, JiraIssueBuilder, JiraIssueLink, JiraProject};
+use domain::error::JiraError;
+use domain::port::jira::{JiraIssuePage, JiraSyncKind};
+use domain::repository::jira::JiraIssueLinkRepository;
+use domain::test_support::jira::{
+    InMemoryJiraIssueLinkRepository, InMemoryJiraIssuePort, InMemoryJiraIssueRepository,
+    InMemoryJiraProjectRepository, InMemoryJiraSyncLockPort, JiraProjectRepositoryMethod,
+};
+use domain::value_object::jira::{
+    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId, JiraProjectKey,
+    JiraProjectName,
+};
+
+use crate::dto::command::jira::{JiraIssueSyncParams, JiraIssueSyncStatus};
+use crate::error::command::jira::{JiraIssueSyncError, JiraIssueSyncParamsError};
+
+use super::test_mocks::*;
+use super::{JiraIssueSyncUseCase, JiraIssueSyncUseCaseImpl};
+
+/// A project repository holding one project for each of `keys`.
+fn project_repository(keys: Vec<JiraProjectKey>) -> Arc<InMemoryJiraProjectRepository> {
+    let projects = keys
+        .into_iter()
+        .zip(1..)
+        .map(|(key, id)| {
+            JiraProject::new(JiraProjectId::new(id), key, JiraProjectName::new("Project"))
+        })
+        .collect();
+    Arc::new(InMemoryJiraProjectRepository::with_projects(projects))
+}
+
+fn create_test_issue(id: i64) -> JiraIssue {
+    create_test_issue_updated_at(id, Utc::now())
+}
+
+fn create_test_issue_updated_at(id: i64, updated_at: DateTime<Utc>) -> JiraIssue {
+    create_project_issue("TEST", id, updated_at)
+}
+
+fn create_project_issue(project_key: &str, id: i64, updated_at: DateTime<Utc>) -> JiraIssue {
+    JiraIssueBuilder::new()
+        .id(JiraIssueId::new(id))
+        .project_id(JiraProjectId::new(1))
+        .key(JiraIssueKey::new(format!("{}-{}", project_key, id)))
+        .summary(format!("Test Issue {}", id))
+        .issue_type(JiraIssueType::Task)
+        .priority(JiraIssuePriority::Medium)
+        .created_at(Utc::now())
+        .updated_at(updated_at)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn execute_should_return_total_count_when_sync_succeeds() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issues = vec![
+        vec![create_test_issue(1), create_test_issue(2)],
+        vec![create_test_issue(3)],
+    ];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().synced(), 3);
+}
+
+#[tokio::test]
+async fn execute_should_return_project_key_fetch_failed_when_repository_fails() {
+    let project_repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+        JiraProjectRepositoryMethod::FindAllProjectKeys,
+        JiraError::database_error("Connection failed"),
+    ));
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![]));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(result.is_err());
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::ProjectKeyFetchFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn execute_should_return_zero_when_no_issues() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![]));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().synced(), 0);
+}
+
+#[tokio::test]
+async fn execute_should_return_issue_persist_failed_when_repository_fails() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(
+        InMemoryJiraIssueRepository::new().fail_next(JiraError::database_error("Insert failed")),
+    );
+    let issues = vec![vec![create_test_issue(1)]];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(result.is_err());
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::IssuePersistFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn execute_with_params_should_return_project_key_fetch_failed_when_dry_run() {
+    let project_repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+        JiraProjectRepositoryMethod::FindAllProjectKeys,
+        JiraError::database_error("Connection failed"),
+    ));
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![]));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let params = JiraIssueSyncParams::new(Utc::now()).with_dry_run(true);
+
+    let result = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::ProjectKeyFetchFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn execute_with_params_should_stop_after_in_flight_batch_when_cancelled() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let (cancel_tx, cancel_rx) = oneshot::channel();
+    let issue_repo = Arc::new(CancellingJiraIssueRepository {
+        committed: Mutex::new(vec![]),
+        cancel: Mutex::new(Some(cancel_tx)),
+    });
+    let issues = vec![
+        vec![create_test_issue(1), create_test_issue(2)],
+        vec![create_test_issue(3), create_test_issue(4)],
+        vec![create_test_issue(5)],
+    ];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo.clone(), issue_port);
+
+    let result = usecase
+        .execute_with_params(
+            JiraIssueSyncParams::new(Utc::now()),
+            Box::pin(async move {
+                let _ = cancel_rx.await;
+            }),
+        )
+        .await;
+
+    let status = result.unwrap();
+    assert!(matches!(status, JiraIssueSyncStatus::Cancelled(_)));
+    assert_eq!(status.report().synced, 2);
+    assert_eq!(status.report().pages_fetched, 1);
+    assert_eq!(
+        *issue_repo.committed.lock().unwrap(),
+        vec![vec![JiraIssueId::new(1), JiraIssueId::new(2)]]
+    );
+}
+
+#[tokio::test]
+async fn execute_with_params_should_return_completed_when_not_cancelled() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port =
+        Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![vec![create_test_issue(1)]]));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase
+        .execute_with_params(
+            JiraIssueSyncParams::new(Utc::now()),
+            Box::pin(future::pending()),
+        )
+        .await;
+
+    let status = result.unwrap();
+    assert!(matches!(status, JiraIssueSyncStatus::Completed(_)));
+    assert_eq!(status.report().synced, 1);
+    assert_eq!(status.report().pages_fetched, 1);
+}
+
+#[tokio::test]
+async fn execute_should_issue_one_query_per_project_key_chunk() {
+    let project_keys: Vec<JiraProjectKey> = (0..250)
+        .map(|i| JiraProjectKey::new(format!("P{}", i)))
+        .collect();
+    let project_repo = project_repository(project_keys);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new());
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port.clone())
+        .with_project_key_chunk_size(100);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(result.is_ok());
+    let chunk_sizes: Vec<usize> = issue_port
+        .calls()
+        .iter()
+        .map(|call| call.project_keys.len())
+        .collect();
+    assert_eq!(chunk_sizes, vec![100, 100, 50]);
+}
+
+#[tokio::test]
+async fn execute_with_params_should_count_pages_fetched() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issues = vec![
+        vec![create_test_issue(1), create_test_issue(2)],
+        vec![create_test_issue(3)],
+        vec![],
+    ];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let result = usecase
+        .execute_with_params(
+            JiraIssueSyncParams::new(Utc::now()),
+            Box::pin(future::pending()),
+        )
+        .await;
+
+    let report = result.unwrap().into_report();
+    assert_eq!(report.pages_fetched, 3);
+    assert_eq!(report.synced, 3);
+}
+
+#[tokio::test]
+async fn execute_should_count_issues_rejected_by_the_port() {
+    let pages = vec![
+        JiraIssuePage::new(vec![create_test_issue(1)]).with_rejected(2),
+        JiraIssuePage::new(vec![create_test_issue(2)]),
+        JiraIssuePage::new(vec![]).with_rejected(1),
+    ];
+
+    for max_in_flight_issues in [None, Some(2)] {
+        let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+        let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+        let issue_port =
+            Arc::new(InMemoryJiraIssuePort::new().with_issue_pages("TEST", pages.clone()));
+        let mut usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+        if let Some(max) = max_in_flight_issues {
+            usecase = usecase.with_max_in_flight_issues(max);
+        }
+
+        let report = usecase.execute(Utc::now()).await.unwrap();
+
+        assert_eq!(report.synced, 2);
+        assert_eq!(report.rejected, 3);
+        assert_eq!(report.pages_fetched, 3);
+    }
+}
+
+#[tokio::test]
+async fn execute_with_params_should_sync_only_explicit_project_keys_when_given() {
+    let project_repo = Arc::new(InMemoryJiraProjectRepository::new());
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(
+        InMemoryJiraIssuePort::new()
+            .with_pages("ALPHA", vec![vec![create_test_issue(1)]])
+            .with_pages("BETA", vec![vec![create_test_issue(2)]])
+            .with_pages("GAMMA", vec![vec![create_test_issue(3)]]),
+    );
+
+    let usecase =
+        JiraIssueSyncUseCaseImpl::new(project_repo.clone(), issue_repo, issue_port.clone());
+    let params = JiraIssueSyncParams::new(Utc::now()).with_project_keys(vec![
+        JiraProjectKey::new("ALPHA"),
+        JiraProjectKey::new("GAMMA"),
+    ]);
+
+    let result = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await;
+
+    assert_eq!(result.unwrap().synced(), 2);
+    assert!(project_repo.calls().is_empty());
+    assert_eq!(
+        issue_port
+            .calls()
+            .into_iter()
+            .map(|call| call.project_keys)
+            .collect::<Vec<_>>(),
+        vec![vec![
+            JiraProjectKey::new("ALPHA"),
+            JiraProjectKey::new("GAMMA")
+        ]]
+    );
+}
+
+#[tokio::test]
+async fn execute_should_report_counts_watermark_and_duration_across_batches() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(SkippingJiraIssueRepository {
+        skip: vec![JiraIssueId::new(4)],
+    });
+    let at = |hour: u32| Utc.with_ymd_and_hms(2024, 3, 1, hour, 0, 0).unwrap();
+    let issues = vec![
+        vec![
+            create_test_issue_updated_at(1, at(9)),
+            create_test_issue_updated_at(2, at(12)),
+        ],
+        vec![],
+        vec![
+            create_test_issue_updated_at(3, at(10)),
+            // Skipped, so its later timestamp must not move the watermark
+            create_test_issue_updated_at(4, at(15)),
+        ],
+    ];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let report = usecase.execute(Utc::now()).await.unwrap();
+
+    assert_eq!(report.synced, 3);
+    assert_eq!(report.synced(), 3);
+    assert_eq!(report.skipped, 1);
+    assert_eq!(report.pages_fetched, 3);
+    assert_eq!(report.watermark, Some(at(12)));
+    assert!(report.duration >= std::time::Duration::from_millis(10));
+}
+
+#[tokio::test(start_paused = true)]
+async fn execute_should_fetch_ahead_within_cap_when_max_in_flight_issues_set() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let counter = Arc::new(InFlightCounter::default());
+    let issue_repo = Arc::new(SlowJiraIssueRepository {
+        counter: counter.clone(),
+    });
+    let pages: Vec<Vec<JiraIssue>> = (0..5)
+        .map(|page| (1..=3).map(|i| create_test_issue(page * 3 + i)).collect())
+        .collect();
+    let issue_port = Arc::new(CountingJiraIssuePort {
+        pages,
+        counter: counter.clone(),
+    });
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_max_in_flight_issues(7);
+
+    let report = usecase.execute(Utc::now()).await.unwrap();
+
+    assert_eq!(report.synced, 15);
+    assert_eq!(report.pages_fetched, 5);
+    // Two pages of three fit under the cap; a third has to wait for a permit
+    assert_eq!(counter.max_unpersisted.load(Ordering::SeqCst), 6);
+    assert_eq!(
+        *counter.persisted_ids.lock().unwrap(),
+        (1..=15).map(JiraIssueId::new).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn execute_should_fail_when_persisting_fails_while_fetching_ahead() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(
+        InMemoryJiraIssueRepository::new().fail_next(JiraError::database_error("Insert failed")),
+    );
+    let pages: Vec<Vec<JiraIssue>> = (0..5)
+        .map(|page| (1..=3).map(|i| create_test_issue(page * 3 + i)).collect())
+        .collect();
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", pages));
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_max_in_flight_issues(7);
+
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        usecase.execute(Utc::now()),
+    )
+    .await
+    .expect("sync must not hang after a persist failure");
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::IssuePersistFailed(_)
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn execute_should_persist_each_page_before_fetching_the_next_by_default() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let counter = Arc::new(InFlightCounter::default());
+    let issue_repo = Arc::new(SlowJiraIssueRepository {
+        counter: counter.clone(),
+    });
+    let pages: Vec<Vec<JiraIssue>> = (0..3)
+        .map(|page| (1..=3).map(|i| create_test_issue(page * 3 + i)).collect())
+        .collect();
+    let issue_port = Arc::new(CountingJiraIssuePort {
+        pages,
+        counter: counter.clone(),
+    });
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let report = usecase.execute(Utc::now()).await.unwrap();
+
+    assert_eq!(report.synced, 9);
+    assert_eq!(counter.max_unpersisted.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn execute_should_replace_links_of_synced_issues_when_issue_links_enabled() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let link = |source: &str, target: &str, link_type: &str| {
+        JiraIssueLink::new(
+            JiraIssueKey::new(source),
+            JiraIssueKey::new(target),
+            link_type,
+        )
+    };
+    let issues = vec![vec![
+        create_test_issue(1).with_links(vec![link("TEST-1", "TEST-2", "Blocks")]),
+        create_test_issue(2).with_links(vec![
+            link("TEST-1", "TEST-2", "Blocks"),
+            link("OTHER-9", "TEST-2", "Relates"),
+        ]),
+    ]];
+    let issue_port = Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", issues));
+    let link_repo = Arc::new(InMemoryJiraIssueLinkRepository::new());
+    link_repo
+        .replace_for_issues(
+            vec![JiraIssueKey::new("OTHER-8")],
+            vec![
+                // No longer reported for TEST-1, so it is removed
+                link("TEST-1", "OTHER-8", "Blocks"),
+                link("OTHER-8", "OTHER-7", "Relates"),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_issue_links(link_repo.clone());
+
+    let report = usecase.execute(Utc::now()).await.unwrap();
+
+    assert_eq!(report.synced, 2);
+    assert_eq!(
+        link_repo.links(),
+        vec![
+            link("OTHER-8", "OTHER-7", "Relates"),
+            link("TEST-1", "TEST-2", "Blocks"),
+            link("OTHER-9", "TEST-2", "Relates"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn execute_should_return_link_persist_failed_when_link_repository_fails() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port =
+        Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![vec![create_test_issue(1)]]));
+    let link_repo = Arc::new(
+        InMemoryJiraIssueLinkRepository::new()
+            .fail_next(JiraError::database_error("Insert failed")),
+    );
+
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_issue_links(link_repo);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::LinkPersistFailed(_)
+    ));
+}
+
+/// Half past `hour` on the first day of 2024.
+fn hour(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, hour, 30, 0).unwrap()
+}
+
+#[tokio::test]
+async fn execute_with_params_should_return_invalid_params_when_until_before_since() {
+    let project_repo = project_repository(vec![]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(WindowedJiraIssuePort::new(vec![]));
+    let usecase =
+        JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo.clone(), issue_port.clone());
+    let params = JiraIssueSyncParams::new(hour(5)).with_until(hour(1));
+
+    let result = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::InvalidParams(JiraIssueSyncParamsError::UntilBeforeSince { .. })
+    ));
+    assert!(issue_port.searches.lock().unwrap().is_empty());
+    assert!(issue_repo.batches().is_empty());
+}
+
+#[tokio::test]
+async fn execute_with_params_should_sync_windows_of_given_projects_up_to_the_limit() {
+    let since = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let until = Utc.with_ymd_and_hms(2024, 1, 1, 6, 0, 0).unwrap();
+    let window_end = |hours| since + chrono::Duration::hours(hours);
+    let params = JiraIssueSyncParams::new(since)
+        .with_until(until)
+        .with_window(chrono::Duration::hours(2))
+        .with_project_keys(vec![JiraProjectKey::new("ONLY")])
+        .with_persist_batch_size(2)
+        .with_max_issues(5);
+    // Three issues in each of the first two windows and one in the last
+    let issues = vec![
+        create_test_issue_updated_at(1, hour(0)),
+        create_test_issue_updated_at(2, hour(0)),
+        create_test_issue_updated_at(3, hour(1)),
+        create_test_issue_updated_at(4, hour(2)),
+        create_test_issue_updated_at(5, hour(3)),
+        create_test_issue_updated_at(6, hour(3)),
+        create_test_issue_updated_at(7, hour(4)),
+    ];
+
+    for max_in_flight_issues in [None, Some(2)] {
+        // Fails if the use case asks for the stored project keys
+        let project_repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+            JiraProjectRepositoryMethod::FindAllProjectKeys,
+            JiraError::database_error("Not expected"),
+        ));
+        let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+        let issue_port = Arc::new(WindowedJiraIssuePort::new(issues.clone()));
+        let mut usecase =
+            JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo.clone(), issue_port.clone());
+        if let Some(max) = max_in_flight_issues {
+            usecase = usecase.with_max_in_flight_issues(max);
+        }
+
+        let status = usecase
+            .execute_with_params(params.clone(), Box::pin(future::pending()))
+            .await
+            .unwrap();
+
+        let JiraIssueSyncStatus::Completed(report) = status else {
+            panic!("expected a completed sync, got {:?}", status);
+        };
+        assert_eq!(report.synced, 5);
+        assert_eq!(report.pages_fetched, 2);
+        assert_eq!(report.watermark, Some(hour(3)));
+        // The last window is never searched once the limit is reached
+        assert_eq!(
+            *issue_port.searches.lock().unwrap(),
+            vec![
+                (
+                    vec![JiraProjectKey::new("ONLY")],
+                    since,
+                    Some(window_end(2))
+                ),
+                (
+                    vec![JiraProjectKey::new("ONLY")],
+                    window_end(2),
+                    Some(window_end(4))
+                ),
+            ]
+        );
+        assert_eq!(
+            issue_repo.batches(),
+            vec![
+                vec![JiraIssueId::new(1), JiraIssueId::new(2)],
+                vec![JiraIssueId::new(3)],
+                vec![JiraIssueId::new(4), JiraIssueId::new(5)],
+            ]
+        );
+    }
+}
+
+#[tokio::test]
+async fn execute_with_params_should_count_without_writing_when_dry_run() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let link_repo = Arc::new(InMemoryJiraIssueLinkRepository::new());
+    let issue_port = Arc::new(WindowedJiraIssuePort::new(vec![
+        create_test_issue_updated_at(1, hour(1)),
+        create_test_issue_updated_at(2, hour(2)),
+    ]));
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo.clone(), issue_port)
+        .with_issue_links(link_repo.clone());
+    let params = JiraIssueSyncParams::new(hour(0)).with_dry_run(true);
+
+    let status = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await
+        .unwrap();
+
+    assert_eq!(status.synced(), 2);
+    assert_eq!(status.report().watermark, Some(hour(2)));
+    assert!(issue_repo.batches().is_empty());
+    assert!(link_repo.links().is_empty());
+}
+
+#[tokio::test]
+async fn execute_with_params_should_count_issues_per_project_when_dry_run() {
+    let project_repo = project_repository(vec![
+        JiraProjectKey::new("ALPHA"),
+        JiraProjectKey::new("BETA"),
+        JiraProjectKey::new("GAMMA"),
+    ]);
+    let issue_repo = Arc::new(
+        InMemoryJiraIssueRepository::new()
+            .fail_next(JiraError::database_error("dry run must not persist")),
+    );
+    let issue_port = Arc::new(
+        InMemoryJiraIssuePort::new()
+            .with_pages(
+                "ALPHA",
+                vec![
+                    vec![
+                        create_project_issue("ALPHA", 1, Utc::now()),
+                        create_project_issue("ALPHA", 2, Utc::now()),
+                    ],
+                    vec![create_project_issue("ALPHA", 3, Utc::now())],
+                ],
+            )
+            .with_pages(
+                "BETA",
+                vec![vec![create_project_issue("BETA", 4, Utc::now())]],
+            ),
+    );
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+    let params = JiraIssueSyncParams::new(Utc::now()).with_dry_run(true);
+
+    let status = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await
+        .unwrap();
+
+    assert_eq!(status.synced(), 4);
+    assert_eq!(
+        status.report().per_project,
+        vec![
+            (JiraProjectKey::new("ALPHA"), 3),
+            (JiraProjectKey::new("BETA"), 1),
+            (JiraProjectKey::new("GAMMA"), 0),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn execute_should_leave_per_project_counts_empty_when_not_dry_run() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port =
+        Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![vec![create_test_issue(1)]]));
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port);
+
+    let report = usecase.execute(Utc::now()).await.unwrap();
+
+    assert_eq!(report.synced, 1);
+    assert!(report.per_project.is_empty());
+}
+
+#[tokio::test]
+async fn execute_should_return_already_running_when_sync_lock_is_held() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port =
+        Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![vec![create_test_issue(1)]]));
+    let lock_port = Arc::new(InMemoryJiraSyncLockPort::new().held_elsewhere(JiraSyncKind::Issues));
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo.clone(), issue_port)
+        .with_sync_lock(lock_port);
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::AlreadyRunning
+    ));
+    assert!(issue_repo.batches().is_empty());
+}
+
+#[tokio::test]
+async fn execute_should_release_sync_lock_when_sync_fails() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(
+        InMemoryJiraIssueRepository::new().fail_next(JiraError::database_error("Insert failed")),
+    );
+    let issue_port =
+        Arc::new(InMemoryJiraIssuePort::new().with_pages("TEST", vec![vec![create_test_issue(1)]]));
+    let lock_port = Arc::new(InMemoryJiraSyncLockPort::new());
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_sync_lock(lock_port.clone());
+
+    let result = usecase.execute(Utc::now()).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        JiraIssueSyncError::IssuePersistFailed(_)
+    ));
+    assert!(!lock_port.is_held(JiraSyncKind::Issues));
+}
+
+#[tokio::test]
+async fn execute_with_params_should_not_take_sync_lock_when_dry_run() {
+    let project_repo = project_repository(vec![JiraProjectKey::new("TEST")]);
+    let issue_repo = Arc::new(InMemoryJiraIssueRepository::new());
+    let issue_port = Arc::new(WindowedJiraIssuePort::new(vec![
+        create_test_issue_updated_at(1, hour(1)),
+    ]));
+    let lock_port = Arc::new(InMemoryJiraSyncLockPort::new().held_elsewhere(JiraSyncKind::Issues));
+    let usecase = JiraIssueSyncUseCaseImpl::new(project_repo, issue_repo, issue_port)
+        .with_sync_lock(lock_port);
+    let params = JiraIssueSyncParams::new(hour(0)).with_dry_run(true);
+
+    let status = usecase
+        .execute_with_params(params, Box::pin(future::pending()))
+        .await
+        .unwrap();
+
+    assert_eq!(status.synced(), 1);
+}
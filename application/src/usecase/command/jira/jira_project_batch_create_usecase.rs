@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::repository::jira::JiraProjectRepository;
+
+use crate::dto::command::jira::{
+    CreateJiraProjectDto, JiraProjectBatchCreateResult, JiraProjectBatchMode,
+};
+use crate::error::command::jira::{JiraProjectBatchCreateError, JiraProjectInputRejection};
+
+/// Use case for creating many Jira projects at once.
+#[async_trait]
+pub trait JiraProjectBatchCreateUseCase: Send + Sync {
+    /// Creates a batch of Jira projects.
+    ///
+    /// Every input is validated first, then the valid ones are written in one
+    /// transaction. In partial-success mode invalid inputs are reported in the result
+    /// and skipped; in all-or-nothing mode any invalid input fails the whole batch.
+    ///
+    /// # Arguments
+    /// * `inputs` - The input data for each project
+    /// * `mode` - How to handle invalid inputs
+    ///
+    /// # Returns
+    /// The created projects and the rejected inputs, or an error
+    async fn execute(
+        &self,
+        inputs: Vec<CreateJiraProjectDto>,
+        mode: JiraProjectBatchMode,
+    ) -> Result<JiraProjectBatchCreateResult, JiraProjectBatchCreateError>;
+}
+
+/// Implementation of JiraProjectBatchCreateUseCase.
+pub struct JiraProjectBatchCreateUseCaseImpl<R>
+where
+    R: JiraProjectRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> JiraProjectBatchCreateUseCaseImpl<R>
+where
+    R: JiraProjectRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> JiraProjectBatchCreateUseCase for JiraProjectBatchCreateUseCaseImpl<R>
+where
+    R: JiraProjectRepository,
+{
+    async fn execute(
+        &self,
+        inputs: Vec<CreateJiraProjectDto>,
+        mode: JiraProjectBatchMode,
+    ) -> Result<JiraProjectBatchCreateResult, JiraProjectBatchCreateError> {
+        // 1. Validate every input
+        let mut projects = Vec::with_capacity(inputs.len());
+        let mut rejected = Vec::new();
+        for (index, input) in inputs.into_iter().enumerate() {
+            match input.into_project() {
+                Ok(project) => projects.push(project),
+                Err(errors) => rejected.push(JiraProjectInputRejection { index, errors }),
+            }
+        }
+
+        if mode == JiraProjectBatchMode::AllOrNothing && !rejected.is_empty() {
+            return Err(JiraProjectBatchCreateError::ValidationFailed(rejected));
+        }
+        if projects.is_empty() {
+            return Ok(JiraProjectBatchCreateResult {
+                created: vec![],
+                rejected,
+            });
+        }
+
+        // 2. Persist the valid projects in one transaction
+        let created = self
+            .repository
+            .create_many(projects)
+            .await
+            .map_err(JiraProjectBatchCreateError::CreationFailed)?;
+
+        Ok(JiraProjectBatchCreateResult { created, rejected })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::command::jira::JiraProjectField;
+    use domain::entity::jira::JiraProject;
+    use domain::error::JiraError;
+    use domain::test_support::jira::{InMemoryJiraProjectRepository, JiraProjectRepositoryMethod};
+
+    fn input(id: &str, key: &str, name: &str) -> CreateJiraProjectDto {
+        CreateJiraProjectDto {
+            id: id.to_string(),
+            key: key.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn batch_with_invalid_key() -> Vec<CreateJiraProjectDto> {
+        vec![
+            input("1", "ONE", "Project One"),
+            input("2", "", "Project Two"),
+            input("3", "THREE", "Project Three"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn execute_should_create_every_project_when_all_inputs_are_valid() {
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectBatchCreateUseCaseImpl::new(repo.clone());
+
+        let result = usecase
+            .execute(
+                vec![
+                    input("1", "ONE", "Project One"),
+                    input("2", "TWO", "Project Two"),
+                ],
+                JiraProjectBatchMode::AllOrNothing,
+            )
+            .await
+            .unwrap();
+
+        let expected = vec![
+            JiraProject::of("1", "ONE", "Project One").unwrap(),
+            JiraProject::of("2", "TWO", "Project Two").unwrap(),
+        ];
+        assert_eq!(result.created, expected);
+        assert!(result.rejected.is_empty());
+        assert_eq!(repo.projects(), expected);
+        assert_eq!(repo.calls(), vec![JiraProjectRepositoryMethod::CreateMany]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_create_valid_inputs_and_report_invalid_one_when_partial_success() {
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectBatchCreateUseCaseImpl::new(repo.clone());
+
+        let result = usecase
+            .execute(
+                batch_with_invalid_key(),
+                JiraProjectBatchMode::PartialSuccess,
+            )
+            .await
+            .unwrap();
+
+        let created_keys: Vec<&str> = result.created.iter().map(|p| p.key.value()).collect();
+        assert_eq!(created_keys, vec!["ONE", "THREE"]);
+        assert_eq!(result.rejected.len(), 1);
+        assert_eq!(result.rejected[0].index, 1);
+        let fields: Vec<JiraProjectField> =
+            result.rejected[0].errors.iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec![JiraProjectField::Key]);
+        assert_eq!(repo.projects().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn execute_should_create_nothing_when_all_or_nothing_and_one_input_is_invalid() {
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectBatchCreateUseCaseImpl::new(repo.clone());
+
+        let result = usecase
+            .execute(batch_with_invalid_key(), JiraProjectBatchMode::AllOrNothing)
+            .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Validation error: input 1: key: Project key cannot be empty"
+        );
+        assert!(matches!(
+            error,
+            JiraProjectBatchCreateError::ValidationFailed(rejected) if rejected.len() == 1
+        ));
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_skip_repository_when_every_input_is_invalid() {
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectBatchCreateUseCaseImpl::new(repo.clone());
+
+        let result = usecase
+            .execute(
+                vec![input("invalid", "", "")],
+                JiraProjectBatchMode::PartialSuccess,
+            )
+            .await
+            .unwrap();
+
+        assert!(result.created.is_empty());
+        assert_eq!(result.rejected.len(), 1);
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_creation_error_when_repository_fails() {
+        let repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+            JiraProjectRepositoryMethod::CreateMany,
+            JiraError::database_error("Duplicate key"),
+        ));
+        let usecase = JiraProjectBatchCreateUseCaseImpl::new(repo.clone());
+
+        let result = usecase
+            .execute(
+                batch_with_invalid_key(),
+                JiraProjectBatchMode::PartialSuccess,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectBatchCreateError::CreationFailed(_)
+        ));
+        assert!(repo.projects().is_empty());
+    }
+}
@@ -3,10 +3,14 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use domain::entity::jira::JiraProject;
+use domain::port::jira::JiraProjectPort;
 use domain::repository::jira::JiraProjectRepository;
+use domain::value_object::jira::JiraProjectKey;
 
 use crate::dto::command::jira::CreateJiraProjectDto;
-use crate::error::command::jira::JiraProjectCreateError;
+use crate::error::command::jira::{
+    JiraProjectCreateError, JiraProjectField, JiraProjectFieldError,
+};
 
 /// Use case for creating a Jira project.
 #[async_trait]
@@ -22,6 +26,12 @@ pub trait JiraProjectCreateUseCase: Send + Sync {
         &self,
         input: CreateJiraProjectDto,
     ) -> Result<JiraProject, JiraProjectCreateError>;
+
+    /// Creates the project with `key`, taking its id and name from Jira.
+    ///
+    /// Fails with `NotFoundInJira` if Jira has no project with this key, and with
+    /// `JiraNotConfigured` if no Jira project port was injected.
+    async fn execute_from_jira(&self, key: String) -> Result<JiraProject, JiraProjectCreateError>;
 }
 
 /// Implementation of JiraProjectCreateUseCase.
@@ -30,6 +40,7 @@ where
     R: JiraProjectRepository,
 {
     repository: Arc<R>,
+    jira_project_port: Option<Arc<dyn JiraProjectPort>>,
 }
 
 impl<R> JiraProjectCreateUseCaseImpl<R>
@@ -37,7 +48,16 @@ where
     R: JiraProjectRepository,
 {
     pub fn new(repository: Arc<R>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            jira_project_port: None,
+        }
+    }
+
+    /// Enables creating projects from a key alone, with the rest fetched from Jira.
+    pub fn with_jira_project_port(mut self, jira_project_port: Arc<dyn JiraProjectPort>) -> Self {
+        self.jira_project_port = Some(jira_project_port);
+        self
     }
 }
 
@@ -50,7 +70,8 @@ where
         &self,
         input: CreateJiraProjectDto,
     ) -> Result<JiraProject, JiraProjectCreateError> {
-        let project = JiraProject::of(input.id, input.key, input.name)
+        let project = input
+            .into_project()
             .map_err(JiraProjectCreateError::ValidationFailed)?;
 
         self.repository
@@ -58,12 +79,37 @@ where
             .await
             .map_err(JiraProjectCreateError::CreationFailed)
     }
+
+    async fn execute_from_jira(&self, key: String) -> Result<JiraProject, JiraProjectCreateError> {
+        let key = JiraProjectKey::of(key).map_err(|e| {
+            JiraProjectCreateError::ValidationFailed(vec![JiraProjectFieldError::new(
+                JiraProjectField::Key,
+                e,
+            )])
+        })?;
+        let port = self
+            .jira_project_port
+            .as_ref()
+            .ok_or(JiraProjectCreateError::JiraNotConfigured)?;
+
+        let project = port
+            .fetch_project_by_key(&key)
+            .await
+            .map_err(JiraProjectCreateError::ProjectFetchFailed)?
+            .ok_or(JiraProjectCreateError::NotFoundInJira(key))?;
+
+        self.repository
+            .create(project)
+            .await
+            .map_err(JiraProjectCreateError::CreationFailed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use domain::error::JiraError;
+    use domain::test_support::jira::{InMemoryJiraProjectPort, JiraProjectPortMethod};
     use std::sync::Mutex;
 
     struct MockJiraProjectRepository {
@@ -93,6 +139,13 @@ mod tests {
             Ok(None)
         }
 
+        async fn exists(
+            &self,
+            _id: domain::value_object::jira::JiraProjectId,
+        ) -> Result<bool, JiraError> {
+            Ok(false)
+        }
+
         async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
             self.create_result
                 .lock()
@@ -101,6 +154,13 @@ mod tests {
                 .unwrap_or(Ok(project))
         }
 
+        async fn create_many(
+            &self,
+            projects: Vec<JiraProject>,
+        ) -> Result<Vec<JiraProject>, JiraError> {
+            Ok(projects)
+        }
+
         async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
             Ok(project)
         }
@@ -134,39 +194,86 @@ mod tests {
         assert_eq!(project.name.value(), "Test Project");
     }
 
-    #[tokio::test]
-    async fn execute_should_return_validation_error_when_id_is_invalid() {
+    /// Runs the use case with the given input and returns the fields reported as invalid.
+    async fn invalid_fields(id: &str, key: &str, name: &str) -> Vec<JiraProjectField> {
         let repo = Arc::new(MockJiraProjectRepository::new(Err(
             JiraError::database_error("Should not be called"),
         )));
         let usecase = JiraProjectCreateUseCaseImpl::new(repo);
 
         let input = CreateJiraProjectDto {
-            id: "invalid".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
+            id: id.to_string(),
+            key: key.to_string(),
+            name: name.to_string(),
         };
 
-        let result = usecase.execute(input).await;
+        match usecase.execute(input).await {
+            Err(JiraProjectCreateError::ValidationFailed(errors)) => {
+                errors.iter().map(|error| error.field).collect()
+            }
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
 
-        assert!(result.is_err());
-        assert!(matches!(
-            result.unwrap_err(),
-            JiraProjectCreateError::ValidationFailed(_)
-        ));
+    #[tokio::test]
+    async fn execute_should_return_id_field_error_when_id_is_invalid() {
+        let fields = invalid_fields("invalid", "TEST", "Test Project").await;
+
+        assert_eq!(fields, vec![JiraProjectField::Id]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_key_field_error_when_key_is_empty() {
+        let fields = invalid_fields("123", "", "Test Project").await;
+
+        assert_eq!(fields, vec![JiraProjectField::Key]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_name_field_error_when_name_is_empty() {
+        let fields = invalid_fields("123", "TEST", "").await;
+
+        assert_eq!(fields, vec![JiraProjectField::Name]);
     }
 
     #[tokio::test]
-    async fn execute_should_return_validation_error_when_name_is_empty() {
+    async fn execute_should_return_every_field_error_when_all_fields_are_invalid() {
+        let fields = invalid_fields("invalid", "", "").await;
+
+        assert_eq!(
+            fields,
+            vec![
+                JiraProjectField::Id,
+                JiraProjectField::Key,
+                JiraProjectField::Name
+            ]
+        );
+    }
+
+    #[test]
+    fn validation_error_message_names_each_field() {
+        let error = JiraProjectCreateError::ValidationFailed(vec![
+            JiraProjectFieldError::new(JiraProjectField::Key, JiraError::empty_project_key()),
+            JiraProjectFieldError::new(JiraProjectField::Name, JiraError::empty_project_name()),
+        ]);
+
+        assert_eq!(
+            error.to_string(),
+            "Validation error: key: Project key cannot be empty; name: Project name cannot be empty"
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_creation_error_when_repository_fails() {
         let repo = Arc::new(MockJiraProjectRepository::new(Err(
-            JiraError::database_error("Should not be called"),
+            JiraError::database_error("Database error"),
         )));
         let usecase = JiraProjectCreateUseCaseImpl::new(repo);
 
         let input = CreateJiraProjectDto {
             id: "123".to_string(),
             key: "TEST".to_string(),
-            name: "".to_string(),
+            name: "Test Project".to_string(),
         };
 
         let result = usecase.execute(input).await;
@@ -174,29 +281,76 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            JiraProjectCreateError::ValidationFailed(_)
+            JiraProjectCreateError::CreationFailed(_)
         ));
     }
 
     #[tokio::test]
-    async fn execute_should_return_creation_error_when_repository_fails() {
+    async fn execute_from_jira_should_create_project_with_fetched_id_and_name() {
+        let upstream = JiraProject::of("123", "TEST", "Test Project").unwrap();
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![upstream.clone()]));
+        let repo = Arc::new(MockJiraProjectRepository::new(Ok(upstream.clone())));
+        let usecase = JiraProjectCreateUseCaseImpl::new(repo).with_jira_project_port(port.clone());
+
+        let project = usecase.execute_from_jira("TEST".to_string()).await.unwrap();
+
+        assert_eq!(project, upstream);
+        assert_eq!(port.requested_keys(), vec![JiraProjectKey::new("TEST")]);
+    }
+
+    #[tokio::test]
+    async fn execute_from_jira_should_return_not_found_when_key_is_missing_upstream() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
         let repo = Arc::new(MockJiraProjectRepository::new(Err(
-            JiraError::database_error("Database error"),
+            JiraError::database_error("Should not be called"),
         )));
-        let usecase = JiraProjectCreateUseCaseImpl::new(repo);
+        let usecase = JiraProjectCreateUseCaseImpl::new(repo).with_jira_project_port(port);
 
-        let input = CreateJiraProjectDto {
-            id: "123".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
-        };
+        let result = usecase.execute_from_jira("MISSING".to_string()).await;
 
-        let result = usecase.execute(input).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectCreateError::NotFoundInJira(key) if key.value() == "MISSING"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_from_jira_should_return_fetch_failed_when_port_fails() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]).fail_next(
+            JiraProjectPortMethod::FetchProjectByKey,
+            JiraError::api_error("Connection failed"),
+        ));
+        let repo = Arc::new(MockJiraProjectRepository::new(Ok(JiraProject::of(
+            "123",
+            "TEST",
+            "Test Project",
+        )
+        .unwrap())));
+        let usecase = JiraProjectCreateUseCaseImpl::new(repo).with_jira_project_port(port);
+
+        let result = usecase.execute_from_jira("TEST".to_string()).await;
 
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            JiraProjectCreateError::CreationFailed(_)
+            JiraProjectCreateError::ProjectFetchFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_from_jira_should_return_not_configured_without_port() {
+        let repo = Arc::new(MockJiraProjectRepository::new(Ok(JiraProject::of(
+            "123",
+            "TEST",
+            "Test Project",
+        )
+        .unwrap())));
+        let usecase = JiraProjectCreateUseCaseImpl::new(repo);
+
+        let result = usecase.execute_from_jira("TEST".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectCreateError::JiraNotConfigured
         ));
     }
 }
@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entity::jira::JiraProject;
+use domain::port::jira::JiraProjectPort;
+use domain::repository::jira::JiraProjectRepository;
+use domain::value_object::jira::JiraProjectKey;
+
+use crate::error::command::jira::JiraProjectSingleSyncError;
+
+/// Use case for syncing a single Jira project by key.
+#[async_trait]
+pub trait JiraProjectSingleSyncUseCase: Send + Sync {
+    /// Fetches one project from the Jira API and upserts it.
+    ///
+    /// # Arguments
+    /// * `key` - The Jira project key (e.g., "PROJ")
+    ///
+    /// # Returns
+    /// The synced project, or an error if it does not exist in Jira
+    async fn execute(&self, key: String) -> Result<JiraProject, JiraProjectSingleSyncError>;
+}
+
+/// Implementation of JiraProjectSingleSyncUseCase.
+pub struct JiraProjectSingleSyncUseCaseImpl<P, R>
+where
+    P: JiraProjectPort,
+    R: JiraProjectRepository,
+{
+    jira_project_port: Arc<P>,
+    jira_project_repository: Arc<R>,
+}
+
+impl<P, R> JiraProjectSingleSyncUseCaseImpl<P, R>
+where
+    P: JiraProjectPort,
+    R: JiraProjectRepository,
+{
+    pub fn new(jira_project_port: Arc<P>, jira_project_repository: Arc<R>) -> Self {
+        Self {
+            jira_project_port,
+            jira_project_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, R> JiraProjectSingleSyncUseCase for JiraProjectSingleSyncUseCaseImpl<P, R>
+where
+    P: JiraProjectPort,
+    R: JiraProjectRepository,
+{
+    async fn execute(&self, key: String) -> Result<JiraProject, JiraProjectSingleSyncError> {
+        // 1. Validate key
+        let project_key =
+            JiraProjectKey::of(key.trim()).map_err(JiraProjectSingleSyncError::ValidationFailed)?;
+
+        // 2. Fetch project from Jira API
+        let project = self
+            .jira_project_port
+            .fetch_project_by_key(&project_key)
+            .await
+            .map_err(JiraProjectSingleSyncError::ProjectFetchFailed)?
+            .ok_or_else(|| JiraProjectSingleSyncError::NotFound(project_key))?;
+
+        // 3. Persist project
+        self.jira_project_repository
+            .bulk_upsert(vec![project.clone()])
+            .await
+            .map_err(JiraProjectSingleSyncError::ProjectPersistFailed)?;
+
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+    use domain::test_support::jira::{
+        InMemoryJiraProjectPort, InMemoryJiraProjectRepository, JiraProjectPortMethod,
+        JiraProjectRepositoryMethod,
+    };
+    use domain::value_object::jira::{JiraProjectId, JiraProjectName};
+
+    fn create_test_project() -> JiraProject {
+        JiraProject::new(
+            JiraProjectId::new(1),
+            JiraProjectKey::new("PROJ"),
+            JiraProjectName::new("Project"),
+        )
+    }
+
+    #[tokio::test]
+    async fn execute_should_upsert_and_return_project_when_found() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![create_test_project()]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectSingleSyncUseCaseImpl::new(port, repo.clone());
+
+        let result = usecase.execute("PROJ".to_string()).await;
+
+        assert_eq!(result.unwrap(), create_test_project());
+        assert_eq!(repo.projects(), vec![create_test_project()]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_not_found_when_project_does_not_exist() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectSingleSyncUseCaseImpl::new(port, repo.clone());
+
+        let result = usecase.execute("MISSING".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSingleSyncError::NotFound(key) if key.value() == "MISSING"
+        ));
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_validation_error_when_key_is_empty() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectSingleSyncUseCaseImpl::new(port, repo);
+
+        let result = usecase.execute("  ".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSingleSyncError::ValidationFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_fetch_failed_when_port_fails() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]).fail_next(
+            JiraProjectPortMethod::FetchProjectByKey,
+            JiraError::api_error("Connection failed"),
+        ));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let usecase = JiraProjectSingleSyncUseCaseImpl::new(port, repo);
+
+        let result = usecase.execute("PROJ".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSingleSyncError::ProjectFetchFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_persist_failed_when_repository_fails() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![create_test_project()]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+            JiraProjectRepositoryMethod::BulkUpsert,
+            JiraError::database_error("Insert failed"),
+        ));
+        let usecase = JiraProjectSingleSyncUseCaseImpl::new(port, repo);
+
+        let result = usecase.execute("PROJ".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSingleSyncError::ProjectPersistFailed(_)
+        ));
+    }
+}
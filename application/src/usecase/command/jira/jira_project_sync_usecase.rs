@@ -1,22 +1,34 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use domain::port::jira::JiraProjectPort;
+use domain::entity::jira::JiraProject;
+use domain::port::jira::{JiraProjectPort, JiraSyncKind, JiraSyncLockPort};
 use domain::repository::jira::JiraProjectRepository;
+use domain::value_object::jira::JiraProjectId;
 
+use crate::dto::command::jira::{JiraProjectIdConflict, JiraProjectSyncStats};
 use crate::error::command::jira::JiraProjectSyncError;
 
+use super::sync_lock::{acquire_sync_lock, release_sync_lock};
+
 /// Use case for syncing Jira projects from external API.
 #[async_trait]
 pub trait JiraProjectSyncUseCase: Send + Sync {
     /// Syncs Jira projects from the external API.
     ///
     /// Fetches all projects from the Jira API and persists them to the database.
+    /// In strict mode an empty API result is an error, since it usually means a
+    /// wrong base URL or credentials.
+    ///
+    /// Projects sharing an id are reduced to the one whose key sorts first, and each
+    /// such id is reported as a conflict. When built with a sync lock, fails with
+    /// `AlreadyRunning` while another project sync holds it.
     ///
     /// # Returns
-    /// The total number of projects synced, or an error
-    async fn execute(&self) -> Result<i32, JiraProjectSyncError>;
+    /// The number of projects synced and any id conflicts, or an error
+    async fn execute(&self) -> Result<JiraProjectSyncStats, JiraProjectSyncError>;
 }
 
 /// Implementation of JiraProjectSyncUseCase.
@@ -27,6 +39,8 @@ where
 {
     jira_project_port: Arc<P>,
     jira_project_repository: Arc<R>,
+    strict: bool,
+    sync_lock_port: Option<Arc<dyn JiraSyncLockPort>>,
 }
 
 impl<P, R> JiraProjectSyncUseCaseImpl<P, R>
@@ -38,17 +52,26 @@ where
         Self {
             jira_project_port,
             jira_project_repository,
+            strict: false,
+            sync_lock_port: None,
         }
     }
-}
 
-#[async_trait]
-impl<P, R> JiraProjectSyncUseCase for JiraProjectSyncUseCaseImpl<P, R>
-where
-    P: JiraProjectPort,
-    R: JiraProjectRepository,
-{
-    async fn execute(&self) -> Result<i32, JiraProjectSyncError> {
+    /// Enables strict mode, returning `NoProjects` instead of `Ok(0)` when the API
+    /// returns no projects.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Holds the project sync lock while a sync runs, so that no other instance syncs
+    /// projects at the same time.
+    pub fn with_sync_lock(mut self, sync_lock_port: Arc<dyn JiraSyncLockPort>) -> Self {
+        self.sync_lock_port = Some(sync_lock_port);
+        self
+    }
+
+    async fn sync(&self) -> Result<JiraProjectSyncStats, JiraProjectSyncError> {
         // 1. Fetch all projects from Jira API
         let projects = self
             .jira_project_port
@@ -57,10 +80,14 @@ where
             .map_err(JiraProjectSyncError::ProjectFetchFailed)?;
 
         if projects.is_empty() {
-            return Ok(0);
+            if self.strict {
+                return Err(JiraProjectSyncError::NoProjects);
+            }
+            return Ok(JiraProjectSyncStats::default());
         }
 
-        let count = projects.len() as i32;
+        let (projects, id_conflicts) = dedup_by_id(projects);
+        let synced = projects.len() as i32;
 
         // 2. Persist projects to database
         self.jira_project_repository
@@ -68,82 +95,82 @@ where
             .await
             .map_err(JiraProjectSyncError::ProjectPersistFailed)?;
 
-        Ok(count)
+        Ok(JiraProjectSyncStats {
+            synced,
+            id_conflicts,
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use domain::entity::jira::JiraProject;
-    use domain::error::JiraError;
-    use domain::value_object::jira::{JiraProjectId, JiraProjectKey, JiraProjectName};
-    use std::sync::Mutex;
-
-    struct MockJiraProjectPort {
-        result: Mutex<Option<Result<Vec<JiraProject>, JiraError>>>,
-    }
-
-    impl MockJiraProjectPort {
-        fn new(result: Result<Vec<JiraProject>, JiraError>) -> Self {
-            Self {
-                result: Mutex::new(Some(result)),
-            }
-        }
+#[async_trait]
+impl<P, R> JiraProjectSyncUseCase for JiraProjectSyncUseCaseImpl<P, R>
+where
+    P: JiraProjectPort,
+    R: JiraProjectRepository,
+{
+    async fn execute(&self) -> Result<JiraProjectSyncStats, JiraProjectSyncError> {
+        let lock = acquire_sync_lock(
+            self.sync_lock_port.as_ref(),
+            JiraSyncKind::Projects,
+            JiraProjectSyncError::AlreadyRunning,
+            JiraProjectSyncError::LockFailed,
+        )
+        .await?;
+        let result = self.sync().await;
+        release_sync_lock(lock).await;
+        result
     }
+}
 
-    #[async_trait]
-    impl JiraProjectPort for MockJiraProjectPort {
-        async fn fetch_projects(&self) -> Result<Vec<JiraProject>, JiraError> {
-            self.result
-                .lock()
-                .unwrap()
-                .take()
-                .expect("result already consumed")
+/// Keeps one project per id, preferring the key that sorts first, so the upsert does
+/// not depend on the order Jira returned them in. Projects keep their fetched order.
+fn dedup_by_id(projects: Vec<JiraProject>) -> (Vec<JiraProject>, Vec<JiraProjectIdConflict>) {
+    let mut kept: Vec<JiraProject> = Vec::with_capacity(projects.len());
+    let mut index_by_id: HashMap<JiraProjectId, usize> = HashMap::new();
+    let mut conflicts: Vec<JiraProjectIdConflict> = Vec::new();
+
+    for project in projects {
+        let Some(&index) = index_by_id.get(&project.id) else {
+            index_by_id.insert(project.id, kept.len());
+            kept.push(project);
+            continue;
+        };
+
+        let dropped = if project.key.value() < kept[index].key.value() {
+            std::mem::replace(&mut kept[index], project)
+        } else {
+            project
+        };
+        match conflicts.iter_mut().find(|c| c.id == dropped.id) {
+            Some(conflict) => conflict.dropped.push(dropped.key),
+            None => conflicts.push(JiraProjectIdConflict {
+                id: dropped.id,
+                kept: kept[index].key.clone(),
+                dropped: vec![dropped.key],
+            }),
         }
     }
 
-    struct MockJiraProjectRepository {
-        bulk_upsert_result: Mutex<Option<Result<Vec<JiraProject>, JiraError>>>,
-    }
-
-    impl MockJiraProjectRepository {
-        fn new(bulk_upsert_result: Result<Vec<JiraProject>, JiraError>) -> Self {
-            Self {
-                bulk_upsert_result: Mutex::new(Some(bulk_upsert_result)),
-            }
-        }
+    // A later duplicate may have replaced the project kept when the conflict was found
+    for conflict in &mut conflicts {
+        conflict.kept = kept[index_by_id[&conflict.id]].key.clone();
+        conflict.dropped.sort_by(|a, b| a.value().cmp(b.value()));
     }
 
-    #[async_trait]
-    impl JiraProjectRepository for MockJiraProjectRepository {
-        async fn find_all_project_keys(&self) -> Result<Vec<JiraProjectKey>, JiraError> {
-            Ok(vec![])
-        }
-
-        async fn find_by_id(&self, _id: JiraProjectId) -> Result<Option<JiraProject>, JiraError> {
-            Ok(None)
-        }
-
-        async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
-            Ok(project)
-        }
-
-        async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
-            Ok(project)
-        }
+    (kept, conflicts)
+}
 
-        async fn bulk_upsert(
-            &self,
-            projects: Vec<JiraProject>,
-        ) -> Result<Vec<JiraProject>, JiraError> {
-            self.bulk_upsert_result
-                .lock()
-                .unwrap()
-                .take()
-                .unwrap_or(Ok(projects))
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::jira::JiraProject;
+    use domain::error::JiraError;
+    use domain::port::jira::JiraSyncKind;
+    use domain::test_support::jira::{
+        InMemoryJiraProjectPort, InMemoryJiraProjectRepository, InMemoryJiraSyncLockPort,
+        JiraProjectPortMethod, JiraProjectRepositoryMethod,
+    };
+    use domain::value_object::jira::{JiraProjectId, JiraProjectKey, JiraProjectName};
 
     fn create_test_project(id: i64, key: &str, name: &str) -> JiraProject {
         JiraProject::new(
@@ -159,42 +186,45 @@ mod tests {
             create_test_project(1, "PROJ1", "Project One"),
             create_test_project(2, "PROJ2", "Project Two"),
         ];
-        let port = Arc::new(MockJiraProjectPort::new(Ok(projects)));
-        let repo = Arc::new(MockJiraProjectRepository::new(Ok(vec![])));
+        let port = Arc::new(InMemoryJiraProjectPort::new(projects.clone()));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
 
-        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo);
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo.clone());
 
         let result = usecase.execute().await;
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 2);
+        let stats = result.unwrap();
+        assert_eq!(stats.synced, 2);
+        assert!(stats.id_conflicts.is_empty());
+        assert_eq!(repo.projects(), projects);
+        assert_eq!(repo.calls(), vec![JiraProjectRepositoryMethod::BulkUpsert]);
     }
 
     #[tokio::test]
     async fn execute_should_return_zero_when_no_projects() {
-        let port = Arc::new(MockJiraProjectPort::new(Ok(vec![])));
-        let repo = Arc::new(MockJiraProjectRepository::new(Ok(vec![])));
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
 
-        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo);
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo.clone());
 
         let result = usecase.execute().await;
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 0);
+        assert_eq!(result.unwrap(), JiraProjectSyncStats::default());
+        assert!(repo.calls().is_empty());
     }
 
     #[tokio::test]
     async fn execute_should_return_fetch_failed_when_port_fails() {
-        let port = Arc::new(MockJiraProjectPort::new(Err(JiraError::api_error(
-            "Connection failed",
-        ))));
-        let repo = Arc::new(MockJiraProjectRepository::new(Ok(vec![])));
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]).fail_next(
+            JiraProjectPortMethod::FetchProjects,
+            JiraError::api_error("Connection failed"),
+        ));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
 
         let usecase = JiraProjectSyncUseCaseImpl::new(port, repo);
 
         let result = usecase.execute().await;
 
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
             JiraProjectSyncError::ProjectFetchFailed(_)
@@ -204,19 +234,132 @@ mod tests {
     #[tokio::test]
     async fn execute_should_return_persist_failed_when_repository_fails() {
         let projects = vec![create_test_project(1, "PROJ1", "Project One")];
-        let port = Arc::new(MockJiraProjectPort::new(Ok(projects)));
-        let repo = Arc::new(MockJiraProjectRepository::new(Err(
+        let port = Arc::new(InMemoryJiraProjectPort::new(projects));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new().fail_next(
+            JiraProjectRepositoryMethod::BulkUpsert,
             JiraError::database_error("Insert failed"),
-        )));
+        ));
 
-        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo);
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo.clone());
 
         let result = usecase.execute().await;
 
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
             JiraProjectSyncError::ProjectPersistFailed(_)
         ));
+        assert!(repo.projects().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_no_projects_when_strict_and_port_returns_empty() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo).with_strict(true);
+
+        let result = usecase.execute().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSyncError::NoProjects
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_zero_when_lenient_and_port_returns_empty() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo).with_strict(false);
+
+        let result = usecase.execute().await;
+
+        assert_eq!(result.unwrap().synced, 0);
+    }
+
+    #[tokio::test]
+    async fn execute_should_keep_first_sorted_key_when_projects_share_an_id() {
+        let fetched = vec![
+            create_test_project(1, "RENAMED", "Project Renamed"),
+            create_test_project(2, "OTHER", "Other"),
+            create_test_project(1, "ORIGINAL", "Project Original"),
+        ];
+        let reversed: Vec<JiraProject> = fetched.iter().rev().cloned().collect();
+
+        for projects in [fetched, reversed] {
+            let port = Arc::new(InMemoryJiraProjectPort::new(projects));
+            let repo = Arc::new(InMemoryJiraProjectRepository::new());
+            let usecase = JiraProjectSyncUseCaseImpl::new(port, repo.clone());
+
+            let stats = usecase.execute().await.unwrap();
+
+            assert_eq!(stats.synced, 2);
+            assert_eq!(
+                stats.id_conflicts,
+                vec![JiraProjectIdConflict {
+                    id: JiraProjectId::new(1),
+                    kept: JiraProjectKey::new("ORIGINAL"),
+                    dropped: vec![JiraProjectKey::new("RENAMED")],
+                }]
+            );
+            let stored = repo.projects();
+            let kept = stored
+                .iter()
+                .find(|project| project.id == JiraProjectId::new(1))
+                .unwrap();
+            assert_eq!(kept.key.value(), "ORIGINAL");
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_already_running_when_sync_lock_is_held() {
+        let projects = vec![create_test_project(1, "PROJ1", "Project One")];
+        let port = Arc::new(InMemoryJiraProjectPort::new(projects));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let lock_port =
+            Arc::new(InMemoryJiraSyncLockPort::new().held_elsewhere(JiraSyncKind::Projects));
+
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo.clone()).with_sync_lock(lock_port);
+
+        let result = usecase.execute().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSyncError::AlreadyRunning
+        ));
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_release_sync_lock_when_sync_completes() {
+        let projects = vec![create_test_project(1, "PROJ1", "Project One")];
+        let port = Arc::new(InMemoryJiraProjectPort::new(projects));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let lock_port = Arc::new(InMemoryJiraSyncLockPort::new());
+
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo).with_sync_lock(lock_port.clone());
+
+        assert_eq!(usecase.execute().await.unwrap().synced, 1);
+        assert!(!lock_port.is_held(JiraSyncKind::Projects));
+        assert_eq!(usecase.execute().await.unwrap().synced, 1);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_lock_failed_when_lock_port_fails() {
+        let port = Arc::new(InMemoryJiraProjectPort::new(vec![]));
+        let repo = Arc::new(InMemoryJiraProjectRepository::new());
+        let lock_port = Arc::new(
+            InMemoryJiraSyncLockPort::new().fail_next(JiraError::database_error("No connection")),
+        );
+
+        let usecase = JiraProjectSyncUseCaseImpl::new(port, repo).with_sync_lock(lock_port);
+
+        let result = usecase.execute().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSyncError::LockFailed(_)
+        ));
     }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use domain::entity::jira::JiraProject;
+use domain::error::JiraError;
 use domain::repository::jira::JiraProjectRepository;
 use domain::value_object::jira::JiraProjectId;
 
@@ -14,6 +15,10 @@ use crate::error::command::jira::JiraProjectUpdateError;
 pub trait JiraProjectUpdateUseCase: Send + Sync {
     /// Updates an existing Jira project.
     ///
+    /// Only the fields set in `input` are changed and validated. When none are set the
+    /// project is returned as stored, without a write. When `input` carries the version
+    /// the client read, a project changed since then is reported as a conflict.
+    ///
     /// # Arguments
     /// * `input` - The input data for updating the project
     ///
@@ -61,22 +66,43 @@ where
             .map_err(JiraProjectUpdateError::FindFailed)?
             .ok_or(JiraProjectUpdateError::NotFound(id))?;
 
-        // Update key and name
+        // The stored version must be the one the client read; the patched project keeps
+        // it, so the repository also rejects a write that races this check
+        if let Some(expected) = input.expected_updated_at
+            && existing.updated_at != Some(expected)
+        {
+            return Err(JiraProjectUpdateError::Conflict(JiraError::conflict(
+                format!(
+                    "Project {} was modified since {}",
+                    id,
+                    expected.to_rfc3339()
+                ),
+            )));
+        }
+
+        if input.key.is_none() && input.name.is_none() {
+            return Ok(existing);
+        }
+
+        // Apply the provided fields
         let updated_project = existing
-            .update(input.key, input.name)
+            .patch(input.key, input.name)
             .map_err(JiraProjectUpdateError::ValidationFailed)?;
 
-        self.repository
-            .update(updated_project)
-            .await
-            .map_err(JiraProjectUpdateError::UpdateFailed)
+        // The repository rejects the write if the project changed since it was read
+        self.repository.update(updated_project).await.map_err(|e| {
+            if e.is_conflict() {
+                JiraProjectUpdateError::Conflict(e)
+            } else {
+                JiraProjectUpdateError::UpdateFailed(e)
+            }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use domain::error::JiraError;
     use std::sync::Mutex;
 
     struct MockJiraProjectRepository {
@@ -94,6 +120,14 @@ mod tests {
                 update_result: Mutex::new(Some(update_result)),
             }
         }
+
+        /// Writes succeed and return the project as written.
+        fn echoing(find_result: Result<Option<JiraProject>, JiraError>) -> Self {
+            Self {
+                find_result: Mutex::new(Some(find_result)),
+                update_result: Mutex::new(None),
+            }
+        }
     }
 
     #[async_trait]
@@ -115,10 +149,24 @@ mod tests {
                 .expect("find_result already consumed")
         }
 
+        async fn exists(
+            &self,
+            _id: domain::value_object::jira::JiraProjectId,
+        ) -> Result<bool, JiraError> {
+            Ok(false)
+        }
+
         async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
             Ok(project)
         }
 
+        async fn create_many(
+            &self,
+            projects: Vec<JiraProject>,
+        ) -> Result<Vec<JiraProject>, JiraError> {
+            Ok(projects)
+        }
+
         async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
             self.update_result
                 .lock()
@@ -147,8 +195,9 @@ mod tests {
 
         let input = UpdateJiraProjectDto {
             id: "123".to_string(),
-            key: "TEST".to_string(),
-            name: "Updated Project".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Updated Project".to_string()),
+            expected_updated_at: None,
         };
 
         let result = usecase.execute(input).await;
@@ -170,8 +219,9 @@ mod tests {
 
         let input = UpdateJiraProjectDto {
             id: "invalid".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Test Project".to_string()),
+            expected_updated_at: None,
         };
 
         let result = usecase.execute(input).await;
@@ -193,8 +243,9 @@ mod tests {
 
         let input = UpdateJiraProjectDto {
             id: "123".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Test Project".to_string()),
+            expected_updated_at: None,
         };
 
         let result = usecase.execute(input).await;
@@ -217,8 +268,9 @@ mod tests {
 
         let input = UpdateJiraProjectDto {
             id: "123".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Test Project".to_string()),
+            expected_updated_at: None,
         };
 
         let result = usecase.execute(input).await;
@@ -240,8 +292,9 @@ mod tests {
 
         let input = UpdateJiraProjectDto {
             id: "123".to_string(),
-            key: "TEST".to_string(),
-            name: "Test Project".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Test Project".to_string()),
+            expected_updated_at: None,
         };
 
         let result = usecase.execute(input).await;
@@ -252,4 +305,170 @@ mod tests {
             JiraProjectUpdateError::FindFailed(_)
         ));
     }
+
+    #[tokio::test]
+    async fn execute_should_return_conflict_when_project_version_is_stale() {
+        let existing_project = JiraProject::of("123", "TEST", "Test Project")
+            .unwrap()
+            .with_updated_at(chrono::Utc::now());
+        let repo = Arc::new(MockJiraProjectRepository::new(
+            Ok(Some(existing_project)),
+            Err(JiraError::conflict("Project 123 was modified")),
+        ));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: Some("TEST".to_string()),
+            name: Some("Renamed Project".to_string()),
+            expected_updated_at: None,
+        };
+
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectUpdateError::Conflict(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_update_only_name_when_key_is_omitted() {
+        let existing_project = JiraProject::of("123", "KEEP", "Old Project").unwrap();
+        let repo = Arc::new(MockJiraProjectRepository::echoing(Ok(Some(
+            existing_project,
+        ))));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: None,
+            name: Some("Renamed Project".to_string()),
+            expected_updated_at: None,
+        };
+
+        let project = usecase.execute(input).await.unwrap();
+
+        assert_eq!(project.key.value(), "KEEP");
+        assert_eq!(project.name.value(), "Renamed Project");
+    }
+
+    #[tokio::test]
+    async fn execute_should_update_only_key_when_name_is_omitted() {
+        let existing_project = JiraProject::of("123", "OLD", "Kept Name").unwrap();
+        let repo = Arc::new(MockJiraProjectRepository::echoing(Ok(Some(
+            existing_project,
+        ))));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: Some("NEW".to_string()),
+            name: None,
+            expected_updated_at: None,
+        };
+
+        let project = usecase.execute(input).await.unwrap();
+
+        assert_eq!(project.key.value(), "NEW");
+        assert_eq!(project.name.value(), "Kept Name");
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_existing_project_without_writing_when_no_fields_given() {
+        let existing_project = JiraProject::of("123", "TEST", "Test Project").unwrap();
+        let repo = Arc::new(MockJiraProjectRepository::new(
+            Ok(Some(existing_project.clone())),
+            Err(JiraError::database_error("Should not be called")),
+        ));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: None,
+            name: None,
+            expected_updated_at: None,
+        };
+
+        let result = usecase.execute(input).await;
+
+        assert_eq!(result.unwrap(), existing_project);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_validation_error_when_provided_name_is_empty() {
+        let existing_project = JiraProject::of("123", "TEST", "Test Project").unwrap();
+        let repo = Arc::new(MockJiraProjectRepository::new(
+            Ok(Some(existing_project)),
+            Err(JiraError::database_error("Should not be called")),
+        ));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: None,
+            name: Some(String::new()),
+            expected_updated_at: None,
+        };
+
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectUpdateError::ValidationFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_conflict_without_writing_when_client_version_is_stale() {
+        let stored_version = chrono::Utc::now();
+        let client_version = stored_version - chrono::Duration::seconds(5);
+        let existing_project = JiraProject::of("123", "TEST", "Test Project")
+            .unwrap()
+            .with_updated_at(stored_version);
+        let repo = Arc::new(MockJiraProjectRepository::new(
+            Ok(Some(existing_project)),
+            Err(JiraError::database_error("Should not be called")),
+        ));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo.clone());
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: None,
+            name: Some("Renamed Project".to_string()),
+            expected_updated_at: Some(client_version),
+        };
+
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectUpdateError::Conflict(_)
+        ));
+        assert!(repo.update_result.lock().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn execute_should_update_when_client_version_matches() {
+        let version = chrono::Utc::now();
+        let existing_project = JiraProject::of("123", "TEST", "Test Project")
+            .unwrap()
+            .with_updated_at(version);
+        let repo = Arc::new(MockJiraProjectRepository::echoing(Ok(Some(
+            existing_project,
+        ))));
+        let usecase = JiraProjectUpdateUseCaseImpl::new(repo);
+
+        let input = UpdateJiraProjectDto {
+            id: "123".to_string(),
+            key: None,
+            name: Some("Renamed Project".to_string()),
+            expected_updated_at: Some(version),
+        };
+
+        let project = usecase.execute(input).await.unwrap();
+
+        assert_eq!(project.name.value(), "Renamed Project");
+        assert_eq!(project.updated_at, Some(version));
+    }
 }
@@ -1,9 +1,24 @@
+mod jira_issue_changelog_sync_usecase;
 mod jira_issue_sync_usecase;
+mod jira_project_batch_create_usecase;
 mod jira_project_create_usecase;
+mod jira_project_single_sync_usecase;
 mod jira_project_sync_usecase;
 mod jira_project_update_usecase;
+mod sync_lock;
 
-pub use jira_issue_sync_usecase::{JiraIssueSyncUseCase, JiraIssueSyncUseCaseImpl};
+pub use jira_issue_changelog_sync_usecase::{
+    JiraIssueChangelogSyncUseCase, JiraIssueChangelogSyncUseCaseImpl,
+};
+pub use jira_issue_sync_usecase::{
+    DEFAULT_PROJECT_KEY_CHUNK_SIZE, JiraIssueSyncUseCase, JiraIssueSyncUseCaseImpl,
+};
+pub use jira_project_batch_create_usecase::{
+    JiraProjectBatchCreateUseCase, JiraProjectBatchCreateUseCaseImpl,
+};
 pub use jira_project_create_usecase::{JiraProjectCreateUseCase, JiraProjectCreateUseCaseImpl};
+pub use jira_project_single_sync_usecase::{
+    JiraProjectSingleSyncUseCase, JiraProjectSingleSyncUseCaseImpl,
+};
 pub use jira_project_sync_usecase::{JiraProjectSyncUseCase, JiraProjectSyncUseCaseImpl};
 pub use jira_project_update_usecase::{JiraProjectUpdateUseCase, JiraProjectUpdateUseCaseImpl};
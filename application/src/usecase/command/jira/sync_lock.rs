@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use domain::error::JiraError;
+use domain::port::jira::{JiraSyncKind, JiraSyncLock, JiraSyncLockPort};
+
+/// Acquires the lock of `kind` if the use case was built with a lock port.
+///
+/// Fails with `already_running` when another sync of the same kind holds the lock.
+pub(super) async fn acquire_sync_lock<E>(
+    port: Option<&Arc<dyn JiraSyncLockPort>>,
+    kind: JiraSyncKind,
+    already_running: E,
+    lock_failed: fn(JiraError) -> E,
+) -> Result<Option<Box<dyn JiraSyncLock>>, E> {
+    let Some(port) = port else {
+        return Ok(None);
+    };
+    match port.try_acquire(kind).await {
+        Ok(Some(lock)) => Ok(Some(lock)),
+        Ok(None) => Err(already_running),
+        Err(e) => Err(lock_failed(e)),
+    }
+}
+
+/// Releases a lock taken by [`acquire_sync_lock`], if any.
+pub(super) async fn release_sync_lock(lock: Option<Box<dyn JiraSyncLock>>) {
+    if let Some(lock) = lock {
+        lock.release().await;
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::port::jira::JiraAuthPort;
+
+use crate::error::query::jira::JiraAuthCheckQueryError;
+
+/// Use case for checking that the configured Jira credentials work.
+#[async_trait]
+pub trait JiraAuthCheckQueryUseCase: Send + Sync {
+    /// Checks the configured credentials against the Jira API.
+    ///
+    /// # Returns
+    /// Ok if Jira accepts the credentials, or an error
+    async fn execute(&self) -> Result<(), JiraAuthCheckQueryError>;
+}
+
+/// Implementation of JiraAuthCheckQueryUseCase.
+pub struct JiraAuthCheckQueryUseCaseImpl<P>
+where
+    P: JiraAuthPort,
+{
+    port: Arc<P>,
+}
+
+impl<P> JiraAuthCheckQueryUseCaseImpl<P>
+where
+    P: JiraAuthPort,
+{
+    pub fn new(port: Arc<P>) -> Self {
+        Self { port }
+    }
+}
+
+#[async_trait]
+impl<P> JiraAuthCheckQueryUseCase for JiraAuthCheckQueryUseCaseImpl<P>
+where
+    P: JiraAuthPort,
+{
+    async fn execute(&self) -> Result<(), JiraAuthCheckQueryError> {
+        self.port
+            .validate_credentials()
+            .await
+            .map_err(JiraAuthCheckQueryError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+    use std::sync::Mutex;
+
+    struct MockJiraAuthPort {
+        result: Mutex<Option<Result<(), JiraError>>>,
+    }
+
+    impl MockJiraAuthPort {
+        fn new(result: Result<(), JiraError>) -> Self {
+            Self {
+                result: Mutex::new(Some(result)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl JiraAuthPort for MockJiraAuthPort {
+        async fn validate_credentials(&self) -> Result<(), JiraError> {
+            self.result
+                .lock()
+                .unwrap()
+                .take()
+                .expect("result already consumed")
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_should_succeed_when_credentials_are_valid() {
+        let usecase = JiraAuthCheckQueryUseCaseImpl::new(Arc::new(MockJiraAuthPort::new(Ok(()))));
+
+        let result = usecase.execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_credentials_when_unauthorized() {
+        let usecase = JiraAuthCheckQueryUseCaseImpl::new(Arc::new(MockJiraAuthPort::new(Err(
+            JiraError::unauthorized("401 Unauthorized"),
+        ))));
+
+        let result = usecase.execute().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraAuthCheckQueryError::InvalidCredentials(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_check_failed_when_api_fails() {
+        let usecase = JiraAuthCheckQueryUseCaseImpl::new(Arc::new(MockJiraAuthPort::new(Err(
+            JiraError::api_error("500 Internal Server Error"),
+        ))));
+
+        let result = usecase.execute().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraAuthCheckQueryError::CheckFailed(_)
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream};
+
+use domain::value_object::PageSize;
+use domain::value_object::jira::JiraIssueId;
+
+use crate::dto::query::jira::JiraIssueQueryDto;
+use crate::error::query::jira::JiraIssueExportQueryError;
+use crate::repository::jira::JiraIssueQueryRepository;
+
+/// Use case for exporting all Jira issues in stable id order.
+pub trait JiraIssueExportQueryUseCase: Send + Sync {
+    /// Streams every issue in batches using keyset pagination on id.
+    ///
+    /// # Arguments
+    /// * `batch_size` - The number of issues fetched per batch
+    ///
+    /// # Returns
+    /// A stream of issue batches; the stream ends after the last batch or the first error
+    fn execute(
+        &self,
+        batch_size: i32,
+    ) -> BoxStream<'_, Result<Vec<JiraIssueQueryDto>, JiraIssueExportQueryError>>;
+}
+
+/// Implementation of JiraIssueExportQueryUseCase.
+pub struct JiraIssueExportQueryUseCaseImpl<R: JiraIssueQueryRepository> {
+    jira_issue_repository: Arc<R>,
+}
+
+impl<R: JiraIssueQueryRepository> JiraIssueExportQueryUseCaseImpl<R> {
+    pub fn new(jira_issue_repository: Arc<R>) -> Self {
+        Self {
+            jira_issue_repository,
+        }
+    }
+}
+
+impl<R: JiraIssueQueryRepository> JiraIssueExportQueryUseCase
+    for JiraIssueExportQueryUseCaseImpl<R>
+{
+    fn execute(
+        &self,
+        batch_size: i32,
+    ) -> BoxStream<'_, Result<Vec<JiraIssueQueryDto>, JiraIssueExportQueryError>> {
+        let valid_batch_size = match PageSize::of(batch_size) {
+            Ok(size) => size,
+            Err(e) => {
+                return Box::pin(stream::once(async move {
+                    Err(JiraIssueExportQueryError::InvalidBatchSize(e))
+                }));
+            }
+        };
+
+        // State: Some(cursor) while there may be more rows, None once exhausted.
+        Box::pin(stream::unfold(
+            Some(None::<JiraIssueId>),
+            move |state| async move {
+                let cursor = state?;
+
+                match self
+                    .jira_issue_repository
+                    .list_after(cursor, valid_batch_size)
+                    .await
+                {
+                    Ok(batch) if batch.is_empty() => None,
+                    Ok(batch) => {
+                        let next = if batch.len() < valid_batch_size.value() as usize {
+                            None
+                        } else {
+                            batch.last().map(|dto| Some(JiraIssueId::new(dto.id)))
+                        };
+                        Some((Ok(batch), next))
+                    }
+                    Err(e) => Some((Err(JiraIssueExportQueryError::IssueFetchFailed(e)), None)),
+                }
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+    use domain::value_object::jira::JiraProjectId;
+    use futures::StreamExt;
+    use std::collections::HashSet;
+
+    use crate::test_support::jira::{
+        InMemoryJiraIssueQueryRepository, JiraIssueQueryRepositoryMethod,
+    };
+
+    fn repository_with(count: i64) -> InMemoryJiraIssueQueryRepository {
+        InMemoryJiraIssueQueryRepository::new().with_project_issues(
+            JiraProjectId::new(1),
+            (1..=count).map(create_test_dto).collect(),
+        )
+    }
+
+    fn create_test_dto(id: i64) -> JiraIssueQueryDto {
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("TEST-{}", id))
+            .summary(format!("Test Issue {}", id))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn execute_should_yield_every_issue_exactly_once() {
+        let repository = Arc::new(repository_with(1000));
+        let usecase = JiraIssueExportQueryUseCaseImpl::new(repository);
+
+        let batches: Vec<_> = usecase.execute(100).collect().await;
+
+        assert_eq!(batches.len(), 10);
+        let ids: Vec<i64> = batches
+            .into_iter()
+            .flat_map(|batch| batch.unwrap())
+            .map(|dto| dto.id)
+            .collect();
+        let unique: HashSet<i64> = ids.iter().copied().collect();
+        assert_eq!(ids.len(), 1000);
+        assert_eq!(unique.len(), 1000);
+    }
+
+    #[tokio::test]
+    async fn execute_should_stop_after_partial_last_batch() {
+        let repository = Arc::new(repository_with(250));
+        let usecase = JiraIssueExportQueryUseCaseImpl::new(repository);
+
+        let batches: Vec<_> = usecase.execute(100).collect().await;
+
+        let sizes: Vec<usize> = batches.into_iter().map(|b| b.unwrap().len()).collect();
+        assert_eq!(sizes, vec![100, 100, 50]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_batch_size_when_batch_size_is_zero() {
+        let repository = Arc::new(repository_with(10));
+        let usecase = JiraIssueExportQueryUseCaseImpl::new(repository);
+
+        let batches: Vec<_> = usecase.execute(0).collect().await;
+
+        assert_eq!(batches.len(), 1);
+        assert!(matches!(
+            batches[0],
+            Err(JiraIssueExportQueryError::InvalidBatchSize(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_issue_fetch_failed_when_repository_fails() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::ListAfter,
+            JiraError::database_error("Connection failed"),
+        ));
+        let usecase = JiraIssueExportQueryUseCaseImpl::new(repository);
+
+        let batches: Vec<_> = usecase.execute(100).collect().await;
+
+        assert_eq!(batches.len(), 1);
+        assert!(matches!(
+            batches[0],
+            Err(JiraIssueExportQueryError::IssueFetchFailed(_))
+        ));
+    }
+}
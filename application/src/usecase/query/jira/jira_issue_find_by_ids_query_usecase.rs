@@ -56,61 +56,27 @@ impl<R: JiraIssueQueryRepository> JiraIssueFindByIdsQueryUseCase
 mod tests {
     use super::*;
     use domain::error::JiraError;
-    use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
-    use domain::value_object::{Page, PageNumber, PageSize};
-    use std::sync::Mutex;
+    use domain::value_object::jira::JiraProjectId;
 
-    struct MockJiraIssueQueryRepository {
-        find_by_ids_result: Mutex<Option<Result<Vec<JiraIssueQueryDto>, JiraError>>>,
-    }
-
-    impl MockJiraIssueQueryRepository {
-        fn new(find_by_ids_result: Result<Vec<JiraIssueQueryDto>, JiraError>) -> Self {
-            Self {
-                find_by_ids_result: Mutex::new(Some(find_by_ids_result)),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl JiraIssueQueryRepository for MockJiraIssueQueryRepository {
-        async fn find_by_ids(
-            &self,
-            _ids: Vec<JiraIssueId>,
-        ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
-            self.find_by_ids_result
-                .lock()
-                .unwrap()
-                .take()
-                .expect("find_by_ids_result already consumed")
-        }
-
-        async fn list(
-            &self,
-            _page_number: PageNumber,
-            _page_size: PageSize,
-        ) -> Result<Page<JiraIssueQueryDto>, JiraError> {
-            unimplemented!()
-        }
-    }
+    use crate::test_support::jira::{
+        InMemoryJiraIssueQueryRepository, JiraIssueQueryRepositoryMethod,
+    };
 
     fn create_test_dto(id: i64) -> JiraIssueQueryDto {
-        JiraIssueQueryDto::new(
-            id,
-            format!("TEST-{}", id),
-            format!("Test Issue {}", id),
-            None,
-            JiraIssueType::Task,
-            JiraIssuePriority::Medium,
-            chrono::Utc::now(),
-            chrono::Utc::now(),
-        )
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("TEST-{}", id))
+            .summary(format!("Test Issue {}", id))
+            .build()
     }
 
     #[tokio::test]
     async fn execute_should_return_issues_when_found() {
-        let dtos = vec![create_test_dto(1), create_test_dto(2)];
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(dtos.clone())));
+        let dtos = vec![create_test_dto(1), create_test_dto(2), create_test_dto(3)];
+        let repository = Arc::new(
+            InMemoryJiraIssueQueryRepository::new()
+                .with_project_issues(JiraProjectId::new(1), dtos),
+        );
         let usecase = JiraIssueFindByIdsQueryUseCaseImpl::new(repository);
 
         let result = usecase
@@ -119,12 +85,12 @@ mod tests {
 
         assert!(result.is_ok());
         let found_dtos = result.unwrap();
-        assert_eq!(found_dtos.len(), 2);
+        assert_eq!(found_dtos, vec![create_test_dto(1), create_test_dto(2)]);
     }
 
     #[tokio::test]
     async fn execute_should_return_empty_vec_when_no_issues_found() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(vec![])));
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
         let usecase = JiraIssueFindByIdsQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(vec![JiraIssueId::new(999)]).await;
@@ -135,9 +101,10 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_issue_fetch_failed_when_repository_fails() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Err(
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::FindByIds,
             JiraError::database_error("Connection failed"),
-        )));
+        ));
         let usecase = JiraIssueFindByIdsQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(vec![JiraIssueId::new(1)]).await;
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::value_object::jira::JiraIssueKey;
+
+use crate::dto::query::jira::JiraIssueLinkQueryDto;
+use crate::error::query::jira::JiraIssueLinkFindByKeysQueryError;
+use crate::repository::jira::JiraIssueLinkQueryRepository;
+
+/// Use case for finding the links of Jira issues by issue key.
+#[async_trait]
+pub trait JiraIssueLinkFindByKeysQueryUseCase: Send + Sync {
+    /// Finds every link that has one of the given issues at either end.
+    ///
+    /// # Arguments
+    /// * `keys` - The issue keys whose links to find
+    ///
+    /// # Returns
+    /// The links involving any of the keys, each listed once
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraIssueLinkFindByKeysQueryError>;
+}
+
+/// Implementation of JiraIssueLinkFindByKeysQueryUseCase.
+pub struct JiraIssueLinkFindByKeysQueryUseCaseImpl<R: JiraIssueLinkQueryRepository> {
+    jira_issue_link_repository: Arc<R>,
+}
+
+impl<R: JiraIssueLinkQueryRepository> JiraIssueLinkFindByKeysQueryUseCaseImpl<R> {
+    pub fn new(jira_issue_link_repository: Arc<R>) -> Self {
+        Self {
+            jira_issue_link_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: JiraIssueLinkQueryRepository> JiraIssueLinkFindByKeysQueryUseCase
+    for JiraIssueLinkFindByKeysQueryUseCaseImpl<R>
+{
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraIssueLinkFindByKeysQueryError> {
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.jira_issue_link_repository
+            .find_by_issue_keys(keys)
+            .await
+            .map_err(JiraIssueLinkFindByKeysQueryError::LinkFetchFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+    use std::sync::Mutex;
+
+    struct MockJiraIssueLinkQueryRepository {
+        result: Mutex<Option<Result<Vec<JiraIssueLinkQueryDto>, JiraError>>>,
+    }
+
+    impl MockJiraIssueLinkQueryRepository {
+        fn new(result: Result<Vec<JiraIssueLinkQueryDto>, JiraError>) -> Self {
+            Self {
+                result: Mutex::new(Some(result)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl JiraIssueLinkQueryRepository for MockJiraIssueLinkQueryRepository {
+        async fn find_by_issue_keys(
+            &self,
+            _keys: Vec<JiraIssueKey>,
+        ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraError> {
+            self.result
+                .lock()
+                .unwrap()
+                .take()
+                .expect("result already consumed")
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_links_when_found() {
+        let links = vec![JiraIssueLinkQueryDto::new(
+            "PROJ-1".to_string(),
+            "PROJ-2".to_string(),
+            "Blocks".to_string(),
+        )];
+        let repo = Arc::new(MockJiraIssueLinkQueryRepository::new(Ok(links.clone())));
+        let usecase = JiraIssueLinkFindByKeysQueryUseCaseImpl::new(repo);
+
+        let result = usecase.execute(vec![JiraIssueKey::new("PROJ-1")]).await;
+
+        assert_eq!(result.unwrap(), links);
+    }
+
+    #[tokio::test]
+    async fn execute_should_skip_repository_when_keys_are_empty() {
+        let repo = Arc::new(MockJiraIssueLinkQueryRepository::new(Err(
+            JiraError::database_error("must not be called"),
+        )));
+        let usecase = JiraIssueLinkFindByKeysQueryUseCaseImpl::new(repo);
+
+        let result = usecase.execute(vec![]).await;
+
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_link_fetch_failed_when_repository_fails() {
+        let repo = Arc::new(MockJiraIssueLinkQueryRepository::new(Err(
+            JiraError::database_error("Connection failed"),
+        )));
+        let usecase = JiraIssueLinkFindByKeysQueryUseCaseImpl::new(repo);
+
+        let result = usecase.execute(vec![JiraIssueKey::new("PROJ-1")]).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueLinkFindByKeysQueryError::LinkFetchFailed(_)
+        ));
+    }
+}
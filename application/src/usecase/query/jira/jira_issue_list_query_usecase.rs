@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use domain::value_object::{Page, PageNumber, PageSize};
+use domain::value_object::{PageNumber, PageSize};
 
-use crate::dto::query::jira::JiraIssueQueryDto;
+use crate::dto::query::jira::{
+    JiraIssueListFilter, JiraIssueListQueryMeta, JiraIssueListQueryResult, JiraIssueListSummary,
+};
 use crate::error::query::jira::JiraIssueListQueryError;
 use crate::repository::jira::JiraIssueQueryRepository;
 
@@ -16,14 +18,23 @@ pub trait JiraIssueListQueryUseCase: Send + Sync {
     /// # Arguments
     /// * `page_number` - The page number (1-indexed)
     /// * `page_size` - The number of items per page
+    /// * `filter` - Optional filters to narrow the result
     ///
     /// # Returns
-    /// A page of Jira issues or an error
+    /// A page of Jira issues with the sort and filters it was produced with, or an error
     async fn execute(
         &self,
         page_number: i32,
         page_size: i32,
-    ) -> Result<Page<JiraIssueQueryDto>, JiraIssueListQueryError>;
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListQueryResult, JiraIssueListQueryError>;
+
+    /// Counts the issues `execute` would list for `filter` by type and by priority,
+    /// across all pages.
+    async fn summarize(
+        &self,
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraIssueListQueryError>;
 }
 
 /// Implementation of JiraIssueListUseCase.
@@ -45,95 +56,80 @@ impl<R: JiraIssueQueryRepository> JiraIssueListQueryUseCase for JiraIssueListQue
         &self,
         page_number: i32,
         page_size: i32,
-    ) -> Result<Page<JiraIssueQueryDto>, JiraIssueListQueryError> {
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListQueryResult, JiraIssueListQueryError> {
         let valid_page_number =
             PageNumber::of(page_number).map_err(JiraIssueListQueryError::InvalidPageNumber)?;
 
         let valid_page_size =
             PageSize::of(page_size).map_err(JiraIssueListQueryError::InvalidPageSize)?;
 
+        let page = self
+            .jira_issue_repository
+            .list(valid_page_number, valid_page_size, &filter)
+            .await
+            .map_err(JiraIssueListQueryError::from)?;
+
+        Ok(JiraIssueListQueryResult {
+            page,
+            meta: JiraIssueListQueryMeta::from_filter(&filter),
+        })
+    }
+
+    async fn summarize(
+        &self,
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraIssueListQueryError> {
         self.jira_issue_repository
-            .list(valid_page_number, valid_page_size)
+            .summarize(&filter)
             .await
-            .map_err(JiraIssueListQueryError::IssueFetchFailed)
+            .map_err(JiraIssueListQueryError::from)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use domain::error::JiraError;
-    use domain::value_object::jira::{JiraIssueId, JiraIssuePriority, JiraIssueType};
-    use std::sync::Mutex;
-
-    struct MockJiraIssueQueryRepository {
-        list_result: Mutex<Option<Result<Page<JiraIssueQueryDto>, JiraError>>>,
-    }
-
-    impl MockJiraIssueQueryRepository {
-        fn new(list_result: Result<Page<JiraIssueQueryDto>, JiraError>) -> Self {
-            Self {
-                list_result: Mutex::new(Some(list_result)),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl JiraIssueQueryRepository for MockJiraIssueQueryRepository {
-        async fn find_by_ids(
-            &self,
-            _ids: Vec<JiraIssueId>,
-        ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
-            unimplemented!()
-        }
-
-        async fn list(
-            &self,
-            _page_number: PageNumber,
-            _page_size: PageSize,
-        ) -> Result<Page<JiraIssueQueryDto>, JiraError> {
-            self.list_result
-                .lock()
-                .unwrap()
-                .take()
-                .expect("list_result already consumed")
-        }
-    }
+    use crate::dto::query::jira::{
+        JiraIssueListSort, JiraIssuePriorityCount, JiraIssueQueryDto, JiraIssueTypeCount,
+        JiraSortDirection,
+    };
+    use crate::test_support::jira::{
+        InMemoryJiraIssueQueryRepository, JiraIssueQueryRepositoryMethod,
+    };
+    use domain::error::{JiraError, PageNumberError};
+    use domain::value_object::jira::{JiraIssueType, JiraProjectId};
 
     fn create_test_dto(id: i64) -> JiraIssueQueryDto {
-        JiraIssueQueryDto::new(
-            id,
-            format!("TEST-{}", id),
-            format!("Test Issue {}", id),
-            None,
-            JiraIssueType::Task,
-            JiraIssuePriority::Medium,
-            chrono::Utc::now(),
-            chrono::Utc::now(),
-        )
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("TEST-{}", id))
+            .summary(format!("Test Issue {}", id))
+            .build()
     }
 
     #[tokio::test]
     async fn execute_should_return_page_of_issues_with_valid_pagination() {
-        let dtos: Vec<JiraIssueQueryDto> = (1..=10).map(create_test_dto).collect();
-        let expected_page = Page::new(100, dtos);
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(expected_page.clone())));
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().with_project_issues(
+            JiraProjectId::new(1),
+            (1..=100).map(create_test_dto).collect(),
+        ));
         let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
 
-        let result = usecase.execute(1, 10).await;
+        let result = usecase.execute(1, 10, JiraIssueListFilter::default()).await;
 
         assert!(result.is_ok());
-        let page = result.unwrap();
+        let page = result.unwrap().page;
         assert_eq!(page.total_count, 100);
         assert_eq!(page.items.len(), 10);
     }
 
     #[tokio::test]
     async fn execute_should_return_invalid_page_number_when_page_number_is_zero() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
         let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
 
-        let result = usecase.execute(0, 10).await;
+        let result = usecase.execute(0, 10, JiraIssueListFilter::default()).await;
 
         assert!(result.is_err());
         assert!(matches!(
@@ -142,12 +138,27 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn execute_should_report_negative_page_number_in_error_message() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+
+        let result = usecase
+            .execute(-3, 10, JiraIssueListFilter::default())
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid page number: Page number must be at least 1, but was -3"
+        );
+    }
+
     #[tokio::test]
     async fn execute_should_return_invalid_page_size_when_page_size_is_zero() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
         let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
 
-        let result = usecase.execute(1, 0).await;
+        let result = usecase.execute(1, 0, JiraIssueListFilter::default()).await;
 
         assert!(result.is_err());
         assert!(matches!(
@@ -158,10 +169,12 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_invalid_page_size_when_page_size_exceeds_maximum() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
         let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
 
-        let result = usecase.execute(1, 101).await;
+        let result = usecase
+            .execute(1, 101, JiraIssueListFilter::default())
+            .await;
 
         assert!(result.is_err());
         assert!(matches!(
@@ -172,12 +185,13 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_issue_fetch_failed_when_repository_fails() {
-        let repository = Arc::new(MockJiraIssueQueryRepository::new(Err(
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::List,
             JiraError::database_error("Connection failed"),
-        )));
+        ));
         let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
 
-        let result = usecase.execute(1, 10).await;
+        let result = usecase.execute(1, 10, JiraIssueListFilter::default()).await;
 
         assert!(result.is_err());
         assert!(matches!(
@@ -185,4 +199,129 @@ mod tests {
             JiraIssueListQueryError::IssueFetchFailed(_)
         ));
     }
+
+    #[tokio::test]
+    async fn execute_should_pass_reported_by_filter_to_repository() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository.clone());
+        let filter = JiraIssueListFilter::new(Some("account-1".to_string()));
+
+        let result = usecase.execute(1, 10, filter.clone()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(repository.filters(), vec![filter]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_page_number_when_offset_overflows() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+
+        let result = usecase
+            .execute(i32::MAX, 100, JiraIssueListFilter::default())
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueListQueryError::InvalidPageNumber(PageNumberError::AboveMaximum { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_repository_timeout_when_repository_times_out() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::List,
+            JiraError::timeout("canceling statement due to statement timeout"),
+        ));
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute(1, 10, JiraIssueListFilter::default()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueListQueryError::RepositoryTimeout(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_echo_default_sort_and_filters_when_arguments_are_omitted() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+
+        let meta = usecase
+            .execute(1, 10, JiraIssueListFilter::default())
+            .await
+            .unwrap()
+            .meta;
+
+        assert_eq!(meta.sort_by, JiraIssueListSort::UpdatedAt);
+        assert_eq!(meta.direction, JiraSortDirection::Descending);
+        assert_eq!(meta.filters.reported_by, None);
+        assert!(!meta.filters.overdue);
+        assert_eq!(meta.filters.synced_before, None);
+    }
+
+    #[tokio::test]
+    async fn execute_should_echo_given_sort_and_filters() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+        let filter = JiraIssueListFilter::new(Some("account-1".to_string()))
+            .with_overdue(true)
+            .with_sort(JiraIssueListSort::Watches);
+
+        let meta = usecase.execute(1, 10, filter.clone()).await.unwrap().meta;
+
+        assert_eq!(meta.sort_by, JiraIssueListSort::Watches);
+        assert_eq!(meta.direction, JiraSortDirection::Descending);
+        assert_eq!(meta.filters, filter);
+    }
+
+    #[tokio::test]
+    async fn summarize_should_pass_filter_to_repository() {
+        let bug = |id| {
+            JiraIssueQueryDto::builder()
+                .id(id)
+                .issue_type(JiraIssueType::Bug)
+                .build()
+        };
+        let repository = Arc::new(
+            InMemoryJiraIssueQueryRepository::new()
+                .with_project_issues(JiraProjectId::new(1), vec![bug(1), bug(2)]),
+        );
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository.clone());
+        let filter = JiraIssueListFilter::new(Some("account-1".to_string())).with_overdue(true);
+
+        let result = usecase.summarize(filter.clone()).await;
+
+        assert_eq!(
+            result.unwrap(),
+            JiraIssueListSummary {
+                by_type: vec![JiraIssueTypeCount {
+                    issue_type: JiraIssueType::Bug,
+                    count: 2,
+                }],
+                by_priority: vec![JiraIssuePriorityCount {
+                    priority: None,
+                    count: 2,
+                }],
+            }
+        );
+        assert_eq!(repository.filters(), vec![filter]);
+    }
+
+    #[tokio::test]
+    async fn summarize_should_return_repository_timeout_when_repository_times_out() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::Summarize,
+            JiraError::timeout("canceling statement due to statement timeout"),
+        ));
+        let usecase = JiraIssueListQueryUseCaseImpl::new(repository);
+
+        let result = usecase.summarize(JiraIssueListFilter::default()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueListQueryError::RepositoryTimeout(_)
+        ));
+    }
 }
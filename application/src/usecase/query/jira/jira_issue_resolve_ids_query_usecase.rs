@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey};
+
+use crate::error::query::jira::JiraIssueResolveIdsQueryError;
+use crate::repository::jira::JiraIssueQueryRepository;
+
+/// Use case for resolving Jira issue keys to internal issue IDs.
+#[async_trait]
+pub trait JiraIssueResolveIdsQueryUseCase: Send + Sync {
+    /// Resolves Jira issue keys to their IDs.
+    ///
+    /// # Arguments
+    /// * `keys` - The list of issue keys to resolve
+    ///
+    /// # Returns
+    /// A map from key to ID (keys that don't exist are absent)
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraIssueResolveIdsQueryError>;
+}
+
+/// Implementation of JiraIssueResolveIdsQueryUseCase.
+pub struct JiraIssueResolveIdsQueryUseCaseImpl<R: JiraIssueQueryRepository> {
+    jira_issue_repository: Arc<R>,
+}
+
+impl<R: JiraIssueQueryRepository> JiraIssueResolveIdsQueryUseCaseImpl<R> {
+    pub fn new(jira_issue_repository: Arc<R>) -> Self {
+        Self {
+            jira_issue_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: JiraIssueQueryRepository> JiraIssueResolveIdsQueryUseCase
+    for JiraIssueResolveIdsQueryUseCaseImpl<R>
+{
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraIssueResolveIdsQueryError> {
+        self.jira_issue_repository
+            .find_ids_by_keys(keys)
+            .await
+            .map_err(JiraIssueResolveIdsQueryError::IssueFetchFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+    use domain::value_object::jira::JiraProjectId;
+
+    use crate::dto::query::jira::JiraIssueQueryDto;
+    use crate::test_support::jira::{
+        InMemoryJiraIssueQueryRepository, JiraIssueQueryRepositoryMethod,
+    };
+
+    /// A repository holding an issue for each of the given keys and ids.
+    fn repository_with(known: &[(&str, i64)]) -> InMemoryJiraIssueQueryRepository {
+        InMemoryJiraIssueQueryRepository::new().with_project_issues(
+            JiraProjectId::new(1),
+            known
+                .iter()
+                .map(|(key, id)| JiraIssueQueryDto::builder().id(*id).key(*key).build())
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn execute_should_omit_unknown_keys_when_resolving_mixed_keys() {
+        let repository = Arc::new(repository_with(&[("PROJ-1", 10), ("PROJ-2", 20)]));
+        let usecase = JiraIssueResolveIdsQueryUseCaseImpl::new(repository);
+
+        let result = usecase
+            .execute(vec![
+                JiraIssueKey::new("PROJ-1"),
+                JiraIssueKey::new("MISSING-1"),
+                JiraIssueKey::new("PROJ-2"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result.get(&JiraIssueKey::new("PROJ-1")),
+            Some(&JiraIssueId::new(10))
+        );
+        assert_eq!(
+            result.get(&JiraIssueKey::new("PROJ-2")),
+            Some(&JiraIssueId::new(20))
+        );
+        assert!(!result.contains_key(&JiraIssueKey::new("MISSING-1")));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_issue_fetch_failed_when_repository_fails() {
+        let repository = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::FindIdsByKeys,
+            JiraError::database_error("Connection failed"),
+        ));
+        let usecase = JiraIssueResolveIdsQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute(vec![JiraIssueKey::new("PROJ-1")]).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraIssueResolveIdsQueryError::IssueFetchFailed(_)
+        ));
+    }
+}
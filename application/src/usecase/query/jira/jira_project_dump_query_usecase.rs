@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey};
+
+use crate::dto::query::jira::JiraProjectDumpDto;
+use crate::error::query::jira::JiraProjectDumpQueryError;
+use crate::repository::jira::{JiraIssueQueryRepository, JiraProjectQueryRepository};
+
+/// Use case for reading a project and all of its issues, e.g. for a support dump.
+#[async_trait]
+pub trait JiraProjectDumpQueryUseCase: Send + Sync {
+    /// Reads the project with the given key and every issue stored for it.
+    ///
+    /// # Arguments
+    /// * `key` - The project key
+    ///
+    /// # Returns
+    /// The project and its issues, or an error if the key is invalid or unknown
+    async fn execute(&self, key: String) -> Result<JiraProjectDumpDto, JiraProjectDumpQueryError>;
+}
+
+/// Implementation of JiraProjectDumpQueryUseCase.
+pub struct JiraProjectDumpQueryUseCaseImpl<P, I>
+where
+    P: JiraProjectQueryRepository,
+    I: JiraIssueQueryRepository,
+{
+    jira_project_repository: Arc<P>,
+    jira_issue_repository: Arc<I>,
+}
+
+impl<P, I> JiraProjectDumpQueryUseCaseImpl<P, I>
+where
+    P: JiraProjectQueryRepository,
+    I: JiraIssueQueryRepository,
+{
+    pub fn new(jira_project_repository: Arc<P>, jira_issue_repository: Arc<I>) -> Self {
+        Self {
+            jira_project_repository,
+            jira_issue_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, I> JiraProjectDumpQueryUseCase for JiraProjectDumpQueryUseCaseImpl<P, I>
+where
+    P: JiraProjectQueryRepository,
+    I: JiraIssueQueryRepository,
+{
+    async fn execute(&self, key: String) -> Result<JiraProjectDumpDto, JiraProjectDumpQueryError> {
+        let valid_key =
+            JiraProjectKey::of(key).map_err(JiraProjectDumpQueryError::InvalidProjectKey)?;
+
+        let project = self
+            .jira_project_repository
+            .find_by_key(&valid_key)
+            .await
+            .map_err(JiraProjectDumpQueryError::ProjectFetchFailed)?
+            .ok_or_else(|| JiraProjectDumpQueryError::ProjectNotFound(valid_key.to_string()))?;
+
+        let issues = self
+            .jira_issue_repository
+            .list_by_project(JiraProjectId::new(project.id))
+            .await
+            .map_err(JiraProjectDumpQueryError::IssueFetchFailed)?;
+
+        Ok(JiraProjectDumpDto { project, issues })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::JiraError;
+
+    use crate::dto::query::jira::{JiraIssueQueryDto, JiraProjectQueryDto};
+    use crate::test_support::jira::{
+        InMemoryJiraIssueQueryRepository, InMemoryJiraProjectQueryRepository,
+        JiraIssueQueryRepositoryMethod,
+    };
+
+    fn project() -> JiraProjectQueryDto {
+        JiraProjectQueryDto::new(10, "PROJ".to_string(), "Project".to_string())
+    }
+
+    fn issue(id: i64) -> JiraIssueQueryDto {
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("PROJ-{}", id))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_project_with_its_issues() {
+        let project_repo = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(vec![
+            project(),
+        ]));
+        let issue_repo = Arc::new(
+            InMemoryJiraIssueQueryRepository::new()
+                .with_project_issues(JiraProjectId::new(10), vec![issue(1), issue(2)])
+                .with_project_issues(JiraProjectId::new(20), vec![issue(3)]),
+        );
+        let usecase = JiraProjectDumpQueryUseCaseImpl::new(project_repo, issue_repo.clone());
+
+        let dump = usecase.execute("PROJ".to_string()).await.unwrap();
+
+        assert_eq!(dump.project, project());
+        assert_eq!(dump.issues, vec![issue(1), issue(2)]);
+        assert_eq!(
+            issue_repo.calls(),
+            vec![JiraIssueQueryRepositoryMethod::ListByProject]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_not_found_when_key_is_unknown() {
+        let project_repo = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(vec![
+            project(),
+        ]));
+        let issue_repo = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraProjectDumpQueryUseCaseImpl::new(project_repo, issue_repo.clone());
+
+        let result = usecase.execute("OTHER".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectDumpQueryError::ProjectNotFound(key) if key == "OTHER"
+        ));
+        assert!(issue_repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_project_key_when_key_is_empty() {
+        let project_repo = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let issue_repo = Arc::new(InMemoryJiraIssueQueryRepository::new());
+        let usecase = JiraProjectDumpQueryUseCaseImpl::new(project_repo, issue_repo);
+
+        let result = usecase.execute(String::new()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectDumpQueryError::InvalidProjectKey(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_issue_fetch_failed_when_issue_repository_fails() {
+        let project_repo = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(vec![
+            project(),
+        ]));
+        let issue_repo = Arc::new(InMemoryJiraIssueQueryRepository::new().fail_next(
+            JiraIssueQueryRepositoryMethod::ListByProject,
+            JiraError::database_error("Connection lost"),
+        ));
+        let usecase = JiraProjectDumpQueryUseCaseImpl::new(project_repo, issue_repo);
+
+        let result = usecase.execute("PROJ".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectDumpQueryError::IssueFetchFailed(_)
+        ));
+    }
+}
@@ -54,42 +54,10 @@ impl<R: JiraProjectQueryRepository> JiraProjectFindByIdsQueryUseCase
 mod tests {
     use super::*;
     use domain::error::JiraError;
-    use domain::value_object::{Page, PageNumber, PageSize};
-    use std::sync::Mutex;
 
-    struct MockJiraProjectQueryRepository {
-        find_result: Mutex<Option<Result<Vec<JiraProjectQueryDto>, JiraError>>>,
-    }
-
-    impl MockJiraProjectQueryRepository {
-        fn new(find_result: Result<Vec<JiraProjectQueryDto>, JiraError>) -> Self {
-            Self {
-                find_result: Mutex::new(Some(find_result)),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl JiraProjectQueryRepository for MockJiraProjectQueryRepository {
-        async fn find_by_ids(
-            &self,
-            _ids: Vec<JiraProjectId>,
-        ) -> Result<Vec<JiraProjectQueryDto>, JiraError> {
-            self.find_result
-                .lock()
-                .unwrap()
-                .take()
-                .expect("find_result already consumed")
-        }
-
-        async fn list(
-            &self,
-            _page_number: PageNumber,
-            _page_size: PageSize,
-        ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
-            unimplemented!()
-        }
-    }
+    use crate::test_support::jira::{
+        InMemoryJiraProjectQueryRepository, JiraProjectQueryRepositoryMethod,
+    };
 
     fn create_test_dto(id: i64) -> JiraProjectQueryDto {
         JiraProjectQueryDto::new(id, format!("PROJ{}", id), format!("Project {}", id))
@@ -97,8 +65,8 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_projects_when_found() {
-        let dtos = vec![create_test_dto(1), create_test_dto(2)];
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(dtos.clone())));
+        let dtos = vec![create_test_dto(1), create_test_dto(2), create_test_dto(3)];
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(dtos));
         let usecase = JiraProjectFindByIdsQueryUseCaseImpl::new(repository);
 
         let ids = vec![JiraProjectId::new(1), JiraProjectId::new(2)];
@@ -106,12 +74,12 @@ mod tests {
 
         assert!(result.is_ok());
         let projects = result.unwrap();
-        assert_eq!(projects.len(), 2);
+        assert_eq!(projects, vec![create_test_dto(1), create_test_dto(2)]);
     }
 
     #[tokio::test]
     async fn execute_should_return_empty_vec_when_no_projects_found() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(vec![])));
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
         let usecase = JiraProjectFindByIdsQueryUseCaseImpl::new(repository);
 
         let ids = vec![JiraProjectId::new(999)];
@@ -123,9 +91,10 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_project_fetch_failed_when_repository_fails() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Err(
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new().fail_next(
+            JiraProjectQueryRepositoryMethod::FindByIds,
             JiraError::database_error("Connection failed"),
-        )));
+        ));
         let usecase = JiraProjectFindByIdsQueryUseCaseImpl::new(repository);
 
         let ids = vec![JiraProjectId::new(1)];
@@ -55,50 +55,18 @@ impl<R: JiraProjectQueryRepository> JiraProjectListQueryUseCase
         self.repository
             .list(valid_page_number, valid_page_size)
             .await
-            .map_err(JiraProjectListQueryError::ProjectFetchFailed)
+            .map_err(JiraProjectListQueryError::from)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use domain::error::JiraError;
-    use domain::value_object::jira::JiraProjectId;
-    use std::sync::Mutex;
+    use domain::error::{JiraError, PageNumberError};
 
-    struct MockJiraProjectQueryRepository {
-        list_result: Mutex<Option<Result<Page<JiraProjectQueryDto>, JiraError>>>,
-    }
-
-    impl MockJiraProjectQueryRepository {
-        fn new(list_result: Result<Page<JiraProjectQueryDto>, JiraError>) -> Self {
-            Self {
-                list_result: Mutex::new(Some(list_result)),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl JiraProjectQueryRepository for MockJiraProjectQueryRepository {
-        async fn find_by_ids(
-            &self,
-            _ids: Vec<JiraProjectId>,
-        ) -> Result<Vec<JiraProjectQueryDto>, JiraError> {
-            unimplemented!()
-        }
-
-        async fn list(
-            &self,
-            _page_number: PageNumber,
-            _page_size: PageSize,
-        ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
-            self.list_result
-                .lock()
-                .unwrap()
-                .take()
-                .expect("list_result already consumed")
-        }
-    }
+    use crate::test_support::jira::{
+        InMemoryJiraProjectQueryRepository, JiraProjectQueryRepositoryMethod,
+    };
 
     fn create_test_dto(id: i64) -> JiraProjectQueryDto {
         JiraProjectQueryDto::new(id, format!("PROJ{}", id), format!("Project {}", id))
@@ -106,11 +74,8 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_page_of_projects_with_valid_pagination() {
-        let dtos: Vec<JiraProjectQueryDto> = (1..=10).map(create_test_dto).collect();
-        let expected_page = Page::new(100, dtos);
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(
-            expected_page.clone()
-        )));
+        let dtos: Vec<JiraProjectQueryDto> = (1..=100).map(create_test_dto).collect();
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(dtos));
         let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(1, 10).await;
@@ -123,7 +88,7 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_invalid_page_number_when_page_number_is_zero() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
         let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(0, 10).await;
@@ -137,7 +102,7 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_invalid_page_size_when_page_size_is_zero() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
         let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(1, 0).await;
@@ -151,7 +116,7 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_invalid_page_size_when_page_size_exceeds_maximum() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Ok(Page::empty())));
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
         let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(1, 101).await;
@@ -165,9 +130,10 @@ mod tests {
 
     #[tokio::test]
     async fn execute_should_return_project_fetch_failed_when_repository_fails() {
-        let repository = Arc::new(MockJiraProjectQueryRepository::new(Err(
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new().fail_next(
+            JiraProjectQueryRepositoryMethod::List,
             JiraError::database_error("Connection failed"),
-        )));
+        ));
         let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
 
         let result = usecase.execute(1, 10).await;
@@ -178,4 +144,45 @@ mod tests {
             JiraProjectListQueryError::ProjectFetchFailed(_)
         ));
     }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_page_number_when_offset_overflows() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute(i32::MAX, 100).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectListQueryError::InvalidPageNumber(PageNumberError::AboveMaximum { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_repository_timeout_when_repository_times_out() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new().fail_next(
+            JiraProjectQueryRepositoryMethod::List,
+            JiraError::timeout("canceling statement due to statement timeout"),
+        ));
+        let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute(1, 10).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectListQueryError::RepositoryTimeout(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_empty_page_when_no_projects_exist() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let usecase = JiraProjectListQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute(1, 10).await;
+
+        let page = result.expect("an empty result is a success, not an error");
+        assert_eq!(page.total_count, 0);
+        assert!(page.is_empty());
+    }
 }
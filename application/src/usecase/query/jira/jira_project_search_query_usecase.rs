@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::value_object::{Page, PageNumber, PageSize};
+
+use crate::dto::query::jira::JiraProjectQueryDto;
+use crate::error::query::jira::JiraProjectSearchQueryError;
+use crate::repository::jira::JiraProjectQueryRepository;
+
+/// Use case for searching Jira projects by name or key substring.
+#[async_trait]
+pub trait JiraProjectSearchQueryUseCase: Send + Sync {
+    /// Searches Jira projects whose name or key contains the query.
+    ///
+    /// # Arguments
+    /// * `query` - The substring to match (case-insensitive)
+    /// * `page_number` - The page number (1-indexed)
+    /// * `page_size` - The number of items per page
+    ///
+    /// # Returns
+    /// A page of matching Jira projects or an error
+    async fn execute(
+        &self,
+        query: String,
+        page_number: i32,
+        page_size: i32,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraProjectSearchQueryError>;
+}
+
+/// Implementation of JiraProjectSearchQueryUseCase.
+pub struct JiraProjectSearchQueryUseCaseImpl<R: JiraProjectQueryRepository> {
+    repository: Arc<R>,
+}
+
+impl<R: JiraProjectQueryRepository> JiraProjectSearchQueryUseCaseImpl<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JiraProjectQueryRepository> JiraProjectSearchQueryUseCase
+    for JiraProjectSearchQueryUseCaseImpl<R>
+{
+    async fn execute(
+        &self,
+        query: String,
+        page_number: i32,
+        page_size: i32,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraProjectSearchQueryError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(JiraProjectSearchQueryError::EmptyQuery);
+        }
+
+        let valid_page_number =
+            PageNumber::of(page_number).map_err(JiraProjectSearchQueryError::InvalidPageNumber)?;
+
+        let valid_page_size =
+            PageSize::of(page_size).map_err(JiraProjectSearchQueryError::InvalidPageSize)?;
+
+        self.repository
+            .search(query, valid_page_number, valid_page_size)
+            .await
+            .map_err(JiraProjectSearchQueryError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::{JiraError, PageNumberError};
+
+    use crate::test_support::jira::{
+        InMemoryJiraProjectQueryRepository, JiraProjectQueryRepositoryMethod,
+    };
+
+    fn create_test_dto(id: i64) -> JiraProjectQueryDto {
+        JiraProjectQueryDto::new(id, format!("PROJ{}", id), format!("Project {}", id))
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_matching_projects() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::with_projects(vec![
+            create_test_dto(1),
+            JiraProjectQueryDto::new(2, "OTHER".to_string(), "Billing".to_string()),
+        ]));
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository.clone());
+
+        let result = usecase.execute("  Project ".to_string(), 1, 10).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().items, vec![create_test_dto(1)]);
+        assert_eq!(repository.searches(), vec!["Project".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_empty_query_when_query_is_blank() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute("   ".to_string(), 1, 10).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSearchQueryError::EmptyQuery
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_page_size_when_page_size_is_zero() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute("proj".to_string(), 1, 0).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSearchQueryError::InvalidPageSize(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_invalid_page_number_when_offset_overflows() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new());
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute("proj".to_string(), i32::MAX, 100).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSearchQueryError::InvalidPageNumber(PageNumberError::AboveMaximum { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_project_fetch_failed_when_repository_fails() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new().fail_next(
+            JiraProjectQueryRepositoryMethod::Search,
+            JiraError::database_error("Connection failed"),
+        ));
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute("proj".to_string(), 1, 10).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSearchQueryError::ProjectFetchFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_should_return_repository_timeout_when_repository_times_out() {
+        let repository = Arc::new(InMemoryJiraProjectQueryRepository::new().fail_next(
+            JiraProjectQueryRepositoryMethod::Search,
+            JiraError::timeout("canceling statement due to statement timeout"),
+        ));
+        let usecase = JiraProjectSearchQueryUseCaseImpl::new(repository);
+
+        let result = usecase.execute("proj".to_string(), 1, 10).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            JiraProjectSearchQueryError::RepositoryTimeout(_)
+        ));
+    }
+}
@@ -1,15 +1,37 @@
+mod jira_auth_check_query_usecase;
+mod jira_issue_export_query_usecase;
 mod jira_issue_find_by_ids_query_usecase;
+mod jira_issue_link_find_by_keys_query_usecase;
 mod jira_issue_list_query_usecase;
+mod jira_issue_resolve_ids_query_usecase;
+mod jira_project_dump_query_usecase;
 mod jira_project_find_by_ids_query_usecase;
 mod jira_project_list_query_usecase;
+mod jira_project_search_query_usecase;
 
+pub use jira_auth_check_query_usecase::{JiraAuthCheckQueryUseCase, JiraAuthCheckQueryUseCaseImpl};
+pub use jira_issue_export_query_usecase::{
+    JiraIssueExportQueryUseCase, JiraIssueExportQueryUseCaseImpl,
+};
 pub use jira_issue_find_by_ids_query_usecase::{
     JiraIssueFindByIdsQueryUseCase, JiraIssueFindByIdsQueryUseCaseImpl,
 };
+pub use jira_issue_link_find_by_keys_query_usecase::{
+    JiraIssueLinkFindByKeysQueryUseCase, JiraIssueLinkFindByKeysQueryUseCaseImpl,
+};
 pub use jira_issue_list_query_usecase::{JiraIssueListQueryUseCase, JiraIssueListQueryUseCaseImpl};
+pub use jira_issue_resolve_ids_query_usecase::{
+    JiraIssueResolveIdsQueryUseCase, JiraIssueResolveIdsQueryUseCaseImpl,
+};
+pub use jira_project_dump_query_usecase::{
+    JiraProjectDumpQueryUseCase, JiraProjectDumpQueryUseCaseImpl,
+};
 pub use jira_project_find_by_ids_query_usecase::{
     JiraProjectFindByIdsQueryUseCase, JiraProjectFindByIdsQueryUseCaseImpl,
 };
 pub use jira_project_list_query_usecase::{
     JiraProjectListQueryUseCase, JiraProjectListQueryUseCaseImpl,
 };
+pub use jira_project_search_query_usecase::{
+    JiraProjectSearchQueryUseCase, JiraProjectSearchQueryUseCaseImpl,
+};
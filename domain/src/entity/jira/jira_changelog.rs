@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+
+use crate::value_object::jira::{JiraIssueKey, JiraUser};
+
+/// A single field change in the history of a Jira issue.
+///
+/// Jira groups the field changes made at once into a history; each item of a history
+/// becomes one entry. `from_value` and `to_value` hold the displayed values, e.g.
+/// "To Do" and "In Progress" for a status change, and are None when the field was empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraChangelogEntry {
+    pub issue_key: JiraIssueKey,
+    /// Id of the Jira history the change belongs to.
+    pub history_id: i64,
+    /// Position of the change within its history; one history may change a field twice,
+    /// e.g. when several links are added.
+    pub position: i32,
+    pub field: String,
+    pub from_value: Option<String>,
+    pub to_value: Option<String>,
+    /// The user who made the change, or None for changes made by Jira itself.
+    pub author: Option<JiraUser>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl JiraChangelogEntry {
+    /// Creates a new JiraChangelogEntry.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        issue_key: JiraIssueKey,
+        history_id: i64,
+        position: i32,
+        field: impl Into<String>,
+        from_value: Option<String>,
+        to_value: Option<String>,
+        author: Option<JiraUser>,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            issue_key,
+            history_id,
+            position,
+            field: field.into(),
+            from_value,
+            to_value,
+            author,
+            changed_at,
+        }
+    }
+}
+
+/// The change history of one Jira issue, oldest change first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraChangelog {
+    pub issue_key: JiraIssueKey,
+    pub entries: Vec<JiraChangelogEntry>,
+}
+
+impl JiraChangelog {
+    /// Creates a changelog from `entries`, ordering them by time of change.
+    pub fn new(issue_key: JiraIssueKey, mut entries: Vec<JiraChangelogEntry>) -> Self {
+        entries.sort_by_key(|entry| (entry.changed_at, entry.history_id, entry.position));
+        Self { issue_key, entries }
+    }
+
+    /// Returns the changes made to `field`, oldest first.
+    pub fn changes_of<'a>(
+        &'a self,
+        field: &'a str,
+    ) -> impl Iterator<Item = &'a JiraChangelogEntry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.field == field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(history_id: i64, field: &str, to: &str, hour: u32) -> JiraChangelogEntry {
+        JiraChangelogEntry::new(
+            JiraIssueKey::new("PROJ-1"),
+            history_id,
+            0,
+            field,
+            None,
+            Some(to.to_string()),
+            Some(JiraUser::new("account-1", "Jane Doe")),
+            Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_changelog_orders_entries_by_time() {
+        let changelog = JiraChangelog::new(
+            JiraIssueKey::new("PROJ-1"),
+            vec![
+                entry(3, "status", "Done", 12),
+                entry(1, "status", "In Progress", 9),
+                entry(2, "assignee", "Jane Doe", 10),
+            ],
+        );
+
+        let ids: Vec<i64> = changelog.entries.iter().map(|e| e.history_id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_changes_of_filters_by_field() {
+        let changelog = JiraChangelog::new(
+            JiraIssueKey::new("PROJ-1"),
+            vec![
+                entry(1, "status", "In Progress", 9),
+                entry(2, "assignee", "Jane Doe", 10),
+                entry(3, "status", "Done", 12),
+            ],
+        );
+
+        let statuses: Vec<Option<&str>> = changelog
+            .changes_of("status")
+            .map(|e| e.to_value.as_deref())
+            .collect();
+        assert_eq!(statuses, vec![Some("In Progress"), Some("Done")]);
+    }
+}
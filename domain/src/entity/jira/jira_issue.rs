@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::{JiraIssueChangeSet, JiraIssueField, JiraIssueLink};
 
 use crate::value_object::jira::{
-    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId,
+    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId, JiraStatusCategory,
+    JiraUser,
 };
 
 /// Represents a Jira issue entity.
@@ -14,7 +17,27 @@ pub struct JiraIssue {
     pub summary: String,
     pub description: Option<String>,
     pub issue_type: JiraIssueType,
-    pub priority: JiraIssuePriority,
+    /// Priority of the issue; None when Jira reports the issue without one.
+    pub priority: Option<JiraIssuePriority>,
+    pub reporter: Option<JiraUser>,
+    /// Number of users watching the issue, when Jira reports it.
+    pub watches: Option<i32>,
+    /// Number of votes on the issue, when Jira reports it.
+    pub votes: Option<i32>,
+    /// Category of the issue's current status, when Jira reports one.
+    pub status_category: Option<JiraStatusCategory>,
+    /// Originally estimated time in seconds, when time tracking is enabled.
+    pub original_estimate_seconds: Option<i64>,
+    /// Estimated time left in seconds, when time tracking is enabled.
+    pub remaining_estimate_seconds: Option<i64>,
+    /// Time logged so far in seconds, when time tracking is enabled.
+    pub time_spent_seconds: Option<i64>,
+    /// Name of the resolution, such as "Done" or "Won't Do"; None while unresolved.
+    pub resolution: Option<String>,
+    /// Day the issue is due, when one is set.
+    pub due_date: Option<NaiveDate>,
+    /// Links to other issues, when they were fetched with the issue.
+    pub links: Vec<JiraIssueLink>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -29,7 +52,8 @@ impl JiraIssue {
         summary: String,
         description: Option<String>,
         issue_type: JiraIssueType,
-        priority: JiraIssuePriority,
+        priority: Option<JiraIssuePriority>,
+        reporter: Option<JiraUser>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -41,10 +65,141 @@ impl JiraIssue {
             description,
             issue_type,
             priority,
+            reporter,
+            watches: None,
+            votes: None,
+            status_category: None,
+            original_estimate_seconds: None,
+            remaining_estimate_seconds: None,
+            time_spent_seconds: None,
+            resolution: None,
+            due_date: None,
+            links: Vec::new(),
             created_at,
             updated_at,
         }
     }
+
+    /// Attaches the watcher count reported by Jira.
+    pub fn with_watches(mut self, watches: Option<i32>) -> Self {
+        self.watches = watches;
+        self
+    }
+
+    /// Attaches the vote count reported by Jira.
+    pub fn with_votes(mut self, votes: Option<i32>) -> Self {
+        self.votes = votes;
+        self
+    }
+
+    /// Attaches the status category reported by Jira.
+    pub fn with_status_category(mut self, status_category: Option<JiraStatusCategory>) -> Self {
+        self.status_category = status_category;
+        self
+    }
+
+    /// Attaches the original estimate, in seconds, reported by Jira.
+    pub fn with_original_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.original_estimate_seconds = seconds;
+        self
+    }
+
+    /// Attaches the remaining estimate, in seconds, reported by Jira.
+    pub fn with_remaining_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.remaining_estimate_seconds = seconds;
+        self
+    }
+
+    /// Attaches the time spent, in seconds, reported by Jira.
+    pub fn with_time_spent_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.time_spent_seconds = seconds;
+        self
+    }
+
+    /// Attaches the resolution reported by Jira.
+    pub fn with_resolution(mut self, resolution: Option<String>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Attaches the due date reported by Jira.
+    pub fn with_due_date(mut self, due_date: Option<NaiveDate>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
+    /// Attaches the links to other issues reported by Jira.
+    pub fn with_links(mut self, links: Vec<JiraIssueLink>) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Returns true if the issue's status is in the Done category.
+    pub fn is_done(&self) -> bool {
+        self.status_category == Some(JiraStatusCategory::Done)
+    }
+
+    /// Returns true if the issue is unresolved and its due date is before `today`.
+    /// An issue due today is not overdue yet.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.resolution.is_none() && self.due_date.is_some_and(|due_date| due_date < today)
+    }
+
+    /// Compares this issue with another and returns the fields that differ.
+    /// The issue id identifies the issue and `created_at` is immutable, so neither is compared.
+    /// Links are stored apart from the issue and are not compared either.
+    pub fn diff(&self, other: &JiraIssue) -> JiraIssueChangeSet {
+        let mut fields = Vec::new();
+        if self.project_id != other.project_id {
+            fields.push(JiraIssueField::ProjectId);
+        }
+        if self.key != other.key {
+            fields.push(JiraIssueField::Key);
+        }
+        if self.summary != other.summary {
+            fields.push(JiraIssueField::Summary);
+        }
+        if self.description != other.description {
+            fields.push(JiraIssueField::Description);
+        }
+        if self.issue_type != other.issue_type {
+            fields.push(JiraIssueField::IssueType);
+        }
+        if self.priority != other.priority {
+            fields.push(JiraIssueField::Priority);
+        }
+        if self.reporter != other.reporter {
+            fields.push(JiraIssueField::Reporter);
+        }
+        if self.watches != other.watches {
+            fields.push(JiraIssueField::Watches);
+        }
+        if self.votes != other.votes {
+            fields.push(JiraIssueField::Votes);
+        }
+        if self.status_category != other.status_category {
+            fields.push(JiraIssueField::StatusCategory);
+        }
+        if self.original_estimate_seconds != other.original_estimate_seconds {
+            fields.push(JiraIssueField::OriginalEstimate);
+        }
+        if self.remaining_estimate_seconds != other.remaining_estimate_seconds {
+            fields.push(JiraIssueField::RemainingEstimate);
+        }
+        if self.time_spent_seconds != other.time_spent_seconds {
+            fields.push(JiraIssueField::TimeSpent);
+        }
+        if self.resolution != other.resolution {
+            fields.push(JiraIssueField::Resolution);
+        }
+        if self.due_date != other.due_date {
+            fields.push(JiraIssueField::DueDate);
+        }
+        if self.updated_at != other.updated_at {
+            fields.push(JiraIssueField::UpdatedAt);
+        }
+        JiraIssueChangeSet::new(fields)
+    }
 }
 
 /// Builder for JiraIssue to simplify construction.
@@ -57,6 +212,15 @@ pub struct JiraIssueBuilder {
     description: Option<String>,
     issue_type: Option<JiraIssueType>,
     priority: Option<JiraIssuePriority>,
+    reporter: Option<JiraUser>,
+    watches: Option<i32>,
+    votes: Option<i32>,
+    status_category: Option<JiraStatusCategory>,
+    original_estimate_seconds: Option<i64>,
+    remaining_estimate_seconds: Option<i64>,
+    time_spent_seconds: Option<i64>,
+    resolution: Option<String>,
+    due_date: Option<NaiveDate>,
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
 }
@@ -101,6 +265,70 @@ impl JiraIssueBuilder {
         self
     }
 
+    /// Sets the issue type, falling back to `JiraIssueType::default()` (`Task`) when absent.
+    pub fn issue_type_or_default(mut self, issue_type: Option<JiraIssueType>) -> Self {
+        self.issue_type = Some(issue_type.unwrap_or_default());
+        self
+    }
+
+    /// Sets the priority, keeping an absent priority as `None`.
+    pub fn optional_priority(mut self, priority: Option<JiraIssuePriority>) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Fills an unset issue type with its default (`Task`). The priority is left as is,
+    /// since issues without one are valid; without this, `build` requires an issue type.
+    pub fn with_defaults(mut self) -> Self {
+        self.issue_type = Some(self.issue_type.unwrap_or_default());
+        self
+    }
+
+    pub fn reporter(mut self, reporter: Option<JiraUser>) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
+    pub fn watches(mut self, watches: Option<i32>) -> Self {
+        self.watches = watches;
+        self
+    }
+
+    pub fn votes(mut self, votes: Option<i32>) -> Self {
+        self.votes = votes;
+        self
+    }
+
+    pub fn status_category(mut self, status_category: Option<JiraStatusCategory>) -> Self {
+        self.status_category = status_category;
+        self
+    }
+
+    pub fn original_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.original_estimate_seconds = seconds;
+        self
+    }
+
+    pub fn remaining_estimate_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.remaining_estimate_seconds = seconds;
+        self
+    }
+
+    pub fn time_spent_seconds(mut self, seconds: Option<i64>) -> Self {
+        self.time_spent_seconds = seconds;
+        self
+    }
+
+    pub fn resolution(mut self, resolution: Option<String>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn due_date(mut self, due_date: Option<NaiveDate>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
     pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
         self.created_at = Some(created_at);
         self
@@ -112,7 +340,7 @@ impl JiraIssueBuilder {
     }
 
     /// Builds the JiraIssue.
-    /// Returns None if any required field is missing.
+    /// Returns None if any required field is missing; the priority is optional.
     pub fn build(self) -> Option<JiraIssue> {
         Some(JiraIssue {
             id: self.id?,
@@ -121,7 +349,17 @@ impl JiraIssueBuilder {
             summary: self.summary?,
             description: self.description,
             issue_type: self.issue_type?,
-            priority: self.priority?,
+            priority: self.priority,
+            reporter: self.reporter,
+            watches: self.watches,
+            votes: self.votes,
+            status_category: self.status_category,
+            original_estimate_seconds: self.original_estimate_seconds,
+            remaining_estimate_seconds: self.remaining_estimate_seconds,
+            time_spent_seconds: self.time_spent_seconds,
+            resolution: self.resolution,
+            due_date: self.due_date,
+            links: Vec::new(),
             created_at: self.created_at?,
             updated_at: self.updated_at?,
         })
@@ -141,7 +379,8 @@ mod tests {
             "Test Issue".to_string(),
             Some("Description".to_string()),
             JiraIssueType::Task,
-            JiraIssuePriority::Medium,
+            Some(JiraIssuePriority::Medium),
+            Some(JiraUser::new("account-1", "Jane Doe")),
             now,
             now,
         )
@@ -156,7 +395,11 @@ mod tests {
         assert_eq!(issue.summary, "Test Issue");
         assert_eq!(issue.description, Some("Description".to_string()));
         assert_eq!(issue.issue_type, JiraIssueType::Task);
-        assert_eq!(issue.priority, JiraIssuePriority::Medium);
+        assert_eq!(issue.priority, Some(JiraIssuePriority::Medium));
+        assert_eq!(
+            issue.reporter.as_ref().map(|r| r.account_id()),
+            Some("account-1")
+        );
     }
 
     #[test]
@@ -181,6 +424,109 @@ mod tests {
         assert_eq!(issue.issue_type, JiraIssueType::Bug);
     }
 
+    #[test]
+    fn test_jira_issue_new_has_no_watches_or_votes() {
+        let issue = create_test_issue();
+
+        assert_eq!(issue.watches, None);
+        assert_eq!(issue.votes, None);
+    }
+
+    #[test]
+    fn test_jira_issue_with_watches_and_votes() {
+        let original = create_test_issue();
+        let issue = original.clone().with_watches(Some(7)).with_votes(Some(3));
+
+        assert_eq!(issue.watches, Some(7));
+        assert_eq!(issue.votes, Some(3));
+        assert_eq!(
+            original.diff(&issue).fields(),
+            &[JiraIssueField::Watches, JiraIssueField::Votes]
+        );
+    }
+
+    #[test]
+    fn test_jira_issue_is_done_only_in_done_category() {
+        let issue = create_test_issue();
+
+        assert!(!issue.is_done());
+        assert!(
+            !issue
+                .clone()
+                .with_status_category(Some(JiraStatusCategory::ToDo))
+                .is_done()
+        );
+        assert!(
+            !issue
+                .clone()
+                .with_status_category(Some(JiraStatusCategory::InProgress))
+                .is_done()
+        );
+        assert!(
+            issue
+                .with_status_category(Some(JiraStatusCategory::Done))
+                .is_done()
+        );
+    }
+
+    #[test]
+    fn test_jira_issue_diff_status_category() {
+        let issue = create_test_issue();
+        let done = issue
+            .clone()
+            .with_status_category(Some(JiraStatusCategory::Done));
+
+        assert_eq!(
+            issue.diff(&done).fields(),
+            &[JiraIssueField::StatusCategory]
+        );
+    }
+
+    #[test]
+    fn test_jira_issue_new_has_no_time_tracking() {
+        let issue = create_test_issue();
+
+        assert_eq!(issue.original_estimate_seconds, None);
+        assert_eq!(issue.remaining_estimate_seconds, None);
+        assert_eq!(issue.time_spent_seconds, None);
+    }
+
+    #[test]
+    fn test_jira_issue_diff_time_tracking() {
+        let issue = create_test_issue();
+        let logged = issue
+            .clone()
+            .with_original_estimate_seconds(Some(28_800))
+            .with_remaining_estimate_seconds(Some(14_400))
+            .with_time_spent_seconds(Some(14_400));
+
+        assert_eq!(
+            issue.diff(&logged).fields(),
+            &[
+                JiraIssueField::OriginalEstimate,
+                JiraIssueField::RemainingEstimate,
+                JiraIssueField::TimeSpent
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jira_issue_is_overdue_only_when_unresolved_and_past_due() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        let due = |day: u32| Some(NaiveDate::from_ymd_opt(2024, 6, day).unwrap());
+        let issue = create_test_issue();
+
+        assert!(issue.clone().with_due_date(due(14)).is_overdue(today));
+        assert!(!issue.clone().with_due_date(due(15)).is_overdue(today));
+        assert!(!issue.clone().is_overdue(today));
+        assert!(
+            !issue
+                .with_due_date(due(14))
+                .with_resolution(Some("Done".to_string()))
+                .is_overdue(today)
+        );
+    }
+
     #[test]
     fn test_jira_issue_builder_missing_required() {
         let issue = JiraIssueBuilder::new()
@@ -190,4 +536,133 @@ mod tests {
 
         assert!(issue.is_none());
     }
+
+    #[test]
+    fn test_jira_issue_diff_identical_is_empty() {
+        let issue = create_test_issue();
+        let diff = issue.diff(&issue.clone());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_jira_issue_diff_ignores_created_at() {
+        let issue = create_test_issue();
+        let mut other = issue.clone();
+        other.created_at = issue.created_at - chrono::Duration::days(1);
+        assert!(issue.diff(&other).is_empty());
+    }
+
+    #[test]
+    fn test_jira_issue_diff_summary_and_priority() {
+        let issue = create_test_issue();
+        let mut other = issue.clone();
+        other.summary = "Updated Issue".to_string();
+        other.priority = Some(JiraIssuePriority::Highest);
+
+        let diff = issue.diff(&other);
+
+        assert_eq!(
+            diff.fields(),
+            &[JiraIssueField::Summary, JiraIssueField::Priority]
+        );
+        assert!(!diff.contains(JiraIssueField::IssueType));
+    }
+
+    #[test]
+    fn test_jira_issue_diff_reporter() {
+        let issue = create_test_issue();
+        let mut other = issue.clone();
+        other.reporter = None;
+
+        assert_eq!(issue.diff(&other).fields(), &[JiraIssueField::Reporter]);
+    }
+
+    fn builder_without_type_and_priority() -> JiraIssueBuilder {
+        let now = Utc::now();
+        JiraIssueBuilder::new()
+            .id(JiraIssueId::new(3))
+            .project_id(JiraProjectId::new(300))
+            .key(JiraIssueKey::new("PROJ-3"))
+            .summary("Defaulted Issue")
+            .created_at(now)
+            .updated_at(now)
+    }
+
+    #[test]
+    fn test_jira_issue_builder_with_defaults_fills_missing_issue_type() {
+        let issue = builder_without_type_and_priority()
+            .with_defaults()
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.issue_type, JiraIssueType::Task);
+        assert_eq!(issue.priority, None);
+    }
+
+    #[test]
+    fn test_jira_issue_builder_with_defaults_keeps_set_fields() {
+        let issue = builder_without_type_and_priority()
+            .issue_type(JiraIssueType::Bug)
+            .priority(JiraIssuePriority::Highest)
+            .with_defaults()
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.issue_type, JiraIssueType::Bug);
+        assert_eq!(issue.priority, Some(JiraIssuePriority::Highest));
+    }
+
+    #[test]
+    fn test_jira_issue_builder_or_default_methods() {
+        let issue = builder_without_type_and_priority()
+            .issue_type_or_default(None)
+            .optional_priority(Some(JiraIssuePriority::Low))
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.issue_type, JiraIssueType::Task);
+        assert_eq!(issue.priority, Some(JiraIssuePriority::Low));
+    }
+
+    #[test]
+    fn test_jira_issue_builder_optional_priority_keeps_none() {
+        let issue = builder_without_type_and_priority()
+            .issue_type(JiraIssueType::Bug)
+            .optional_priority(None)
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.priority, None);
+    }
+
+    #[test]
+    fn test_jira_issue_builder_without_defaults_stays_strict() {
+        assert!(builder_without_type_and_priority().build().is_none());
+
+        let issue = builder_without_type_and_priority()
+            .issue_type(JiraIssueType::Bug)
+            .build()
+            .unwrap();
+        assert_eq!(issue.issue_type, JiraIssueType::Bug);
+        assert_eq!(issue.priority, None);
+    }
+
+    #[test]
+    fn test_jira_issue_builder_keeps_issue_without_priority() {
+        let issue = builder_without_type_and_priority()
+            .issue_type(JiraIssueType::Story)
+            .build()
+            .unwrap();
+
+        assert_eq!(issue.priority, None);
+    }
+
+    #[test]
+    fn test_jira_issue_diff_priority_removed() {
+        let issue = create_test_issue();
+        let mut other = issue.clone();
+        other.priority = None;
+
+        assert_eq!(issue.diff(&other).fields(), &[JiraIssueField::Priority]);
+    }
 }
@@ -0,0 +1,78 @@
+/// A field of JiraIssue that can be compared for change detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JiraIssueField {
+    ProjectId,
+    Key,
+    Summary,
+    Description,
+    IssueType,
+    Priority,
+    Reporter,
+    Watches,
+    Votes,
+    StatusCategory,
+    OriginalEstimate,
+    RemainingEstimate,
+    TimeSpent,
+    Resolution,
+    DueDate,
+    UpdatedAt,
+}
+
+impl JiraIssueField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JiraIssueField::ProjectId => "project_id",
+            JiraIssueField::Key => "key",
+            JiraIssueField::Summary => "summary",
+            JiraIssueField::Description => "description",
+            JiraIssueField::IssueType => "issue_type",
+            JiraIssueField::Priority => "priority",
+            JiraIssueField::Reporter => "reporter",
+            JiraIssueField::Watches => "watches",
+            JiraIssueField::Votes => "votes",
+            JiraIssueField::StatusCategory => "status_category",
+            JiraIssueField::OriginalEstimate => "original_estimate_seconds",
+            JiraIssueField::RemainingEstimate => "remaining_estimate_seconds",
+            JiraIssueField::TimeSpent => "time_spent_seconds",
+            JiraIssueField::Resolution => "resolution",
+            JiraIssueField::DueDate => "due_date",
+            JiraIssueField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+impl std::fmt::Display for JiraIssueField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The set of fields that differ between two versions of a JiraIssue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueChangeSet {
+    fields: Vec<JiraIssueField>,
+}
+
+impl JiraIssueChangeSet {
+    pub fn new(fields: Vec<JiraIssueField>) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the changed fields in declaration order.
+    pub fn fields(&self) -> &[JiraIssueField] {
+        &self.fields
+    }
+
+    pub fn contains(&self, field: JiraIssueField) -> bool {
+        self.fields.contains(&field)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+}
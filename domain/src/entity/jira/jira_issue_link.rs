@@ -0,0 +1,59 @@
+use crate::value_object::jira::JiraIssueKey;
+
+/// A directed relationship between two Jira issues, such as "blocks" or "relates to".
+///
+/// The link reads from `source` to `target` in the link type's outward direction,
+/// e.g. `PROJ-1 blocks PROJ-2`. Both sides of a link in Jira therefore map to the same
+/// value. Issues are referenced by key, since the other side may not have been synced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JiraIssueLink {
+    pub source: JiraIssueKey,
+    pub target: JiraIssueKey,
+    /// Name of the Jira link type, e.g. "Blocks" or "Relates".
+    pub link_type: String,
+}
+
+impl JiraIssueLink {
+    /// Creates a new JiraIssueLink.
+    pub fn new(source: JiraIssueKey, target: JiraIssueKey, link_type: impl Into<String>) -> Self {
+        Self {
+            source,
+            target,
+            link_type: link_type.into(),
+        }
+    }
+
+    /// Returns true if `key` is the source or the target of this link.
+    pub fn involves(&self, key: &JiraIssueKey) -> bool {
+        &self.source == key || &self.target == key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jira_issue_link_creation() {
+        let link = JiraIssueLink::new(
+            JiraIssueKey::new("PROJ-1"),
+            JiraIssueKey::new("PROJ-2"),
+            "Blocks",
+        );
+        assert_eq!(link.source.value(), "PROJ-1");
+        assert_eq!(link.target.value(), "PROJ-2");
+        assert_eq!(link.link_type, "Blocks");
+    }
+
+    #[test]
+    fn test_jira_issue_link_involves_both_ends() {
+        let link = JiraIssueLink::new(
+            JiraIssueKey::new("PROJ-1"),
+            JiraIssueKey::new("PROJ-2"),
+            "Relates",
+        );
+        assert!(link.involves(&JiraIssueKey::new("PROJ-1")));
+        assert!(link.involves(&JiraIssueKey::new("PROJ-2")));
+        assert!(!link.involves(&JiraIssueKey::new("PROJ-3")));
+    }
+}
@@ -1,19 +1,30 @@
+use chrono::{DateTime, Utc};
+
 use crate::error::JiraError;
 use crate::value_object::jira::{JiraProjectId, JiraProjectKey, JiraProjectName};
 
 /// Represents a Jira project entity.
 /// This is the core domain object for Jira projects.
+///
+/// `updated_at` is the version of the stored project, used for optimistic concurrency.
+/// It is `None` for projects that have not been loaded from storage.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JiraProject {
     pub id: JiraProjectId,
     pub key: JiraProjectKey,
     pub name: JiraProjectName,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 impl JiraProject {
     /// Creates a new JiraProject without validation.
     pub fn new(id: JiraProjectId, key: JiraProjectKey, name: JiraProjectName) -> Self {
-        Self { id, key, name }
+        Self {
+            id,
+            key,
+            name,
+            updated_at: None,
+        }
     }
 
     /// Creates a new JiraProject with validation.
@@ -25,10 +36,19 @@ impl JiraProject {
         let id = JiraProjectId::of(id)?;
         let key = JiraProjectKey::of(key)?;
         let name = JiraProjectName::of(name)?;
-        Ok(Self { id, key, name })
+        Ok(Self::new(id, key, name))
+    }
+
+    /// Returns this project with the stored version attached.
+    pub fn with_updated_at(self, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            updated_at: Some(updated_at),
+            ..self
+        }
     }
 
     /// Returns a new JiraProject with updated key and name, keeping the same id.
+    /// The version is kept so the write can be checked against the stored project.
     pub fn update(
         self,
         key: impl Into<String>,
@@ -40,6 +60,19 @@ impl JiraProject {
             id: self.id,
             key,
             name,
+            updated_at: self.updated_at,
+        })
+    }
+
+    /// Returns a new JiraProject with only the given fields replaced, keeping the same id
+    /// and version. Only the provided values are validated.
+    pub fn patch(self, key: Option<String>, name: Option<String>) -> Result<Self, JiraError> {
+        let key = key.map(JiraProjectKey::of).transpose()?;
+        let name = name.map(JiraProjectName::of).transpose()?;
+        Ok(Self {
+            key: key.unwrap_or(self.key),
+            name: name.unwrap_or(self.name),
+            ..self
         })
     }
 }
@@ -131,4 +164,39 @@ mod tests {
         let updated = project.update("NEWKEY", "");
         assert!(updated.is_err());
     }
+
+    #[test]
+    fn test_jira_project_patch_replaces_only_given_fields() {
+        let project = JiraProject::of("100", "PROJ", "My Project").unwrap();
+
+        let renamed = project
+            .clone()
+            .patch(None, Some("New Name".into()))
+            .unwrap();
+        assert_eq!(renamed.key.value(), "PROJ");
+        assert_eq!(renamed.name.value(), "New Name");
+
+        let rekeyed = project.clone().patch(Some("NEWKEY".into()), None).unwrap();
+        assert_eq!(rekeyed.key.value(), "NEWKEY");
+        assert_eq!(rekeyed.name.value(), "My Project");
+
+        assert_eq!(project.clone().patch(None, None).unwrap(), project);
+    }
+
+    #[test]
+    fn test_jira_project_patch_validates_given_fields() {
+        let project = JiraProject::of("100", "PROJ", "My Project").unwrap();
+        assert!(project.clone().patch(None, Some(String::new())).is_err());
+        assert!(project.patch(Some(String::new()), None).is_err());
+    }
+
+    #[test]
+    fn test_jira_project_update_keeps_version() {
+        let version = Utc::now();
+        let project = JiraProject::of("100", "PROJ", "My Project")
+            .unwrap()
+            .with_updated_at(version);
+        let updated = project.update("NEWKEY", "New Name").unwrap();
+        assert_eq!(updated.updated_at, Some(version));
+    }
 }
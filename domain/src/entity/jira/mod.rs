@@ -1,5 +1,11 @@
+mod jira_changelog;
 mod jira_issue;
+mod jira_issue_change_set;
+mod jira_issue_link;
 mod jira_project;
 
+pub use jira_changelog::*;
 pub use jira_issue::*;
+pub use jira_issue_change_set::*;
+pub use jira_issue_link::*;
 pub use jira_project::*;
@@ -2,7 +2,25 @@ use crate::error::DomainError;
 use thiserror::Error;
 
 /// Errors specific to Jira domain operations.
+///
+/// New variants may be added in any release, so matches outside this crate need a
+/// wildcard arm:
+///
+/// ```
+/// use domain::error::JiraError;
+///
+/// fn is_retryable(error: &JiraError) -> bool {
+///     match error {
+///         JiraError::Timeout { .. } | JiraError::CircuitOpen { .. } => true,
+///         JiraError::Unauthorized { .. } => false,
+///         _ => false,
+///     }
+/// }
+///
+/// assert!(!is_retryable(&JiraError::EmptyProjectKey));
+/// ```
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum JiraError {
     #[error("Invalid JIRA issue ID format")]
     InvalidId {
@@ -17,13 +35,39 @@ pub enum JiraError {
         cause: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 
+    #[error("Operation timed out: {message}")]
+    Timeout {
+        message: String,
+        #[source]
+        cause: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    #[error("Authentication failed: {message}")]
+    Unauthorized { message: String },
+
+    #[error("Conflict: {message}")]
+    Conflict { message: String },
+
+    /// A failed API call. `status` is the HTTP status Jira answered with, or None when
+    /// no response was received (connection failures, bodies cut off mid-read).
     #[error("API error: {message}")]
     ApiError {
+        message: String,
+        status: Option<u16>,
+        #[source]
+        cause: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    #[error("Failed to parse API response: {message}")]
+    ParseError {
         message: String,
         #[source]
         cause: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 
+    #[error("Circuit open: {message}")]
+    CircuitOpen { message: String },
+
     #[error("Unknown issue type: {value}")]
     UnknownIssueType { value: String },
 
@@ -41,6 +85,12 @@ pub enum JiraError {
 
     #[error("Project key cannot be empty")]
     EmptyProjectKey,
+
+    #[error("Invalid project key: {value}")]
+    InvalidProjectKey { value: String },
+
+    #[error("Malformed issue key: {value}")]
+    MalformedIssueKey { value: String },
 }
 
 impl DomainError for JiraError {}
@@ -63,9 +113,54 @@ impl JiraError {
         }
     }
 
+    pub fn timeout(message: impl Into<String>) -> Self {
+        Self::Timeout {
+            message: message.into(),
+            cause: None,
+        }
+    }
+
+    pub fn timeout_with_cause(
+        message: impl Into<String>,
+        cause: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::Timeout {
+            message: message.into(),
+            cause: Some(Box::new(cause)),
+        }
+    }
+
+    /// Returns true if the operation was cancelled because it timed out.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout { .. })
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized {
+            message: message.into(),
+        }
+    }
+
+    /// Returns true if the Jira API rejected the configured credentials.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Unauthorized { .. })
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict {
+            message: message.into(),
+        }
+    }
+
+    /// Returns true if a write was rejected because the record changed since it was read.
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::Conflict { .. })
+    }
+
     pub fn api_error(message: impl Into<String>) -> Self {
         Self::ApiError {
             message: message.into(),
+            status: None,
             cause: None,
         }
     }
@@ -75,11 +170,80 @@ impl JiraError {
         cause: impl std::error::Error + Send + Sync + 'static,
     ) -> Self {
         Self::ApiError {
+            message: message.into(),
+            status: None,
+            cause: Some(Box::new(cause)),
+        }
+    }
+
+    pub fn api_error_with_status(status: u16, message: impl Into<String>) -> Self {
+        Self::ApiError {
+            message: message.into(),
+            status: Some(status),
+            cause: None,
+        }
+    }
+
+    /// Returns the HTTP status of a failed API call, if Jira answered.
+    pub fn api_status(&self) -> Option<u16> {
+        match self {
+            Self::ApiError { status, .. } => *status,
+            _ => None,
+        }
+    }
+
+    pub fn parse_error_with_cause(
+        message: impl Into<String>,
+        cause: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::ParseError {
             message: message.into(),
             cause: Some(Box::new(cause)),
         }
     }
 
+    /// Returns true if an API response could not be parsed, which retrying will not fix.
+    pub fn is_parse_error(&self) -> bool {
+        matches!(self, Self::ParseError { .. })
+    }
+
+    pub fn circuit_open(message: impl Into<String>) -> Self {
+        Self::CircuitOpen {
+            message: message.into(),
+        }
+    }
+
+    /// Returns true if the request was rejected without being sent because Jira is failing.
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self, Self::CircuitOpen { .. })
+    }
+
+    /// Returns true if the failure is likely transient, so the same operation may
+    /// succeed when tried again later: timeouts, API calls that got no response, were
+    /// rate limited (408, 429) or failed upstream (5xx), an open circuit and database
+    /// errors. Invalid input, other rejected API calls (such as a 400 for bad JQL or a
+    /// 404), rejected credentials, conflicts and unparseable responses are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ApiError { status, .. } => {
+                status.is_none_or(|status| matches!(status, 408 | 429 | 500..=599))
+            }
+            Self::Timeout { .. } | Self::CircuitOpen { .. } | Self::DatabaseError { .. } => true,
+            Self::InvalidId { .. }
+            | Self::Unauthorized { .. }
+            | Self::Conflict { .. }
+            | Self::ParseError { .. }
+            | Self::UnknownIssueType { .. }
+            | Self::UnknownPriority { .. }
+            | Self::InvalidProjectId { .. }
+            | Self::EmptyProjectName
+            | Self::ProjectNameTooLong { .. }
+            | Self::EmptyProjectKey
+            | Self::InvalidProjectKey { .. }
+            | Self::MalformedIssueKey { .. } => false,
+        }
+    }
+
     pub fn invalid_id(cause: impl std::error::Error + Send + Sync + 'static) -> Self {
         Self::InvalidId {
             cause: Some(Box::new(cause)),
@@ -113,4 +277,43 @@ impl JiraError {
     pub fn empty_project_key() -> Self {
         Self::EmptyProjectKey
     }
+
+    pub fn invalid_project_key(value: impl Into<String>) -> Self {
+        Self::InvalidProjectKey {
+            value: value.into(),
+        }
+    }
+
+    pub fn malformed_issue_key(value: impl Into<String>) -> Self {
+        Self::MalformedIssueKey {
+            value: value.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_without_response_is_retryable() {
+        assert!(JiraError::api_error("Failed to send request to Jira").is_retryable());
+    }
+
+    #[test]
+    fn test_api_error_is_retryable_for_rate_limits_and_server_errors() {
+        for status in [408, 429, 500, 502, 503, 504] {
+            let error = JiraError::api_error_with_status(status, "Jira API returned error");
+            assert!(error.is_retryable(), "{status}");
+            assert_eq!(error.api_status(), Some(status));
+        }
+    }
+
+    #[test]
+    fn test_api_error_is_not_retryable_for_client_errors() {
+        for status in [400, 403, 404, 410, 422] {
+            let error = JiraError::api_error_with_status(status, "Jira API returned error");
+            assert!(!error.is_retryable(), "{status}");
+        }
+    }
 }
@@ -0,0 +1,17 @@
+use super::DomainError;
+use thiserror::Error;
+
+/// Errors for JiraIssueFilter validation.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum JiraIssueFilterError {
+    #[error("Project filter must name at least one project")]
+    EmptyProjectList,
+}
+
+impl DomainError for JiraIssueFilterError {}
+
+impl JiraIssueFilterError {
+    pub fn empty_project_list() -> Self {
+        Self::EmptyProjectList
+    }
+}
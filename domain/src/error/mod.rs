@@ -1,9 +1,13 @@
 mod domain_error;
 pub mod jira;
+mod jira_issue_filter_error;
 mod page_number_error;
 mod page_size_error;
+mod sync_window_error;
 
 pub use domain_error::*;
 pub use jira::*;
+pub use jira_issue_filter_error::*;
 pub use page_number_error::*;
 pub use page_size_error::*;
+pub use sync_window_error::*;
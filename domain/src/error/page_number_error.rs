@@ -6,6 +6,9 @@ use thiserror::Error;
 pub enum PageNumberError {
     #[error("Page number must be at least 1, but was {value}")]
     BelowMinimum { value: i32 },
+
+    #[error("Page number must be at most {max}, but was {value}")]
+    AboveMaximum { value: i32, max: i32 },
 }
 
 impl DomainError for PageNumberError {}
@@ -14,4 +17,8 @@ impl PageNumberError {
     pub fn below_minimum(value: i32) -> Self {
         Self::BelowMinimum { value }
     }
+
+    pub fn above_maximum(value: i32, max: i32) -> Self {
+        Self::AboveMaximum { value, max }
+    }
 }
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Duration, Utc};
+use thiserror::Error;
+
+use super::DomainError;
+
+/// Errors for SyncWindow validation.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SyncWindowError {
+    #[error("Sync window end {until} is before its start {since}")]
+    UntilBeforeSince {
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    },
+
+    #[error("Sync window chunk size must be positive, but was {chunk_size}")]
+    NonPositiveChunkSize { chunk_size: Duration },
+}
+
+impl DomainError for SyncWindowError {}
+
+impl SyncWindowError {
+    pub fn until_before_since(since: DateTime<Utc>, until: DateTime<Utc>) -> Self {
+        Self::UntilBeforeSince { since, until }
+    }
+
+    pub fn non_positive_chunk_size(chunk_size: Duration) -> Self {
+        Self::NonPositiveChunkSize { chunk_size }
+    }
+}
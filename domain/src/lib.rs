@@ -2,4 +2,6 @@ pub mod entity;
 pub mod error;
 pub mod port;
 pub mod repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod value_object;
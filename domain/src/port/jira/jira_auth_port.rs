@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::error::JiraError;
+
+/// Port interface for checking access to the Jira API.
+/// This is implemented by the infrastructure layer adapter.
+#[async_trait]
+pub trait JiraAuthPort: Send + Sync {
+    /// Verifies that the configured credentials are accepted by Jira.
+    ///
+    /// Returns `JiraError::Unauthorized` if Jira rejects the credentials.
+    async fn validate_credentials(&self) -> Result<(), JiraError>;
+}
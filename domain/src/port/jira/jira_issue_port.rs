@@ -1,10 +1,34 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::StreamExt;
 use futures::stream::BoxStream;
 
-use crate::entity::jira::JiraIssue;
+use crate::entity::jira::{JiraChangelogEntry, JiraIssue};
 use crate::error::JiraError;
-use crate::value_object::jira::JiraProjectKey;
+use crate::value_object::jira::{JiraIssueKey, JiraProjectKey};
+
+/// One page of issues fetched from Jira.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssuePage {
+    /// The issues of the page that could be read.
+    pub issues: Vec<JiraIssue>,
+    /// Number of issues on the page that could not be converted and were left out.
+    pub rejected: usize,
+}
+
+impl JiraIssuePage {
+    pub fn new(issues: Vec<JiraIssue>) -> Self {
+        Self {
+            issues,
+            rejected: 0,
+        }
+    }
+
+    pub fn with_rejected(mut self, rejected: usize) -> Self {
+        self.rejected = rejected;
+        self
+    }
+}
 
 /// Port interface for fetching Jira issues from external API.
 /// This is implemented by the infrastructure layer adapter.
@@ -13,11 +37,39 @@ pub trait JiraIssuePort: Send + Sync {
     /// Fetches issues from the Jira API for the given project keys
     /// that have been updated since the specified time.
     ///
-    /// Returns a stream of results, where each item is either a batch of issues
+    /// Returns a stream of results, where each item is either a page of issues
     /// or an error. This allows for streaming paginated results asynchronously.
     fn fetch_issues(
         &self,
         project_keys: Vec<JiraProjectKey>,
         since: DateTime<Utc>,
-    ) -> BoxStream<'_, Result<Vec<JiraIssue>, JiraError>>;
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>>;
+
+    /// Fetches issues like [`fetch_issues`](Self::fetch_issues), leaving out issues
+    /// updated at or after `until`.
+    ///
+    /// The default drops those issues from the pages of `fetch_issues`; adapters that
+    /// can bound the query itself should override it.
+    fn fetch_issues_until(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.fetch_issues(project_keys, since)
+            .map(move |page| {
+                page.map(|mut page| {
+                    page.issues.retain(|issue| issue.updated_at < until);
+                    page
+                })
+            })
+            .boxed()
+    }
+
+    /// Fetches the complete change history of the issue with `key`, following every
+    /// page of the changelog.
+    async fn fetch_issue_changelog(
+        &self,
+        key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError>;
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 
 use crate::entity::jira::JiraProject;
 use crate::error::JiraError;
+use crate::value_object::jira::JiraProjectKey;
 
 /// Port interface for fetching Jira projects from external API.
 /// This is implemented by the infrastructure layer adapter.
@@ -11,4 +12,12 @@ pub trait JiraProjectPort: Send + Sync {
     ///
     /// Returns a list of all accessible projects, or an error if the API call fails.
     async fn fetch_projects(&self) -> Result<Vec<JiraProject>, JiraError>;
+
+    /// Fetches a single project by key from the Jira API.
+    ///
+    /// Returns None if the project does not exist.
+    async fn fetch_project_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProject>, JiraError>;
 }
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::error::JiraError;
+
+/// The kinds of sync that must not run concurrently with another sync of the same kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JiraSyncKind {
+    Projects,
+    Issues,
+    Changelogs,
+}
+
+/// A held sync lock. Dropping it without [`release`](Self::release) still frees the
+/// lock, though possibly only after a delay.
+#[async_trait]
+pub trait JiraSyncLock: Send {
+    /// Frees the lock so the next sync of the same kind can start.
+    async fn release(self: Box<Self>);
+}
+
+/// Port interface for excluding concurrent syncs across every running instance.
+/// This is implemented by the infrastructure layer adapter.
+#[async_trait]
+pub trait JiraSyncLockPort: Send + Sync {
+    /// Acquires the lock of `kind` without waiting.
+    ///
+    /// Returns `None` if another sync of the same kind holds it.
+    async fn try_acquire(
+        &self,
+        kind: JiraSyncKind,
+    ) -> Result<Option<Box<dyn JiraSyncLock>>, JiraError>;
+}
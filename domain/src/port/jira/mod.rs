@@ -1,5 +1,9 @@
+mod jira_auth_port;
 mod jira_issue_port;
 mod jira_project_port;
+mod jira_sync_lock_port;
 
+pub use jira_auth_port::*;
 pub use jira_issue_port::*;
 pub use jira_project_port::*;
+pub use jira_sync_lock_port::*;
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraChangelogEntry;
+use crate::error::JiraError;
+
+/// Repository interface for persisting the change history of Jira issues.
+/// This is implemented by the infrastructure layer.
+#[async_trait]
+pub trait JiraChangelogRepository: Send + Sync {
+    /// Stores the entries that are not stored yet, atomically.
+    ///
+    /// A Jira history never changes once written, so entries already stored are kept
+    /// as they are. Returns the number of newly stored entries.
+    async fn bulk_insert(&self, entries: Vec<JiraChangelogEntry>) -> Result<u64, JiraError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraIssueLink;
+use crate::error::JiraError;
+use crate::value_object::jira::JiraIssueKey;
+
+/// Repository interface for persisting links between Jira issues.
+/// This is implemented by the infrastructure layer.
+#[async_trait]
+pub trait JiraIssueLinkRepository: Send + Sync {
+    /// Replaces every stored link involving `issue_keys` with `links`, atomically.
+    ///
+    /// An issue's links as fetched from Jira are complete in both directions, so links
+    /// of these issues that are no longer reported are removed.
+    async fn replace_for_issues(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+        links: Vec<JiraIssueLink>,
+    ) -> Result<(), JiraError>;
+}
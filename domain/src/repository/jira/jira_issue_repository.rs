@@ -7,6 +7,6 @@ use crate::error::JiraError;
 /// This is implemented by the infrastructure layer.
 #[async_trait]
 pub trait JiraIssueRepository: Send + Sync {
-    /// Inserts or updates multiple issues atomically.
+    /// Inserts or updates multiple issues atomically, returning the issues written.
     async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError>;
 }
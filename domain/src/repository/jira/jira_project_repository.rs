@@ -14,9 +14,16 @@ pub trait JiraProjectRepository: Send + Sync {
     /// Finds a project by its ID.
     async fn find_by_id(&self, id: JiraProjectId) -> Result<Option<JiraProject>, JiraError>;
 
+    /// Returns whether a project with the given ID exists, without loading it.
+    async fn exists(&self, id: JiraProjectId) -> Result<bool, JiraError>;
+
     /// Creates a new Jira project.
     async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError>;
 
+    /// Creates multiple projects in one transaction.
+    /// Fails without creating any of them if one cannot be created.
+    async fn create_many(&self, projects: Vec<JiraProject>) -> Result<Vec<JiraProject>, JiraError>;
+
     /// Updates an existing Jira project.
     async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError>;
 
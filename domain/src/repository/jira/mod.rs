@@ -1,5 +1,9 @@
+mod jira_changelog_repository;
+mod jira_issue_link_repository;
 mod jira_issue_repository;
 pub mod jira_project_repository;
 
+pub use jira_changelog_repository::*;
+pub use jira_issue_link_repository::*;
 pub use jira_issue_repository::*;
 pub use jira_project_repository::*;
@@ -0,0 +1,54 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraChangelogEntry;
+use crate::error::JiraError;
+use crate::repository::jira::JiraChangelogRepository;
+use crate::test_support::ProgrammedFailures;
+
+/// In-memory [`JiraChangelogRepository`] keeping entries unique by history id and position.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraChangelogRepository {
+    entries: Mutex<Vec<JiraChangelogEntry>>,
+    failures: ProgrammedFailures<()>,
+}
+
+impl InMemoryJiraChangelogRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the next `bulk_insert` return `error` without storing any entry.
+    pub fn fail_next(self, error: JiraError) -> Self {
+        self.failures.push((), error);
+        self
+    }
+
+    /// Returns the stored entries, in insertion order.
+    pub fn entries(&self) -> Vec<JiraChangelogEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl JiraChangelogRepository for InMemoryJiraChangelogRepository {
+    async fn bulk_insert(&self, entries: Vec<JiraChangelogEntry>) -> Result<u64, JiraError> {
+        if let Some(error) = self.failures.take(()) {
+            return Err(error);
+        }
+
+        let mut stored = self.entries.lock().unwrap();
+        let mut inserted = 0;
+        for entry in entries {
+            let exists = stored
+                .iter()
+                .any(|e| e.history_id == entry.history_id && e.position == entry.position);
+            if !exists {
+                stored.push(entry);
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraIssueLink;
+use crate::error::JiraError;
+use crate::repository::jira::JiraIssueLinkRepository;
+use crate::test_support::ProgrammedFailures;
+use crate::value_object::jira::JiraIssueKey;
+
+/// In-memory [`JiraIssueLinkRepository`] holding the current set of links.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraIssueLinkRepository {
+    links: Mutex<Vec<JiraIssueLink>>,
+    failures: ProgrammedFailures<()>,
+}
+
+impl InMemoryJiraIssueLinkRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the next `replace_for_issues` return `error` without changing any link.
+    pub fn fail_next(self, error: JiraError) -> Self {
+        self.failures.push((), error);
+        self
+    }
+
+    /// Returns the stored links, in insertion order.
+    pub fn links(&self) -> Vec<JiraIssueLink> {
+        self.links.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl JiraIssueLinkRepository for InMemoryJiraIssueLinkRepository {
+    async fn replace_for_issues(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+        links: Vec<JiraIssueLink>,
+    ) -> Result<(), JiraError> {
+        if let Some(error) = self.failures.take(()) {
+            return Err(error);
+        }
+
+        let mut stored = self.links.lock().unwrap();
+        stored.retain(|link| !issue_keys.iter().any(|key| link.involves(key)));
+        for link in links {
+            if !stored.contains(&link) {
+                stored.push(link);
+            }
+        }
+        Ok(())
+    }
+}
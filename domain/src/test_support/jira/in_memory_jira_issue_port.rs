@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream};
+
+use crate::entity::jira::{JiraChangelogEntry, JiraIssue};
+use crate::error::JiraError;
+use crate::port::jira::{JiraIssuePage, JiraIssuePort};
+use crate::test_support::ProgrammedFailures;
+use crate::value_object::jira::{JiraIssueKey, JiraProjectKey};
+
+/// A method of [`JiraIssuePort`], used to program failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraIssuePortMethod {
+    FetchIssues,
+    FetchIssueChangelog,
+}
+
+/// A recorded call to [`JiraIssuePort::fetch_issues`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchIssuesCall {
+    pub project_keys: Vec<JiraProjectKey>,
+    pub since: DateTime<Utc>,
+}
+
+/// [`JiraIssuePort`] serving programmed pages of issues per project key.
+///
+/// A fetch streams the pages of each requested key in request order; keys without
+/// programmed pages contribute nothing. Issues without a programmed changelog have an
+/// empty history.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraIssuePort {
+    pages_by_project: HashMap<JiraProjectKey, Vec<JiraIssuePage>>,
+    changelogs: HashMap<JiraIssueKey, Vec<JiraChangelogEntry>>,
+    calls: Mutex<Vec<FetchIssuesCall>>,
+    failures: ProgrammedFailures<JiraIssuePortMethod>,
+}
+
+impl InMemoryJiraIssuePort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Programs the pages returned for `project_key`.
+    pub fn with_pages(self, project_key: &str, pages: Vec<Vec<JiraIssue>>) -> Self {
+        self.with_issue_pages(
+            project_key,
+            pages.into_iter().map(JiraIssuePage::new).collect(),
+        )
+    }
+
+    /// Programs the pages returned for `project_key`, including their rejected counts.
+    pub fn with_issue_pages(mut self, project_key: &str, pages: Vec<JiraIssuePage>) -> Self {
+        self.pages_by_project
+            .insert(JiraProjectKey::new(project_key), pages);
+        self
+    }
+
+    /// Programs the changelog returned for `issue_key`.
+    pub fn with_changelog(mut self, issue_key: &str, entries: Vec<JiraChangelogEntry>) -> Self {
+        self.changelogs
+            .insert(JiraIssueKey::new(issue_key), entries);
+        self
+    }
+
+    /// Makes the next call of `method` fail with `error`; an issue fetch yields it as
+    /// its only item.
+    pub fn fail_next(self, method: JiraIssuePortMethod, error: JiraError) -> Self {
+        self.failures.push(method, error);
+        self
+    }
+
+    /// Returns every fetch made so far, in call order.
+    pub fn calls(&self) -> Vec<FetchIssuesCall> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl JiraIssuePort for InMemoryJiraIssuePort {
+    fn fetch_issues(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.calls.lock().unwrap().push(FetchIssuesCall {
+            project_keys: project_keys.clone(),
+            since,
+        });
+        if let Some(error) = self.failures.take(JiraIssuePortMethod::FetchIssues) {
+            return Box::pin(stream::iter([Err(error)]));
+        }
+
+        let pages: Vec<JiraIssuePage> = project_keys
+            .iter()
+            .filter_map(|key| self.pages_by_project.get(key))
+            .flatten()
+            .cloned()
+            .collect();
+        Box::pin(stream::iter(pages.into_iter().map(Ok)))
+    }
+
+    async fn fetch_issue_changelog(
+        &self,
+        key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError> {
+        if let Some(error) = self.failures.take(JiraIssuePortMethod::FetchIssueChangelog) {
+            return Err(error);
+        }
+        Ok(self.changelogs.get(key).cloned().unwrap_or_default())
+    }
+}
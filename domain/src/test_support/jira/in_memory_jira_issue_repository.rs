@@ -0,0 +1,61 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraIssue;
+use crate::error::JiraError;
+use crate::repository::jira::JiraIssueRepository;
+use crate::test_support::ProgrammedFailures;
+use crate::value_object::jira::JiraIssueId;
+
+/// In-memory [`JiraIssueRepository`] that keeps every upserted batch.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraIssueRepository {
+    issues: Mutex<Vec<JiraIssue>>,
+    batches: Mutex<Vec<Vec<JiraIssueId>>>,
+    failures: ProgrammedFailures<()>,
+}
+
+impl InMemoryJiraIssueRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the next `bulk_upsert` return `error` without storing its batch.
+    pub fn fail_next(self, error: JiraError) -> Self {
+        self.failures.push((), error);
+        self
+    }
+
+    /// Returns the stored issues, one per id, in first-insertion order.
+    pub fn issues(&self) -> Vec<JiraIssue> {
+        self.issues.lock().unwrap().clone()
+    }
+
+    /// Returns the issue ids of every committed batch, in commit order.
+    pub fn batches(&self) -> Vec<Vec<JiraIssueId>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl JiraIssueRepository for InMemoryJiraIssueRepository {
+    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
+        if let Some(error) = self.failures.take(()) {
+            return Err(error);
+        }
+
+        let mut stored = self.issues.lock().unwrap();
+        for issue in &issues {
+            match stored.iter_mut().find(|existing| existing.id == issue.id) {
+                Some(existing) => *existing = issue.clone(),
+                None => stored.push(issue.clone()),
+            }
+        }
+        self.batches
+            .lock()
+            .unwrap()
+            .push(issues.iter().map(|issue| issue.id).collect());
+        Ok(issues)
+    }
+}
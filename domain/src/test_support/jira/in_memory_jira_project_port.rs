@@ -0,0 +1,70 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraProject;
+use crate::error::JiraError;
+use crate::port::jira::JiraProjectPort;
+use crate::test_support::ProgrammedFailures;
+use crate::value_object::jira::JiraProjectKey;
+
+/// A method of [`JiraProjectPort`], used to program failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraProjectPortMethod {
+    FetchProjects,
+    FetchProjectByKey,
+}
+
+/// [`JiraProjectPort`] serving a fixed set of projects, standing in for the Jira API.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraProjectPort {
+    projects: Vec<JiraProject>,
+    requested_keys: Mutex<Vec<JiraProjectKey>>,
+    failures: ProgrammedFailures<JiraProjectPortMethod>,
+}
+
+impl InMemoryJiraProjectPort {
+    /// Creates a port that serves `projects`.
+    pub fn new(projects: Vec<JiraProject>) -> Self {
+        Self {
+            projects,
+            ..Self::default()
+        }
+    }
+
+    /// Makes the next call of `method` return `error`.
+    pub fn fail_next(self, method: JiraProjectPortMethod, error: JiraError) -> Self {
+        self.failures.push(method, error);
+        self
+    }
+
+    /// Returns the keys passed to `fetch_project_by_key`, in call order.
+    pub fn requested_keys(&self) -> Vec<JiraProjectKey> {
+        self.requested_keys.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl JiraProjectPort for InMemoryJiraProjectPort {
+    async fn fetch_projects(&self) -> Result<Vec<JiraProject>, JiraError> {
+        if let Some(error) = self.failures.take(JiraProjectPortMethod::FetchProjects) {
+            return Err(error);
+        }
+        Ok(self.projects.clone())
+    }
+
+    async fn fetch_project_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProject>, JiraError> {
+        self.requested_keys.lock().unwrap().push(key.clone());
+        if let Some(error) = self.failures.take(JiraProjectPortMethod::FetchProjectByKey) {
+            return Err(error);
+        }
+        Ok(self
+            .projects
+            .iter()
+            .find(|project| &project.key == key)
+            .cloned())
+    }
+}
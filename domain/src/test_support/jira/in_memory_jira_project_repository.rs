@@ -0,0 +1,184 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::entity::jira::JiraProject;
+use crate::error::JiraError;
+use crate::repository::jira::JiraProjectRepository;
+use crate::test_support::ProgrammedFailures;
+use crate::value_object::jira::{JiraProjectId, JiraProjectKey};
+
+/// A method of [`JiraProjectRepository`], used to record calls and program failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraProjectRepositoryMethod {
+    FindAllProjectKeys,
+    FindById,
+    Exists,
+    Create,
+    CreateMany,
+    Update,
+    BulkUpsert,
+}
+
+/// In-memory [`JiraProjectRepository`] that stores projects in insertion order.
+///
+/// Writes replace a stored project with the same id, or append it otherwise.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraProjectRepository {
+    projects: Mutex<Vec<JiraProject>>,
+    calls: Mutex<Vec<JiraProjectRepositoryMethod>>,
+    failures: ProgrammedFailures<JiraProjectRepositoryMethod>,
+}
+
+impl InMemoryJiraProjectRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a repository pre-populated with `projects`.
+    pub fn with_projects(projects: Vec<JiraProject>) -> Self {
+        Self {
+            projects: Mutex::new(projects),
+            ..Self::default()
+        }
+    }
+
+    /// Makes the next call of `method` return `error` without touching the stored projects.
+    pub fn fail_next(self, method: JiraProjectRepositoryMethod, error: JiraError) -> Self {
+        self.failures.push(method, error);
+        self
+    }
+
+    /// Returns the stored projects.
+    pub fn projects(&self) -> Vec<JiraProject> {
+        self.projects.lock().unwrap().clone()
+    }
+
+    /// Returns the methods called so far, in call order.
+    pub fn calls(&self) -> Vec<JiraProjectRepositoryMethod> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: JiraProjectRepositoryMethod) -> Result<(), JiraError> {
+        self.calls.lock().unwrap().push(method);
+        match self.failures.take(method) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn store(&self, project: &JiraProject) {
+        let mut projects = self.projects.lock().unwrap();
+        match projects.iter_mut().find(|stored| stored.id == project.id) {
+            Some(stored) => *stored = project.clone(),
+            None => projects.push(project.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl JiraProjectRepository for InMemoryJiraProjectRepository {
+    async fn find_all_project_keys(&self) -> Result<Vec<JiraProjectKey>, JiraError> {
+        self.record(JiraProjectRepositoryMethod::FindAllProjectKeys)?;
+        Ok(self
+            .projects
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|project| project.key.clone())
+            .collect())
+    }
+
+    async fn find_by_id(&self, id: JiraProjectId) -> Result<Option<JiraProject>, JiraError> {
+        self.record(JiraProjectRepositoryMethod::FindById)?;
+        Ok(self
+            .projects
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|project| project.id == id)
+            .cloned())
+    }
+
+    async fn exists(&self, id: JiraProjectId) -> Result<bool, JiraError> {
+        self.record(JiraProjectRepositoryMethod::Exists)?;
+        Ok(self
+            .projects
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|project| project.id == id))
+    }
+
+    async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
+        self.record(JiraProjectRepositoryMethod::Create)?;
+        self.store(&project);
+        Ok(project)
+    }
+
+    async fn create_many(&self, projects: Vec<JiraProject>) -> Result<Vec<JiraProject>, JiraError> {
+        self.record(JiraProjectRepositoryMethod::CreateMany)?;
+        for project in &projects {
+            self.store(project);
+        }
+        Ok(projects)
+    }
+
+    async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
+        self.record(JiraProjectRepositoryMethod::Update)?;
+        self.store(&project);
+        Ok(project)
+    }
+
+    async fn bulk_upsert(&self, projects: Vec<JiraProject>) -> Result<Vec<JiraProject>, JiraError> {
+        self.record(JiraProjectRepositoryMethod::BulkUpsert)?;
+        for project in &projects {
+            self.store(project);
+        }
+        Ok(projects)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: i64, key: &str) -> JiraProject {
+        JiraProject::of(id.to_string(), key, "Project").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bulk_upsert_replaces_by_id_and_records_calls() {
+        let repository = InMemoryJiraProjectRepository::with_projects(vec![project(1, "OLD")]);
+
+        repository
+            .bulk_upsert(vec![project(1, "NEW"), project(2, "TWO")])
+            .await
+            .unwrap();
+        let keys = repository.find_all_project_keys().await.unwrap();
+
+        assert_eq!(
+            keys,
+            vec![JiraProjectKey::new("NEW"), JiraProjectKey::new("TWO")]
+        );
+        assert_eq!(
+            repository.calls(),
+            vec![
+                JiraProjectRepositoryMethod::BulkUpsert,
+                JiraProjectRepositoryMethod::FindAllProjectKeys
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fail_next_fails_only_the_programmed_call() {
+        let repository = InMemoryJiraProjectRepository::new().fail_next(
+            JiraProjectRepositoryMethod::Create,
+            JiraError::database_error("Insert failed"),
+        );
+
+        assert!(repository.create(project(1, "PROJ")).await.is_err());
+        assert!(repository.create(project(1, "PROJ")).await.is_ok());
+        assert_eq!(repository.projects(), vec![project(1, "PROJ")]);
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::error::JiraError;
+use crate::port::jira::{JiraSyncKind, JiraSyncLock, JiraSyncLockPort};
+use crate::test_support::ProgrammedFailures;
+
+/// [`JiraSyncLockPort`] keeping the held locks in memory, standing in for the database.
+#[derive(Debug, Default)]
+pub struct InMemoryJiraSyncLockPort {
+    held: Arc<Mutex<HashSet<JiraSyncKind>>>,
+    failures: ProgrammedFailures<()>,
+}
+
+impl InMemoryJiraSyncLockPort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the lock of `kind` as held by another instance, which never releases it.
+    pub fn held_elsewhere(self, kind: JiraSyncKind) -> Self {
+        self.held.lock().unwrap().insert(kind);
+        self
+    }
+
+    /// Makes the next `try_acquire` return `error`.
+    pub fn fail_next(self, error: JiraError) -> Self {
+        self.failures.push((), error);
+        self
+    }
+
+    /// Returns whether the lock of `kind` is currently held.
+    pub fn is_held(&self, kind: JiraSyncKind) -> bool {
+        self.held.lock().unwrap().contains(&kind)
+    }
+}
+
+#[async_trait]
+impl JiraSyncLockPort for InMemoryJiraSyncLockPort {
+    async fn try_acquire(
+        &self,
+        kind: JiraSyncKind,
+    ) -> Result<Option<Box<dyn JiraSyncLock>>, JiraError> {
+        if let Some(error) = self.failures.take(()) {
+            return Err(error);
+        }
+
+        if !self.held.lock().unwrap().insert(kind) {
+            return Ok(None);
+        }
+        Ok(Some(Box::new(InMemoryJiraSyncLock {
+            held: self.held.clone(),
+            kind,
+        })))
+    }
+}
+
+/// A lock handed out by [`InMemoryJiraSyncLockPort`], freed on release or drop.
+struct InMemoryJiraSyncLock {
+    held: Arc<Mutex<HashSet<JiraSyncKind>>>,
+    kind: JiraSyncKind,
+}
+
+#[async_trait]
+impl JiraSyncLock for InMemoryJiraSyncLock {
+    async fn release(self: Box<Self>) {}
+}
+
+impl Drop for InMemoryJiraSyncLock {
+    fn drop(&mut self) {
+        self.held.lock().unwrap().remove(&self.kind);
+    }
+}
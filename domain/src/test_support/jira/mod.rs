@@ -0,0 +1,15 @@
+mod in_memory_jira_changelog_repository;
+mod in_memory_jira_issue_link_repository;
+mod in_memory_jira_issue_port;
+mod in_memory_jira_issue_repository;
+mod in_memory_jira_project_port;
+mod in_memory_jira_project_repository;
+mod in_memory_jira_sync_lock_port;
+
+pub use in_memory_jira_changelog_repository::*;
+pub use in_memory_jira_issue_link_repository::*;
+pub use in_memory_jira_issue_port::*;
+pub use in_memory_jira_issue_repository::*;
+pub use in_memory_jira_project_port::*;
+pub use in_memory_jira_project_repository::*;
+pub use in_memory_jira_sync_lock_port::*;
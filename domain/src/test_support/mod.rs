@@ -0,0 +1,40 @@
+//! Configurable in-memory test doubles for the domain ports and repositories.
+//!
+//! Available to this crate's tests and, through the `test-support` feature, to
+//! downstream crates' tests. Each double records the calls it receives and can be
+//! programmed to fail, so use case tests don't need hand-written mocks.
+
+pub mod jira;
+
+use std::sync::Mutex;
+
+use crate::error::JiraError;
+
+/// Errors programmed to be returned by the next call of a given method.
+///
+/// Public so the test doubles of downstream crates can program failures the same way.
+#[derive(Debug)]
+pub struct ProgrammedFailures<M> {
+    failures: Mutex<Vec<(M, JiraError)>>,
+}
+
+impl<M: PartialEq> ProgrammedFailures<M> {
+    pub fn push(&self, method: M, error: JiraError) {
+        self.failures.lock().unwrap().push((method, error));
+    }
+
+    /// Removes and returns the first error programmed for `method`.
+    pub fn take(&self, method: M) -> Option<JiraError> {
+        let mut failures = self.failures.lock().unwrap();
+        let index = failures.iter().position(|(m, _)| *m == method)?;
+        Some(failures.remove(index).1)
+    }
+}
+
+impl<M> Default for ProgrammedFailures<M> {
+    fn default() -> Self {
+        Self {
+            failures: Mutex::new(Vec::new()),
+        }
+    }
+}
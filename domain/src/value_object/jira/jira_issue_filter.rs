@@ -0,0 +1,203 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::{JiraIssueKey, JiraProjectKey};
+use crate::entity::jira::JiraIssue;
+use crate::error::JiraIssueFilterError;
+
+/// A single condition a Jira issue has to meet to match a [`JiraIssueFilter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JiraIssueCriterion {
+    /// The issue belongs to one of these projects.
+    ProjectIn(Vec<JiraProjectKey>),
+    /// The issue was reported by the user with this Atlassian account ID.
+    ReportedBy(String),
+    /// The issue is unresolved and its due date has passed.
+    Overdue,
+    /// The issue was last updated at or after this time.
+    UpdatedSince(DateTime<Utc>),
+    /// The issue was last updated before this time.
+    UpdatedBefore(DateTime<Utc>),
+}
+
+/// Criteria selecting Jira issues, independent of where the issues are searched.
+///
+/// An issue matches when it meets every criterion; a filter without criteria matches
+/// every issue. The infrastructure translates the same filter into a SQL condition for
+/// stored issues and into JQL for the Jira API.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueFilter {
+    criteria: Vec<JiraIssueCriterion>,
+}
+
+impl JiraIssueFilter {
+    /// Creates a filter matching every issue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `criterion` to the conditions an issue has to meet.
+    fn with(mut self, criterion: JiraIssueCriterion) -> Self {
+        self.criteria.push(criterion);
+        self
+    }
+
+    /// Restricts the filter to issues of `project_keys`.
+    ///
+    /// Fails if `project_keys` is empty: JQL has no way to say `project in ()`, so such a
+    /// criterion would match nothing in SQL but be rejected by Jira.
+    pub fn with_projects(
+        self,
+        project_keys: Vec<JiraProjectKey>,
+    ) -> Result<Self, JiraIssueFilterError> {
+        if project_keys.is_empty() {
+            return Err(JiraIssueFilterError::empty_project_list());
+        }
+        Ok(self.with(JiraIssueCriterion::ProjectIn(project_keys)))
+    }
+
+    /// Restricts the filter to issues reported by `account_id`.
+    pub fn with_reporter(self, account_id: impl Into<String>) -> Self {
+        self.with(JiraIssueCriterion::ReportedBy(account_id.into()))
+    }
+
+    /// Restricts the filter to unresolved issues past their due date.
+    pub fn with_overdue(self) -> Self {
+        self.with(JiraIssueCriterion::Overdue)
+    }
+
+    /// Restricts the filter to issues updated in `[since, until)`; `until` is optional.
+    pub fn with_updated_between(self, since: DateTime<Utc>, until: Option<DateTime<Utc>>) -> Self {
+        let filter = self.with(JiraIssueCriterion::UpdatedSince(since));
+        match until {
+            Some(until) => filter.with(JiraIssueCriterion::UpdatedBefore(until)),
+            None => filter,
+        }
+    }
+
+    /// Returns the criteria in the order they were added.
+    pub fn criteria(&self) -> &[JiraIssueCriterion] {
+        &self.criteria
+    }
+
+    /// Returns true if the filter has no criteria.
+    pub fn is_empty(&self) -> bool {
+        self.criteria.is_empty()
+    }
+
+    /// Returns true if `issue` meets every criterion, with `today` deciding what is overdue.
+    pub fn matches(&self, issue: &JiraIssue, today: NaiveDate) -> bool {
+        self.criteria.iter().all(|criterion| match criterion {
+            JiraIssueCriterion::ProjectIn(keys) => project_key_in(&issue.key, keys),
+            JiraIssueCriterion::ReportedBy(account_id) => issue
+                .reporter
+                .as_ref()
+                .is_some_and(|reporter| reporter.account_id() == account_id),
+            JiraIssueCriterion::Overdue => {
+                issue.resolution.is_none() && issue.due_date.is_some_and(|due| due < today)
+            }
+            JiraIssueCriterion::UpdatedSince(since) => issue.updated_at >= *since,
+            JiraIssueCriterion::UpdatedBefore(until) => issue.updated_at < *until,
+        })
+    }
+}
+
+fn project_key_in(issue_key: &JiraIssueKey, project_keys: &[JiraProjectKey]) -> bool {
+    issue_key
+        .project_key()
+        .is_ok_and(|key| project_keys.contains(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::jira::JiraIssueBuilder;
+    use crate::value_object::jira::{
+        JiraIssueId, JiraIssuePriority, JiraIssueType, JiraProjectId, JiraUser,
+    };
+    use chrono::TimeZone;
+
+    fn issue(key: &str, due_date: Option<NaiveDate>, resolution: Option<&str>) -> JiraIssue {
+        JiraIssueBuilder::new()
+            .id(JiraIssueId::new(1))
+            .project_id(JiraProjectId::new(100))
+            .key(JiraIssueKey::new(key))
+            .summary("Issue")
+            .issue_type(JiraIssueType::Task)
+            .priority(JiraIssuePriority::Medium)
+            .reporter(Some(JiraUser::new("account-1", "Jane Doe")))
+            .due_date(due_date)
+            .resolution(resolution.map(String::from))
+            .created_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .updated_at(Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+    }
+
+    #[test]
+    fn test_empty_filter_matches_every_issue() {
+        let filter = JiraIssueFilter::new();
+
+        assert!(filter.is_empty());
+        assert!(filter.matches(&issue("PROJ-1", None, None), today()));
+    }
+
+    #[test]
+    fn test_empty_project_list_is_rejected() {
+        assert_eq!(
+            JiraIssueFilter::new().with_projects(vec![]),
+            Err(JiraIssueFilterError::EmptyProjectList)
+        );
+    }
+
+    #[test]
+    fn test_filter_keeps_criteria_in_order() {
+        let since = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let filter = JiraIssueFilter::new()
+            .with_projects(vec![JiraProjectKey::new("PROJ")])
+            .unwrap()
+            .with_updated_between(since, None)
+            .with_overdue();
+
+        assert_eq!(
+            filter.criteria(),
+            &[
+                JiraIssueCriterion::ProjectIn(vec![JiraProjectKey::new("PROJ")]),
+                JiraIssueCriterion::UpdatedSince(since),
+                JiraIssueCriterion::Overdue,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matches_requires_every_criterion() {
+        let filter = JiraIssueFilter::new()
+            .with_projects(vec![JiraProjectKey::new("PROJ")])
+            .unwrap()
+            .with_reporter("account-1")
+            .with_overdue();
+        let due = NaiveDate::from_ymd_opt(2024, 1, 15);
+
+        assert!(filter.matches(&issue("PROJ-1", due, None), today()));
+        assert!(!filter.matches(&issue("OTHER-1", due, None), today()));
+        assert!(!filter.matches(&issue("PROJ-1", due, Some("Done")), today()));
+        assert!(!filter.matches(&issue("PROJ-1", None, None), today()));
+    }
+
+    #[test]
+    fn test_matches_updated_range_is_half_open() {
+        let updated = Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap();
+        let issue = issue("PROJ-1", None, None);
+
+        let from_update = JiraIssueFilter::new().with_updated_between(updated, None);
+        let until_update = JiraIssueFilter::new()
+            .with_updated_between(updated - chrono::Days::new(1), Some(updated));
+
+        assert!(from_update.matches(&issue, today()));
+        assert!(!until_update.matches(&issue, today()));
+    }
+}
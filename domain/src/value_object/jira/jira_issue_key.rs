@@ -1,17 +1,51 @@
+use crate::error::JiraError;
+
+use super::JiraProjectKey;
+
 /// Represents a Jira issue key (e.g., "PROJ-123").
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JiraIssueKey(String);
 
 impl JiraIssueKey {
-    /// Creates a new JiraIssueKey.
+    /// Creates a new JiraIssueKey without validation.
     pub fn new(value: impl Into<String>) -> Self {
         Self(value.into())
     }
 
+    /// Creates a new JiraIssueKey with validation.
+    ///
+    /// Fails with `MalformedIssueKey` unless the key is a valid project key, a hyphen
+    /// and a numeric suffix (`PROJ-123`).
+    pub fn of(value: impl Into<String>) -> Result<Self, JiraError> {
+        let key = Self::new(value);
+        match key.project_key() {
+            Ok(_) => Ok(key),
+            Err(_) => Err(JiraError::malformed_issue_key(key.0)),
+        }
+    }
+
     /// Returns the inner value.
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// Returns the key of the project this issue belongs to, i.e. the part before
+    /// the issue number (`PROJ` for `PROJ-123`).
+    ///
+    /// Fails with `MalformedIssueKey` unless the key is a non-empty prefix, a hyphen
+    /// and a numeric suffix.
+    pub fn project_key(&self) -> Result<JiraProjectKey, JiraError> {
+        match self.0.rsplit_once('-') {
+            Some((prefix, number))
+                if !prefix.is_empty()
+                    && !number.is_empty()
+                    && number.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                JiraProjectKey::of(prefix)
+            }
+            _ => Err(JiraError::malformed_issue_key(&self.0)),
+        }
+    }
 }
 
 impl From<String> for JiraIssueKey {
@@ -26,6 +60,19 @@ impl From<&str> for JiraIssueKey {
     }
 }
 
+impl AsRef<str> for JiraIssueKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Lets a `HashMap<JiraIssueKey, _>` be queried with a plain `&str`.
+impl std::borrow::Borrow<str> for JiraIssueKey {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 impl std::fmt::Display for JiraIssueKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -66,4 +113,62 @@ mod tests {
         let key = JiraIssueKey::new("PROJ-123");
         assert_eq!(format!("{}", key), "PROJ-123");
     }
+
+    #[test]
+    fn test_jira_issue_key_hash_map_lookup_by_str() {
+        let mut counts = std::collections::HashMap::new();
+        counts.insert(JiraIssueKey::new("PROJ-123"), 3);
+
+        assert_eq!(counts.get("PROJ-123"), Some(&3));
+        assert_eq!(counts.get("OTHER"), None);
+    }
+
+    #[test]
+    fn test_jira_issue_key_project_key() {
+        let key = JiraIssueKey::new("PROJ-123");
+        assert_eq!(key.project_key().unwrap(), JiraProjectKey::new("PROJ"));
+    }
+
+    #[test]
+    fn test_jira_issue_key_project_key_malformed() {
+        for value in ["PROJ123", "-123", "PROJ-", "PROJ-12a", ""] {
+            let result = JiraIssueKey::new(value).project_key();
+            assert!(
+                matches!(result, Err(JiraError::MalformedIssueKey { .. })),
+                "{value} should be malformed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_jira_issue_key_of_valid() {
+        assert_eq!(JiraIssueKey::of("PROJ-123").unwrap().value(), "PROJ-123");
+    }
+
+    #[test]
+    fn test_jira_issue_key_of_rejects_malformed_keys() {
+        for value in [
+            "PROJ123",
+            "proj-1",
+            "P-1",
+            "../myself-1",
+            "PROJ-1?expand=all",
+            "",
+        ] {
+            let result = JiraIssueKey::of(value);
+            assert!(
+                matches!(&result, Err(JiraError::MalformedIssueKey { value: v }) if v == value),
+                "{value} should be malformed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_jira_issue_key_as_ref() {
+        fn len(value: impl AsRef<str>) -> usize {
+            value.as_ref().len()
+        }
+
+        assert_eq!(len(JiraIssueKey::new("PROJ-123")), 8);
+    }
 }
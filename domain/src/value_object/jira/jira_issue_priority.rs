@@ -1,16 +1,40 @@
 use crate::error::JiraError;
 
 /// Represents the priority of a Jira issue.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// Non-exhaustive; matches outside this crate need a wildcard arm:
+///
+/// ```
+/// use domain::value_object::jira::JiraIssuePriority;
+///
+/// let urgent = match JiraIssuePriority::High {
+///     JiraIssuePriority::Highest | JiraIssuePriority::High => true,
+///     _ => false,
+/// };
+/// assert!(urgent);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum JiraIssuePriority {
     Lowest,
     Low,
+    #[default]
     Medium,
     High,
     Highest,
 }
 
 impl JiraIssuePriority {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 5] = [
+        Self::Highest,
+        Self::High,
+        Self::Medium,
+        Self::Low,
+        Self::Lowest,
+    ];
+
     /// Returns the string representation of the priority.
     pub fn as_str(&self) -> &'static str {
         match self {
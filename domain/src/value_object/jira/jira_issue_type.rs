@@ -1,16 +1,40 @@
 use crate::error::JiraError;
 
 /// Represents the type of a Jira issue.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Non-exhaustive; matches outside this crate need a wildcard arm:
+///
+/// ```
+/// use domain::value_object::jira::JiraIssueType;
+///
+/// let is_container = match JiraIssueType::Epic {
+///     JiraIssueType::Epic | JiraIssueType::Story => true,
+///     _ => false,
+/// };
+/// assert!(is_container);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum JiraIssueType {
     Epic,
     Story,
+    #[default]
     Task,
     Subtask,
     Bug,
 }
 
 impl JiraIssueType {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 5] = [
+        Self::Epic,
+        Self::Story,
+        Self::Task,
+        Self::Subtask,
+        Self::Bug,
+    ];
+
     /// Returns the string representation of the issue type.
     pub fn as_str(&self) -> &'static str {
         match self {
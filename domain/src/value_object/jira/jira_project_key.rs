@@ -2,6 +2,11 @@ use crate::error::JiraError;
 
 /// Represents a Jira project key (e.g., "PROJ").
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct JiraProjectKey(String);
 
 impl JiraProjectKey {
@@ -11,14 +16,28 @@ impl JiraProjectKey {
     }
 
     /// Creates a new JiraProjectKey with validation.
+    ///
+    /// The key must follow Jira's format: an uppercase letter followed by at least one
+    /// uppercase letter, digit or underscore. Keys end up in Jira API paths, so nothing
+    /// else is let through.
     pub fn of(value: impl Into<String>) -> Result<Self, JiraError> {
         let value = value.into();
         if value.is_empty() {
             return Err(JiraError::empty_project_key());
         }
+        if !Self::is_valid(&value) {
+            return Err(JiraError::invalid_project_key(value));
+        }
         Ok(Self(value))
     }
 
+    fn is_valid(value: &str) -> bool {
+        let mut bytes = value.bytes();
+        bytes.next().is_some_and(|b| b.is_ascii_uppercase())
+            && bytes.len() > 0
+            && bytes.all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+    }
+
     /// Returns the inner value.
     pub fn value(&self) -> &str {
         &self.0
@@ -41,6 +60,25 @@ impl TryFrom<&str> for JiraProjectKey {
     }
 }
 
+impl AsRef<str> for JiraProjectKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Hashes and compares like the inner string, so `&str` can look up keyed maps.
+impl std::borrow::Borrow<str> for JiraProjectKey {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<JiraProjectKey> for String {
+    fn from(value: JiraProjectKey) -> Self {
+        value.0
+    }
+}
+
 impl std::fmt::Display for JiraProjectKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -73,6 +111,33 @@ mod tests {
         assert_eq!(err.to_string(), "Project key cannot be empty");
     }
 
+    #[test]
+    fn test_jira_project_key_of_accepts_jira_format() {
+        for key in ["AB", "PROJ", "P2", "MY_PROJ_1"] {
+            assert!(JiraProjectKey::of(key).is_ok(), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_jira_project_key_of_rejects_other_formats() {
+        for key in [
+            "P",
+            "proj",
+            "2PROJ",
+            "_PROJ",
+            "PRO-J",
+            "../myself",
+            "X?expand=all",
+            "PROJ/",
+        ] {
+            let err = JiraProjectKey::of(key).unwrap_err();
+            assert!(
+                matches!(&err, JiraError::InvalidProjectKey { value } if value == key),
+                "{key}: {err}"
+            );
+        }
+    }
+
     #[test]
     fn test_jira_project_key_from_string() {
         let key = JiraProjectKey::new(String::from("DEV"));
@@ -92,4 +157,44 @@ mod tests {
         assert!(key.is_ok());
         assert_eq!(key.unwrap().value(), "TEST");
     }
+
+    #[test]
+    fn test_jira_project_key_hash_map_lookup_by_str() {
+        let mut counts = std::collections::HashMap::new();
+        counts.insert(JiraProjectKey::new("PROJ"), 3);
+
+        assert_eq!(counts.get("PROJ"), Some(&3));
+        assert_eq!(counts.get("OTHER"), None);
+    }
+
+    #[test]
+    fn test_jira_project_key_as_ref() {
+        fn len(value: impl AsRef<str>) -> usize {
+            value.as_ref().len()
+        }
+
+        assert_eq!(len(JiraProjectKey::new("PROJ")), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_project_key_serde_round_trip() {
+        let key = JiraProjectKey::new("PROJ");
+
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, r#""PROJ""#);
+        assert_eq!(serde_json::from_str::<JiraProjectKey>(&json).unwrap(), key);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_project_key_deserialize_empty_fails_validation() {
+        let err = serde_json::from_str::<JiraProjectKey>(r#""""#).unwrap_err();
+
+        // serde_json appends the position to the validation message
+        assert!(
+            err.to_string()
+                .starts_with(&JiraError::EmptyProjectKey.to_string())
+        );
+    }
 }
@@ -4,6 +4,11 @@ use crate::error::JiraError;
 /// - Must not be empty
 /// - Must not exceed 255 characters
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct JiraProjectName(String);
 
 impl JiraProjectName {
@@ -51,6 +56,12 @@ impl TryFrom<&str> for JiraProjectName {
     }
 }
 
+impl From<JiraProjectName> for String {
+    fn from(value: JiraProjectName) -> Self {
+        value.0
+    }
+}
+
 impl std::fmt::Display for JiraProjectName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -115,4 +126,39 @@ mod tests {
         assert!(name.is_ok());
         assert_eq!(name.unwrap().value(), "Test Project");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_project_name_serde_round_trip() {
+        let name = JiraProjectName::new("My Project");
+
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, r#""My Project""#);
+        assert_eq!(
+            serde_json::from_str::<JiraProjectName>(&json).unwrap(),
+            name
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_project_name_deserialize_empty_fails_validation() {
+        let err = serde_json::from_str::<JiraProjectName>(r#""""#).unwrap_err();
+
+        // serde_json appends the position to the validation message
+        assert!(
+            err.to_string()
+                .starts_with(&JiraError::EmptyProjectName.to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_project_name_deserialize_too_long_fails_validation() {
+        let json = format!(r#""{}""#, "a".repeat(256));
+
+        let err = serde_json::from_str::<JiraProjectName>(&json).unwrap_err();
+
+        assert!(err.to_string().contains("exceeds maximum length"));
+    }
 }
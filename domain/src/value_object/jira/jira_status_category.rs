@@ -0,0 +1,89 @@
+/// The category a Jira workflow status belongs to.
+///
+/// Every status, however a project names it, falls into one of these three,
+/// which makes the category the reliable way to tell whether work is finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JiraStatusCategory {
+    #[cfg_attr(feature = "serde", serde(rename = "To Do"))]
+    ToDo,
+    #[cfg_attr(feature = "serde", serde(rename = "In Progress"))]
+    InProgress,
+    Done,
+}
+
+impl JiraStatusCategory {
+    /// Every variant, in workflow order.
+    pub const ALL: [Self; 3] = [Self::ToDo, Self::InProgress, Self::Done];
+
+    /// Maps a Jira `statusCategory.key` ("new", "indeterminate", "done") to a category.
+    /// Returns None for keys without a category, such as "undefined".
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "new" => Some(Self::ToDo),
+            "indeterminate" => Some(Self::InProgress),
+            "done" => Some(Self::Done),
+            _ => None,
+        }
+    }
+
+    /// Returns the display name Jira uses for the category.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ToDo => "To Do",
+            Self::InProgress => "In Progress",
+            Self::Done => "Done",
+        }
+    }
+}
+
+impl std::fmt::Display for JiraStatusCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jira_status_category_from_key() {
+        assert_eq!(
+            JiraStatusCategory::from_key("new"),
+            Some(JiraStatusCategory::ToDo)
+        );
+        assert_eq!(
+            JiraStatusCategory::from_key("indeterminate"),
+            Some(JiraStatusCategory::InProgress)
+        );
+        assert_eq!(
+            JiraStatusCategory::from_key("done"),
+            Some(JiraStatusCategory::Done)
+        );
+    }
+
+    #[test]
+    fn test_jira_status_category_from_unknown_key() {
+        assert_eq!(JiraStatusCategory::from_key("undefined"), None);
+        assert_eq!(JiraStatusCategory::from_key("Done"), None);
+    }
+
+    #[test]
+    fn test_jira_status_category_display() {
+        assert_eq!(JiraStatusCategory::InProgress.to_string(), "In Progress");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jira_status_category_serializes_as_display_name() {
+        for category in JiraStatusCategory::ALL {
+            let json = serde_json::to_string(&category).unwrap();
+            assert_eq!(json, format!("\"{}\"", category.as_str()));
+            assert_eq!(
+                serde_json::from_str::<JiraStatusCategory>(&json).unwrap(),
+                category
+            );
+        }
+    }
+}
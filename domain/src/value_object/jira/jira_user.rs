@@ -0,0 +1,51 @@
+/// Represents a Jira user referenced by an issue (e.g., reporter).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JiraUser {
+    account_id: String,
+    display_name: String,
+}
+
+impl JiraUser {
+    /// Creates a new JiraUser.
+    pub fn new(account_id: impl Into<String>, display_name: impl Into<String>) -> Self {
+        Self {
+            account_id: account_id.into(),
+            display_name: display_name.into(),
+        }
+    }
+
+    /// Returns the Atlassian account ID.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Returns the display name.
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+}
+
+impl std::fmt::Display for JiraUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jira_user_creation() {
+        let user = JiraUser::new("5b10a2844c20165700ede21g", "Jane Doe");
+        assert_eq!(user.account_id(), "5b10a2844c20165700ede21g");
+        assert_eq!(user.display_name(), "Jane Doe");
+    }
+
+    #[test]
+    fn test_jira_user_display() {
+        let user = JiraUser::new("abc", "Jane Doe");
+        assert_eq!(format!("{}", user), "Jane Doe");
+    }
+}
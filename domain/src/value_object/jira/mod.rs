@@ -1,3 +1,4 @@
+mod jira_issue_filter;
 mod jira_issue_id;
 mod jira_issue_key;
 mod jira_issue_priority;
@@ -5,7 +6,10 @@ mod jira_issue_type;
 mod jira_project_id;
 mod jira_project_key;
 mod jira_project_name;
+mod jira_status_category;
+mod jira_user;
 
+pub use jira_issue_filter::*;
 pub use jira_issue_id::*;
 pub use jira_issue_key::*;
 pub use jira_issue_priority::*;
@@ -13,3 +17,5 @@ pub use jira_issue_type::*;
 pub use jira_project_id::*;
 pub use jira_project_key::*;
 pub use jira_project_name::*;
+pub use jira_status_category::*;
+pub use jira_user::*;
@@ -2,7 +2,9 @@ pub mod jira;
 mod page;
 mod page_number;
 mod page_size;
+mod sync_window;
 
 pub use page::*;
 pub use page_number::*;
 pub use page_size::*;
+pub use sync_window::*;
@@ -1,20 +1,66 @@
+use super::{PageNumber, PageSize};
+
 /// Generic container for paginated results.
+///
+/// `page_number` and `page_size` describe the requested page. A page created
+/// without pagination metadata is treated as a single page holding all items.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Page<T> {
     pub total_count: i32,
     pub items: Vec<T>,
+    pub page_number: i32,
+    pub page_size: i32,
 }
 
 impl<T> Page<T> {
     pub fn new(total_count: i32, items: Vec<T>) -> Self {
-        Self { total_count, items }
+        let page_size = items.len() as i32;
+        Self {
+            total_count,
+            items,
+            page_number: PageNumber::MIN_VALUE,
+            page_size,
+        }
     }
 
     pub fn empty() -> Self {
+        Self::new(0, Vec::new())
+    }
+
+    /// Attaches the requested page number and size to this page.
+    pub fn with_pagination(self, page_number: PageNumber, page_size: PageSize) -> Self {
         Self {
-            total_count: 0,
-            items: Vec::new(),
+            page_number: page_number.value(),
+            page_size: page_size.value(),
+            ..self
+        }
+    }
+
+    /// Returns the total number of pages for the current page size.
+    pub fn total_pages(&self) -> i32 {
+        if self.total_count <= 0 {
+            return 0;
+        }
+        if self.page_size <= 0 {
+            return 1;
         }
+        (self.total_count + self.page_size - 1) / self.page_size
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.page_number < self.total_pages()
+    }
+
+    pub fn has_previous_page(&self) -> bool {
+        self.page_number > PageNumber::MIN_VALUE
+    }
+
+    /// Returns true if the requested page lies past the last page.
+    ///
+    /// Tells "no more pages" apart from "no results": the first page of an empty
+    /// result is in range.
+    pub fn is_out_of_range(&self) -> bool {
+        self.page_number > self.total_pages().max(PageNumber::MIN_VALUE)
     }
 
     pub fn is_empty(&self) -> bool {
@@ -24,6 +70,29 @@ impl<T> Page<T> {
     pub fn len(&self) -> usize {
         self.items.len()
     }
+
+    /// Returns an iterator over the items on this page.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
 }
 
 impl<T> Default for Page<T> {
@@ -50,10 +119,90 @@ mod tests {
         assert!(page.is_empty());
     }
 
+    #[test]
+    fn test_page_with_pagination_middle_page() {
+        let page = Page::new(25, vec![11, 12, 13, 14, 15, 16, 17, 18, 19, 20])
+            .with_pagination(PageNumber::of(2).unwrap(), PageSize::of(10).unwrap());
+        assert_eq!(page.page_number, 2);
+        assert_eq!(page.page_size, 10);
+        assert_eq!(page.total_pages(), 3);
+        assert!(page.has_next_page());
+        assert!(page.has_previous_page());
+        assert!(!page.is_out_of_range());
+    }
+
+    #[test]
+    fn test_page_with_pagination_last_page() {
+        let page = Page::new(25, vec![21, 22, 23, 24, 25])
+            .with_pagination(PageNumber::of(3).unwrap(), PageSize::of(10).unwrap());
+        assert_eq!(page.total_pages(), 3);
+        assert!(!page.has_next_page());
+        assert!(page.has_previous_page());
+        assert!(!page.is_out_of_range());
+    }
+
+    #[test]
+    fn test_page_beyond_last_page_is_out_of_range() {
+        let page: Page<i32> = Page::new(25, vec![])
+            .with_pagination(PageNumber::of(999).unwrap(), PageSize::of(10).unwrap());
+        assert!(page.is_empty());
+        assert!(page.is_out_of_range());
+        assert!(!page.has_next_page());
+    }
+
+    #[test]
+    fn test_page_first_page_of_empty_result_is_in_range() {
+        let page: Page<i32> = Page::new(0, vec![])
+            .with_pagination(PageNumber::of(1).unwrap(), PageSize::of(10).unwrap());
+        assert!(!page.is_out_of_range());
+
+        let page: Page<i32> = Page::new(0, vec![])
+            .with_pagination(PageNumber::of(2).unwrap(), PageSize::of(10).unwrap());
+        assert!(page.is_out_of_range());
+    }
+
+    #[test]
+    fn test_page_without_pagination_is_single_page() {
+        let page = Page::new(3, vec![1, 2, 3]);
+        assert_eq!(page.page_number, 1);
+        assert_eq!(page.total_pages(), 1);
+        assert!(!page.has_next_page());
+        assert!(!page.has_previous_page());
+    }
+
+    #[test]
+    fn test_page_empty_has_no_pages() {
+        let page: Page<i32> = Page::empty();
+        assert_eq!(page.total_pages(), 0);
+        assert!(!page.has_next_page());
+    }
+
     #[test]
     fn test_page_default() {
         let page: Page<String> = Page::default();
         assert_eq!(page.total_count, 0);
         assert!(page.is_empty());
     }
+
+    #[test]
+    fn test_page_iter_and_len() {
+        let page = Page::new(10, vec![1, 2, 3]);
+        assert_eq!(page.len(), 3);
+        assert!(!page.is_empty());
+        assert_eq!(page.iter().sum::<i32>(), 6);
+
+        let mut seen = Vec::new();
+        for item in &page {
+            seen.push(*item);
+        }
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert_eq!(page.total_count, 10);
+    }
+
+    #[test]
+    fn test_page_into_iter_yields_items() {
+        let page = Page::new(10, vec!["a".to_string(), "b".to_string()]);
+        let items: Vec<String> = page.into_iter().collect();
+        assert_eq!(items, vec!["a".to_string(), "b".to_string()]);
+    }
 }
@@ -1,18 +1,25 @@
 use crate::error::PageNumberError;
 
+use super::PageSize;
+
 /// Represents a page number for pagination.
-/// Must be at least 1.
+/// Must be between 1 and MAX_VALUE.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PageNumber(i32);
 
 impl PageNumber {
     pub const MIN_VALUE: i32 = 1;
+    /// Largest page number whose offset fits in an i32 at the largest page size.
+    pub const MAX_VALUE: i32 = i32::MAX / PageSize::MAX_VALUE + 1;
 
     /// Creates a new PageNumber with validation.
     pub fn of(value: i32) -> Result<Self, PageNumberError> {
         if value < Self::MIN_VALUE {
             return Err(PageNumberError::below_minimum(value));
         }
+        if value > Self::MAX_VALUE {
+            return Err(PageNumberError::above_maximum(value, Self::MAX_VALUE));
+        }
         Ok(Self(value))
     }
 
@@ -20,6 +27,12 @@ impl PageNumber {
     pub fn value(&self) -> i32 {
         self.0
     }
+
+    /// Returns the row offset of this page for the given page size.
+    /// MAX_VALUE keeps it within i32 for every valid page size.
+    pub fn offset(&self, page_size: PageSize) -> i64 {
+        (self.0 as i64 - 1) * page_size.value() as i64
+    }
 }
 
 impl TryFrom<i32> for PageNumber {
@@ -74,4 +87,47 @@ mod tests {
         let page2 = PageNumber::of(5).unwrap();
         assert_eq!(page1, page2);
     }
+
+    #[test]
+    fn test_offset_first_page() {
+        let page_number = PageNumber::of(1).unwrap();
+        assert_eq!(page_number.offset(PageSize::of(10).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_offset_third_page() {
+        let page_number = PageNumber::of(3).unwrap();
+        assert_eq!(page_number.offset(PageSize::of(25).unwrap()), 50);
+    }
+
+    #[test]
+    fn test_page_number_above_maximum() {
+        let result = PageNumber::of(i32::MAX);
+        assert_eq!(
+            result.unwrap_err(),
+            PageNumberError::AboveMaximum {
+                value: i32::MAX,
+                max: PageNumber::MAX_VALUE
+            }
+        );
+    }
+
+    #[test]
+    fn test_offset_of_last_page_fits_in_i32() {
+        let page_number = PageNumber::of(PageNumber::MAX_VALUE).unwrap();
+        let offset = page_number.offset(PageSize::of(PageSize::MAX_VALUE).unwrap());
+        assert!(offset <= i32::MAX as i64);
+    }
+
+    #[test]
+    fn test_page_number_error_message_includes_offending_value() {
+        assert_eq!(
+            PageNumber::of(0).unwrap_err().to_string(),
+            "Page number must be at least 1, but was 0"
+        );
+        assert_eq!(
+            PageNumber::of(-5).unwrap_err().to_string(),
+            "Page number must be at least 1, but was -5"
+        );
+    }
 }
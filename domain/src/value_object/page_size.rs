@@ -95,4 +95,20 @@ mod tests {
         let size2 = PageSize::of(25).unwrap();
         assert_eq!(size1, size2);
     }
+
+    #[test]
+    fn test_page_size_error_message_includes_offending_value() {
+        assert_eq!(
+            PageSize::of(0).unwrap_err().to_string(),
+            "Page size must be at least 1, but was 0"
+        );
+        assert_eq!(
+            PageSize::of(-5).unwrap_err().to_string(),
+            "Page size must be at least 1, but was -5"
+        );
+        assert_eq!(
+            PageSize::of(101).unwrap_err().to_string(),
+            "Page size must be at most 100, but was 101"
+        );
+    }
 }
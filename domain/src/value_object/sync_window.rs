@@ -0,0 +1,149 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::error::SyncWindowError;
+
+/// Range of update times covered by a sync, from `since` inclusive to `until` exclusive.
+/// `since` is never after `until`; an empty range is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SyncWindow {
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+}
+
+impl SyncWindow {
+    /// Creates a new SyncWindow with validation.
+    pub fn of(since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Self, SyncWindowError> {
+        if until < since {
+            return Err(SyncWindowError::until_before_since(since, until));
+        }
+        Ok(Self { since, until })
+    }
+
+    /// Creates a SyncWindow ending at `until`, or at the current time when it is None.
+    pub fn until_or_now(
+        since: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Self, SyncWindowError> {
+        Self::of(since, until.unwrap_or_else(Utc::now))
+    }
+
+    pub fn since(&self) -> DateTime<Utc> {
+        self.since
+    }
+
+    pub fn until(&self) -> DateTime<Utc> {
+        self.until
+    }
+
+    /// Splits the range into consecutive windows of `chunk_size`, oldest first.
+    ///
+    /// The windows cover the range exactly: the last one is cut short at `until`, and an
+    /// empty range yields itself as the only window.
+    pub fn chunks(&self, chunk_size: Duration) -> Result<SyncWindowChunks, SyncWindowError> {
+        if chunk_size <= Duration::zero() {
+            return Err(SyncWindowError::non_positive_chunk_size(chunk_size));
+        }
+        Ok(SyncWindowChunks {
+            next_since: Some(self.since),
+            until: self.until,
+            chunk_size,
+        })
+    }
+}
+
+/// Iterator over the sub-windows of a [`SyncWindow`], created by [`SyncWindow::chunks`].
+#[derive(Debug, Clone)]
+pub struct SyncWindowChunks {
+    next_since: Option<DateTime<Utc>>,
+    until: DateTime<Utc>,
+    chunk_size: Duration,
+}
+
+impl Iterator for SyncWindowChunks {
+    type Item = SyncWindow;
+
+    fn next(&mut self) -> Option<SyncWindow> {
+        let since = self.next_since?;
+        let until = since
+            .checked_add_signed(self.chunk_size)
+            .map_or(self.until, |end| end.min(self.until));
+        self.next_since = (until < self.until).then_some(until);
+        Some(SyncWindow { since, until })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    fn window(since: u32, until: u32) -> SyncWindow {
+        SyncWindow::of(at(since), at(until)).unwrap()
+    }
+
+    #[test]
+    fn test_since_after_until_is_rejected() {
+        let result = SyncWindow::of(at(3), at(1));
+
+        assert_eq!(
+            result,
+            Err(SyncWindowError::UntilBeforeSince {
+                since: at(3),
+                until: at(1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_until_defaults_to_now() {
+        let since = Utc::now() - Duration::hours(1);
+
+        let sync_window = SyncWindow::until_or_now(since, None).unwrap();
+
+        assert_eq!(sync_window.since(), since);
+        assert!(sync_window.until() > since);
+        assert!(sync_window.until() <= Utc::now());
+    }
+
+    #[test]
+    fn test_range_shorter_than_chunk_is_a_single_window() {
+        let chunks: Vec<SyncWindow> = window(0, 1).chunks(Duration::hours(2)).unwrap().collect();
+
+        assert_eq!(chunks, vec![window(0, 1)]);
+    }
+
+    #[test]
+    fn test_empty_range_is_a_single_window() {
+        let chunks: Vec<SyncWindow> = window(4, 4).chunks(Duration::hours(2)).unwrap().collect();
+
+        assert_eq!(chunks, vec![window(4, 4)]);
+    }
+
+    #[test]
+    fn test_chunks_cover_the_range_exactly() {
+        let chunks: Vec<SyncWindow> = window(0, 5).chunks(Duration::hours(2)).unwrap().collect();
+
+        assert_eq!(chunks, vec![window(0, 2), window(2, 4), window(4, 5)]);
+    }
+
+    #[test]
+    fn test_chunks_dividing_the_range_evenly_have_no_empty_tail() {
+        let chunks: Vec<SyncWindow> = window(0, 4).chunks(Duration::hours(2)).unwrap().collect();
+
+        assert_eq!(chunks, vec![window(0, 2), window(2, 4)]);
+    }
+
+    #[test]
+    fn test_non_positive_chunk_size_is_rejected() {
+        let result = window(0, 5).chunks(Duration::zero());
+
+        assert!(matches!(
+            result,
+            Err(SyncWindowError::NonPositiveChunkSize { .. })
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+-- Add reporter columns to jira_issue
+ALTER TABLE jira_issue
+    ADD COLUMN reporter_account_id   VARCHAR(128),
+    ADD COLUMN reporter_display_name VARCHAR(255);
+
+CREATE INDEX idx_jira_issue_reporter_account_id ON jira_issue (reporter_account_id);
@@ -0,0 +1,3 @@
+-- Add updated_at to jira_project for optimistic concurrency on updates
+ALTER TABLE jira_project
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
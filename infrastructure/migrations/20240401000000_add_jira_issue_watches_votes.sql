@@ -0,0 +1,6 @@
+-- Add watcher and vote aggregates to jira_issue
+ALTER TABLE jira_issue
+    ADD COLUMN watches INTEGER,
+    ADD COLUMN votes   INTEGER;
+
+CREATE INDEX idx_jira_issue_watches ON jira_issue (watches);
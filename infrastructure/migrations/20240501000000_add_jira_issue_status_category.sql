@@ -0,0 +1,11 @@
+-- Add the status category (To Do / In Progress / Done) to jira_issue
+CREATE TYPE jira_status_category AS ENUM (
+    'to_do',
+    'in_progress',
+    'done'
+);
+
+ALTER TABLE jira_issue
+    ADD COLUMN status_category jira_status_category;
+
+CREATE INDEX idx_jira_issue_status_category ON jira_issue (status_category);
@@ -0,0 +1,11 @@
+-- Create jira_issue_link table
+-- Links reference issues by key, since the other side of a link may not be synced
+CREATE TABLE jira_issue_link
+(
+    source_key VARCHAR(50)  NOT NULL,
+    target_key VARCHAR(50)  NOT NULL,
+    link_type  VARCHAR(255) NOT NULL,
+    PRIMARY KEY (source_key, target_key, link_type)
+);
+
+CREATE INDEX idx_jira_issue_link_target_key ON jira_issue_link (target_key);
@@ -0,0 +1,5 @@
+-- Add time tracking, in seconds, to jira_issue
+ALTER TABLE jira_issue
+    ADD COLUMN original_estimate_seconds  BIGINT,
+    ADD COLUMN remaining_estimate_seconds BIGINT,
+    ADD COLUMN time_spent_seconds         BIGINT;
@@ -0,0 +1,7 @@
+-- Add the resolution and due date to jira_issue
+ALTER TABLE jira_issue
+    ADD COLUMN resolution TEXT,
+    ADD COLUMN due_date   DATE;
+
+-- Serves the overdue filter, which only looks at unresolved issues
+CREATE INDEX idx_jira_issue_unresolved_due_date ON jira_issue (due_date) WHERE resolution IS NULL;
@@ -0,0 +1,8 @@
+-- Plain-text descriptions get their own text column; the jsonb column is kept for ADF documents
+ALTER TABLE jira_issue ADD COLUMN description_text TEXT;
+
+-- Descriptions written so far are plain text stored as JSON strings
+UPDATE jira_issue
+SET description_text = description #>> '{}',
+    description      = NULL
+WHERE jsonb_typeof(description) = 'string';
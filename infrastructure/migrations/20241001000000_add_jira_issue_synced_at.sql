@@ -0,0 +1,6 @@
+-- Add the time each issue was last written by a sync, independent of Jira's updated_at
+ALTER TABLE jira_issue
+    ADD COLUMN synced_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+-- Serves the stale-issue filter
+CREATE INDEX idx_jira_issue_synced_at ON jira_issue (synced_at);
@@ -0,0 +1,17 @@
+-- Create jira_issue_changelog table
+-- One row per changed field; a Jira history id with the item position identifies it
+CREATE TABLE jira_issue_changelog
+(
+    history_id          BIGINT       NOT NULL,
+    position            INTEGER      NOT NULL,
+    issue_key           VARCHAR(50)  NOT NULL,
+    field               VARCHAR(255) NOT NULL,
+    from_value          TEXT,
+    to_value            TEXT,
+    author_account_id   VARCHAR(128),
+    author_display_name VARCHAR(255),
+    changed_at          TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (history_id, position)
+);
+
+CREATE INDEX idx_jira_issue_changelog_issue_key_changed_at ON jira_issue_changelog (issue_key, changed_at);
@@ -0,0 +1,3 @@
+-- Keep issues Jira reports without a priority instead of rejecting them
+ALTER TABLE jira_issue
+    ALTER COLUMN priority DROP NOT NULL;
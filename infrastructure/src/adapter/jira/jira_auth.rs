@@ -0,0 +1,147 @@
+use reqwest::RequestBuilder;
+
+use super::jira_api_config::JiraApiConfigError;
+
+/// Replaces credentials in redacted text.
+const REDACTED: &str = "[REDACTED]";
+
+/// Authentication mode for Jira API requests.
+#[derive(Clone, PartialEq, Eq)]
+pub enum JiraAuth {
+    /// HTTP basic auth with an account email and API token.
+    Basic { email: String, api_token: String },
+    /// Anonymous access for public Jira instances; no Authorization header is sent.
+    None,
+}
+
+impl JiraAuth {
+    /// Chooses basic auth when both credentials are present and anonymous mode when
+    /// neither is. Only one of them is a misconfiguration rather than a request for
+    /// anonymous access, so it is rejected. Empty values count as absent.
+    pub fn from_credentials(
+        email: Option<String>,
+        api_token: Option<String>,
+    ) -> Result<Self, JiraApiConfigError> {
+        let email = email.filter(|email| !email.is_empty());
+        let api_token = api_token.filter(|api_token| !api_token.is_empty());
+        match (email, api_token) {
+            (Some(email), Some(api_token)) => Ok(JiraAuth::Basic { email, api_token }),
+            (None, None) => Ok(JiraAuth::None),
+            _ => Err(JiraApiConfigError::PartialCredentials),
+        }
+    }
+
+    /// Applies this authentication to a request.
+    pub fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            JiraAuth::Basic { email, api_token } => request.basic_auth(email, Some(api_token)),
+            JiraAuth::None => request,
+        }
+    }
+
+    /// Replaces any occurrence of the configured email or API token in `text`.
+    /// Used before logging or surfacing Jira error bodies, which may echo request details.
+    pub fn redact(&self, text: &str) -> String {
+        match self {
+            JiraAuth::Basic { email, api_token } => text
+                .replace(api_token.as_str(), REDACTED)
+                .replace(email.as_str(), REDACTED),
+            JiraAuth::None => text.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Debug for JiraAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JiraAuth::Basic { .. } => f
+                .debug_struct("Basic")
+                .field("email", &REDACTED)
+                .field("api_token", &REDACTED)
+                .finish(),
+            JiraAuth::None => write!(f, "None"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+    use reqwest::header::AUTHORIZATION;
+
+    #[test]
+    fn test_none_sends_no_authorization_header() {
+        let request = JiraAuth::None
+            .apply(Client::new().get("https://example.atlassian.net/rest/api/3/project"))
+            .build()
+            .unwrap();
+
+        assert!(request.headers().get(AUTHORIZATION).is_none());
+    }
+
+    #[test]
+    fn test_basic_sends_authorization_header() {
+        let auth = JiraAuth::Basic {
+            email: "user@example.com".to_string(),
+            api_token: "token".to_string(),
+        };
+        let request = auth
+            .apply(Client::new().get("https://example.atlassian.net/rest/api/3/project"))
+            .build()
+            .unwrap();
+
+        assert!(request.headers().get(AUTHORIZATION).is_some());
+    }
+
+    #[test]
+    fn test_from_credentials_without_credentials_is_none() {
+        assert_eq!(
+            JiraAuth::from_credentials(None, None).unwrap(),
+            JiraAuth::None
+        );
+        assert_eq!(
+            JiraAuth::from_credentials(Some(String::new()), Some(String::new())).unwrap(),
+            JiraAuth::None
+        );
+    }
+
+    #[test]
+    fn test_from_credentials_with_only_one_credential_is_rejected() {
+        for (email, api_token) in [
+            (Some("user@example.com".to_string()), None),
+            (None, Some("token".to_string())),
+            (Some("user@example.com".to_string()), Some(String::new())),
+        ] {
+            assert!(matches!(
+                JiraAuth::from_credentials(email, api_token),
+                Err(JiraApiConfigError::PartialCredentials)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_redact_replaces_email_and_token() {
+        let auth = JiraAuth::Basic {
+            email: "user@example.com".to_string(),
+            api_token: "secret-token".to_string(),
+        };
+
+        let redacted = auth.redact("user user@example.com sent secret-token");
+
+        assert_eq!(redacted, "user [REDACTED] sent [REDACTED]");
+    }
+
+    #[test]
+    fn test_debug_does_not_expose_credentials() {
+        let auth = JiraAuth::Basic {
+            email: "user@example.com".to_string(),
+            api_token: "secret-token".to_string(),
+        };
+
+        let debug = format!("{:?}", auth);
+
+        assert!(!debug.contains("secret-token"));
+        assert!(!debug.contains("user@example.com"));
+    }
+}
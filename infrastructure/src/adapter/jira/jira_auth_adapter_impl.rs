@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode};
+use tracing::debug;
+
+use domain::error::JiraError;
+use domain::port::jira::JiraAuthPort;
+
+use super::jira_api_config::JiraApiConfig;
+use super::jira_rate_limiter::JiraRateLimiter;
+use super::jira_response::error_for_status;
+
+/// Implementation of JiraAuthPort that calls the Jira REST API v3 `myself` endpoint.
+pub struct JiraAuthAdapterImpl {
+    client: Client,
+    config: JiraApiConfig,
+    rate_limiter: Arc<JiraRateLimiter>,
+}
+
+impl JiraAuthAdapterImpl {
+    pub fn new(config: JiraApiConfig, rate_limiter: Arc<JiraRateLimiter>) -> Self {
+        let client = config
+            .client_tuning
+            .build_client()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            client,
+            config,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl JiraAuthPort for JiraAuthAdapterImpl {
+    async fn validate_credentials(&self) -> Result<(), JiraError> {
+        let url = self.config.api_url("rest/api/3/myself");
+        debug!("Checking Jira credentials: {}", url);
+
+        self.rate_limiter.acquire().await;
+
+        // No retry: a credential check should fail fast
+        let response = self
+            .config
+            .auth
+            .apply(self.client.get(&url))
+            .send()
+            .await
+            .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
+
+        let status = response.status();
+        if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+            return Err(JiraError::unauthorized(format!(
+                "Jira returned {} for the configured credentials",
+                status
+            )));
+        }
+
+        error_for_status(response, &self.config.auth).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::jira::{
+        JiraAuth, JiraCircuitBreakerPolicy, JiraClientTuning, JiraIssueTypeAliases, JiraRetryPolicy,
+    };
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn validate_with_status(status: u16) -> Result<(), JiraError> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(status))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy::default(),
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+
+        JiraAuthAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)))
+            .validate_credentials()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_ok() {
+        assert!(validate_with_status(200).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_unauthorized() {
+        let error = validate_with_status(401).await.unwrap_err();
+
+        assert!(error.is_unauthorized());
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_forbidden() {
+        let error = validate_with_status(403).await.unwrap_err();
+
+        assert!(error.is_unauthorized());
+    }
+}
@@ -0,0 +1,373 @@
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use domain::error::JiraError;
+
+/// Thresholds for the Jira API circuit breaker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraCircuitBreakerPolicy {
+    /// Consecutive failures that open the circuit; `0` disables the breaker.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a trial request is let through.
+    pub cooldown_secs: u64,
+}
+
+impl JiraCircuitBreakerPolicy {
+    /// Creates a new JiraCircuitBreakerPolicy from environment variables, falling back to defaults.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            failure_threshold: std::env::var("JIRA_CIRCUIT_BREAKER_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.failure_threshold),
+            cooldown_secs: std::env::var("JIRA_CIRCUIT_BREAKER_COOLDOWN_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.cooldown_secs),
+        }
+    }
+}
+
+impl Default for JiraCircuitBreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed {
+        consecutive_failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    /// The cooldown has elapsed; the outcome of a single trial request decides whether
+    /// the circuit closes.
+    HalfOpen {
+        trial_in_flight: bool,
+    },
+}
+
+/// How a request's outcome affects the circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Success,
+    /// A transient failure, one that says Jira itself may be unhealthy.
+    Failure,
+    /// A failure that says nothing about Jira's health, such as rejected credentials,
+    /// a 4xx response or an unparseable body.
+    Neutral,
+}
+
+impl Outcome {
+    fn of<T>(result: &Result<T, JiraError>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(e) if e.is_retryable() => Self::Failure,
+            Err(_) => Self::Neutral,
+        }
+    }
+}
+
+/// Circuit breaker guarding Jira API requests.
+///
+/// After `failure_threshold` consecutive transient failures the circuit opens and requests
+/// fail fast with [`JiraError::CircuitOpen`]; failures that are not retryable leave the
+/// count unchanged. Once the cooldown elapses a single trial request is let through while
+/// the others keep failing fast: a success closes the circuit, a transient failure reopens
+/// it for another cooldown.
+#[derive(Debug)]
+pub struct JiraCircuitBreaker {
+    policy: JiraCircuitBreakerPolicy,
+    state: Mutex<CircuitState>,
+}
+
+/// Frees the half-open trial slot if the trial request ends without an outcome, e.g.
+/// because its future was dropped.
+struct TrialGuard<'a>(&'a JiraCircuitBreaker);
+
+impl Drop for TrialGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.0.state.lock().unwrap();
+        if let CircuitState::HalfOpen {
+            trial_in_flight: true,
+        } = *state
+        {
+            *state = CircuitState::HalfOpen {
+                trial_in_flight: false,
+            };
+        }
+    }
+}
+
+impl JiraCircuitBreaker {
+    pub fn new(policy: JiraCircuitBreakerPolicy) -> Self {
+        Self {
+            policy,
+            state: Mutex::new(CircuitState::Closed {
+                consecutive_failures: 0,
+            }),
+        }
+    }
+
+    /// Runs `operation` unless the circuit is open, recording its outcome.
+    pub async fn call<T, Fut>(&self, operation: Fut) -> Result<T, JiraError>
+    where
+        Fut: Future<Output = Result<T, JiraError>>,
+    {
+        if self.policy.failure_threshold == 0 {
+            return operation.await;
+        }
+
+        let trial = self.try_acquire()?;
+        let _guard = trial.then(|| TrialGuard(self));
+        let result = operation.await;
+        self.record(Outcome::of(&result));
+        result
+    }
+
+    /// Lets the request through, or rejects it while the circuit is open or a half-open
+    /// trial is in flight. Returns whether the request is the trial.
+    fn try_acquire(&self) -> Result<bool, JiraError> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            CircuitState::Closed { .. } => Ok(false),
+            CircuitState::Open { until } if Instant::now() >= until => {
+                info!("Jira circuit breaker half-open, sending trial request");
+                *state = CircuitState::HalfOpen {
+                    trial_in_flight: true,
+                };
+                Ok(true)
+            }
+            CircuitState::HalfOpen {
+                trial_in_flight: false,
+            } => {
+                *state = CircuitState::HalfOpen {
+                    trial_in_flight: true,
+                };
+                Ok(true)
+            }
+            CircuitState::Open { .. }
+            | CircuitState::HalfOpen {
+                trial_in_flight: true,
+            } => Err(JiraError::circuit_open(
+                "Jira API is failing; requests are paused",
+            )),
+        }
+    }
+
+    /// Updates the circuit with the outcome of a request.
+    fn record(&self, outcome: Outcome) {
+        let mut state = self.state.lock().unwrap();
+        *state = match (*state, outcome) {
+            (_, Outcome::Success) => CircuitState::Closed {
+                consecutive_failures: 0,
+            },
+            // The trial told nothing about Jira's health; let the next request try
+            (CircuitState::HalfOpen { .. }, Outcome::Neutral) => CircuitState::HalfOpen {
+                trial_in_flight: false,
+            },
+            (state, Outcome::Neutral) => state,
+            (
+                CircuitState::Closed {
+                    consecutive_failures,
+                },
+                Outcome::Failure,
+            ) if consecutive_failures + 1 < self.policy.failure_threshold => CircuitState::Closed {
+                consecutive_failures: consecutive_failures + 1,
+            },
+            (_, Outcome::Failure) => {
+                warn!(
+                    "Jira circuit breaker open for {}s after repeated failures",
+                    self.policy.cooldown_secs
+                );
+                CircuitState::Open {
+                    until: Instant::now() + Duration::from_secs(self.policy.cooldown_secs),
+                }
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn breaker() -> JiraCircuitBreaker {
+        JiraCircuitBreaker::new(JiraCircuitBreakerPolicy {
+            failure_threshold: 2,
+            cooldown_secs: 10,
+        })
+    }
+
+    /// A mock request that counts how often it is actually sent.
+    async fn request(calls: &AtomicU32, succeed: bool) -> Result<(), JiraError> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        if succeed {
+            Ok(())
+        } else {
+            Err(JiraError::api_error("Jira is down"))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_transitions_closed_open_half_open_closed() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+
+        // Closed: failures below the threshold still reach Jira
+        assert!(breaker.call(request(&calls, false)).await.is_err());
+        assert!(breaker.call(request(&calls, false)).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Open: requests fail fast without being sent
+        let err = breaker.call(request(&calls, true)).await.unwrap_err();
+        assert!(err.is_circuit_open());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Half-open after the cooldown: the trial request is sent and closes the circuit
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(breaker.call(request(&calls, true)).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // Closed again
+        assert!(breaker.call(request(&calls, false)).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_trial_reopens_circuit() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+        for _ in 0..2 {
+            let _ = breaker.call(request(&calls, false)).await;
+        }
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(
+            !breaker
+                .call(request(&calls, false))
+                .await
+                .unwrap_err()
+                .is_circuit_open()
+        );
+
+        let err = breaker.call(request(&calls, true)).await.unwrap_err();
+        assert!(err.is_circuit_open());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_consecutive_failures() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+
+        let _ = breaker.call(request(&calls, false)).await;
+        let _ = breaker.call(request(&calls, true)).await;
+        let _ = breaker.call(request(&calls, false)).await;
+
+        assert!(breaker.call(request(&calls, true)).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_zero_threshold_disables_breaker() {
+        let breaker = JiraCircuitBreaker::new(JiraCircuitBreakerPolicy {
+            failure_threshold: 0,
+            cooldown_secs: 10,
+        });
+        let calls = AtomicU32::new(0);
+
+        for _ in 0..5 {
+            assert!(
+                !breaker
+                    .call(request(&calls, false))
+                    .await
+                    .unwrap_err()
+                    .is_circuit_open()
+            );
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_errors_do_not_open_circuit() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+
+        for _ in 0..5 {
+            let result = breaker
+                .call(async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(JiraError::unauthorized("Bad token"))
+                })
+                .await;
+            assert!(!result.unwrap_err().is_circuit_open());
+        }
+        let _ = breaker.call(request(&calls, false)).await;
+
+        // One transient failure after the non-retryable ones stays below the threshold
+        assert!(breaker.call(request(&calls, true)).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 7);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_lets_a_single_trial_through() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+        for _ in 0..2 {
+            let _ = breaker.call(request(&calls, false)).await;
+        }
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let trial = breaker.call(async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            released.await.unwrap();
+            Ok(())
+        });
+        let concurrent = async {
+            // Runs while the trial is waiting to be released
+            let result = breaker.call(request(&calls, true)).await;
+            release.send(()).unwrap();
+            result
+        };
+        let (trial, concurrent) = tokio::join!(trial, concurrent);
+
+        assert!(trial.is_ok());
+        assert!(concurrent.unwrap_err().is_circuit_open());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(breaker.call(request(&calls, true)).await.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dropped_trial_frees_the_trial_slot() {
+        let breaker = breaker();
+        let calls = AtomicU32::new(0);
+        for _ in 0..2 {
+            let _ = breaker.call(request(&calls, false)).await;
+        }
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        drop(
+            tokio::time::timeout(
+                Duration::from_secs(1),
+                breaker.call(std::future::pending::<Result<(), JiraError>>()),
+            )
+            .await,
+        );
+
+        assert!(breaker.call(request(&calls, true)).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use reqwest::Client;
+
+/// HTTP client tuning for the Jira adapters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraClientTuning {
+    pub timeout_secs: u64,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout_secs: u64,
+    pub requests_per_second: u32,
+    pub user_agent: String,
+}
+
+impl JiraClientTuning {
+    /// Creates a new JiraClientTuning from environment variables, falling back to defaults.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            timeout_secs: std::env::var("JIRA_HTTP_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.timeout_secs),
+            pool_max_idle_per_host: std::env::var("JIRA_POOL_MAX_IDLE_PER_HOST")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.pool_max_idle_per_host),
+            pool_idle_timeout_secs: std::env::var("JIRA_POOL_IDLE_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.pool_idle_timeout_secs),
+            requests_per_second: std::env::var("JIRA_REQUESTS_PER_SECOND")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.requests_per_second),
+            user_agent: std::env::var("JIRA_USER_AGENT").unwrap_or(default.user_agent),
+        }
+    }
+
+    /// Builds a reqwest client with these settings applied.
+    pub fn build_client(&self) -> Result<Client, reqwest::Error> {
+        Client::builder()
+            .timeout(Duration::from_secs(self.timeout_secs))
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(Duration::from_secs(self.pool_idle_timeout_secs))
+            .user_agent(&self.user_agent)
+            .build()
+    }
+}
+
+impl Default for JiraClientTuning {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 30,
+            pool_max_idle_per_host: 32,
+            pool_idle_timeout_secs: 90,
+            requests_per_second: 1,
+            user_agent: format!("clean-architecture-rust/{}", env!("CARGO_PKG_VERSION")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use wiremock::matchers::{header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Sends two requests one after the other and returns how many connections the server
+    /// accepted for them.
+    async fn connections_for_two_requests(tuning: JiraClientTuning) -> usize {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let counter = accepted.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    while let Ok(n) = socket.read(&mut buf).await {
+                        if n == 0 {
+                            break;
+                        }
+                        let response = b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n";
+                        if socket.write_all(response).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let client = tuning.build_client().unwrap();
+        for _ in 0..2 {
+            let response = client.get(&url).send().await.unwrap();
+            assert_eq!(response.status(), 200);
+        }
+        accepted.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_client_reuses_idle_connection_by_default() {
+        let connections = connections_for_two_requests(JiraClientTuning::default()).await;
+
+        assert_eq!(connections, 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_without_idle_pool_opens_connection_per_request() {
+        let tuning = JiraClientTuning {
+            pool_max_idle_per_host: 0,
+            ..Default::default()
+        };
+
+        let connections = connections_for_two_requests(tuning).await;
+
+        assert_eq!(connections, 2);
+    }
+
+    #[test]
+    fn test_default_user_agent_includes_version() {
+        let tuning = JiraClientTuning::default();
+        assert_eq!(
+            tuning.user_agent,
+            format!("clean-architecture-rust/{}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_sends_configured_user_agent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("user-agent", "jira-sync-test/1.0"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tuning = JiraClientTuning {
+            user_agent: "jira-sync-test/1.0".to_string(),
+            ..Default::default()
+        };
+        let response = tuning
+            .build_client()
+            .unwrap()
+            .get(server.uri())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+}
@@ -1,180 +1,733 @@
-use std::time::Duration;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use backoff::ExponentialBackoff;
-use backoff::future::retry_notify;
 use chrono::{DateTime, Utc};
-use futures::stream::BoxStream;
-use reqwest::Client;
-use tracing::{debug, error, warn};
+use futures::stream::{BoxStream, Stream};
+use futures::{FutureExt, StreamExt};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::{debug, info, warn};
 
-use domain::entity::jira::JiraIssue;
+use domain::entity::jira::{JiraChangelogEntry, JiraIssue};
 use domain::error::JiraError;
-use domain::port::jira::JiraIssuePort;
-use domain::value_object::jira::JiraProjectKey;
+use domain::port::jira::{JiraIssuePage, JiraIssuePort};
+use domain::value_object::jira::{JiraIssueFilter, JiraIssueKey, JiraProjectKey};
 
 use super::jira_api_config::JiraApiConfig;
-use crate::dto::jira::{JiraIssueResponseDto, JiraSearchRequestDto, JiraSearchResponseDto};
+use super::jira_circuit_breaker::JiraCircuitBreaker;
+use super::jira_issue_filter_jql::jira_issue_filter_jql;
+use super::jira_rate_limiter::JiraRateLimiter;
+use super::jira_response::{error_for_status, json_body};
+use super::jira_server_time_zone::{JiraServerTimeZone, JiraServerTimeZoneCache};
+use crate::dto::jira::{
+    JiraClassicSearchRequestDto, JiraClassicSearchResponseDto, JiraIssueResponseDto,
+    JiraSearchRequestDto, JiraSearchResponseDto,
+};
 
-const MAX_RESULTS: i32 = 100;
-const API_CALL_DELAY_MS: u64 = 1000;
-const INITIAL_BACKOFF_MS: u64 = 500;
-const MAX_ELAPSED_SECS: u64 = 30;
+pub(super) const MAX_RESULTS: i32 = 100;
+
+/// Issue fields requested from either search endpoint.
+const SEARCH_FIELDS: [&str; 18] = [
+    "project",
+    "summary",
+    "description",
+    "issuetype",
+    "priority",
+    "reporter",
+    "watches",
+    "votes",
+    "status",
+    "timetracking",
+    "timeoriginalestimate",
+    "timeestimate",
+    "timespent",
+    "resolution",
+    "duedate",
+    "issuelinks",
+    "created",
+    "updated",
+];
+
+/// Position of the next page of a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PageCursor {
+    /// Enhanced `search/jql` paging; None for the first page.
+    Token(Option<String>),
+    /// Classic `search` paging by offset.
+    StartAt(i32),
+}
 
 /// Implementation of JiraIssuePort that fetches issues from Jira REST API v3.
 pub struct JiraIssueAdapterImpl {
-    client: Client,
-    config: JiraApiConfig,
+    pub(super) client: Client,
+    pub(super) config: JiraApiConfig,
+    pub(super) rate_limiter: Arc<JiraRateLimiter>,
+    pub(super) circuit_breaker: JiraCircuitBreaker,
+    pub(super) server_time_zone: JiraServerTimeZoneCache,
+    /// Set once the enhanced search endpoint answered 404 or 410; later searches go
+    /// straight to the classic endpoint.
+    classic_search: AtomicBool,
 }
 
 impl JiraIssueAdapterImpl {
-    pub fn new(config: JiraApiConfig) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
+    pub fn new(config: JiraApiConfig, rate_limiter: Arc<JiraRateLimiter>) -> Self {
+        let client = config
+            .client_tuning
+            .build_client()
             .expect("Failed to create HTTP client");
 
-        Self { client, config }
-    }
+        let circuit_breaker = JiraCircuitBreaker::new(config.circuit_breaker.clone());
 
-    /// Builds the JQL query for fetching issues.
-    fn build_jql(&self, project_keys: &[JiraProjectKey], since: DateTime<Utc>) -> String {
-        // Quote each project key to handle reserved words like "IS"
-        let keys: Vec<String> = project_keys
-            .iter()
-            .map(|k| format!("\"{}\"", k.value()))
-            .collect();
-        let keys_str = keys.join(", ");
-        let since_str = since.format("%Y-%m-%d %H:%M").to_string();
+        Self {
+            client,
+            config,
+            rate_limiter,
+            circuit_breaker,
+            server_time_zone: JiraServerTimeZoneCache::default(),
+            classic_search: AtomicBool::new(false),
+        }
+    }
 
-        format!("project in ({}) AND updated >= '{}'", keys_str, since_str)
+    /// Builds the JQL query for fetching the issues matching `filter`, with times in the
+    /// server's `time_zone`. Sub-tasks are excluded when `include_subtasks` is off.
+    fn build_jql(&self, filter: &JiraIssueFilter, time_zone: JiraServerTimeZone) -> String {
+        let jql = match time_zone {
+            JiraServerTimeZone::Named(tz) => jira_issue_filter_jql(filter, &tz),
+            JiraServerTimeZone::Fixed(offset) => jira_issue_filter_jql(filter, &offset),
+        };
+        if self.config.include_subtasks {
+            jql
+        } else {
+            format!("{} AND issuetype != \"Sub-task\"", jql)
+        }
     }
 
-    /// Fetches a single page of issues from the API with retry logic.
+    /// Fetches the page of issues at `cursor` with retry logic, returning the issues and
+    /// the cursor of the next page.
+    ///
+    /// Uses the enhanced `search/jql` endpoint unless it has been found unavailable, in
+    /// which case the classic `search` endpoint is paged by offset instead. Falling back
+    /// mid-search restarts at the first page; the repeated issues are upserted again.
     async fn fetch_page(
         &self,
         jql: &str,
-        next_page_token: Option<String>,
-    ) -> Result<JiraSearchResponseDto, JiraError> {
-        let url = format!("{}/rest/api/3/search/jql", self.config.base_url);
+        cursor: PageCursor,
+    ) -> Result<(Vec<JiraIssueResponseDto>, Option<PageCursor>), JiraError> {
+        let start_at = match cursor {
+            PageCursor::Token(token) if !self.classic_search.load(Ordering::Relaxed) => {
+                match self.fetch_enhanced_page(jql, token).await? {
+                    Some(response) => {
+                        let next = response.next_page().map(|t| PageCursor::Token(Some(t)));
+                        return Ok((response.issues, next));
+                    }
+                    None => {
+                        if !self.classic_search.swap(true, Ordering::Relaxed) {
+                            warn!(
+                                "Jira enhanced search endpoint is unavailable, falling back to /rest/api/3/search"
+                            );
+                        }
+                        0
+                    }
+                }
+            }
+            PageCursor::Token(_) => 0,
+            PageCursor::StartAt(start_at) => start_at,
+        };
 
+        let response = self.fetch_classic_page(jql, start_at).await?;
+        let next = response.next_start_at().map(PageCursor::StartAt);
+        Ok((response.issues, next))
+    }
+
+    /// Fetches a page from `POST /rest/api/3/search/jql`.
+    /// Returns None without retrying when the endpoint answers 404 or 410.
+    async fn fetch_enhanced_page(
+        &self,
+        jql: &str,
+        next_page_token: Option<String>,
+    ) -> Result<Option<JiraSearchResponseDto>, JiraError> {
+        let url = self.config.api_url("rest/api/3/search/jql");
         let request = JiraSearchRequestDto {
             jql: jql.to_string(),
-            fields: vec![
-                "project".to_string(),
-                "summary".to_string(),
-                "description".to_string(),
-                "issuetype".to_string(),
-                "priority".to_string(),
-                "created".to_string(),
-                "updated".to_string(),
-            ],
+            fields: SEARCH_FIELDS.map(String::from).to_vec(),
             max_results: MAX_RESULTS,
             next_page_token,
         };
 
-        let backoff = ExponentialBackoff {
-            max_elapsed_time: Some(Duration::from_secs(MAX_ELAPSED_SECS)),
-            initial_interval: Duration::from_millis(INITIAL_BACKOFF_MS),
-            multiplier: 2.0,
-            ..Default::default()
+        self.config
+            .retry_policy
+            .retry(|| self.circuit_breaker.call(self.do_fetch(&url, &request)))
+            .await
+    }
+
+    /// Fetches a page from `POST /rest/api/3/search`.
+    async fn fetch_classic_page(
+        &self,
+        jql: &str,
+        start_at: i32,
+    ) -> Result<JiraClassicSearchResponseDto, JiraError> {
+        let url = self.config.api_url("rest/api/3/search");
+        let request = JiraClassicSearchRequestDto {
+            jql: jql.to_string(),
+            fields: SEARCH_FIELDS.map(String::from).to_vec(),
+            max_results: MAX_RESULTS,
+            start_at,
         };
 
-        retry_notify(
-            backoff,
-            || async {
-                self.do_fetch(&url, &request)
-                    .await
-                    .map_err(backoff::Error::transient)
-            },
-            |err, duration| {
-                warn!(
-                    "Jira API request failed: {}, retrying in {:?}",
-                    err, duration
-                );
+        self.config
+            .retry_policy
+            .retry(|| {
+                self.circuit_breaker
+                    .call(self.do_fetch(&url, &request))
+                    .map(|result| {
+                        result?.ok_or_else(|| {
+                            JiraError::api_error("Jira search endpoint is not available")
+                        })
+                    })
+            })
+            .await
+    }
+
+    /// Streams every page of issues matching the JQL.
+    fn fetch_pages(
+        &self,
+        jql: String,
+    ) -> impl Stream<Item = Result<JiraIssuePage, JiraError>> + '_ {
+        let description_max_chars = self.config.description_max_chars;
+        let issue_type_aliases = &self.config.issue_type_aliases;
+
+        futures::stream::unfold(
+            (jql, Some(PageCursor::Token(None))),
+            move |(jql, cursor)| async move {
+                // None means we've reached the end
+                let cursor = cursor?;
+
+                match self.fetch_page(&jql, cursor).await {
+                    Ok((issues, next)) => {
+                        let total = issues.len();
+                        let issues: Vec<JiraIssue> = issues
+                            .into_iter()
+                            .filter_map(|issue: JiraIssueResponseDto| {
+                                let key = issue.key.clone();
+                                issue
+                                    .into_domain_detailed(description_max_chars, issue_type_aliases)
+                                    .inspect_err(|e| warn!("Skipping Jira issue {}: {}", key, e))
+                                    .ok()
+                            })
+                            .collect();
+                        let rejected = total - issues.len();
+
+                        Some((
+                            Ok(JiraIssuePage::new(issues).with_rejected(rejected)),
+                            (jql, next),
+                        ))
+                    }
+                    Err(e) => Some((Err(e), (jql, None))),
+                }
             },
         )
-        .await
     }
 
     /// Performs the actual HTTP request.
-    async fn do_fetch(
-        &self,
-        url: &str,
-        request: &JiraSearchRequestDto,
-    ) -> Result<JiraSearchResponseDto, JiraError> {
-        debug!("Fetching issues from Jira: jql={}", request.jql);
+    /// A 404 or 410 response, meaning the endpoint is not available, yields None.
+    async fn do_fetch<B, T>(&self, url: &str, request: &B) -> Result<Option<T>, JiraError>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        debug!("Fetching issues from Jira: {}", url);
+
+        self.rate_limiter.acquire().await;
 
         let response = self
-            .client
-            .post(url)
-            .basic_auth(&self.config.email, Some(&self.config.api_token))
+            .config
+            .auth
+            .apply(self.client.post(url))
             .json(request)
             .send()
             .await
             .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            error!("Jira API error: status={}, body={}", status, body);
-            return Err(JiraError::api_error(format!(
-                "Jira API returned error: {} - {}",
-                status, body
-            )));
+        if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) {
+            return Ok(None);
         }
 
-        response
-            .json::<JiraSearchResponseDto>()
-            .await
-            .map_err(|e| JiraError::api_error_with_cause("Failed to parse Jira response", e))
+        let response = error_for_status(response, &self.config.auth).await?;
+
+        json_body::<T>(response).await.map(Some)
     }
 }
 
+impl JiraIssueAdapterImpl {
+    /// Streams the pages of one search over `project_keys`, bounded by `until` if given.
+    fn search(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        // Return empty stream if no project keys provided
+        let filter = match JiraIssueFilter::new().with_projects(project_keys) {
+            Ok(filter) => filter.with_updated_between(since, until),
+            Err(_) => {
+                warn!("No project keys provided, returning empty stream");
+                return Box::pin(futures::stream::empty());
+            }
+        };
+
+        // The JQL `updated` clause is evaluated in the server's timezone
+        Box::pin(
+            futures::stream::once(self.server_time_zone())
+                .map(move |time_zone| match time_zone {
+                    Ok(time_zone) => {
+                        let jql = self.build_jql(&filter, time_zone);
+                        // Logged once per search rather than per page to keep the output readable
+                        if self.config.log_jql {
+                            info!("Searching Jira issues: since={}, jql={}", since, jql);
+                        }
+                        self.fetch_pages(jql).left_stream()
+                    }
+                    Err(e) => futures::stream::once(async { Err(e) }).right_stream(),
+                })
+                .flatten(),
+        )
+    }
+}
+
+#[async_trait::async_trait]
 impl JiraIssuePort for JiraIssueAdapterImpl {
     fn fetch_issues(
         &self,
         project_keys: Vec<JiraProjectKey>,
         since: DateTime<Utc>,
-    ) -> BoxStream<'_, Result<Vec<JiraIssue>, JiraError>> {
-        // Return empty stream if no project keys provided
-        if project_keys.is_empty() {
-            warn!("No project keys provided, returning empty stream");
-            return Box::pin(futures::stream::empty());
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.search(project_keys, since, None)
+    }
+
+    fn fetch_issues_until(
+        &self,
+        project_keys: Vec<JiraProjectKey>,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<JiraIssuePage, JiraError>> {
+        self.search(project_keys, since, Some(until))
+    }
+
+    async fn fetch_issue_changelog(
+        &self,
+        key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError> {
+        self.fetch_changelog(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::jira::{
+        JiraAuth, JiraCircuitBreakerPolicy, JiraClientTuning, JiraIssueTypeAliases, JiraRetryPolicy,
+    };
+    use chrono::Offset;
+    use std::io::Write;
+    use std::sync::Mutex;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Collects formatted log output so tests can inspect it.
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
         }
 
-        let jql = self.build_jql(&project_keys, since);
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
 
-        Box::pin(futures::stream::unfold(
-            (jql, Some(String::new())),
-            move |(jql, next_token)| async move {
-                // None means we've reached the end
-                let token = next_token?;
-                let token_opt = if token.is_empty() { None } else { Some(token) };
+    #[tokio::test]
+    async fn test_jql_logged_once_per_search() {
+        let server = MockServer::start().await;
+        mount_utc_server_info(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_partial_json(
+                serde_json::json!({ "nextPageToken": "page-2" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "isLast": true
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "nextPageToken": "page-2"
+            })))
+            .mount(&server)
+            .await;
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy::default(),
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: true,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        let adapter = JiraIssueAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)));
 
-                // Add delay between API calls to avoid rate limiting
-                if token_opt.is_some() {
-                    tokio::time::sleep(Duration::from_millis(API_CALL_DELAY_MS)).await;
-                }
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_max_level(tracing::Level::INFO)
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
 
-                match self.fetch_page(&jql, token_opt).await {
-                    Ok(response) => {
-                        let issues: Vec<JiraIssue> = response
-                            .issues
-                            .into_iter()
-                            .filter_map(|issue: JiraIssueResponseDto| issue.into_domain())
-                            .collect();
+        let pages: Vec<_> = adapter
+            .fetch_issues(vec![JiraProjectKey::new("PROJ")], Utc::now())
+            .collect()
+            .await;
 
-                        let next = if response.is_last {
-                            None
-                        } else {
-                            response.next_page_token
-                        };
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(output.matches("jql=project in (\"PROJ\")").count(), 1);
+    }
 
-                        Some((Ok(issues), (jql, next)))
-                    }
-                    Err(e) => Some((Err(e), (jql, None))),
-                }
-            },
-        ))
+    fn create_adapter(include_subtasks: bool) -> JiraIssueAdapterImpl {
+        create_adapter_for("http://localhost".to_string(), include_subtasks)
+    }
+
+    fn create_adapter_for(base_url: String, include_subtasks: bool) -> JiraIssueAdapterImpl {
+        let config = JiraApiConfig {
+            base_url,
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy::default(),
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        JiraIssueAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)))
+    }
+
+    fn since() -> DateTime<Utc> {
+        "2024-01-02T03:04:00Z".parse().unwrap()
+    }
+
+    fn utc() -> JiraServerTimeZone {
+        JiraServerTimeZone::Fixed(Utc.fix())
+    }
+
+    fn filter(until: Option<DateTime<Utc>>) -> JiraIssueFilter {
+        JiraIssueFilter::new()
+            .with_projects(vec![JiraProjectKey::new("PROJ")])
+            .unwrap()
+            .with_updated_between(since(), until)
+    }
+
+    #[test]
+    fn test_build_jql_includes_subtasks_by_default() {
+        let jql = create_adapter(true).build_jql(&filter(None), utc());
+
+        assert_eq!(
+            jql,
+            "project in (\"PROJ\") AND updated >= '2024-01-02 03:04'"
+        );
+    }
+
+    #[test]
+    fn test_build_jql_excludes_subtasks_when_disabled() {
+        let jql = create_adapter(false).build_jql(&filter(None), utc());
+
+        assert_eq!(
+            jql,
+            "project in (\"PROJ\") AND updated >= '2024-01-02 03:04' AND issuetype != \"Sub-task\""
+        );
+    }
+
+    #[test]
+    fn test_build_jql_rounds_until_up_to_the_minute() {
+        let adapter = create_adapter(true);
+
+        let on_minute = adapter.build_jql(
+            &filter(Some("2024-01-03T00:00:00Z".parse().unwrap())),
+            utc(),
+        );
+        let within_minute = adapter.build_jql(
+            &filter(Some("2024-01-03T00:00:30Z".parse().unwrap())),
+            utc(),
+        );
+
+        assert_eq!(
+            on_minute,
+            "project in (\"PROJ\") AND updated >= '2024-01-02 03:04' AND updated < '2024-01-03 00:00'"
+        );
+        assert_eq!(
+            within_minute,
+            "project in (\"PROJ\") AND updated >= '2024-01-02 03:04' AND updated < '2024-01-03 00:01'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_jql_uses_server_timezone_fetched_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "serverTime": "2024-01-02T12:34:56.789+0900"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_partial_json(serde_json::json!({
+                "jql": "project in (\"PROJ\") AND updated >= '2024-01-02 12:04'"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "isLast": true
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+        let since: DateTime<Utc> = "2024-01-02T03:04:00Z".parse().unwrap();
+
+        for _ in 0..2 {
+            let pages: Vec<_> = adapter
+                .fetch_issues(vec![JiraProjectKey::new("PROJ")], since)
+                .collect()
+                .await;
+            assert!(pages.iter().all(Result::is_ok));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jql_follows_named_server_timezone_across_dst() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "serverTime": "2024-01-02T12:34:56.789+0100",
+                "timeZone": "Europe/Berlin"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(body_partial_json(serde_json::json!({
+                "jql": "project in (\"PROJ\") AND updated >= '2024-03-01 13:00' AND updated < '2024-04-01 14:00'"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "isLast": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+
+        let pages: Vec<_> = adapter
+            .fetch_issues_until(
+                vec![JiraProjectKey::new("PROJ")],
+                "2024-03-01T12:00:00Z".parse().unwrap(),
+                "2024-04-01T12:00:00Z".parse().unwrap(),
+            )
+            .collect()
+            .await;
+
+        assert!(pages.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn test_search_fails_when_server_time_zone_is_unknown() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "isLast": true
+            })))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let mut adapter = create_adapter_for(server.uri(), true);
+        adapter.config.retry_policy.initial_backoff_ms = 1;
+
+        let pages: Vec<_> = adapter
+            .fetch_issues(vec![JiraProjectKey::new("PROJ")], since())
+            .collect()
+            .await;
+
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_without_project_keys_sends_no_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+
+        let pages: Vec<_> = adapter.fetch_issues(vec![], since()).collect().await;
+
+        assert!(pages.is_empty());
+    }
+
+    async fn mount_utc_server_info(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "serverTime": "2024-01-02T12:34:56.789+0000"
+            })))
+            .mount(server)
+            .await;
+    }
+
+    fn issue_json(id: i64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id.to_string(),
+            "key": format!("PROJ-{}", id),
+            "fields": {
+                "project": { "id": "100", "key": "PROJ" },
+                "summary": "Test Issue",
+                "issuetype": { "name": "Task" },
+                "priority": { "name": "Medium" },
+                "created": "2024-01-01T00:00:00Z",
+                "updated": "2024-01-02T00:00:00Z"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_enhanced_search_is_used_when_available() {
+        let server = MockServer::start().await;
+        mount_utc_server_info(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [issue_json(1)],
+                "isLast": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+
+        let pages: Vec<_> = adapter
+            .fetch_issues(vec![JiraProjectKey::new("PROJ")], Utc::now())
+            .collect()
+            .await;
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap().issues.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unconvertible_issues_are_counted_as_rejected() {
+        let server = MockServer::start().await;
+        mount_utc_server_info(&server).await;
+        let mut unknown_type = issue_json(2);
+        unknown_type["fields"]["issuetype"]["name"] = "Spaceship".into();
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [issue_json(1), unknown_type],
+                "isLast": true
+            })))
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+
+        let pages: Vec<_> = adapter
+            .fetch_issues(vec![JiraProjectKey::new("PROJ")], Utc::now())
+            .collect()
+            .await;
+
+        let page = pages[0].as_ref().unwrap();
+        assert_eq!(page.issues.len(), 1);
+        assert_eq!(page.rejected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_classic_search_when_enhanced_search_is_gone() {
+        let server = MockServer::start().await;
+        mount_utc_server_info(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(410))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(serde_json::json!({ "startAt": 0 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [issue_json(1), issue_json(2)],
+                "startAt": 0,
+                "total": 3
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(serde_json::json!({ "startAt": 2 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [issue_json(3)],
+                "startAt": 2,
+                "total": 3
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri(), true);
+
+        // The second search goes straight to the classic endpoint
+        for _ in 0..2 {
+            let pages: Vec<_> = adapter
+                .fetch_issues(vec![JiraProjectKey::new("PROJ")], Utc::now())
+                .collect()
+                .await;
+
+            let counts: Vec<usize> = pages
+                .into_iter()
+                .map(|page| page.unwrap().issues.len())
+                .collect();
+            assert_eq!(counts, vec![2, 1]);
+        }
     }
 }
@@ -0,0 +1,194 @@
+use tracing::{debug, warn};
+
+use domain::entity::jira::JiraChangelogEntry;
+use domain::error::JiraError;
+use domain::value_object::jira::JiraIssueKey;
+
+use super::jira_api_config::path_segment;
+use super::jira_issue_adapter_impl::{JiraIssueAdapterImpl, MAX_RESULTS};
+use super::jira_response::{error_for_status, json_body};
+use crate::dto::jira::JiraChangelogPageDto;
+
+impl JiraIssueAdapterImpl {
+    /// Fetches every changelog page of `key` and flattens the histories into entries.
+    /// Histories with an unparseable id are skipped.
+    pub(super) async fn fetch_changelog(
+        &self,
+        key: &JiraIssueKey,
+    ) -> Result<Vec<JiraChangelogEntry>, JiraError> {
+        let mut entries = Vec::new();
+        let mut start_at = Some(0);
+
+        while let Some(offset) = start_at {
+            let page = self.fetch_changelog_page(key, offset).await?;
+            start_at = page.next_start_at();
+            for history in page.values {
+                let id = history.id.clone();
+                match history.into_domain(key) {
+                    Some(changes) => entries.extend(changes),
+                    None => warn!("Skipping changelog history {} of {}: invalid id", id, key),
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Fetches the changelog page of `key` starting at `start_at` with retry logic.
+    async fn fetch_changelog_page(
+        &self,
+        key: &JiraIssueKey,
+        start_at: i32,
+    ) -> Result<JiraChangelogPageDto, JiraError> {
+        let url = format!(
+            "{}?startAt={}&maxResults={}",
+            self.config.api_url(&format!(
+                "rest/api/3/issue/{}/changelog",
+                path_segment(key.value())
+            )),
+            start_at,
+            MAX_RESULTS
+        );
+
+        self.config
+            .retry_policy
+            .retry(|| self.circuit_breaker.call(self.do_get_changelog(&url)))
+            .await
+    }
+
+    /// Performs the HTTP request for a changelog page.
+    async fn do_get_changelog(&self, url: &str) -> Result<JiraChangelogPageDto, JiraError> {
+        debug!("Fetching issue changelog from Jira: {}", url);
+
+        self.rate_limiter.acquire().await;
+
+        let response = self
+            .config
+            .auth
+            .apply(self.client.get(url))
+            .send()
+            .await
+            .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
+
+        let response = error_for_status(response, &self.config.auth).await?;
+
+        json_body::<JiraChangelogPageDto>(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::adapter::jira::{
+        JiraApiConfig, JiraAuth, JiraCircuitBreakerPolicy, JiraClientTuning, JiraIssueTypeAliases,
+        JiraRateLimiter, JiraRetryPolicy,
+    };
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn create_adapter_for(base_url: String) -> JiraIssueAdapterImpl {
+        let config = JiraApiConfig {
+            base_url,
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy::default(),
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        JiraIssueAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)))
+    }
+
+    fn history_json(id: &str, field: &str, to: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "author": { "accountId": "account-1", "displayName": "Jane Doe" },
+            "created": "2024-01-02T09:30:00.000+0000",
+            "items": [{ "field": field, "fromString": null, "toString": to }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fetch_issue_changelog_follows_every_page() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/changelog"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": false,
+                "values": [
+                    history_json("10001", "status", "In Progress"),
+                    history_json("10002", "assignee", "Jane Doe")
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/changelog"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "isLast": true,
+                "values": [history_json("10003", "status", "Done")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri());
+
+        let entries = adapter
+            .fetch_changelog(&JiraIssueKey::new("PROJ-1"))
+            .await
+            .unwrap();
+
+        let changes: Vec<(i64, &str, Option<&str>)> = entries
+            .iter()
+            .map(|e| (e.history_id, e.field.as_str(), e.to_value.as_deref()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (10001, "status", Some("In Progress")),
+                (10002, "assignee", Some("Jane Doe")),
+                (10003, "status", Some("Done")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_issue_changelog_keeps_key_in_one_path_segment() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/..%2Fmyself%3Fx%3D1/changelog"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 0,
+                "isLast": true,
+                "values": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_for(server.uri());
+
+        let entries = adapter
+            .fetch_changelog(&JiraIssueKey::new("../myself?x=1"))
+            .await
+            .unwrap();
+
+        assert!(entries.is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, DurationRound, TimeDelta, TimeZone, Utc};
+
+use domain::value_object::jira::{JiraIssueCriterion, JiraIssueFilter};
+
+/// Translates `filter` into a JQL condition, with times written in `tz`, the Jira server's
+/// timezone. A filter without criteria yields an empty string.
+///
+/// JQL compares `updated` at minute precision, so an `UpdatedSince` bound is rounded
+/// down and an `UpdatedBefore` bound up; the search may return issues up to a minute
+/// outside the bounds.
+pub fn jira_issue_filter_jql<Tz>(filter: &JiraIssueFilter, tz: &Tz) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let clauses: Vec<String> = filter
+        .criteria()
+        .iter()
+        .map(|criterion| match criterion {
+            JiraIssueCriterion::ProjectIn(keys) => {
+                // Quote each project key to handle reserved words like "IS"
+                let keys: Vec<String> = keys.iter().map(|key| quote(key.value())).collect();
+                format!("project in ({})", keys.join(", "))
+            }
+            JiraIssueCriterion::ReportedBy(account_id) => {
+                format!("reporter = {}", quote(account_id))
+            }
+            JiraIssueCriterion::Overdue => "resolution IS EMPTY AND duedate < startOfDay()".into(),
+            JiraIssueCriterion::UpdatedSince(since) => {
+                format!("updated >= '{}'", jql_minute(*since, tz))
+            }
+            JiraIssueCriterion::UpdatedBefore(until) => {
+                let minute = TimeDelta::minutes(1);
+                let mut until_minute = until.duration_trunc(minute).unwrap_or(*until);
+                if until_minute < *until {
+                    until_minute += minute;
+                }
+                format!("updated < '{}'", jql_minute(until_minute, tz))
+            }
+        })
+        .collect();
+    clauses.join(" AND ")
+}
+
+/// Wraps `value` in double quotes, escaping quotes and backslashes inside it.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Formats `time` in `tz` the way JQL date comparisons expect.
+fn jql_minute<Tz>(time: DateTime<Utc>, tz: &Tz) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    time.with_timezone(tz).format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+    use domain::value_object::jira::JiraProjectKey;
+
+    #[test]
+    fn test_filter_translates_to_jql() {
+        let filter = JiraIssueFilter::new()
+            .with_projects(vec![JiraProjectKey::new("PROJ"), JiraProjectKey::new("IS")])
+            .unwrap()
+            .with_reporter("account-1")
+            .with_overdue()
+            .with_updated_between(
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 45).unwrap(),
+                Some(Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 30).unwrap()),
+            );
+
+        assert_eq!(
+            jira_issue_filter_jql(&filter, &FixedOffset::east_opt(9 * 3600).unwrap()),
+            "project in (\"PROJ\", \"IS\") AND reporter = \"account-1\" \
+             AND resolution IS EMPTY AND duedate < startOfDay() \
+             AND updated >= '2024-01-01 09:00' AND updated < '2024-02-01 09:01'"
+        );
+    }
+
+    #[test]
+    fn test_named_time_zone_writes_each_bound_with_its_own_offset() {
+        let filter = JiraIssueFilter::new().with_updated_between(
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap()),
+        );
+
+        // Berlin is UTC+1 in March before the change to summer time, UTC+2 in April
+        assert_eq!(
+            jira_issue_filter_jql(&filter, &chrono_tz::Europe::Berlin),
+            "updated >= '2024-03-01 13:00' AND updated < '2024-04-01 14:00'"
+        );
+    }
+
+    #[test]
+    fn test_quoted_values_are_escaped() {
+        let filter = JiraIssueFilter::new().with_reporter("a\"b\\c");
+
+        assert_eq!(
+            jira_issue_filter_jql(&filter, &FixedOffset::east_opt(0).unwrap()),
+            "reporter = \"a\\\"b\\\\c\""
+        );
+    }
+
+    #[test]
+    fn test_empty_filter_yields_empty_jql() {
+        assert_eq!(
+            jira_issue_filter_jql(&JiraIssueFilter::new(), &FixedOffset::east_opt(0).unwrap()),
+            ""
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use domain::error::JiraError;
+use domain::value_object::jira::JiraIssueType;
+
+/// Operator-defined issue type names mapped onto the canonical [`JiraIssueType`].
+///
+/// Jira instances name their issue types differently ("Sub-task", "Technical task",
+/// localized names). Aliases are matched case-insensitively and consulted before the
+/// built-in names, so a known name can also be remapped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JiraIssueTypeAliases {
+    aliases: HashMap<String, JiraIssueType>,
+}
+
+impl JiraIssueTypeAliases {
+    pub fn new(aliases: HashMap<String, JiraIssueType>) -> Self {
+        Self {
+            aliases: aliases
+                .into_iter()
+                .map(|(name, issue_type)| (name.to_lowercase(), issue_type))
+                .collect(),
+        }
+    }
+
+    /// Creates aliases from JIRA_ISSUE_TYPE_ALIASES, a comma-separated list of
+    /// `name=type` pairs such as `Sub-task=Subtask,Technical task=Task`.
+    /// Pairs whose type is not a built-in name are ignored.
+    pub fn from_env() -> Self {
+        std::env::var("JIRA_ISSUE_TYPE_ALIASES")
+            .map(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    fn parse(value: &str) -> Self {
+        Self::new(
+            value
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .filter_map(|(name, issue_type)| {
+                    let name = name.trim();
+                    let issue_type = issue_type.trim().parse().ok()?;
+                    (!name.is_empty()).then(|| (name.to_string(), issue_type))
+                })
+                .collect(),
+        )
+    }
+
+    /// Resolves an issue type name from Jira, trying the aliases before the built-in names.
+    pub fn resolve(&self, name: &str) -> Result<JiraIssueType, JiraError> {
+        match self.aliases.get(&name.to_lowercase()) {
+            Some(&issue_type) => Ok(issue_type),
+            None => name.parse(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases() -> JiraIssueTypeAliases {
+        JiraIssueTypeAliases::new(HashMap::from([
+            ("Sub-task".to_string(), JiraIssueType::Subtask),
+            ("Technical task".to_string(), JiraIssueType::Task),
+        ]))
+    }
+
+    #[test]
+    fn test_aliased_name_resolves_to_mapped_type() {
+        let aliases = aliases();
+
+        assert_eq!(aliases.resolve("Sub-task").unwrap(), JiraIssueType::Subtask);
+        assert_eq!(
+            aliases.resolve("technical TASK").unwrap(),
+            JiraIssueType::Task
+        );
+    }
+
+    #[test]
+    fn test_unaliased_name_falls_through_to_built_in_names() {
+        let aliases = aliases();
+
+        assert_eq!(aliases.resolve("Bug").unwrap(), JiraIssueType::Bug);
+        assert!(aliases.resolve("Incident").is_err());
+    }
+
+    #[test]
+    fn test_parse_skips_malformed_pairs() {
+        let aliases = JiraIssueTypeAliases::parse(
+            "Sub-task=Subtask, Aufgabe = task,Incident=Unknown,=Bug,Story",
+        );
+
+        assert_eq!(
+            aliases,
+            JiraIssueTypeAliases::new(HashMap::from([
+                ("Sub-task".to_string(), JiraIssueType::Subtask),
+                ("Aufgabe".to_string(), JiraIssueType::Task),
+            ]))
+        );
+    }
+}
@@ -1,93 +1,189 @@
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
 
-use backoff::ExponentialBackoff;
-use backoff::future::retry_notify;
-use reqwest::Client;
-use tracing::{debug, error, warn};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Client, StatusCode};
+use tracing::debug;
 
 use domain::entity::jira::JiraProject;
 use domain::error::JiraError;
 use domain::port::jira::JiraProjectPort;
+use domain::value_object::jira::JiraProjectKey;
 
-use super::jira_api_config::JiraApiConfig;
+use super::jira_api_config::{JiraApiConfig, path_segment};
+use super::jira_circuit_breaker::JiraCircuitBreaker;
+use super::jira_rate_limiter::JiraRateLimiter;
+use super::jira_response::{error_for_status, json_body};
 use crate::dto::jira::JiraProjectResponseDto;
 
-const INITIAL_BACKOFF_MS: u64 = 500;
-const MAX_ELAPSED_SECS: u64 = 30;
+/// The last project list Jira returned together with its ETag.
+struct CachedProjects {
+    etag: String,
+    projects: Vec<JiraProject>,
+}
+
+/// Response to a project list request.
+enum ProjectListResponse {
+    Modified {
+        etag: Option<String>,
+        projects: Vec<JiraProjectResponseDto>,
+    },
+    /// 304: the cached project list is still current.
+    NotModified,
+}
 
 /// Implementation of JiraProjectPort that fetches projects from Jira REST API v3.
+///
+/// With `project_etag_cache` enabled, the project list is kept in memory and requested
+/// again with `If-None-Match`, reusing it when Jira answers 304 Not Modified.
 pub struct JiraProjectAdapterImpl {
     client: Client,
     config: JiraApiConfig,
+    rate_limiter: Arc<JiraRateLimiter>,
+    circuit_breaker: JiraCircuitBreaker,
+    project_cache: Mutex<Option<CachedProjects>>,
 }
 
 impl JiraProjectAdapterImpl {
-    pub fn new(config: JiraApiConfig) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
+    pub fn new(config: JiraApiConfig, rate_limiter: Arc<JiraRateLimiter>) -> Self {
+        let client = config
+            .client_tuning
+            .build_client()
             .expect("Failed to create HTTP client");
 
-        Self { client, config }
+        let circuit_breaker = JiraCircuitBreaker::new(config.circuit_breaker.clone());
+
+        Self {
+            client,
+            config,
+            rate_limiter,
+            circuit_breaker,
+            project_cache: Mutex::new(None),
+        }
     }
 
-    /// Fetches all projects from the API with retry logic.
-    async fn fetch_all_projects(&self) -> Result<Vec<JiraProjectResponseDto>, JiraError> {
-        let url = format!("{}/rest/api/3/project", self.config.base_url);
+    /// Returns the ETag of the cached project list, if caching is enabled and one is held.
+    fn cached_etag(&self) -> Option<String> {
+        if !self.config.project_etag_cache {
+            return None;
+        }
+        self.project_cache
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|cache| cache.etag.clone())
+    }
 
-        let backoff = ExponentialBackoff {
-            max_elapsed_time: Some(Duration::from_secs(MAX_ELAPSED_SECS)),
-            initial_interval: Duration::from_millis(INITIAL_BACKOFF_MS),
-            multiplier: 2.0,
-            ..Default::default()
-        };
+    /// Fetches all projects from the API with retry logic, sending `etag` as
+    /// `If-None-Match` when given.
+    async fn fetch_all_projects(
+        &self,
+        etag: Option<&str>,
+    ) -> Result<ProjectListResponse, JiraError> {
+        let url = self.config.api_url("rest/api/3/project");
 
-        retry_notify(
-            backoff,
-            || async { self.do_fetch(&url).await.map_err(backoff::Error::transient) },
-            |err, duration| {
-                warn!(
-                    "Jira API request failed: {}, retrying in {:?}",
-                    err, duration
-                );
-            },
-        )
-        .await
+        self.config
+            .retry_policy
+            .retry(|| self.circuit_breaker.call(self.do_fetch(&url, etag)))
+            .await
     }
 
-    /// Performs the actual HTTP request.
-    async fn do_fetch(&self, url: &str) -> Result<Vec<JiraProjectResponseDto>, JiraError> {
-        debug!("Fetching projects from Jira: {}", url);
+    /// Fetches a single project by key with retry logic.
+    /// A 404 response is not retried and yields None.
+    async fn fetch_one_project(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProjectResponseDto>, JiraError> {
+        let url = self
+            .config
+            .api_url(&format!("rest/api/3/project/{}", path_segment(key.value())));
+
+        self.config
+            .retry_policy
+            .retry(|| self.circuit_breaker.call(self.do_fetch_one(&url)))
+            .await
+    }
+
+    /// Performs the HTTP request for a single project.
+    async fn do_fetch_one(&self, url: &str) -> Result<Option<JiraProjectResponseDto>, JiraError> {
+        debug!("Fetching project from Jira: {}", url);
+
+        self.rate_limiter.acquire().await;
 
         let response = self
-            .client
-            .get(url)
-            .basic_auth(&self.config.email, Some(&self.config.api_token))
+            .config
+            .auth
+            .apply(self.client.get(url))
             .send()
             .await
             .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            error!("Jira API error: status={}, body={}", status, body);
-            return Err(JiraError::api_error(format!(
-                "Jira API returned error: {} - {}",
-                status, body
-            )));
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
         }
 
-        response
-            .json::<Vec<JiraProjectResponseDto>>()
+        let response = error_for_status(response, &self.config.auth).await?;
+
+        json_body::<JiraProjectResponseDto>(response)
+            .await
+            .map(Some)
+    }
+
+    /// Performs the actual HTTP request.
+    async fn do_fetch(
+        &self,
+        url: &str,
+        etag: Option<&str>,
+    ) -> Result<ProjectListResponse, JiraError> {
+        debug!("Fetching projects from Jira: {}", url);
+
+        self.rate_limiter.acquire().await;
+
+        let mut request = self.config.auth.apply(self.client.get(url));
+        if let Some(etag) = etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = request
+            .send()
             .await
-            .map_err(|e| JiraError::api_error_with_cause("Failed to parse Jira response", e))
+            .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(ProjectListResponse::NotModified);
+        }
+
+        let response = error_for_status(response, &self.config.auth).await?;
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let projects = json_body::<Vec<JiraProjectResponseDto>>(response).await?;
+
+        Ok(ProjectListResponse::Modified { etag, projects })
     }
 }
 
 #[async_trait::async_trait]
 impl JiraProjectPort for JiraProjectAdapterImpl {
     async fn fetch_projects(&self) -> Result<Vec<JiraProject>, JiraError> {
-        let responses = self.fetch_all_projects().await?;
+        let cached_etag = self.cached_etag();
+        let (etag, responses) = match self.fetch_all_projects(cached_etag.as_deref()).await? {
+            ProjectListResponse::Modified { etag, projects } => (etag, projects),
+            ProjectListResponse::NotModified => {
+                let cache = self.project_cache.lock().unwrap();
+                let cached = cache.as_ref().ok_or_else(|| {
+                    JiraError::api_error(
+                        "Jira returned 304 Not Modified without a cached project list",
+                    )
+                })?;
+                debug!(
+                    "Project list unchanged, reusing {} cached projects",
+                    cached.projects.len()
+                );
+                return Ok(cached.projects.clone());
+            }
+        };
 
         let projects: Vec<JiraProject> = responses
             .into_iter()
@@ -96,6 +192,298 @@ impl JiraProjectPort for JiraProjectAdapterImpl {
 
         debug!("Fetched {} projects from Jira", projects.len());
 
+        if self.config.project_etag_cache {
+            *self.project_cache.lock().unwrap() = etag.map(|etag| CachedProjects {
+                etag,
+                projects: projects.clone(),
+            });
+        }
+
         Ok(projects)
     }
+
+    async fn fetch_project_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProject>, JiraError> {
+        match self.fetch_one_project(key).await? {
+            Some(response) => response
+                .into_domain()
+                .map(Some)
+                .ok_or_else(|| JiraError::api_error(format!("Invalid project data for {}", key))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::jira::{
+        JiraAuth, JiraCircuitBreakerPolicy, JiraClientTuning, JiraIssueTypeAliases, JiraRetryPolicy,
+    };
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn create_adapter(server: &MockServer) -> JiraProjectAdapterImpl {
+        create_adapter_with_auth(server, JiraAuth::None)
+    }
+
+    fn create_adapter_with_auth(server: &MockServer, auth: JiraAuth) -> JiraProjectAdapterImpl {
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy::default(),
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        JiraProjectAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_project_by_key_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project/PROJ"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "10000",
+                "key": "PROJ",
+                "name": "Project"
+            })))
+            .mount(&server)
+            .await;
+
+        let project = create_adapter(&server)
+            .fetch_project_by_key(&JiraProjectKey::new("PROJ"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(project.id.value(), 10000);
+        assert_eq!(project.key.value(), "PROJ");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_project_by_key_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project/MISSING"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = create_adapter(&server)
+            .fetch_project_by_key(&JiraProjectKey::new("MISSING"))
+            .await;
+
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_project_by_key_keeps_key_in_one_path_segment() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project/..%2Fmyself%3Fexpand%3Dall"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = create_adapter(&server)
+            .fetch_project_by_key(&JiraProjectKey::new("../myself?expand=all"))
+            .await;
+
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_projects_does_not_retry_malformed_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"id\": [not json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = create_adapter(&server).fetch_projects().await;
+
+        assert!(result.unwrap_err().is_parse_error());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_projects_stops_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy {
+                initial_backoff_ms: 1,
+                max_elapsed_secs: 30,
+                max_retries: 2,
+            },
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        let adapter =
+            JiraProjectAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)));
+
+        let result = adapter.fetch_projects().await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_projects_fails_fast_once_circuit_opens() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy {
+                initial_backoff_ms: 1,
+                max_elapsed_secs: 30,
+                max_retries: 5,
+            },
+            circuit_breaker: JiraCircuitBreakerPolicy {
+                failure_threshold: 2,
+                cooldown_secs: 60,
+            },
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        let adapter =
+            JiraProjectAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)));
+
+        let first = adapter.fetch_projects().await.unwrap_err();
+        let second = adapter.fetch_projects().await.unwrap_err();
+
+        assert!(first.is_circuit_open());
+        assert!(second.is_circuit_open());
+    }
+
+    #[tokio::test]
+    async fn test_error_body_redacts_credentials() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project/PROJ"))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .set_body_string("Invalid credentials for user@example.com: secret-token"),
+            )
+            .mount(&server)
+            .await;
+        let adapter = create_adapter_with_auth(
+            &server,
+            JiraAuth::Basic {
+                email: "user@example.com".to_string(),
+                api_token: "secret-token".to_string(),
+            },
+        );
+
+        let error = adapter
+            .do_fetch_one(&format!("{}/rest/api/3/project/PROJ", server.uri()))
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(!error.contains("secret-token"));
+        assert!(!error.contains("user@example.com"));
+        assert!(error.contains("[REDACTED]"));
+    }
+
+    fn create_caching_adapter(server: &MockServer) -> JiraProjectAdapterImpl {
+        let mut adapter = create_adapter(server);
+        adapter.config.project_etag_cache = true;
+        adapter
+    }
+
+    fn project_list_response() -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .insert_header("ETag", "\"v1\"")
+            .set_body_json(serde_json::json!([
+                { "id": "10000", "key": "PROJ", "name": "Project" }
+            ]))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_projects_reuses_cached_projects_on_not_modified() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(project_list_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+        let adapter = create_caching_adapter(&server);
+
+        let first = adapter.fetch_projects().await.unwrap();
+        let second = adapter.fetch_projects().await.unwrap();
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].key.value(), "PROJ");
+        assert_eq!(second, first);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_projects_without_cache_does_not_send_etag() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .and(header_exists("If-None-Match"))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/project"))
+            .respond_with(project_list_response())
+            .expect(2)
+            .mount(&server)
+            .await;
+        let adapter = create_adapter(&server);
+
+        adapter.fetch_projects().await.unwrap();
+        let second = adapter.fetch_projects().await.unwrap();
+
+        assert_eq!(second.len(), 1);
+    }
 }
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Token-bucket rate limiter shared by all Jira API calls.
+///
+/// The bucket holds a single token that refills every `1 / requests_per_second`,
+/// so callers are spaced evenly no matter how many fetch concurrently.
+#[derive(Debug)]
+pub struct JiraRateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl JiraRateLimiter {
+    /// Creates a limiter allowing `requests_per_second` requests per second (minimum 1).
+    pub fn per_second(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until a request is permitted.
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_limits_concurrent_requests() {
+        let limiter = Arc::new(JiraRateLimiter::per_second(2));
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_first_request_is_immediate() {
+        let limiter = JiraRateLimiter::per_second(2);
+        let start = Instant::now();
+
+        limiter.acquire().await;
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+}
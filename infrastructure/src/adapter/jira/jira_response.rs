@@ -0,0 +1,102 @@
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use tracing::error;
+
+use domain::error::JiraError;
+
+use super::jira_auth::JiraAuth;
+
+/// Passes a successful response through; otherwise logs and returns an API error carrying
+/// the status and the body, with the configured credentials redacted from it.
+pub async fn error_for_status(response: Response, auth: &JiraAuth) -> Result<Response, JiraError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = auth.redact(&response.text().await.unwrap_or_default());
+    error!("Jira API error: status={}, body={}", status, body);
+    Err(JiraError::api_error_with_status(
+        status.as_u16(),
+        format!("Jira API returned error: {} - {}", status, body),
+    ))
+}
+
+/// Reads the body of a successful response and parses it as JSON.
+///
+/// Failing to read the body, e.g. when the connection drops mid-body, is an API error
+/// and may be retried; only a body that is not the expected JSON is a parse error.
+pub async fn json_body<T: DeserializeOwned>(response: Response) -> Result<T, JiraError> {
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| JiraError::api_error_with_cause("Failed to read Jira response", e))?;
+    serde_json::from_slice(&body)
+        .map_err(|e| JiraError::parse_error_with_cause("Failed to parse Jira response", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_error_for_status_passes_success_and_reports_failure_with_status() {
+        let server = MockServer::start().await;
+        Mock::given(path("/ok"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(path("/fail"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("down"))
+            .mount(&server)
+            .await;
+        let get = |route: &str| reqwest::get(format!("{}{}", server.uri(), route));
+
+        let ok = error_for_status(get("/ok").await.unwrap(), &JiraAuth::None).await;
+        let failed = error_for_status(get("/fail").await.unwrap(), &JiraAuth::None).await;
+
+        assert_eq!(ok.unwrap().status(), 200);
+        let error = failed.unwrap_err();
+        assert_eq!(error.api_status(), Some(503));
+        let message = error.to_string();
+        assert!(message.contains("503"));
+        assert!(message.contains("down"));
+    }
+
+    #[tokio::test]
+    async fn test_json_body_reports_malformed_json_as_parse_error() {
+        let server = MockServer::start().await;
+        Mock::given(path("/bad"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"id\": [not json"))
+            .mount(&server)
+            .await;
+
+        let response = reqwest::get(format!("{}/bad", server.uri())).await.unwrap();
+        let result = json_body::<serde_json::Value>(response).await;
+
+        assert!(result.unwrap_err().is_parse_error());
+    }
+
+    #[tokio::test]
+    async fn test_json_body_reports_truncated_body_as_retryable_api_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"id\":")
+                .await
+                .unwrap();
+        });
+
+        let response = reqwest::get(format!("http://{}/", addr)).await.unwrap();
+        let error = json_body::<serde_json::Value>(response).await.unwrap_err();
+
+        assert!(matches!(error, JiraError::ApiError { .. }), "{error}");
+        assert!(error.is_retryable());
+    }
+}
@@ -0,0 +1,181 @@
+use std::future::Future;
+use std::time::Duration;
+
+use backoff::ExponentialBackoff;
+use backoff::future::retry_notify;
+use tracing::warn;
+
+use domain::error::JiraError;
+
+/// Retry policy for Jira API requests.
+///
+/// Retries stop at whichever limit is reached first: `max_retries` retries after
+/// the initial attempt, or `max_elapsed_secs` since the first attempt. An open
+/// circuit breaker and failures that are not retryable, such as an unparseable
+/// response or a 4xx other than 408 and 429, are never retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraRetryPolicy {
+    pub initial_backoff_ms: u64,
+    pub max_elapsed_secs: u64,
+    pub max_retries: u32,
+}
+
+impl JiraRetryPolicy {
+    /// Creates a new JiraRetryPolicy from environment variables, falling back to defaults.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            max_retries: std::env::var("JIRA_MAX_RETRIES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.max_retries),
+            ..default
+        }
+    }
+
+    fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff {
+            max_elapsed_time: Some(Duration::from_secs(self.max_elapsed_secs)),
+            initial_interval: Duration::from_millis(self.initial_backoff_ms),
+            multiplier: 2.0,
+            ..Default::default()
+        }
+    }
+
+    /// Runs `operation`, retrying failures with exponential backoff until it
+    /// succeeds or either cap is reached.
+    pub async fn retry<T, F, Fut>(&self, mut operation: F) -> Result<T, JiraError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, JiraError>>,
+    {
+        let mut attempts: u32 = 0;
+
+        retry_notify(
+            self.backoff(),
+            || {
+                attempts += 1;
+                let retries_exhausted = attempts > self.max_retries;
+                let attempt = operation();
+                async move {
+                    attempt.await.map_err(|err| {
+                        if retries_exhausted || err.is_circuit_open() || !err.is_retryable() {
+                            backoff::Error::permanent(err)
+                        } else {
+                            backoff::Error::transient(err)
+                        }
+                    })
+                }
+            },
+            |err, duration| {
+                warn!(
+                    "Jira API request failed: {}, retrying in {:?}",
+                    err, duration
+                );
+            },
+        )
+        .await
+    }
+}
+
+impl Default for JiraRetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: 500,
+            max_elapsed_secs: 30,
+            max_retries: 5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_policy(max_retries: u32) -> JiraRetryPolicy {
+        JiraRetryPolicy {
+            initial_backoff_ms: 1,
+            max_elapsed_secs: 30,
+            max_retries,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_after_max_retries() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), JiraError> = fast_policy(2)
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(JiraError::api_error("always fails"))
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_zero_max_retries_attempts_once() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), JiraError> = fast_policy(0)
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(JiraError::api_error("always fails"))
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_returns_value_after_transient_failure() {
+        let calls = AtomicU32::new(0);
+
+        let result = fast_policy(3)
+            .retry(|| async {
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(JiraError::api_error("flaky"))
+                } else {
+                    Ok(42)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_does_not_retry_open_circuit() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), JiraError> = fast_policy(3)
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(JiraError::circuit_open("paused"))
+            })
+            .await;
+
+        assert!(result.unwrap_err().is_circuit_open());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_does_not_retry_client_errors() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), JiraError> = fast_policy(3)
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(JiraError::api_error_with_status(400, "bad JQL"))
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().api_status(), Some(400));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
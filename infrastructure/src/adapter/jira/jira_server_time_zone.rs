@@ -0,0 +1,254 @@
+use std::time::Duration;
+
+use std::future::Future;
+
+use chrono::FixedOffset;
+use chrono_tz::Tz;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::debug;
+
+use domain::error::JiraError;
+
+use super::jira_issue_adapter_impl::JiraIssueAdapterImpl;
+use super::jira_response::{error_for_status, json_body};
+use crate::dto::jira::JiraServerInfoDto;
+
+/// How long an offset read from `serverTime` is trusted before serverInfo is fetched again.
+const FIXED_OFFSET_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The timezone the Jira server evaluates JQL dates in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraServerTimeZone {
+    /// A named timezone; each date is written with the offset in force at that instant,
+    /// so ranges across a daylight saving change stay exact.
+    Named(Tz),
+    /// The offset of `serverTime` when it was read, for servers that report no timezone
+    /// name. It goes stale at a daylight saving change, so it is re-read periodically.
+    Fixed(FixedOffset),
+}
+
+impl JiraServerTimeZone {
+    /// Prefers the named timezone of `server_info`, falling back to the `serverTime` offset.
+    pub fn from_server_info(server_info: &JiraServerInfoDto) -> Option<Self> {
+        server_info
+            .named_time_zone()
+            .map(Self::Named)
+            .or_else(|| server_info.utc_offset().map(Self::Fixed))
+    }
+
+    /// Whether a value fetched at `fetched_at` may still be used at `now`.
+    pub fn is_fresh(&self, fetched_at: Instant, now: Instant) -> bool {
+        match self {
+            Self::Named(_) => true,
+            Self::Fixed(_) => now.duration_since(fetched_at) < FIXED_OFFSET_TTL,
+        }
+    }
+}
+
+/// The server timezone and when it was fetched; empty until the first search.
+#[derive(Debug, Default)]
+pub struct JiraServerTimeZoneCache(Mutex<Option<(JiraServerTimeZone, Instant)>>);
+
+impl JiraServerTimeZoneCache {
+    /// Returns the cached timezone while it is fresh, otherwise runs `fetch` and caches
+    /// its result. A failed fetch is not cached, so the next call tries again.
+    pub async fn get_or_fetch<F, Fut>(&self, fetch: F) -> Result<JiraServerTimeZone, JiraError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<JiraServerTimeZone, JiraError>>,
+    {
+        let mut cached = self.0.lock().await;
+        if let Some((time_zone, fetched_at)) = *cached
+            && time_zone.is_fresh(fetched_at, Instant::now())
+        {
+            return Ok(time_zone);
+        }
+
+        let time_zone = fetch().await?;
+        *cached = Some((time_zone, Instant::now()));
+        Ok(time_zone)
+    }
+}
+
+impl JiraIssueAdapterImpl {
+    /// Returns the Jira server's timezone, fetched from serverInfo and cached. A named
+    /// timezone is kept; a bare offset is fetched again once it may have gone stale.
+    ///
+    /// Fails when it cannot be determined: guessing UTC would shift the `updated` bound
+    /// for servers west of UTC and silently skip issues. The lookup is retried on the
+    /// next search.
+    pub(super) async fn server_time_zone(&self) -> Result<JiraServerTimeZone, JiraError> {
+        self.server_time_zone
+            .get_or_fetch(|| self.fetch_server_time_zone())
+            .await
+    }
+
+    /// Fetches the server's timezone from `GET /rest/api/3/serverInfo` with retry logic.
+    async fn fetch_server_time_zone(&self) -> Result<JiraServerTimeZone, JiraError> {
+        let url = self.config.api_url("rest/api/3/serverInfo");
+
+        let server_info = self
+            .config
+            .retry_policy
+            .retry(|| self.circuit_breaker.call(self.do_get_server_info(&url)))
+            .await?;
+
+        JiraServerTimeZone::from_server_info(&server_info)
+            .ok_or_else(|| JiraError::api_error("Jira server info has no parseable serverTime"))
+    }
+
+    /// Performs the HTTP request for the server info.
+    async fn do_get_server_info(&self, url: &str) -> Result<JiraServerInfoDto, JiraError> {
+        debug!("Fetching Jira server info: {}", url);
+
+        self.rate_limiter.acquire().await;
+
+        let response = self
+            .config
+            .auth
+            .apply(self.client.get(url))
+            .send()
+            .await
+            .map_err(|e| JiraError::api_error_with_cause("Failed to send request to Jira", e))?;
+
+        let response = error_for_status(response, &self.config.auth).await?;
+
+        json_body::<JiraServerInfoDto>(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::adapter::jira::{
+        JiraApiConfig, JiraAuth, JiraCircuitBreakerPolicy, JiraClientTuning, JiraIssueTypeAliases,
+        JiraRateLimiter, JiraRetryPolicy,
+    };
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_from_server_info_prefers_named_time_zone() {
+        let server_info: JiraServerInfoDto = serde_json::from_value(serde_json::json!({
+            "serverTime": "2024-01-02T12:34:56.789+0100",
+            "timeZone": "Europe/Berlin"
+        }))
+        .unwrap();
+        let offset_only: JiraServerInfoDto = serde_json::from_value(serde_json::json!({
+            "serverTime": "2024-01-02T12:34:56.789+0100"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            JiraServerTimeZone::from_server_info(&server_info),
+            Some(JiraServerTimeZone::Named(chrono_tz::Europe::Berlin))
+        );
+        assert_eq!(
+            JiraServerTimeZone::from_server_info(&offset_only),
+            FixedOffset::east_opt(3600).map(JiraServerTimeZone::Fixed)
+        );
+    }
+
+    #[test]
+    fn test_only_fixed_offset_expires() {
+        let fetched_at = Instant::now();
+        let later = fetched_at + FIXED_OFFSET_TTL;
+        let fixed = JiraServerTimeZone::Fixed(FixedOffset::east_opt(3600).unwrap());
+        let named = JiraServerTimeZone::Named(chrono_tz::Europe::Berlin);
+
+        assert!(fixed.is_fresh(fetched_at, fetched_at));
+        assert!(!fixed.is_fresh(fetched_at, later));
+        assert!(named.is_fresh(fetched_at, later));
+    }
+
+    /// Counts the fetch and returns `result`.
+    async fn fetch(
+        calls: &AtomicU32,
+        result: Result<JiraServerTimeZone, JiraError>,
+    ) -> Result<JiraServerTimeZone, JiraError> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        result
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_refetches_fixed_offset_once_stale() {
+        let cache = JiraServerTimeZoneCache::default();
+        let calls = AtomicU32::new(0);
+        let fixed = JiraServerTimeZone::Fixed(FixedOffset::east_opt(3600).unwrap());
+
+        for _ in 0..2 {
+            let time_zone = cache.get_or_fetch(|| fetch(&calls, Ok(fixed))).await;
+            assert_eq!(time_zone.unwrap(), fixed);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        tokio::time::advance(FIXED_OFFSET_TTL).await;
+        cache
+            .get_or_fetch(|| fetch(&calls, Ok(fixed)))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_does_not_keep_failed_fetch() {
+        let cache = JiraServerTimeZoneCache::default();
+        let calls = AtomicU32::new(0);
+        let named = JiraServerTimeZone::Named(chrono_tz::Europe::Berlin);
+
+        let failed = cache
+            .get_or_fetch(|| fetch(&calls, Err(JiraError::api_error("down"))))
+            .await;
+        let fetched = cache.get_or_fetch(|| fetch(&calls, Ok(named))).await;
+
+        assert!(failed.is_err());
+        assert_eq!(fetched.unwrap(), named);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_server_info_lookup_is_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/serverInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "serverTime": "2024-01-02T12:34:56.789+0900"
+            })))
+            .mount(&server)
+            .await;
+        let config = JiraApiConfig {
+            base_url: server.uri(),
+            context_path: None,
+            auth: JiraAuth::None,
+            client_tuning: JiraClientTuning::default(),
+            retry_policy: JiraRetryPolicy {
+                initial_backoff_ms: 1,
+                ..JiraRetryPolicy::default()
+            },
+            circuit_breaker: JiraCircuitBreakerPolicy::default(),
+            project_etag_cache: false,
+            description_max_chars: None,
+            log_jql: false,
+            include_subtasks: true,
+            issue_type_aliases: JiraIssueTypeAliases::default(),
+        };
+        let adapter = JiraIssueAdapterImpl::new(config, Arc::new(JiraRateLimiter::per_second(100)));
+
+        let time_zone = adapter.server_time_zone().await.unwrap();
+
+        assert_eq!(
+            time_zone,
+            JiraServerTimeZone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap())
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use sqlx::pool::PoolConnection;
+use sqlx::{PgPool, Postgres};
+use tracing::warn;
+
+use domain::error::JiraError;
+use domain::port::jira::{JiraSyncKind, JiraSyncLock, JiraSyncLockPort};
+
+/// First key of every sync lock ("JIRA"), keeping them apart from other advisory locks
+/// taken on the same database.
+const SYNC_LOCK_NAMESPACE: i32 = 0x4A49_5241;
+
+/// Second key of the sync lock of `kind`.
+fn sync_lock_key(kind: JiraSyncKind) -> i32 {
+    match kind {
+        JiraSyncKind::Projects => 1,
+        JiraSyncKind::Issues => 2,
+        JiraSyncKind::Changelogs => 3,
+    }
+}
+
+/// Implementation of JiraSyncLockPort using PostgreSQL session-level advisory locks.
+///
+/// Each held lock keeps the pooled connection that took it, since an advisory lock
+/// belongs to its session. Because the lock is shared through the database, it excludes
+/// syncs across every instance using it.
+pub struct JiraSyncLockAdapterImpl {
+    pool: PgPool,
+}
+
+impl JiraSyncLockAdapterImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JiraSyncLockPort for JiraSyncLockAdapterImpl {
+    async fn try_acquire(
+        &self,
+        kind: JiraSyncKind,
+    ) -> Result<Option<Box<dyn JiraSyncLock>>, JiraError> {
+        let key = sync_lock_key(kind);
+        let mut connection = self.pool.acquire().await.map_err(|e| {
+            JiraError::database_error_with_cause("Failed to connect for the sync lock", e)
+        })?;
+
+        let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1, $2)")
+            .bind(SYNC_LOCK_NAMESPACE)
+            .bind(key)
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to take the sync lock", e))?;
+
+        if !acquired {
+            return Ok(None);
+        }
+        Ok(Some(Box::new(PgJiraSyncLock {
+            connection: Some(connection),
+            key,
+        })))
+    }
+}
+
+/// An advisory lock held on `connection`.
+///
+/// If it is dropped without being released, e.g. when the sync panicked or was aborted,
+/// the connection is closed instead of being returned to the pool, which ends the
+/// session and with it the lock.
+struct PgJiraSyncLock {
+    connection: Option<PoolConnection<Postgres>>,
+    key: i32,
+}
+
+#[async_trait]
+impl JiraSyncLock for PgJiraSyncLock {
+    async fn release(mut self: Box<Self>) {
+        let Some(mut connection) = self.connection.take() else {
+            return;
+        };
+
+        let unlocked = sqlx::query_scalar::<_, bool>("SELECT pg_advisory_unlock($1, $2)")
+            .bind(SYNC_LOCK_NAMESPACE)
+            .bind(self.key)
+            .fetch_one(&mut *connection)
+            .await;
+        if !matches!(unlocked, Ok(true)) {
+            warn!("Failed to release the sync lock, closing its connection");
+            connection.close_on_drop();
+        }
+    }
+}
+
+impl Drop for PgJiraSyncLock {
+    fn drop(&mut self) {
+        if let Some(connection) = &mut self.connection {
+            connection.close_on_drop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use std::time::Duration;
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_second_instance_is_rejected_while_lock_is_held() {
+        let schema = TestSchema::create("sync_lock_rejects_test").await;
+        // Each held lock keeps a connection of its own, as two server instances would
+        let first = JiraSyncLockAdapterImpl::new(schema.pool());
+        let second = JiraSyncLockAdapterImpl::new(schema.pool());
+
+        let lock = first
+            .try_acquire(JiraSyncKind::Projects)
+            .await
+            .unwrap()
+            .unwrap();
+        let rejected = second
+            .try_acquire(JiraSyncKind::Projects)
+            .await
+            .unwrap()
+            .is_none();
+        // Other kinds of sync are not blocked
+        let issues_lock = second.try_acquire(JiraSyncKind::Issues).await.unwrap();
+        let other_kind_acquired = issues_lock.is_some();
+        if let Some(issues_lock) = issues_lock {
+            issues_lock.release().await;
+        }
+        lock.release().await;
+        let reacquired = second.try_acquire(JiraSyncKind::Projects).await.unwrap();
+        let acquired_after_release = reacquired.is_some();
+        if let Some(reacquired) = reacquired {
+            reacquired.release().await;
+        }
+        schema.drop().await;
+
+        assert!(rejected);
+        assert!(other_kind_acquired);
+        assert!(acquired_after_release);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_dropped_lock_is_freed_by_closing_its_connection() {
+        let schema = TestSchema::create("sync_lock_dropped_test").await;
+        let first = JiraSyncLockAdapterImpl::new(schema.pool());
+        let second = JiraSyncLockAdapterImpl::new(schema.pool());
+
+        let lock = first.try_acquire(JiraSyncKind::Changelogs).await.unwrap();
+        let acquired = lock.is_some();
+        drop(lock);
+
+        // The connection is closed in the background
+        let mut reacquired = None;
+        for _ in 0..50 {
+            reacquired = second.try_acquire(JiraSyncKind::Changelogs).await.unwrap();
+            if reacquired.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let freed = reacquired.is_some();
+        if let Some(reacquired) = reacquired {
+            reacquired.release().await;
+        }
+        schema.drop().await;
+
+        assert!(acquired);
+        assert!(freed);
+    }
+}
@@ -1,7 +1,28 @@
 mod jira_api_config;
+mod jira_auth;
+mod jira_auth_adapter_impl;
+mod jira_circuit_breaker;
+mod jira_client_tuning;
 mod jira_issue_adapter_impl;
+mod jira_issue_changelog_adapter;
+mod jira_issue_filter_jql;
+mod jira_issue_type_aliases;
 mod jira_project_adapter_impl;
+mod jira_rate_limiter;
+mod jira_response;
+mod jira_retry_policy;
+mod jira_server_time_zone;
+mod jira_sync_lock_adapter_impl;
 
-pub use jira_api_config::JiraApiConfig;
+pub use jira_api_config::{JiraApiConfig, JiraApiConfigError};
+pub use jira_auth::JiraAuth;
+pub use jira_auth_adapter_impl::JiraAuthAdapterImpl;
+pub use jira_circuit_breaker::{JiraCircuitBreaker, JiraCircuitBreakerPolicy};
+pub use jira_client_tuning::JiraClientTuning;
 pub use jira_issue_adapter_impl::JiraIssueAdapterImpl;
+pub use jira_issue_filter_jql::jira_issue_filter_jql;
+pub use jira_issue_type_aliases::JiraIssueTypeAliases;
 pub use jira_project_adapter_impl::JiraProjectAdapterImpl;
+pub use jira_rate_limiter::JiraRateLimiter;
+pub use jira_retry_policy::JiraRetryPolicy;
+pub use jira_sync_lock_adapter_impl::JiraSyncLockAdapterImpl;
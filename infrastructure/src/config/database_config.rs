@@ -1,3 +1,5 @@
+use futures::future::try_join_all;
+use sqlx::Executor;
 use sqlx::postgres::{PgPool, PgPoolOptions};
 use std::time::Duration;
 
@@ -12,6 +14,11 @@ pub struct DatabaseConfig {
     pub max_connections: u32,
     pub min_connections: u32,
     pub connect_timeout_secs: u64,
+    /// Per-statement timeout for read queries in milliseconds (0 disables it).
+    pub statement_timeout_ms: u64,
+    /// Schema used as the connection `search_path`, for tenants kept in separate schemas.
+    /// None keeps the server's default search path.
+    pub schema: Option<String>,
 }
 
 impl DatabaseConfig {
@@ -38,6 +45,13 @@ impl DatabaseConfig {
                 .unwrap_or_else(|_| "30".to_string())
                 .parse()
                 .unwrap_or(30),
+            statement_timeout_ms: std::env::var("POSTGRES_STATEMENT_TIMEOUT_MS")
+                .unwrap_or_else(|_| "30000".to_string())
+                .parse()
+                .unwrap_or(30000),
+            schema: std::env::var("POSTGRES_SCHEMA")
+                .ok()
+                .filter(|schema| !schema.is_empty()),
         })
     }
 
@@ -49,14 +63,56 @@ impl DatabaseConfig {
         )
     }
 
+    /// Returns the statement timeout for read queries, or None if disabled.
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        (self.statement_timeout_ms > 0).then(|| Duration::from_millis(self.statement_timeout_ms))
+    }
+
+    /// Returns the statement that points a connection at the configured schema, if any.
+    /// The schema is quoted as an identifier, so it is matched case-sensitively.
+    pub fn search_path_statement(&self) -> Option<String> {
+        self.schema
+            .as_ref()
+            .map(|schema| format!("SET search_path TO \"{}\"", schema.replace('"', "\"\"")))
+    }
+
     /// Creates a connection pool using this configuration.
     pub async fn create_pool(&self) -> Result<PgPool, sqlx::Error> {
+        self.pool_options().connect(&self.connection_url()).await
+    }
+
+    /// Opens `min_connections` connections (at least one) and runs `SELECT 1` on each,
+    /// so the first requests after startup do not wait for connections to be established.
+    /// The connections then stay idle in the pool. Returns how many were warmed up.
+    pub async fn warm_up_pool(&self, pool: &PgPool) -> Result<u32, sqlx::Error> {
+        let count = self.min_connections.clamp(1, self.max_connections.max(1));
+
+        // Held together, so each acquire opens its own connection rather than reusing one
+        let mut connections = try_join_all((0..count).map(|_| pool.acquire())).await?;
+        for connection in &mut connections {
+            sqlx::query("SELECT 1").execute(&mut **connection).await?;
+        }
+
+        Ok(count)
+    }
+
+    /// Pool options shared by every pool; each new connection gets the configured search path.
+    fn pool_options(&self) -> PgPoolOptions {
+        let search_path = self.search_path_statement();
+
         PgPoolOptions::new()
             .max_connections(self.max_connections)
             .min_connections(self.min_connections)
             .acquire_timeout(Duration::from_secs(self.connect_timeout_secs))
-            .connect(&self.connection_url())
-            .await
+            .after_connect(move |conn, _meta| {
+                let search_path = search_path.clone();
+                Box::pin(async move {
+                    if let Some(search_path) = search_path {
+                        conn.execute(search_path.as_str()).await?;
+                    }
+                    Ok(())
+                })
+            })
     }
 }
 
@@ -71,6 +127,94 @@ impl Default for DatabaseConfig {
             max_connections: 10,
             min_connections: 1,
             connect_timeout_secs: 30,
+            statement_timeout_ms: 30000,
+            schema: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+
+    fn config_with_schema(schema: Option<&str>) -> DatabaseConfig {
+        DatabaseConfig {
+            schema: schema.map(str::to_string),
+            ..DatabaseConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_search_path_statement_without_schema() {
+        assert_eq!(config_with_schema(None).search_path_statement(), None);
+    }
+
+    #[test]
+    fn test_search_path_statement_quotes_schema() {
+        assert_eq!(
+            config_with_schema(Some("tenant_a")).search_path_statement(),
+            Some("SET search_path TO \"tenant_a\"".to_string())
+        );
+        assert_eq!(
+            config_with_schema(Some("odd\"name")).search_path_statement(),
+            Some("SET search_path TO \"odd\"\"name\"".to_string())
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_warm_up_pool_leaves_min_connections_idle() {
+        let schema = TestSchema::create("warm_up_pool_test").await;
+        let config = DatabaseConfig {
+            min_connections: 3,
+            max_connections: 5,
+            ..DatabaseConfig::default()
+        };
+        // Without a minimum of its own, so only the warm-up opens connections
+        let pool = schema.pool();
+
+        let warmed = config.warm_up_pool(&pool).await.unwrap();
+
+        // Connections are handed back to the pool in the background
+        for _ in 0..100 {
+            if pool.num_idle() == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
         }
+        let (size, idle) = (pool.size(), pool.num_idle());
+        schema.drop().await;
+
+        assert_eq!(warmed, 3);
+        assert_eq!(size, 3);
+        assert_eq!(idle, 3);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_queries_use_configured_schema() {
+        let schema = TestSchema::create("search_path_test").await;
+        schema.insert_project(42, "PATH").await;
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+
+        let pool = config_with_schema(Some("search_path_test"))
+            .pool_options()
+            .connect(&url)
+            .await
+            .unwrap();
+        let current_schema: String = sqlx::query_scalar("SELECT current_schema()")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM jira_project")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        pool.close().await;
+        schema.drop().await;
+
+        assert_eq!(current_schema, "search_path_test");
+        assert_eq!(ids, vec![42]);
     }
 }
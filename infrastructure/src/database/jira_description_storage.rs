@@ -0,0 +1,50 @@
+/// Column a Jira issue description is written to, chosen at deploy time.
+///
+/// Reads accept either column, so switching the storage only affects issues written
+/// afterwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JiraDescriptionStorage {
+    /// Plain text in the `description_text` column.
+    #[default]
+    Text,
+    /// A JSON value in the `description` jsonb column, reserved for ADF documents.
+    Jsonb,
+}
+
+impl JiraDescriptionStorage {
+    /// Reads JIRA_DESCRIPTION_STORAGE (`text` or `jsonb`), falling back to text when unset
+    /// or unknown.
+    pub fn from_env() -> Self {
+        std::env::var("JIRA_DESCRIPTION_STORAGE")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    /// Parses a storage name, ignoring case.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "jsonb" => Some(Self::Jsonb),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ignores_case_and_rejects_unknown_names() {
+        assert_eq!(
+            JiraDescriptionStorage::parse("TEXT"),
+            Some(JiraDescriptionStorage::Text)
+        );
+        assert_eq!(
+            JiraDescriptionStorage::parse(" jsonb "),
+            Some(JiraDescriptionStorage::Jsonb)
+        );
+        assert_eq!(JiraDescriptionStorage::parse("adf"), None);
+    }
+}
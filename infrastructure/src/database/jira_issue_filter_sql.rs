@@ -0,0 +1,157 @@
+use sqlx::{Postgres, QueryBuilder};
+
+use application::dto::query::jira::JiraIssueListFilter;
+use domain::value_object::jira::{JiraIssueCriterion, JiraIssueFilter};
+
+/// Appends ` WHERE ...` selecting the `jira_issue` rows that match `filter`.
+///
+/// Values are bound as parameters, never spliced into the SQL. Nothing is appended for
+/// a filter without criteria.
+pub fn push_jira_issue_filter(builder: &mut QueryBuilder<'_, Postgres>, filter: &JiraIssueFilter) {
+    for (index, criterion) in filter.criteria().iter().enumerate() {
+        builder.push(if index == 0 { " WHERE " } else { " AND " });
+        match criterion {
+            JiraIssueCriterion::ProjectIn(keys) => {
+                let keys: Vec<String> = keys.iter().map(|key| key.value().to_string()).collect();
+                builder
+                    .push("project_id IN (SELECT id FROM jira_project WHERE key = ANY(")
+                    .push_bind(keys)
+                    .push("))");
+            }
+            JiraIssueCriterion::ReportedBy(account_id) => {
+                builder
+                    .push("reporter_account_id = ")
+                    .push_bind(account_id.clone());
+            }
+            JiraIssueCriterion::Overdue => {
+                builder.push("(resolution IS NULL AND due_date < CURRENT_DATE)");
+            }
+            JiraIssueCriterion::UpdatedSince(since) => {
+                builder.push("updated_at >= ").push_bind(*since);
+            }
+            JiraIssueCriterion::UpdatedBefore(until) => {
+                builder.push("updated_at < ").push_bind(*until);
+            }
+        }
+    }
+}
+
+/// Appends ` WHERE ...` selecting the `jira_issue` rows listed for `filter`: the
+/// criteria of its [`issue_filter`](JiraIssueListFilter::issue_filter), then the sync
+/// time of the stored row.
+pub fn push_jira_issue_list_filter(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &JiraIssueListFilter,
+) {
+    let issue_filter = filter.issue_filter();
+    push_jira_issue_filter(builder, &issue_filter);
+    if let Some(synced_before) = filter.synced_before {
+        builder
+            .push(if issue_filter.is_empty() {
+                " WHERE "
+            } else {
+                " AND "
+            })
+            .push("synced_at < ")
+            .push_bind(synced_before);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use chrono::{TimeZone, Utc};
+    use domain::value_object::jira::JiraProjectKey;
+
+    fn filter() -> JiraIssueFilter {
+        JiraIssueFilter::new()
+            .with_projects(vec![
+                JiraProjectKey::new("PROJ"),
+                JiraProjectKey::new("OPS"),
+            ])
+            .unwrap()
+            .with_reporter("account-1")
+            .with_overdue()
+            .with_updated_between(
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                Some(Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()),
+            )
+    }
+
+    #[test]
+    fn test_filter_translates_to_where_clause() {
+        let mut builder = QueryBuilder::new("SELECT id FROM jira_issue");
+
+        push_jira_issue_filter(&mut builder, &filter());
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT id FROM jira_issue \
+             WHERE project_id IN (SELECT id FROM jira_project WHERE key = ANY($1)) \
+             AND reporter_account_id = $2 \
+             AND (resolution IS NULL AND due_date < CURRENT_DATE) \
+             AND updated_at >= $3 \
+             AND updated_at < $4"
+        );
+    }
+
+    #[test]
+    fn test_empty_filter_adds_no_where_clause() {
+        let mut builder = QueryBuilder::new("SELECT id FROM jira_issue");
+
+        push_jira_issue_filter(&mut builder, &JiraIssueFilter::new());
+
+        assert_eq!(builder.sql(), "SELECT id FROM jira_issue");
+    }
+
+    #[test]
+    fn test_list_filter_adds_sync_time_after_issue_criteria() {
+        let synced_before = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let mut builder = QueryBuilder::new("SELECT id FROM jira_issue");
+
+        push_jira_issue_list_filter(
+            &mut builder,
+            &JiraIssueListFilter::new(Some("account-1".to_string()))
+                .with_synced_before(Some(synced_before)),
+        );
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT id FROM jira_issue WHERE reporter_account_id = $1 AND synced_at < $2"
+        );
+    }
+
+    #[test]
+    fn test_list_filter_with_only_sync_time_starts_where_clause() {
+        let synced_before = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let mut builder = QueryBuilder::new("SELECT id FROM jira_issue");
+
+        push_jira_issue_list_filter(
+            &mut builder,
+            &JiraIssueListFilter::new(None).with_synced_before(Some(synced_before)),
+        );
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT id FROM jira_issue WHERE synced_at < $1"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_where_clause_is_accepted_by_postgres() {
+        let schema = TestSchema::create("issue_filter_sql_test").await;
+        let mut builder = QueryBuilder::new("SELECT COUNT(*) FROM jira_issue");
+        push_jira_issue_filter(&mut builder, &filter());
+
+        let count: i64 = builder
+            .build_query_scalar()
+            .fetch_one(&schema.pool())
+            .await
+            .unwrap();
+        schema.drop().await;
+
+        assert_eq!(count, 0);
+    }
+}
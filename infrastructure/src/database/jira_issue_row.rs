@@ -1,9 +1,12 @@
 use application::dto::query::jira::JiraIssueQueryDto;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use domain::entity::jira::JiraIssue;
-use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+use domain::error::JiraError;
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType, JiraStatusCategory, JiraUser};
 use sqlx::FromRow;
 
+use super::JiraDescriptionStorage;
+
 /// Database row representation of a Jira issue.
 #[derive(Debug, Clone, FromRow)]
 pub struct JiraIssueRow {
@@ -11,44 +14,130 @@ pub struct JiraIssueRow {
     pub project_id: i64,
     pub key: String,
     pub summary: String,
+    /// Description written in [`JiraDescriptionStorage::Jsonb`] mode.
     pub description: Option<serde_json::Value>,
+    /// Description written in [`JiraDescriptionStorage::Text`] mode.
+    pub description_text: Option<String>,
     pub issue_type: JiraIssueTypeDb,
-    pub priority: JiraIssuePriorityDb,
+    pub priority: Option<JiraIssuePriorityDb>,
+    pub reporter_account_id: Option<String>,
+    pub reporter_display_name: Option<String>,
+    pub watches: Option<i32>,
+    pub votes: Option<i32>,
+    pub status_category: Option<JiraStatusCategoryDb>,
+    pub original_estimate_seconds: Option<i64>,
+    pub remaining_estimate_seconds: Option<i64>,
+    pub time_spent_seconds: Option<i64>,
+    pub resolution: Option<String>,
+    pub due_date: Option<NaiveDate>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set by the database on every upsert; None for rows not read back from it.
+    pub synced_at: Option<DateTime<Utc>>,
 }
 
 impl JiraIssueRow {
-    pub fn from_domain(issue: &JiraIssue) -> Self {
-        Self {
+    /// Creates a row storing the description as text; see
+    /// [`with_description_storage`](Self::with_description_storage).
+    ///
+    /// Fails for an issue type or priority the database enums cannot store yet.
+    pub fn from_domain(issue: &JiraIssue) -> Result<Self, JiraError> {
+        Ok(Self {
             id: issue.id.value(),
             project_id: issue.project_id.value(),
             key: issue.key.value().to_string(),
             summary: issue.summary.clone(),
-            description: issue
-                .description
+            description: None,
+            description_text: issue.description.clone(),
+            issue_type: issue.issue_type.try_into()?,
+            priority: issue.priority.map(TryInto::try_into).transpose()?,
+            reporter_account_id: issue.reporter.as_ref().map(|r| r.account_id().to_string()),
+            reporter_display_name: issue
+                .reporter
+                .as_ref()
+                .map(|r| r.display_name().to_string()),
+            watches: issue.watches,
+            votes: issue.votes,
+            status_category: issue
+                .status_category
                 .as_ref()
-                .map(|d| serde_json::Value::String(d.clone())),
-            issue_type: JiraIssueTypeDb::from_domain(&issue.issue_type),
-            priority: JiraIssuePriorityDb::from_domain(&issue.priority),
+                .map(JiraStatusCategoryDb::from_domain),
+            original_estimate_seconds: issue.original_estimate_seconds,
+            remaining_estimate_seconds: issue.remaining_estimate_seconds,
+            time_spent_seconds: issue.time_spent_seconds,
+            resolution: issue.resolution.clone(),
+            due_date: issue.due_date,
             created_at: issue.created_at,
             updated_at: issue.updated_at,
+            synced_at: None,
+        })
+    }
+
+    /// Moves the description to the column used by `storage`, leaving the other empty.
+    pub fn with_description_storage(mut self, storage: JiraDescriptionStorage) -> Self {
+        let text = self.description_text();
+        match storage {
+            JiraDescriptionStorage::Text => {
+                self.description = None;
+                self.description_text = text;
+            }
+            JiraDescriptionStorage::Jsonb => {
+                self.description = text.map(serde_json::Value::String);
+                self.description_text = None;
+            }
+        }
+        self
+    }
+
+    /// Returns the description from whichever column holds it.
+    /// A JSON string is returned as its content; other JSON values as serialized JSON.
+    pub fn description_text(&self) -> Option<String> {
+        if let Some(text) = &self.description_text {
+            return Some(text.clone());
         }
+        self.description.as_ref().map(|value| match value {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    }
+
+    /// Returns the reporter if an account ID is stored.
+    pub fn reporter(&self) -> Option<JiraUser> {
+        self.reporter_account_id.as_ref().map(|account_id| {
+            JiraUser::new(
+                account_id.clone(),
+                self.reporter_display_name.clone().unwrap_or_default(),
+            )
+        })
     }
 
     /// Converts directly to DTO without going through domain entity.
     /// This is more efficient for query operations.
     pub fn into_dto(self) -> JiraIssueQueryDto {
+        let reporter = self.reporter();
+        let description = self.description_text();
         JiraIssueQueryDto::new(
             self.id,
             self.key,
             self.summary,
-            self.description.map(|v| v.to_string()),
+            description,
             self.issue_type.into_domain(),
-            self.priority.into_domain(),
+            self.priority.map(JiraIssuePriorityDb::into_domain),
+            reporter,
             self.created_at,
             self.updated_at,
         )
+        .with_watches(self.watches)
+        .with_votes(self.votes)
+        .with_status_category(self.status_category.map(JiraStatusCategoryDb::into_domain))
+        .with_time_tracking(
+            self.original_estimate_seconds,
+            self.remaining_estimate_seconds,
+            self.time_spent_seconds,
+        )
+        .with_resolution(self.resolution)
+        .with_due_date(self.due_date)
+        .with_synced_at(self.synced_at)
     }
 }
 
@@ -73,14 +162,20 @@ impl JiraIssueTypeDb {
             Self::Bug => JiraIssueType::Bug,
         }
     }
+}
+
+impl TryFrom<JiraIssueType> for JiraIssueTypeDb {
+    type Error = JiraError;
 
-    pub fn from_domain(issue_type: &JiraIssueType) -> Self {
+    fn try_from(issue_type: JiraIssueType) -> Result<Self, JiraError> {
         match issue_type {
-            JiraIssueType::Epic => Self::Epic,
-            JiraIssueType::Story => Self::Story,
-            JiraIssueType::Task => Self::Task,
-            JiraIssueType::Subtask => Self::Subtask,
-            JiraIssueType::Bug => Self::Bug,
+            JiraIssueType::Epic => Ok(Self::Epic),
+            JiraIssueType::Story => Ok(Self::Story),
+            JiraIssueType::Task => Ok(Self::Task),
+            JiraIssueType::Subtask => Ok(Self::Subtask),
+            JiraIssueType::Bug => Ok(Self::Bug),
+            // A domain variant without a database counterpart until a migration extends the enum
+            other => Err(JiraError::unknown_issue_type(other.as_str())),
         }
     }
 }
@@ -107,16 +202,6 @@ impl JiraIssuePriorityDb {
         }
     }
 
-    pub fn from_domain(priority: &JiraIssuePriority) -> Self {
-        match priority {
-            JiraIssuePriority::Highest => Self::Highest,
-            JiraIssuePriority::High => Self::High,
-            JiraIssuePriority::Medium => Self::Medium,
-            JiraIssuePriority::Low => Self::Low,
-            JiraIssuePriority::Lowest => Self::Lowest,
-        }
-    }
-
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Highest => "Highest",
@@ -127,3 +212,121 @@ impl JiraIssuePriorityDb {
         }
     }
 }
+
+impl TryFrom<JiraIssuePriority> for JiraIssuePriorityDb {
+    type Error = JiraError;
+
+    fn try_from(priority: JiraIssuePriority) -> Result<Self, JiraError> {
+        match priority {
+            JiraIssuePriority::Highest => Ok(Self::Highest),
+            JiraIssuePriority::High => Ok(Self::High),
+            JiraIssuePriority::Medium => Ok(Self::Medium),
+            JiraIssuePriority::Low => Ok(Self::Low),
+            JiraIssuePriority::Lowest => Ok(Self::Lowest),
+            // Likewise rejected until a migration extends the enum
+            other => Err(JiraError::unknown_priority(other.as_str())),
+        }
+    }
+}
+
+/// Database enum representation for Jira status category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "jira_status_category", rename_all = "snake_case")]
+pub enum JiraStatusCategoryDb {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+impl JiraStatusCategoryDb {
+    pub fn into_domain(self) -> JiraStatusCategory {
+        match self {
+            Self::ToDo => JiraStatusCategory::ToDo,
+            Self::InProgress => JiraStatusCategory::InProgress,
+            Self::Done => JiraStatusCategory::Done,
+        }
+    }
+
+    pub fn from_domain(status_category: &JiraStatusCategory) -> Self {
+        match status_category {
+            JiraStatusCategory::ToDo => Self::ToDo,
+            JiraStatusCategory::InProgress => Self::InProgress,
+            JiraStatusCategory::Done => Self::Done,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::jira::JiraIssueBuilder;
+    use domain::value_object::jira::{JiraIssueId, JiraIssueKey, JiraProjectId};
+
+    fn row_with_description(description: Option<&str>) -> JiraIssueRow {
+        let issue = JiraIssueBuilder::new()
+            .id(JiraIssueId::new(1))
+            .project_id(JiraProjectId::new(1))
+            .key(JiraIssueKey::new("PROJ-1"))
+            .summary("Summary")
+            .description(description.map(str::to_string))
+            .issue_type(JiraIssueType::Task)
+            .priority(JiraIssuePriority::Medium)
+            .created_at(Utc::now())
+            .updated_at(Utc::now())
+            .build()
+            .unwrap();
+        JiraIssueRow::from_domain(&issue).unwrap()
+    }
+
+    #[test]
+    fn test_every_issue_type_round_trips_through_db_enum() {
+        for value in JiraIssueType::ALL {
+            let db = JiraIssueTypeDb::try_from(value).unwrap();
+            assert_eq!(db.into_domain(), value);
+        }
+    }
+
+    #[test]
+    fn test_every_priority_round_trips_through_db_enum() {
+        for value in JiraIssuePriority::ALL {
+            let db = JiraIssuePriorityDb::try_from(value).unwrap();
+            assert_eq!(db.into_domain(), value);
+        }
+    }
+
+    #[test]
+    fn test_text_storage_round_trips_description_through_text_column() {
+        let row = row_with_description(Some("Line one\n\"quoted\" line two"))
+            .with_description_storage(JiraDescriptionStorage::Text);
+
+        assert_eq!(row.description, None);
+        assert_eq!(
+            row.description_text.as_deref(),
+            Some("Line one\n\"quoted\" line two")
+        );
+        assert_eq!(
+            row.into_dto().description.as_deref(),
+            Some("Line one\n\"quoted\" line two")
+        );
+    }
+
+    #[test]
+    fn test_jsonb_storage_round_trips_description_through_jsonb_column() {
+        let row = row_with_description(Some("Plain text"))
+            .with_description_storage(JiraDescriptionStorage::Jsonb);
+
+        assert_eq!(row.description, Some(serde_json::json!("Plain text")));
+        assert_eq!(row.description_text, None);
+        assert_eq!(row.into_dto().description.as_deref(), Some("Plain text"));
+    }
+
+    #[test]
+    fn test_missing_description_stays_empty_in_both_columns() {
+        let row =
+            row_with_description(None).with_description_storage(JiraDescriptionStorage::Jsonb);
+
+        assert_eq!(row.description, None);
+        assert_eq!(row.description_text, None);
+        assert_eq!(row.into_dto().description, None);
+    }
+}
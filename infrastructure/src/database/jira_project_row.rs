@@ -1,4 +1,5 @@
 use application::dto::query::jira::JiraProjectQueryDto;
+use chrono::{DateTime, Utc};
 use domain::entity::jira::JiraProject;
 use domain::value_object::jira::{JiraProjectId, JiraProjectKey, JiraProjectName};
 use sqlx::FromRow;
@@ -9,6 +10,7 @@ pub struct JiraProjectRow {
     pub id: i64,
     pub key: String,
     pub name: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl JiraProjectRow {
@@ -21,6 +23,7 @@ impl JiraProjectRow {
             id: project.id.value(),
             key: project.key.value().to_string(),
             name: project.name.value().to_string(),
+            updated_at: project.updated_at.unwrap_or_else(Utc::now),
         }
     }
 
@@ -32,10 +35,11 @@ impl JiraProjectRow {
             JiraProjectKey::new(self.key.clone()),
             JiraProjectName::new(self.name.clone()),
         )
+        .with_updated_at(self.updated_at)
     }
 
     /// Converts database row to DTO for query operations.
     pub fn into_dto(self) -> JiraProjectQueryDto {
-        JiraProjectQueryDto::new(self.id, self.key, self.name)
+        JiraProjectQueryDto::new(self.id, self.key, self.name).with_updated_at(self.updated_at)
     }
 }
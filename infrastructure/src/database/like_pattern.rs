@@ -0,0 +1,36 @@
+/// Builds a `LIKE`/`ILIKE` pattern matching `value` anywhere in the column.
+///
+/// `%`, `_` and the escape character `\` in `value` are escaped so they match
+/// literally; use with `ESCAPE '\'`.
+pub fn contains_pattern(value: &str) -> String {
+    let mut pattern = String::with_capacity(value.len() + 2);
+    pattern.push('%');
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_pattern_plain_text() {
+        assert_eq!(contains_pattern("Platform"), "%Platform%");
+    }
+
+    #[test]
+    fn test_contains_pattern_escapes_wildcards() {
+        assert_eq!(contains_pattern("50%_off"), r"%50\%\_off%");
+    }
+
+    #[test]
+    fn test_contains_pattern_escapes_backslash() {
+        assert_eq!(contains_pattern(r"a\b"), r"%a\\b%");
+    }
+}
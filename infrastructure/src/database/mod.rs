@@ -1,5 +1,21 @@
+mod jira_description_storage;
+mod jira_issue_filter_sql;
 mod jira_issue_row;
 mod jira_project_row;
+mod like_pattern;
+mod query_error;
+mod read_transaction;
+#[cfg(test)]
+mod test_schema;
 
-pub use jira_issue_row::{JiraIssuePriorityDb, JiraIssueRow, JiraIssueTypeDb};
+pub use jira_description_storage::JiraDescriptionStorage;
+pub use jira_issue_filter_sql::{push_jira_issue_filter, push_jira_issue_list_filter};
+pub use jira_issue_row::{
+    JiraIssuePriorityDb, JiraIssueRow, JiraIssueTypeDb, JiraStatusCategoryDb,
+};
 pub use jira_project_row::JiraProjectRow;
+pub use like_pattern::contains_pattern;
+pub use query_error::map_query_error;
+pub use read_transaction::begin_read_transaction;
+#[cfg(test)]
+pub use test_schema::TestSchema;
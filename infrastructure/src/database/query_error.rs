@@ -0,0 +1,30 @@
+use domain::error::JiraError;
+
+/// SQLSTATE raised when PostgreSQL cancels a statement (e.g., `statement_timeout`).
+const QUERY_CANCELED: &str = "57014";
+
+/// Maps a sqlx error from a read query to a JiraError.
+/// Statement cancellations become `JiraError::Timeout` so callers can retry them.
+pub fn map_query_error(message: &str, error: sqlx::Error) -> JiraError {
+    let cancelled = error
+        .as_database_error()
+        .and_then(|e| e.code())
+        .is_some_and(|code| code == QUERY_CANCELED);
+
+    if cancelled {
+        JiraError::timeout_with_cause(message, error)
+    } else {
+        JiraError::database_error_with_cause(message, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_query_error_non_database_error() {
+        let err = map_query_error("Failed to fetch issues", sqlx::Error::RowNotFound);
+        assert!(matches!(err, JiraError::DatabaseError { .. }));
+    }
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use sqlx::{PgPool, Postgres, Transaction};
+
+/// Begins a read transaction, applying `statement_timeout` for its duration when set.
+///
+/// The timeout is applied with `set_config(..., true)`, which is equivalent to
+/// `SET LOCAL` and is reset automatically when the transaction ends.
+pub async fn begin_read_transaction(
+    pool: &PgPool,
+    statement_timeout: Option<Duration>,
+) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    if let Some(timeout) = statement_timeout {
+        sqlx::query("SELECT set_config('statement_timeout', $1, true)")
+            .bind(format!("{}ms", timeout.as_millis()))
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    Ok(tx)
+}
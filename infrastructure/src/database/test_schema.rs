@@ -0,0 +1,67 @@
+use sqlx::postgres::PgPoolOptions;
+use sqlx::{Executor, PgPool};
+
+/// A freshly migrated schema of its own for one database test.
+///
+/// Rows a test writes stay out of the shared schema and out of other tests' counts. The
+/// schema is recreated on every run, so one left behind by a failed test does no harm.
+pub struct TestSchema {
+    name: String,
+    setup: PgPool,
+    pool: PgPool,
+}
+
+impl TestSchema {
+    /// Recreates schema `name` and migrates it. Requires DATABASE_URL.
+    pub async fn create(name: &str) -> Self {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let setup = PgPool::connect(&url).await.unwrap();
+        setup
+            .execute(format!("DROP SCHEMA IF EXISTS {name} CASCADE; CREATE SCHEMA {name}").as_str())
+            .await
+            .unwrap();
+        let search_path = format!("SET search_path TO {name}");
+        let pool = PgPoolOptions::new()
+            .after_connect(move |conn, _meta| {
+                let search_path = search_path.clone();
+                Box::pin(async move {
+                    conn.execute(search_path.as_str()).await?;
+                    Ok(())
+                })
+            })
+            .connect(&url)
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+        Self {
+            name: name.to_string(),
+            setup,
+            pool,
+        }
+    }
+
+    /// Pool whose connections read and write this schema.
+    pub fn pool(&self) -> PgPool {
+        self.pool.clone()
+    }
+
+    /// Inserts a project for the issues of the test to belong to.
+    pub async fn insert_project(&self, id: i64, key: &str) {
+        sqlx::query("INSERT INTO jira_project (id, key, name) VALUES ($1, $2, $2)")
+            .bind(id)
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .unwrap();
+    }
+
+    /// Drops the schema; call it before the assertions so a passing test cleans up.
+    pub async fn drop(self) {
+        self.pool.close().await;
+        self.setup
+            .execute(format!("DROP SCHEMA {} CASCADE", self.name).as_str())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use domain::entity::jira::JiraChangelogEntry;
+use domain::value_object::jira::JiraIssueKey;
+
+use super::JiraUserDto;
+
+/// Response from the Jira issue changelog API (`/rest/api/3/issue/{key}/changelog`).
+#[derive(Debug, Deserialize)]
+pub struct JiraChangelogPageDto {
+    #[serde(default)]
+    pub values: Vec<JiraChangelogHistoryDto>,
+    #[serde(rename = "startAt", default)]
+    pub start_at: i32,
+    #[serde(default)]
+    pub total: i32,
+    #[serde(rename = "isLast", default)]
+    pub is_last: Option<bool>,
+}
+
+impl JiraChangelogPageDto {
+    /// Returns the offset of the next page, or None if this is the last page.
+    /// `isLast` decides when present; an empty page also ends paging.
+    pub fn next_start_at(&self) -> Option<i32> {
+        let next = self.start_at + self.values.len() as i32;
+        let is_last = self.is_last.unwrap_or(next >= self.total);
+        (!is_last && !self.values.is_empty()).then_some(next)
+    }
+}
+
+/// A set of field changes made at once, as reported by the changelog API.
+#[derive(Debug, Deserialize)]
+pub struct JiraChangelogHistoryDto {
+    pub id: String,
+    #[serde(default)]
+    pub author: Option<JiraUserDto>,
+    pub created: DateTime<Utc>,
+    #[serde(default)]
+    pub items: Vec<JiraChangelogItemDto>,
+}
+
+impl JiraChangelogHistoryDto {
+    /// Converts the history into one entry per changed field.
+    /// Returns None if the history id is not numeric.
+    pub fn into_domain(self, issue_key: &JiraIssueKey) -> Option<Vec<JiraChangelogEntry>> {
+        let history_id: i64 = self.id.parse().ok()?;
+        let author = self.author.map(JiraUserDto::into_domain);
+        let created = self.created;
+
+        Some(
+            self.items
+                .into_iter()
+                .enumerate()
+                .map(|(position, item)| {
+                    JiraChangelogEntry::new(
+                        issue_key.clone(),
+                        history_id,
+                        position as i32,
+                        item.field,
+                        item.from_value,
+                        item.to_value,
+                        author.clone(),
+                        created,
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A single field change within a changelog history.
+#[derive(Debug, Deserialize)]
+pub struct JiraChangelogItemDto {
+    pub field: String,
+    /// Displayed value before the change; `from` holds the raw id and is not kept.
+    #[serde(rename = "fromString", default)]
+    pub from_value: Option<String>,
+    /// Displayed value after the change.
+    #[serde(rename = "toString", default)]
+    pub to_value: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_page() -> serde_json::Value {
+        serde_json::json!({
+            "self": "https://example.atlassian.net/rest/api/3/issue/PROJ-1/changelog?maxResults=100&startAt=0",
+            "maxResults": 100,
+            "startAt": 0,
+            "total": 2,
+            "isLast": true,
+            "values": [
+                {
+                    "id": "10001",
+                    "author": {
+                        "accountId": "account-1",
+                        "displayName": "Jane Doe",
+                        "active": true
+                    },
+                    "created": "2024-01-02T09:30:00.000+0000",
+                    "items": [
+                        {
+                            "field": "status",
+                            "fieldtype": "jira",
+                            "fieldId": "status",
+                            "from": "10000",
+                            "fromString": "To Do",
+                            "to": "10001",
+                            "toString": "In Progress"
+                        },
+                        {
+                            "field": "assignee",
+                            "fieldtype": "jira",
+                            "fieldId": "assignee",
+                            "from": null,
+                            "fromString": null,
+                            "to": "account-1",
+                            "toString": "Jane Doe"
+                        }
+                    ]
+                },
+                {
+                    "id": "10002",
+                    "created": "2024-01-03T18:00:00.000+0900",
+                    "items": [
+                        {
+                            "field": "resolution",
+                            "fieldtype": "jira",
+                            "from": null,
+                            "fromString": null,
+                            "to": "10000",
+                            "toString": "Done"
+                        }
+                    ]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_parse_changelog_page_with_multiple_entries() {
+        let page: JiraChangelogPageDto = serde_json::from_value(sample_page()).unwrap();
+        let key = JiraIssueKey::new("PROJ-1");
+
+        let entries: Vec<JiraChangelogEntry> = page
+            .values
+            .into_iter()
+            .flat_map(|history| history.into_domain(&key).unwrap())
+            .collect();
+
+        assert_eq!(entries.len(), 3);
+
+        let status = &entries[0];
+        assert_eq!(status.issue_key, key);
+        assert_eq!((status.history_id, status.position), (10001, 0));
+        assert_eq!(status.field, "status");
+        assert_eq!(status.from_value.as_deref(), Some("To Do"));
+        assert_eq!(status.to_value.as_deref(), Some("In Progress"));
+        assert_eq!(
+            status.author.as_ref().map(|a| a.account_id()),
+            Some("account-1")
+        );
+        assert_eq!(
+            status.changed_at,
+            Utc.with_ymd_and_hms(2024, 1, 2, 9, 30, 0).unwrap()
+        );
+
+        let assignee = &entries[1];
+        assert_eq!((assignee.history_id, assignee.position), (10001, 1));
+        assert_eq!(assignee.from_value, None);
+        assert_eq!(assignee.to_value.as_deref(), Some("Jane Doe"));
+
+        let resolution = &entries[2];
+        assert_eq!(resolution.history_id, 10002);
+        assert_eq!(resolution.field, "resolution");
+        assert_eq!(resolution.author, None);
+        assert_eq!(
+            resolution.changed_at,
+            Utc.with_ymd_and_hms(2024, 1, 3, 9, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_start_at_follows_is_last_and_total() {
+        let page = |start_at: i32, total: i32, is_last: Option<bool>| JiraChangelogPageDto {
+            values: serde_json::from_value::<JiraChangelogPageDto>(sample_page())
+                .unwrap()
+                .values,
+            start_at,
+            total,
+            is_last,
+        };
+
+        assert_eq!(page(0, 2, Some(true)).next_start_at(), None);
+        assert_eq!(page(0, 10, Some(false)).next_start_at(), Some(2));
+        assert_eq!(page(0, 10, None).next_start_at(), Some(2));
+        assert_eq!(page(8, 10, None).next_start_at(), None);
+    }
+
+    #[test]
+    fn test_history_with_non_numeric_id_is_rejected() {
+        let history: JiraChangelogHistoryDto = serde_json::from_value(serde_json::json!({
+            "id": "abc",
+            "created": "2024-01-02T09:30:00.000+0000",
+            "items": []
+        }))
+        .unwrap();
+
+        assert_eq!(history.into_domain(&JiraIssueKey::new("PROJ-1")), None);
+    }
+}
@@ -1,11 +1,15 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use domain::entity::jira::JiraIssue;
 use domain::value_object::jira::{
-    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId,
+    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraProjectId, JiraStatusCategory, JiraUser,
 };
 
+use super::{JiraIssueLinkDto, JiraTimeTrackingDto};
+use crate::adapter::jira::JiraIssueTypeAliases;
+
 /// Request body for Jira search API.
 #[derive(Debug, Serialize)]
 pub struct JiraSearchRequestDto {
@@ -22,11 +26,57 @@ pub struct JiraSearchRequestDto {
 pub struct JiraSearchResponseDto {
     pub issues: Vec<JiraIssueResponseDto>,
     #[serde(rename = "isLast", default)]
-    pub is_last: bool,
-    #[serde(rename = "nextPageToken")]
+    pub is_last: Option<bool>,
+    #[serde(rename = "nextPageToken", default)]
     pub next_page_token: Option<String>,
 }
 
+impl JiraSearchResponseDto {
+    /// Returns the token for the next page, or None if this is the last page.
+    ///
+    /// A missing or empty `nextPageToken` is the primary terminal condition;
+    /// `isLast: true` also ends paging when present.
+    pub fn next_page(&self) -> Option<String> {
+        if self.is_last == Some(true) {
+            return None;
+        }
+        self.next_page_token
+            .as_ref()
+            .filter(|token| !token.is_empty())
+            .cloned()
+    }
+}
+
+/// Request body for the classic Jira search API (`/rest/api/3/search`), paged by offset.
+#[derive(Debug, Serialize)]
+pub struct JiraClassicSearchRequestDto {
+    pub jql: String,
+    pub fields: Vec<String>,
+    #[serde(rename = "maxResults")]
+    pub max_results: i32,
+    #[serde(rename = "startAt")]
+    pub start_at: i32,
+}
+
+/// Response from the classic Jira search API.
+#[derive(Debug, Deserialize)]
+pub struct JiraClassicSearchResponseDto {
+    pub issues: Vec<JiraIssueResponseDto>,
+    #[serde(rename = "startAt", default)]
+    pub start_at: i32,
+    #[serde(default)]
+    pub total: i32,
+}
+
+impl JiraClassicSearchResponseDto {
+    /// Returns the offset of the next page, or None if this is the last page.
+    /// An empty page also ends paging, so a shrinking `total` cannot loop forever.
+    pub fn next_start_at(&self) -> Option<i32> {
+        let next = self.start_at + self.issues.len() as i32;
+        (!self.issues.is_empty() && next < self.total).then_some(next)
+    }
+}
+
 /// Single issue in Jira search response.
 #[derive(Debug, Deserialize)]
 pub struct JiraIssueResponseDto {
@@ -35,27 +85,139 @@ pub struct JiraIssueResponseDto {
     pub fields: JiraIssueFieldsDto,
 }
 
+/// Reason an issue from the API could not be converted to a domain entity.
+#[derive(Debug, Error)]
+pub enum JiraIssueConversionError {
+    #[error("unparseable issue id '{0}'")]
+    InvalidId(String),
+
+    #[error("unknown issue type '{0}'")]
+    UnknownIssueType(String),
+
+    #[error("unknown priority '{0}'")]
+    UnknownPriority(String),
+
+    /// The project id is absent, which deserializes as empty, or not a number.
+    #[error("missing or unparseable project id '{0}'")]
+    InvalidProjectId(String),
+}
+
 impl JiraIssueResponseDto {
     /// Converts the API response to a domain entity, consuming self.
-    /// Returns None if the response cannot be converted (e.g., unknown issue type or priority).
-    pub fn into_domain(self) -> Option<JiraIssue> {
-        let id: i64 = self.id.parse().ok()?;
-        let issue_type: JiraIssueType = self.fields.issuetype.name.parse().ok()?;
-        let priority: JiraIssuePriority = self.fields.priority.name.parse().ok()?;
+    /// Returns None if the response cannot be converted; see
+    /// [`into_domain_detailed`](Self::into_domain_detailed) for the reason.
+    pub fn into_domain(
+        self,
+        description_max_chars: Option<usize>,
+        issue_type_aliases: &JiraIssueTypeAliases,
+    ) -> Option<JiraIssue> {
+        self.into_domain_detailed(description_max_chars, issue_type_aliases)
+            .ok()
+    }
 
-        let description = self.fields.description.as_ref().map(extract_text_from_adf);
+    /// Converts the API response to a domain entity, consuming self, or returns the first
+    /// field that failed in id, issue type, priority, project id order.
+    /// Issue type names are resolved through `issue_type_aliases` before the built-in names.
+    /// When `description_max_chars` is set, longer descriptions are truncated to that many
+    /// characters followed by [`TRUNCATION_MARKER`].
+    pub fn into_domain_detailed(
+        self,
+        description_max_chars: Option<usize>,
+        issue_type_aliases: &JiraIssueTypeAliases,
+    ) -> Result<JiraIssue, JiraIssueConversionError> {
+        let id: i64 = self
+            .id
+            .parse()
+            .map_err(|_| JiraIssueConversionError::InvalidId(self.id.clone()))?;
+        let issue_type = issue_type_aliases
+            .resolve(&self.fields.issuetype.name)
+            .map_err(|_| {
+                JiraIssueConversionError::UnknownIssueType(self.fields.issuetype.name.clone())
+            })?;
+        // Issues without a priority are kept, an unknown priority name is rejected
+        let priority: Option<JiraIssuePriority> =
+            self.fields
+                .priority
+                .as_ref()
+                .map(|priority| {
+                    priority.name.parse().map_err(|_| {
+                        JiraIssueConversionError::UnknownPriority(priority.name.clone())
+                    })
+                })
+                .transpose()?;
+        let project_id: i64 = self.fields.project.id.parse().map_err(|_| {
+            JiraIssueConversionError::InvalidProjectId(self.fields.project.id.clone())
+        })?;
 
-        Some(JiraIssue::new(
+        let description = self
+            .fields
+            .description
+            .as_ref()
+            .map(extract_text_from_adf)
+            .map(|text| match description_max_chars {
+                Some(max_chars) => truncate_chars(text, max_chars),
+                None => text,
+            });
+        let reporter = self.fields.reporter.map(JiraUserDto::into_domain);
+        let watches = self.fields.watches.map(|w| w.watch_count);
+        let votes = self.fields.votes.map(|v| v.votes);
+        let status_category = self
+            .fields
+            .status
+            .and_then(|status| status.status_category)
+            .and_then(|category| JiraStatusCategory::from_key(&category.key));
+        let time_tracking = self.fields.timetracking.unwrap_or_default().or_flat_fields(
+            self.fields.timeoriginalestimate,
+            self.fields.timeestimate,
+            self.fields.timespent,
+        );
+        let resolution = self.fields.resolution.map(|resolution| resolution.name);
+        let issue_key = JiraIssueKey::new(self.key);
+        let links = self
+            .fields
+            .issuelinks
+            .iter()
+            .filter_map(|link| link.to_domain(&issue_key))
+            .collect();
+
+        Ok(JiraIssue::new(
             JiraIssueId::new(id),
-            JiraProjectId::new(self.fields.project.id.parse().ok()?),
-            JiraIssueKey::new(self.key),
+            JiraProjectId::new(project_id),
+            issue_key,
             self.fields.summary,
             description,
             issue_type,
             priority,
+            reporter,
             self.fields.created,
             self.fields.updated,
-        ))
+        )
+        .with_watches(watches)
+        .with_votes(votes)
+        .with_status_category(status_category)
+        .with_original_estimate_seconds(time_tracking.original_estimate_seconds)
+        .with_remaining_estimate_seconds(time_tracking.remaining_estimate_seconds)
+        .with_time_spent_seconds(time_tracking.time_spent_seconds)
+        .with_resolution(resolution)
+        .with_due_date(self.fields.duedate)
+        .with_links(links))
+    }
+}
+
+/// Appended to descriptions that were cut to the configured maximum length.
+pub const TRUNCATION_MARKER: &str = "…";
+
+/// Truncates text to at most `max_chars` characters, appending [`TRUNCATION_MARKER`]
+/// when anything was removed. Cuts on character boundaries, never inside a UTF-8 sequence.
+fn truncate_chars(text: String, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((byte_index, _)) => {
+            let mut truncated = text;
+            truncated.truncate(byte_index);
+            truncated.push_str(TRUNCATION_MARKER);
+            truncated
+        }
+        None => text,
     }
 }
 
@@ -99,7 +261,35 @@ pub struct JiraIssueFieldsDto {
     /// Description in Atlassian Document Format (ADF) - a JSON structure for rich text
     pub description: Option<serde_json::Value>,
     pub issuetype: JiraIssueTypeDto,
-    pub priority: JiraPriorityDto,
+    /// Null, or absent, for issues without a priority.
+    #[serde(default)]
+    pub priority: Option<JiraPriorityDto>,
+    #[serde(default)]
+    pub reporter: Option<JiraUserDto>,
+    #[serde(default)]
+    pub watches: Option<JiraWatchesDto>,
+    #[serde(default)]
+    pub votes: Option<JiraVotesDto>,
+    #[serde(default)]
+    pub status: Option<JiraStatusDto>,
+    #[serde(default)]
+    pub timetracking: Option<JiraTimeTrackingDto>,
+    /// Original estimate in seconds.
+    #[serde(default)]
+    pub timeoriginalestimate: Option<i64>,
+    /// Remaining estimate in seconds.
+    #[serde(default)]
+    pub timeestimate: Option<i64>,
+    /// Time spent in seconds.
+    #[serde(default)]
+    pub timespent: Option<i64>,
+    #[serde(default)]
+    pub resolution: Option<JiraResolutionDto>,
+    /// Due date as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub duedate: Option<NaiveDate>,
+    #[serde(default)]
+    pub issuelinks: Vec<JiraIssueLinkDto>,
     pub created: DateTime<Utc>,
     pub updated: DateTime<Utc>,
 }
@@ -107,6 +297,7 @@ pub struct JiraIssueFieldsDto {
 /// Project information in Jira issue response.
 #[derive(Debug, Deserialize)]
 pub struct JiraIssueProjectDto {
+    #[serde(default)]
     pub id: String,
     #[allow(dead_code)]
     pub key: String,
@@ -123,3 +314,453 @@ pub struct JiraIssueTypeDto {
 pub struct JiraPriorityDto {
     pub name: String,
 }
+
+/// Watcher aggregate in Jira issue response.
+#[derive(Debug, Deserialize)]
+pub struct JiraWatchesDto {
+    #[serde(rename = "watchCount")]
+    pub watch_count: i32,
+}
+
+/// Vote aggregate in Jira issue response.
+#[derive(Debug, Deserialize)]
+pub struct JiraVotesDto {
+    pub votes: i32,
+}
+
+/// Status information in Jira issue response.
+#[derive(Debug, Deserialize)]
+pub struct JiraStatusDto {
+    #[serde(rename = "statusCategory")]
+    pub status_category: Option<JiraStatusCategoryDto>,
+}
+
+/// Status category of a Jira status; `key` is one of "new", "indeterminate" or "done".
+#[derive(Debug, Deserialize)]
+pub struct JiraStatusCategoryDto {
+    pub key: String,
+}
+
+/// Resolution in Jira issue response; absent or null while the issue is unresolved.
+#[derive(Debug, Deserialize)]
+pub struct JiraResolutionDto {
+    pub name: String,
+}
+
+/// User information in Jira issue response (e.g., reporter).
+#[derive(Debug, Deserialize)]
+pub struct JiraUserDto {
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+}
+
+impl JiraUserDto {
+    pub fn into_domain(self) -> JiraUser {
+        JiraUser::new(self.account_id, self.display_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_json(reporter: serde_json::Value) -> serde_json::Value {
+        issue_json_with_description(reporter, serde_json::Value::Null)
+    }
+
+    fn issue_json_with_description(
+        reporter: serde_json::Value,
+        description: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-1",
+            "fields": {
+                "project": { "id": "100", "key": "PROJ" },
+                "summary": "Test Issue",
+                "description": description,
+                "issuetype": { "name": "Task" },
+                "priority": { "name": "Medium" },
+                "reporter": reporter,
+                "created": "2024-01-01T00:00:00Z",
+                "updated": "2024-01-02T00:00:00Z"
+            }
+        })
+    }
+
+    fn search_response(json: serde_json::Value) -> JiraSearchResponseDto {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn classic_search_response(json: serde_json::Value) -> JiraClassicSearchResponseDto {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_next_start_at_continues_until_total_is_reached() {
+        let issue = issue_json(serde_json::Value::Null);
+        let first = classic_search_response(serde_json::json!({
+            "issues": [issue.clone(), issue.clone()],
+            "startAt": 0,
+            "total": 3
+        }));
+        let last = classic_search_response(serde_json::json!({
+            "issues": [issue],
+            "startAt": 2,
+            "total": 3
+        }));
+
+        assert_eq!(first.next_start_at(), Some(2));
+        assert_eq!(last.next_start_at(), None);
+    }
+
+    #[test]
+    fn test_next_start_at_terminates_on_empty_page() {
+        let response = classic_search_response(serde_json::json!({
+            "issues": [],
+            "startAt": 100,
+            "total": 500
+        }));
+        assert_eq!(response.next_start_at(), None);
+    }
+
+    #[test]
+    fn test_next_page_terminates_when_token_is_null() {
+        let response = search_response(serde_json::json!({
+            "issues": [],
+            "nextPageToken": null
+        }));
+        assert_eq!(response.next_page(), None);
+    }
+
+    #[test]
+    fn test_next_page_continues_when_is_last_absent_and_token_present() {
+        let response = search_response(serde_json::json!({
+            "issues": [],
+            "nextPageToken": "token-2"
+        }));
+        assert_eq!(response.next_page(), Some("token-2".to_string()));
+    }
+
+    #[test]
+    fn test_next_page_terminates_when_is_last_true() {
+        let response = search_response(serde_json::json!({
+            "issues": [],
+            "isLast": true,
+            "nextPageToken": "token-2"
+        }));
+        assert_eq!(response.next_page(), None);
+    }
+
+    #[test]
+    fn test_next_page_terminates_when_token_is_empty() {
+        let response = search_response(serde_json::json!({
+            "issues": [],
+            "isLast": false,
+            "nextPageToken": ""
+        }));
+        assert_eq!(response.next_page(), None);
+    }
+
+    #[test]
+    fn test_into_domain_with_reporter() {
+        let dto: JiraIssueResponseDto = serde_json::from_value(issue_json(serde_json::json!({
+            "accountId": "account-1",
+            "displayName": "Jane Doe"
+        })))
+        .unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.reporter, Some(JiraUser::new("account-1", "Jane Doe")));
+    }
+
+    #[test]
+    fn test_into_domain_without_reporter() {
+        let dto: JiraIssueResponseDto =
+            serde_json::from_value(issue_json(serde_json::Value::Null)).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.reporter, None);
+    }
+
+    #[test]
+    fn test_into_domain_with_priority() {
+        let dto: JiraIssueResponseDto =
+            serde_json::from_value(issue_json(serde_json::Value::Null)).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.priority, Some(JiraIssuePriority::Medium));
+    }
+
+    #[test]
+    fn test_into_domain_keeps_issue_with_null_priority() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["priority"] = serde_json::Value::Null;
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.key.value(), "PROJ-1");
+        assert_eq!(issue.priority, None);
+    }
+
+    #[test]
+    fn test_into_domain_keeps_issue_with_absent_priority() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"].as_object_mut().unwrap().remove("priority");
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.priority, None);
+    }
+
+    fn adf_paragraph(text: &str) -> serde_json::Value {
+        serde_json::json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": text }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_into_domain_truncates_description_over_limit() {
+        let dto: JiraIssueResponseDto = serde_json::from_value(issue_json_with_description(
+            serde_json::Value::Null,
+            adf_paragraph("日本語のテキスト"),
+        ))
+        .unwrap();
+
+        let issue = dto
+            .into_domain(Some(3), &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(
+            issue.description,
+            Some(format!("日本語{}", TRUNCATION_MARKER))
+        );
+    }
+
+    #[test]
+    fn test_into_domain_keeps_description_under_limit() {
+        let dto: JiraIssueResponseDto = serde_json::from_value(issue_json_with_description(
+            serde_json::Value::Null,
+            adf_paragraph("Short"),
+        ))
+        .unwrap();
+
+        let issue = dto
+            .into_domain(Some(5), &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.description, Some("Short".to_string()));
+    }
+
+    #[test]
+    fn test_into_domain_with_watches_and_votes() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["watches"] = serde_json::json!({ "watchCount": 7, "isWatching": false });
+        json["fields"]["votes"] = serde_json::json!({ "votes": 3, "hasVoted": false });
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.watches, Some(7));
+        assert_eq!(issue.votes, Some(3));
+    }
+
+    #[test]
+    fn test_into_domain_without_watches_and_votes() {
+        let dto: JiraIssueResponseDto =
+            serde_json::from_value(issue_json(serde_json::Value::Null)).unwrap();
+
+        let issue = dto
+            .into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap();
+
+        assert_eq!(issue.watches, None);
+        assert_eq!(issue.votes, None);
+    }
+
+    fn issue_with_status_category(key: &str) -> JiraIssue {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["status"] = serde_json::json!({
+            "name": "Whatever the workflow calls it",
+            "statusCategory": { "id": 2, "key": key, "name": "Category" }
+        });
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+        dto.into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_into_domain_maps_status_category() {
+        assert_eq!(
+            issue_with_status_category("new").status_category,
+            Some(JiraStatusCategory::ToDo)
+        );
+        assert_eq!(
+            issue_with_status_category("indeterminate").status_category,
+            Some(JiraStatusCategory::InProgress)
+        );
+        let done = issue_with_status_category("done");
+        assert_eq!(done.status_category, Some(JiraStatusCategory::Done));
+        assert!(done.is_done());
+    }
+
+    #[test]
+    fn test_into_domain_without_status_category() {
+        let dto: JiraIssueResponseDto =
+            serde_json::from_value(issue_json(serde_json::Value::Null)).unwrap();
+
+        assert_eq!(
+            dto.into_domain(None, &JiraIssueTypeAliases::default())
+                .unwrap()
+                .status_category,
+            None
+        );
+        assert_eq!(
+            issue_with_status_category("undefined").status_category,
+            None
+        );
+    }
+
+    fn parse_issue(json: serde_json::Value) -> JiraIssue {
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+        dto.into_domain(None, &JiraIssueTypeAliases::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_into_domain_with_time_tracking() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["timetracking"] = serde_json::json!({
+            "originalEstimate": "1d",
+            "remainingEstimate": "4h",
+            "timeSpent": "4h",
+            "originalEstimateSeconds": 28800,
+            "remainingEstimateSeconds": 14400,
+            "timeSpentSeconds": 14400
+        });
+
+        let issue = parse_issue(json);
+
+        assert_eq!(issue.original_estimate_seconds, Some(28_800));
+        assert_eq!(issue.remaining_estimate_seconds, Some(14_400));
+        assert_eq!(issue.time_spent_seconds, Some(14_400));
+    }
+
+    #[test]
+    fn test_into_domain_with_resolution_and_due_date() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["resolution"] = serde_json::json!({ "id": "10000", "name": "Done" });
+        json["fields"]["duedate"] = serde_json::json!("2024-03-31");
+
+        let issue = parse_issue(json);
+
+        assert_eq!(issue.resolution, Some("Done".to_string()));
+        assert_eq!(issue.due_date, NaiveDate::from_ymd_opt(2024, 3, 31));
+    }
+
+    #[test]
+    fn test_into_domain_unresolved_without_due_date() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["resolution"] = serde_json::Value::Null;
+        json["fields"]["duedate"] = serde_json::Value::Null;
+
+        let issue = parse_issue(json);
+
+        assert_eq!(issue.resolution, None);
+        assert_eq!(issue.due_date, None);
+    }
+
+    fn conversion_error(json: serde_json::Value) -> JiraIssueConversionError {
+        let dto: JiraIssueResponseDto = serde_json::from_value(json).unwrap();
+        dto.into_domain_detailed(None, &JiraIssueTypeAliases::default())
+            .unwrap_err()
+    }
+
+    #[test]
+    fn test_into_domain_detailed_rejects_unparseable_id() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["id"] = serde_json::json!("not-a-number");
+
+        assert!(matches!(
+            conversion_error(json),
+            JiraIssueConversionError::InvalidId(id) if id == "not-a-number"
+        ));
+    }
+
+    #[test]
+    fn test_into_domain_detailed_rejects_unknown_issue_type() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["issuetype"]["name"] = serde_json::json!("Incident");
+
+        assert!(matches!(
+            conversion_error(json),
+            JiraIssueConversionError::UnknownIssueType(name) if name == "Incident"
+        ));
+    }
+
+    #[test]
+    fn test_into_domain_detailed_rejects_unknown_priority() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["priority"]["name"] = serde_json::json!("Blocker");
+
+        assert!(matches!(
+            conversion_error(json),
+            JiraIssueConversionError::UnknownPriority(name) if name == "Blocker"
+        ));
+    }
+
+    #[test]
+    fn test_into_domain_detailed_rejects_missing_project_id() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["project"] = serde_json::json!({ "key": "PROJ" });
+
+        let error = conversion_error(json);
+
+        assert!(matches!(
+            &error,
+            JiraIssueConversionError::InvalidProjectId(id) if id.is_empty()
+        ));
+        assert_eq!(error.to_string(), "missing or unparseable project id ''");
+    }
+
+    #[test]
+    fn test_into_domain_detailed_reports_first_failing_field() {
+        let mut json = issue_json(serde_json::Value::Null);
+        json["fields"]["issuetype"]["name"] = serde_json::json!("Incident");
+        json["fields"]["priority"]["name"] = serde_json::json!("Blocker");
+
+        assert!(matches!(
+            conversion_error(json),
+            JiraIssueConversionError::UnknownIssueType(_)
+        ));
+    }
+
+    #[test]
+    fn test_truncate_chars_on_multibyte_boundary() {
+        assert_eq!(truncate_chars("héllo".to_string(), 2), "hé…");
+        assert_eq!(truncate_chars("héllo".to_string(), 10), "héllo");
+    }
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use domain::entity::jira::JiraIssueLink;
+use domain::value_object::jira::JiraIssueKey;
+
+/// Link to another issue in Jira issue response.
+///
+/// Exactly one of `inward_issue` and `outward_issue` is set: an outward link reads
+/// "this issue blocks the other", an inward one "this issue is blocked by the other".
+#[derive(Debug, Deserialize)]
+pub struct JiraIssueLinkDto {
+    #[serde(rename = "type")]
+    pub link_type: JiraIssueLinkTypeDto,
+    #[serde(rename = "inwardIssue", default)]
+    pub inward_issue: Option<JiraLinkedIssueDto>,
+    #[serde(rename = "outwardIssue", default)]
+    pub outward_issue: Option<JiraLinkedIssueDto>,
+}
+
+impl JiraIssueLinkDto {
+    /// Converts the link as seen from `issue_key` to a domain link in the outward direction.
+    /// Returns None if the response names no linked issue.
+    pub fn to_domain(&self, issue_key: &JiraIssueKey) -> Option<JiraIssueLink> {
+        let (source, target) = match (&self.outward_issue, &self.inward_issue) {
+            (Some(outward), _) => (issue_key.clone(), JiraIssueKey::new(&outward.key)),
+            (None, Some(inward)) => (JiraIssueKey::new(&inward.key), issue_key.clone()),
+            (None, None) => return None,
+        };
+        Some(JiraIssueLink::new(
+            source,
+            target,
+            self.link_type.name.clone(),
+        ))
+    }
+}
+
+/// Link type information in Jira issue link response.
+#[derive(Debug, Deserialize)]
+pub struct JiraIssueLinkTypeDto {
+    pub name: String,
+}
+
+/// The other issue of a link in Jira issue link response.
+#[derive(Debug, Deserialize)]
+pub struct JiraLinkedIssueDto {
+    pub key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links(json: serde_json::Value) -> Vec<JiraIssueLinkDto> {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_to_domain_maps_inward_and_outward_links() {
+        let links = links(serde_json::json!([
+            {
+                "id": "20001",
+                "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                "outwardIssue": { "id": "10002", "key": "PROJ-2" }
+            },
+            {
+                "id": "20002",
+                "type": { "name": "Relates", "inward": "relates to", "outward": "relates to" },
+                "inwardIssue": { "id": "10003", "key": "OTHER-3" }
+            }
+        ]));
+        let issue_key = JiraIssueKey::new("PROJ-1");
+
+        let links: Vec<JiraIssueLink> = links
+            .iter()
+            .filter_map(|link| link.to_domain(&issue_key))
+            .collect();
+
+        assert_eq!(
+            links,
+            vec![
+                JiraIssueLink::new(
+                    JiraIssueKey::new("PROJ-1"),
+                    JiraIssueKey::new("PROJ-2"),
+                    "Blocks"
+                ),
+                JiraIssueLink::new(
+                    JiraIssueKey::new("OTHER-3"),
+                    JiraIssueKey::new("PROJ-1"),
+                    "Relates"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_domain_without_linked_issue() {
+        let links = links(serde_json::json!([{ "id": "20001", "type": { "name": "Blocks" } }]));
+
+        assert_eq!(links[0].to_domain(&JiraIssueKey::new("PROJ-1")), None);
+    }
+}
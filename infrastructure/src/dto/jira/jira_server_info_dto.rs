@@ -0,0 +1,72 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+/// Response from Jira server info API (/rest/api/3/serverInfo).
+#[derive(Debug, Deserialize)]
+pub struct JiraServerInfoDto {
+    /// Current server time with its UTC offset, e.g. "2024-01-02T12:34:56.789+0900".
+    #[serde(rename = "serverTime")]
+    pub server_time: Option<String>,
+    /// IANA name of the server's timezone, e.g. "Europe/Berlin", when the server reports it.
+    #[serde(rename = "timeZone")]
+    pub time_zone: Option<String>,
+}
+
+impl JiraServerInfoDto {
+    /// Extracts the server's UTC offset from `serverTime`.
+    /// Returns None if the field is missing or not in Jira's timestamp format.
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        let server_time = self.server_time.as_deref()?;
+        DateTime::parse_from_str(server_time, "%Y-%m-%dT%H:%M:%S%.f%z")
+            .ok()
+            .map(|time| *time.offset())
+    }
+
+    /// Parses `timeZone` as an IANA timezone.
+    /// Returns None if the field is missing or names no known timezone.
+    pub fn named_time_zone(&self) -> Option<Tz> {
+        self.time_zone.as_deref()?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utc_offset_from_server_info() {
+        let dto: JiraServerInfoDto = serde_json::from_value(serde_json::json!({
+            "baseUrl": "https://example.atlassian.net",
+            "version": "1001.0.0-SNAPSHOT",
+            "deploymentType": "Cloud",
+            "serverTime": "2024-01-02T12:34:56.789+0900",
+            "serverTitle": "Jira"
+        }))
+        .unwrap();
+
+        assert_eq!(dto.utc_offset(), FixedOffset::east_opt(9 * 3600));
+    }
+
+    #[test]
+    fn test_utc_offset_none_when_server_time_missing() {
+        let dto: JiraServerInfoDto =
+            serde_json::from_value(serde_json::json!({ "version": "9.12.0" })).unwrap();
+
+        assert_eq!(dto.utc_offset(), None);
+    }
+
+    #[test]
+    fn test_named_time_zone_parses_iana_name() {
+        let dto: JiraServerInfoDto = serde_json::from_value(serde_json::json!({
+            "serverTime": "2024-01-02T12:34:56.789+0100",
+            "timeZone": "Europe/Berlin"
+        }))
+        .unwrap();
+        let unknown: JiraServerInfoDto =
+            serde_json::from_value(serde_json::json!({ "timeZone": "Mars/Olympus" })).unwrap();
+
+        assert_eq!(dto.named_time_zone(), Some(chrono_tz::Europe::Berlin));
+        assert_eq!(unknown.named_time_zone(), None);
+    }
+}
@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+/// Time tracking aggregate in Jira issue response.
+///
+/// Jira sends an empty object for issues nobody has estimated or logged time on.
+#[derive(Debug, Default, Deserialize)]
+pub struct JiraTimeTrackingDto {
+    #[serde(rename = "originalEstimateSeconds", default)]
+    pub original_estimate_seconds: Option<i64>,
+    #[serde(rename = "remainingEstimateSeconds", default)]
+    pub remaining_estimate_seconds: Option<i64>,
+    #[serde(rename = "timeSpentSeconds", default)]
+    pub time_spent_seconds: Option<i64>,
+}
+
+impl JiraTimeTrackingDto {
+    /// Fills the values the aggregate lacks from the flat `timeoriginalestimate`,
+    /// `timeestimate` and `timespent` fields, which some responses send instead.
+    pub fn or_flat_fields(
+        self,
+        original_estimate_seconds: Option<i64>,
+        remaining_estimate_seconds: Option<i64>,
+        time_spent_seconds: Option<i64>,
+    ) -> Self {
+        Self {
+            original_estimate_seconds: self.original_estimate_seconds.or(original_estimate_seconds),
+            remaining_estimate_seconds: self
+                .remaining_estimate_seconds
+                .or(remaining_estimate_seconds),
+            time_spent_seconds: self.time_spent_seconds.or(time_spent_seconds),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time_tracking(json: serde_json::Value) -> JiraTimeTrackingDto {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_aggregate_is_preferred_over_flat_fields() {
+        let aggregate = time_tracking(serde_json::json!({
+            "originalEstimate": "1d",
+            "originalEstimateSeconds": 28800,
+            "timeSpentSeconds": 14400
+        }));
+
+        let merged = aggregate.or_flat_fields(Some(7200), Some(3600), Some(60));
+
+        assert_eq!(merged.original_estimate_seconds, Some(28_800));
+        assert_eq!(merged.remaining_estimate_seconds, Some(3_600));
+        assert_eq!(merged.time_spent_seconds, Some(14_400));
+    }
+
+    #[test]
+    fn test_empty_aggregate_falls_back_to_flat_fields() {
+        let merged =
+            time_tracking(serde_json::json!({})).or_flat_fields(Some(7200), Some(3600), None);
+
+        assert_eq!(merged.original_estimate_seconds, Some(7_200));
+        assert_eq!(merged.remaining_estimate_seconds, Some(3_600));
+        assert_eq!(merged.time_spent_seconds, None);
+    }
+
+    #[test]
+    fn test_without_any_time_tracking() {
+        let merged = JiraTimeTrackingDto::default().or_flat_fields(None, None, None);
+
+        assert_eq!(merged.original_estimate_seconds, None);
+        assert_eq!(merged.remaining_estimate_seconds, None);
+        assert_eq!(merged.time_spent_seconds, None);
+    }
+}
@@ -1,5 +1,13 @@
+mod jira_changelog_dto;
 mod jira_issue_dto;
+mod jira_issue_link_dto;
 mod jira_project_dto;
+mod jira_server_info_dto;
+mod jira_time_tracking_dto;
 
+pub use jira_changelog_dto::*;
 pub use jira_issue_dto::*;
+pub use jira_issue_link_dto::*;
 pub use jira_project_dto::*;
+pub use jira_server_info_dto::*;
+pub use jira_time_tracking_dto::*;
@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use domain::entity::jira::JiraChangelogEntry;
+use domain::error::JiraError;
+use domain::repository::jira::JiraChangelogRepository;
+
+/// PostgreSQL implementation of JiraChangelogRepository (Command) using sqlx.
+pub struct JiraChangelogRepositoryImpl {
+    pool: PgPool,
+}
+
+impl JiraChangelogRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JiraChangelogRepository for JiraChangelogRepositoryImpl {
+    async fn bulk_insert(&self, entries: Vec<JiraChangelogEntry>) -> Result<u64, JiraError> {
+        if entries.is_empty() {
+            return Ok(0);
+        }
+
+        let history_ids: Vec<i64> = entries.iter().map(|e| e.history_id).collect();
+        let positions: Vec<i32> = entries.iter().map(|e| e.position).collect();
+        let issue_keys: Vec<String> = entries
+            .iter()
+            .map(|e| e.issue_key.value().to_string())
+            .collect();
+        let fields: Vec<String> = entries.iter().map(|e| e.field.clone()).collect();
+        let from_values: Vec<Option<String>> =
+            entries.iter().map(|e| e.from_value.clone()).collect();
+        let to_values: Vec<Option<String>> = entries.iter().map(|e| e.to_value.clone()).collect();
+        let author_account_ids: Vec<Option<String>> = entries
+            .iter()
+            .map(|e| e.author.as_ref().map(|a| a.account_id().to_string()))
+            .collect();
+        let author_display_names: Vec<Option<String>> = entries
+            .iter()
+            .map(|e| e.author.as_ref().map(|a| a.display_name().to_string()))
+            .collect();
+        let changed_ats: Vec<DateTime<Utc>> = entries.iter().map(|e| e.changed_at).collect();
+
+        // A single statement, so the entries are stored all or none
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO jira_issue_changelog (
+                history_id, position, issue_key, field, from_value, to_value,
+                author_account_id, author_display_name, changed_at
+            )
+            SELECT * FROM UNNEST(
+                $1::BIGINT[], $2::INTEGER[], $3::VARCHAR[], $4::VARCHAR[], $5::TEXT[],
+                $6::TEXT[], $7::VARCHAR[], $8::VARCHAR[], $9::TIMESTAMPTZ[]
+            )
+            ON CONFLICT (history_id, position) DO NOTHING
+            "#,
+            &history_ids,
+            &positions,
+            &issue_keys,
+            &fields,
+            &from_values as &[Option<String>],
+            &to_values as &[Option<String>],
+            &author_account_ids as &[Option<String>],
+            &author_display_names as &[Option<String>],
+            &changed_ats
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| JiraError::database_error_with_cause("Failed to insert issue changelog", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use chrono::TimeZone;
+    use domain::value_object::jira::{JiraIssueKey, JiraUser};
+
+    fn entry(history_id: i64, position: i32, to: &str) -> JiraChangelogEntry {
+        JiraChangelogEntry::new(
+            JiraIssueKey::new("CHG-1"),
+            history_id,
+            position,
+            "status",
+            None,
+            Some(to.to_string()),
+            Some(JiraUser::new("account-1", "Jane Doe")),
+            Utc.with_ymd_and_hms(2024, 1, 2, 9, 30, 0).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_bulk_insert_skips_stored_entries() {
+        let schema = TestSchema::create("changelog_insert_test").await;
+        let pool = schema.pool();
+        let repository = JiraChangelogRepositoryImpl::new(pool.clone());
+
+        let first = repository
+            .bulk_insert(vec![
+                entry(900001, 0, "In Progress"),
+                entry(900001, 1, "Done"),
+            ])
+            .await
+            .unwrap();
+        let second = repository
+            .bulk_insert(vec![entry(900001, 1, "Done"), entry(900002, 0, "Closed")])
+            .await
+            .unwrap();
+
+        let rows: Vec<(i64, i32, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT history_id, position, to_value, author_account_id \
+             FROM jira_issue_changelog ORDER BY history_id, position",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        schema.drop().await;
+        assert_eq!((first, second), (2, 1));
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[2].0, 900002);
+        assert_eq!(rows[2].2.as_deref(), Some("Closed"));
+        assert_eq!(rows[2].3.as_deref(), Some("account-1"));
+    }
+}
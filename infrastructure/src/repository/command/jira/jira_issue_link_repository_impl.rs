@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use domain::entity::jira::JiraIssueLink;
+use domain::error::JiraError;
+use domain::repository::jira::JiraIssueLinkRepository;
+use domain::value_object::jira::JiraIssueKey;
+
+/// PostgreSQL implementation of JiraIssueLinkRepository (Command) using sqlx.
+pub struct JiraIssueLinkRepositoryImpl {
+    pool: PgPool,
+}
+
+impl JiraIssueLinkRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JiraIssueLinkRepository for JiraIssueLinkRepositoryImpl {
+    async fn replace_for_issues(
+        &self,
+        issue_keys: Vec<JiraIssueKey>,
+        links: Vec<JiraIssueLink>,
+    ) -> Result<(), JiraError> {
+        if issue_keys.is_empty() && links.is_empty() {
+            return Ok(());
+        }
+
+        let key_values: Vec<String> = issue_keys
+            .iter()
+            .map(|key| key.value().to_string())
+            .collect();
+        let source_keys: Vec<String> = links
+            .iter()
+            .map(|link| link.source.value().to_string())
+            .collect();
+        let target_keys: Vec<String> = links
+            .iter()
+            .map(|link| link.target.value().to_string())
+            .collect();
+        let link_types: Vec<String> = links.iter().map(|link| link.link_type.clone()).collect();
+
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                JiraError::database_error_with_cause("Failed to begin transaction", e)
+            })?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM jira_issue_link
+            WHERE source_key = ANY($1) OR target_key = ANY($1)
+            "#,
+            &key_values
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| JiraError::database_error_with_cause("Failed to delete issue links", e))?;
+
+        // A link seen from both of its issues appears twice
+        sqlx::query!(
+            r#"
+            INSERT INTO jira_issue_link (source_key, target_key, link_type)
+            SELECT * FROM UNNEST($1::VARCHAR[], $2::VARCHAR[], $3::VARCHAR[])
+            ON CONFLICT DO NOTHING
+            "#,
+            &source_keys,
+            &target_keys,
+            &link_types
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| JiraError::database_error_with_cause("Failed to insert issue links", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to commit transaction", e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+
+    fn link(source: &str, target: &str, link_type: &str) -> JiraIssueLink {
+        JiraIssueLink::new(
+            JiraIssueKey::new(source),
+            JiraIssueKey::new(target),
+            link_type,
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_replace_for_issues_persists_and_replaces_links() {
+        let schema = TestSchema::create("issue_link_replace_test").await;
+        let pool = schema.pool();
+        let repository = JiraIssueLinkRepositoryImpl::new(pool.clone());
+
+        repository
+            .replace_for_issues(
+                vec![JiraIssueKey::new("LNK-1"), JiraIssueKey::new("LNK-2")],
+                vec![
+                    link("LNK-1", "LNK-2", "Blocks"),
+                    link("LNK-1", "LNK-2", "Blocks"),
+                    link("LNK-3", "LNK-1", "Relates"),
+                ],
+            )
+            .await
+            .unwrap();
+        // LNK-1 no longer blocks LNK-2; the link from LNK-3 is still reported
+        repository
+            .replace_for_issues(
+                vec![JiraIssueKey::new("LNK-1")],
+                vec![link("LNK-3", "LNK-1", "Relates")],
+            )
+            .await
+            .unwrap();
+
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
+            "SELECT source_key, target_key, link_type FROM jira_issue_link \
+             ORDER BY source_key, target_key",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        schema.drop().await;
+        assert_eq!(
+            rows,
+            vec![(
+                "LNK-3".to_string(),
+                "LNK-1".to_string(),
+                "Relates".to_string()
+            )]
+        );
+    }
+}
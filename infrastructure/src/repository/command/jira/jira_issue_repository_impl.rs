@@ -1,64 +1,188 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 
 use domain::entity::jira::JiraIssue;
 use domain::error::JiraError;
 use domain::repository::jira::JiraIssueRepository;
 
-use crate::database::JiraIssueRow;
+use crate::database::{
+    JiraDescriptionStorage, JiraIssuePriorityDb, JiraIssueRow, JiraIssueTypeDb,
+    JiraStatusCategoryDb,
+};
+
+/// Column used to detect an existing issue when upserting.
+///
+/// The chosen column must carry a unique constraint in the `jira_issue` schema:
+/// - `Id` needs `id` to be the primary key or unique (the default schema).
+/// - `Key` needs a unique constraint on `key`, for schemas keyed by issue key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JiraIssueConflictTarget {
+    #[default]
+    Id,
+    Key,
+}
 
 /// PostgreSQL implementation of JiraIssueRepository (Command) using sqlx.
 pub struct JiraIssueRepositoryImpl {
     pool: PgPool,
+    conflict_target: JiraIssueConflictTarget,
+    description_storage: JiraDescriptionStorage,
 }
 
 impl JiraIssueRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self::with_conflict_target(pool, JiraIssueConflictTarget::default())
     }
-}
 
-#[async_trait]
-impl JiraIssueRepository for JiraIssueRepositoryImpl {
-    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
-        if issues.is_empty() {
-            return Ok(vec![]);
+    /// Creates a repository that upserts on the given conflict target.
+    pub fn with_conflict_target(pool: PgPool, conflict_target: JiraIssueConflictTarget) -> Self {
+        Self {
+            pool,
+            conflict_target,
+            description_storage: JiraDescriptionStorage::default(),
         }
+    }
 
-        let mut tx =
-            self.pool.begin().await.map_err(|e| {
-                JiraError::database_error_with_cause("Failed to begin transaction", e)
-            })?;
-
-        for issue in &issues {
-            let row = JiraIssueRow::from_domain(issue);
+    /// Sets the column descriptions are written to.
+    pub fn with_description_storage(mut self, description_storage: JiraDescriptionStorage) -> Self {
+        self.description_storage = description_storage;
+        self
+    }
 
-            sqlx::query(
+    /// Upserts a single issue on the configured conflict target.
+    /// The conflict column is not overwritten, and the issue id is never changed.
+    async fn upsert(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        row: &JiraIssueRow,
+    ) -> Result<(), sqlx::Error> {
+        match self.conflict_target {
+            JiraIssueConflictTarget::Id => sqlx::query!(
                 r#"
-                INSERT INTO jira_issue (id, project_id, key, summary, description, issue_type, priority, created_at, updated_at)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                INSERT INTO jira_issue (id, project_id, key, summary, description, description_text, issue_type, priority, reporter_account_id, reporter_display_name, watches, votes, status_category, original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
                 ON CONFLICT (id) DO UPDATE SET
                     project_id = EXCLUDED.project_id,
                     key = EXCLUDED.key,
                     summary = EXCLUDED.summary,
                     description = EXCLUDED.description,
+                    description_text = EXCLUDED.description_text,
+                    issue_type = EXCLUDED.issue_type,
+                    priority = EXCLUDED.priority,
+                    reporter_account_id = EXCLUDED.reporter_account_id,
+                    reporter_display_name = EXCLUDED.reporter_display_name,
+                    watches = EXCLUDED.watches,
+                    votes = EXCLUDED.votes,
+                    status_category = EXCLUDED.status_category,
+                    original_estimate_seconds = EXCLUDED.original_estimate_seconds,
+                    remaining_estimate_seconds = EXCLUDED.remaining_estimate_seconds,
+                    time_spent_seconds = EXCLUDED.time_spent_seconds,
+                    resolution = EXCLUDED.resolution,
+                    due_date = EXCLUDED.due_date,
+                    updated_at = EXCLUDED.updated_at,
+                    synced_at = now()
+                "#,
+                row.id,
+                row.project_id,
+                row.key,
+                row.summary,
+                row.description,
+                row.description_text,
+                row.issue_type as JiraIssueTypeDb,
+                row.priority as Option<JiraIssuePriorityDb>,
+                row.reporter_account_id,
+                row.reporter_display_name,
+                row.watches,
+                row.votes,
+                row.status_category as Option<JiraStatusCategoryDb>,
+                row.original_estimate_seconds,
+                row.remaining_estimate_seconds,
+                row.time_spent_seconds,
+                row.resolution,
+                row.due_date,
+                row.created_at,
+                row.updated_at
+            )
+            .execute(&mut **tx)
+            .await
+            .map(|_| ()),
+            JiraIssueConflictTarget::Key => sqlx::query!(
+                r#"
+                INSERT INTO jira_issue (id, project_id, key, summary, description, description_text, issue_type, priority, reporter_account_id, reporter_display_name, watches, votes, status_category, original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
+                ON CONFLICT (key) DO UPDATE SET
+                    project_id = EXCLUDED.project_id,
+                    summary = EXCLUDED.summary,
+                    description = EXCLUDED.description,
+                    description_text = EXCLUDED.description_text,
                     issue_type = EXCLUDED.issue_type,
                     priority = EXCLUDED.priority,
-                    updated_at = EXCLUDED.updated_at
+                    reporter_account_id = EXCLUDED.reporter_account_id,
+                    reporter_display_name = EXCLUDED.reporter_display_name,
+                    watches = EXCLUDED.watches,
+                    votes = EXCLUDED.votes,
+                    status_category = EXCLUDED.status_category,
+                    original_estimate_seconds = EXCLUDED.original_estimate_seconds,
+                    remaining_estimate_seconds = EXCLUDED.remaining_estimate_seconds,
+                    time_spent_seconds = EXCLUDED.time_spent_seconds,
+                    resolution = EXCLUDED.resolution,
+                    due_date = EXCLUDED.due_date,
+                    updated_at = EXCLUDED.updated_at,
+                    synced_at = now()
                 "#,
+                row.id,
+                row.project_id,
+                row.key,
+                row.summary,
+                row.description,
+                row.description_text,
+                row.issue_type as JiraIssueTypeDb,
+                row.priority as Option<JiraIssuePriorityDb>,
+                row.reporter_account_id,
+                row.reporter_display_name,
+                row.watches,
+                row.votes,
+                row.status_category as Option<JiraStatusCategoryDb>,
+                row.original_estimate_seconds,
+                row.remaining_estimate_seconds,
+                row.time_spent_seconds,
+                row.resolution,
+                row.due_date,
+                row.created_at,
+                row.updated_at
             )
-            .bind(row.id)
-            .bind(row.project_id)
-            .bind(&row.key)
-            .bind(&row.summary)
-            .bind(&row.description)
-            .bind(row.issue_type)
-            .bind(row.priority)
-            .bind(row.created_at)
-            .bind(row.updated_at)
-            .execute(&mut *tx)
+            .execute(&mut **tx)
             .await
-            .map_err(|e| JiraError::database_error_with_cause("Failed to upsert issue", e))?;
+            .map(|_| ()),
+        }
+    }
+}
+
+#[async_trait]
+impl JiraIssueRepository for JiraIssueRepositoryImpl {
+    async fn bulk_upsert(&self, issues: Vec<JiraIssue>) -> Result<Vec<JiraIssue>, JiraError> {
+        if issues.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Rejected before anything is written, so one unstorable issue fails the whole batch
+        let rows = issues
+            .iter()
+            .map(|issue| {
+                JiraIssueRow::from_domain(issue)
+                    .map(|row| row.with_description_storage(self.description_storage))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                JiraError::database_error_with_cause("Failed to begin transaction", e)
+            })?;
+
+        for row in &rows {
+            self.upsert(&mut tx, row)
+                .await
+                .map_err(|e| JiraError::database_error_with_cause("Failed to upsert issue", e))?;
         }
 
         tx.commit()
@@ -68,3 +192,179 @@ impl JiraIssueRepository for JiraIssueRepositoryImpl {
         Ok(issues)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use chrono::{DateTime, Utc};
+    use domain::entity::jira::JiraIssueBuilder;
+    use domain::value_object::jira::{
+        JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId,
+    };
+
+    fn issue(id: i64, project_id: i64, key: &str) -> JiraIssue {
+        JiraIssueBuilder::new()
+            .id(JiraIssueId::new(id))
+            .project_id(JiraProjectId::new(project_id))
+            .key(JiraIssueKey::new(key))
+            .summary("Issue")
+            .issue_type(JiraIssueType::Task)
+            .priority(JiraIssuePriority::Medium)
+            .created_at(Utc::now())
+            .updated_at(Utc::now())
+            .build()
+            .unwrap()
+    }
+
+    async fn assert_reupsert_keeps_single_row(
+        conflict_target: JiraIssueConflictTarget,
+        schema_name: &str,
+    ) {
+        let schema = TestSchema::create(schema_name).await;
+        schema.insert_project(1, "UPS").await;
+        let pool = schema.pool();
+        let repository =
+            JiraIssueRepositoryImpl::with_conflict_target(pool.clone(), conflict_target);
+        let issue = issue(1, 1, "UPS-1");
+        let mut updated = issue.clone();
+        updated.summary = "Second".to_string();
+
+        repository.bulk_upsert(vec![issue]).await.unwrap();
+        repository.bulk_upsert(vec![updated]).await.unwrap();
+
+        let rows: Vec<(String,)> = sqlx::query_as("SELECT summary FROM jira_issue")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        schema.drop().await;
+        assert_eq!(rows, vec![("Second".to_string(),)]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_reupsert_on_id_keeps_single_row() {
+        assert_reupsert_keeps_single_row(JiraIssueConflictTarget::Id, "reupsert_on_id_test").await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_reupsert_on_key_keeps_single_row() {
+        assert_reupsert_keeps_single_row(JiraIssueConflictTarget::Key, "reupsert_on_key_test")
+            .await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_upsert_stores_time_tracking() {
+        let schema = TestSchema::create("upsert_time_tracking_test").await;
+        schema.insert_project(1, "TIME").await;
+        let pool = schema.pool();
+        let repository = JiraIssueRepositoryImpl::new(pool.clone());
+        let tracked = issue(1, 1, "TIME-1")
+            .with_original_estimate_seconds(Some(28_800))
+            .with_remaining_estimate_seconds(Some(14_400))
+            .with_time_spent_seconds(Some(14_400));
+
+        repository
+            .bulk_upsert(vec![tracked, issue(2, 1, "TIME-2")])
+            .await
+            .unwrap();
+
+        let rows: Vec<(Option<i64>, Option<i64>, Option<i64>)> = sqlx::query_as(
+            "SELECT original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds
+             FROM jira_issue ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        schema.drop().await;
+        assert_eq!(
+            rows,
+            vec![
+                (Some(28_800), Some(14_400), Some(14_400)),
+                (None, None, None)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_upsert_round_trips_description_in_each_storage() {
+        let schema = TestSchema::create("upsert_description_test").await;
+        schema.insert_project(1, "DESC").await;
+        let pool = schema.pool();
+        let description = "Line one\n\"Quoted\" line two";
+        let described = |id: i64| {
+            let mut issue = issue(id, 1, &format!("DESC-{}", id));
+            issue.description = Some(description.to_string());
+            issue
+        };
+
+        JiraIssueRepositoryImpl::new(pool.clone())
+            .bulk_upsert(vec![described(1)])
+            .await
+            .unwrap();
+        JiraIssueRepositoryImpl::new(pool.clone())
+            .with_description_storage(JiraDescriptionStorage::Jsonb)
+            .bulk_upsert(vec![described(2)])
+            .await
+            .unwrap();
+
+        let columns: Vec<(Option<serde_json::Value>, Option<String>)> =
+            sqlx::query_as("SELECT description, description_text FROM jira_issue ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let read: Vec<JiraIssueRow> = sqlx::query_as("SELECT * FROM jira_issue ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        schema.drop().await;
+        assert_eq!(
+            columns,
+            vec![
+                (None, Some(description.to_string())),
+                (Some(serde_json::json!(description)), None),
+            ]
+        );
+        let descriptions: Vec<Option<String>> = read
+            .into_iter()
+            .map(|row| row.into_dto().description)
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![Some(description.to_string()), Some(description.to_string())]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_reupsert_advances_synced_at_and_keeps_created_at() {
+        let schema = TestSchema::create("reupsert_synced_at_test").await;
+        schema.insert_project(1, "SYNC").await;
+        let pool = schema.pool();
+        let created_at: DateTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+        let mut issue = issue(1, 1, "SYNC-1");
+        issue.created_at = created_at;
+        issue.updated_at = created_at;
+        let repository = JiraIssueRepositoryImpl::new(pool.clone());
+        let read = || {
+            sqlx::query_as::<_, (DateTime<Utc>, DateTime<Utc>)>(
+                "SELECT created_at, synced_at FROM jira_issue WHERE id = 1",
+            )
+            .fetch_one(&pool)
+        };
+
+        repository.bulk_upsert(vec![issue.clone()]).await.unwrap();
+        let first = read().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        repository.bulk_upsert(vec![issue]).await.unwrap();
+        let second = read().await.unwrap();
+
+        schema.drop().await;
+        assert_eq!(first.0, created_at);
+        assert_eq!(second.0, created_at);
+        assert!(second.1 > first.1);
+    }
+}
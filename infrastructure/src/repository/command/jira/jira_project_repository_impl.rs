@@ -22,12 +22,13 @@ impl JiraProjectRepositoryImpl {
 #[async_trait]
 impl JiraProjectRepository for JiraProjectRepositoryImpl {
     async fn find_all_project_keys(&self) -> Result<Vec<JiraProjectKey>, JiraError> {
-        let rows: Vec<JiraProjectRow> = sqlx::query_as(
+        let rows = sqlx::query_as!(
+            JiraProjectRow,
             r#"
-            SELECT id, key, name
+            SELECT id, key, name, updated_at
             FROM jira_project
             ORDER BY key
-            "#,
+            "#
         )
         .fetch_all(&self.pool)
         .await
@@ -37,14 +38,15 @@ impl JiraProjectRepository for JiraProjectRepositoryImpl {
     }
 
     async fn find_by_id(&self, id: JiraProjectId) -> Result<Option<JiraProject>, JiraError> {
-        let row: Option<JiraProjectRow> = sqlx::query_as(
+        let row = sqlx::query_as!(
+            JiraProjectRow,
             r#"
-            SELECT id, key, name
+            SELECT id, key, name, updated_at
             FROM jira_project
             WHERE id = $1
             "#,
+            id.value()
         )
-        .bind(id.value())
         .fetch_optional(&self.pool)
         .await
         .map_err(|e| JiraError::database_error_with_cause("Failed to find project by id", e))?;
@@ -52,19 +54,30 @@ impl JiraProjectRepository for JiraProjectRepositoryImpl {
         Ok(row.map(|r| r.into_domain()))
     }
 
+    async fn exists(&self, id: JiraProjectId) -> Result<bool, JiraError> {
+        sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM jira_project WHERE id = $1) AS "exists!""#,
+            id.value()
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| JiraError::database_error_with_cause("Failed to check project existence", e))
+    }
+
     async fn create(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
         let row = JiraProjectRow::from_domain(&project);
 
-        let created_row: JiraProjectRow = sqlx::query_as(
+        let created_row = sqlx::query_as!(
+            JiraProjectRow,
             r#"
             INSERT INTO jira_project (id, key, name)
             VALUES ($1, $2, $3)
-            RETURNING id, key, name
+            RETURNING id, key, name, updated_at
             "#,
+            row.id,
+            row.key,
+            row.name
         )
-        .bind(row.id)
-        .bind(&row.key)
-        .bind(&row.name)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| JiraError::database_error_with_cause("Failed to create project", e))?;
@@ -72,25 +85,76 @@ impl JiraProjectRepository for JiraProjectRepositoryImpl {
         Ok(created_row.into_domain())
     }
 
+    async fn create_many(&self, projects: Vec<JiraProject>) -> Result<Vec<JiraProject>, JiraError> {
+        if projects.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                JiraError::database_error_with_cause("Failed to begin transaction", e)
+            })?;
+
+        let mut created = Vec::with_capacity(projects.len());
+        for project in &projects {
+            let row = JiraProjectRow::from_domain(project);
+
+            let created_row = sqlx::query_as!(
+                JiraProjectRow,
+                r#"
+                INSERT INTO jira_project (id, key, name)
+                VALUES ($1, $2, $3)
+                RETURNING id, key, name, updated_at
+                "#,
+                row.id,
+                row.key,
+                row.name
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                JiraError::database_error_with_cause(
+                    format!("Failed to create project {}", project.key),
+                    e,
+                )
+            })?;
+            created.push(created_row.into_domain());
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to commit transaction", e))?;
+
+        Ok(created)
+    }
+
     async fn update(&self, project: JiraProject) -> Result<JiraProject, JiraError> {
         let row = JiraProjectRow::from_domain(&project);
 
-        let updated_row: JiraProjectRow = sqlx::query_as(
+        // Only update the row if it still has the version that was read
+        let updated_row = sqlx::query_as!(
+            JiraProjectRow,
             r#"
             UPDATE jira_project
-            SET key = $2, name = $3
-            WHERE id = $1
-            RETURNING id, key, name
+            SET key = $2, name = $3, updated_at = clock_timestamp()
+            WHERE id = $1 AND ($4::timestamptz IS NULL OR updated_at = $4)
+            RETURNING id, key, name, updated_at
             "#,
+            row.id,
+            row.key,
+            row.name,
+            project.updated_at
         )
-        .bind(row.id)
-        .bind(&row.key)
-        .bind(&row.name)
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
         .await
         .map_err(|e| JiraError::database_error_with_cause("Failed to update project", e))?;
 
-        Ok(updated_row.into_domain())
+        updated_row.map(JiraProjectRow::into_domain).ok_or_else(|| {
+            JiraError::conflict(format!(
+                "Project {} was modified or removed since it was read",
+                project.id
+            ))
+        })
     }
 
     async fn bulk_upsert(&self, projects: Vec<JiraProject>) -> Result<Vec<JiraProject>, JiraError> {
@@ -106,18 +170,19 @@ impl JiraProjectRepository for JiraProjectRepositoryImpl {
         for project in &projects {
             let row = JiraProjectRow::from_domain(project);
 
-            sqlx::query(
+            sqlx::query!(
                 r#"
                 INSERT INTO jira_project (id, key, name)
                 VALUES ($1, $2, $3)
                 ON CONFLICT (id) DO UPDATE SET
                     key = EXCLUDED.key,
-                    name = EXCLUDED.name
+                    name = EXCLUDED.name,
+                    updated_at = clock_timestamp()
                 "#,
+                row.id,
+                row.key,
+                row.name
             )
-            .bind(row.id)
-            .bind(&row.key)
-            .bind(&row.name)
             .execute(&mut *tx)
             .await
             .map_err(|e| JiraError::database_error_with_cause("Failed to upsert project", e))?;
@@ -130,3 +195,85 @@ impl JiraProjectRepository for JiraProjectRepositoryImpl {
         Ok(projects)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_update_with_stale_version_returns_conflict() {
+        let schema = TestSchema::create("update_stale_version_test").await;
+        let pool = schema.pool();
+        let repository = JiraProjectRepositoryImpl::new(pool.clone());
+
+        let created = repository
+            .create(JiraProject::of("1", "OCC", "Optimistic").unwrap())
+            .await
+            .unwrap();
+        let first = created.clone().update("OCC", "First writer").unwrap();
+        let stale = created.update("OCC", "Second writer").unwrap();
+
+        repository.update(first).await.unwrap();
+        let result = repository.update(stale).await;
+
+        schema.drop().await;
+        assert!(result.unwrap_err().is_conflict());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_exists_reports_whether_project_is_stored() {
+        let schema = TestSchema::create("project_exists_test").await;
+        let pool = schema.pool();
+        let repository = JiraProjectRepositoryImpl::new(pool.clone());
+
+        repository
+            .create(JiraProject::of("1", "EXISTS", "Exists").unwrap())
+            .await
+            .unwrap();
+        let existing = repository.exists(JiraProjectId::new(1)).await;
+        let missing = repository.exists(JiraProjectId::new(2)).await;
+
+        schema.drop().await;
+        assert!(existing.unwrap());
+        assert!(!missing.unwrap());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_create_many_creates_nothing_when_one_insert_fails() {
+        let schema = TestSchema::create("create_many_rollback_test").await;
+        let pool = schema.pool();
+        let repository = JiraProjectRepositoryImpl::new(pool.clone());
+        repository
+            .create(JiraProject::of("1", "BATCHX", "Existing").unwrap())
+            .await
+            .unwrap();
+
+        let created = repository
+            .create_many(vec![
+                JiraProject::of("2", "BATCHA", "Batch A").unwrap(),
+                JiraProject::of("3", "BATCHB", "Batch B").unwrap(),
+            ])
+            .await;
+        let duplicate = repository
+            .create_many(vec![
+                JiraProject::of("4", "BATCHC", "Batch C").unwrap(),
+                JiraProject::of("1", "BATCHX", "Duplicate").unwrap(),
+            ])
+            .await;
+        let rolled_back = repository.exists(JiraProjectId::new(4)).await;
+
+        schema.drop().await;
+        let keys: Vec<String> = created
+            .unwrap()
+            .iter()
+            .map(|project| project.key.value().to_string())
+            .collect();
+        assert_eq!(keys, vec!["BATCHA", "BATCHB"]);
+        assert!(duplicate.is_err());
+        assert!(!rolled_back.unwrap());
+    }
+}
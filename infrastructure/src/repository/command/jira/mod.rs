@@ -1,5 +1,9 @@
+mod jira_changelog_repository_impl;
+mod jira_issue_link_repository_impl;
 mod jira_issue_repository_impl;
 pub mod jira_project_repository_impl;
 
-pub use jira_issue_repository_impl::JiraIssueRepositoryImpl;
+pub use jira_changelog_repository_impl::JiraChangelogRepositoryImpl;
+pub use jira_issue_link_repository_impl::JiraIssueLinkRepositoryImpl;
+pub use jira_issue_repository_impl::{JiraIssueConflictTarget, JiraIssueRepositoryImpl};
 pub use jira_project_repository_impl::JiraProjectRepositoryImpl;
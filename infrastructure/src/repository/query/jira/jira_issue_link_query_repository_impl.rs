@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use application::dto::query::jira::JiraIssueLinkQueryDto;
+use application::repository::jira::JiraIssueLinkQueryRepository;
+use domain::error::JiraError;
+use domain::value_object::jira::JiraIssueKey;
+
+use crate::database::{begin_read_transaction, map_query_error};
+
+/// PostgreSQL implementation of JiraIssueLinkQueryRepository using sqlx.
+///
+/// Reads run inside a transaction with `statement_timeout` applied, like the other
+/// query repositories.
+pub struct JiraIssueLinkQueryRepositoryImpl {
+    pool: PgPool,
+    statement_timeout: Option<Duration>,
+}
+
+impl JiraIssueLinkQueryRepositoryImpl {
+    pub fn new(pool: PgPool, statement_timeout: Option<Duration>) -> Self {
+        Self {
+            pool,
+            statement_timeout,
+        }
+    }
+}
+
+#[async_trait]
+impl JiraIssueLinkQueryRepository for JiraIssueLinkQueryRepositoryImpl {
+    async fn find_by_issue_keys(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraError> {
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let key_values: Vec<String> = keys.iter().map(|key| key.value().to_string()).collect();
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT source_key, target_key, link_type
+            FROM jira_issue_link
+            WHERE source_key = ANY($1) OR target_key = ANY($1)
+            ORDER BY source_key, target_key, link_type
+            "#,
+            &key_values
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to fetch issue links", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| JiraIssueLinkQueryDto::new(row.source_key, row.target_key, row.link_type))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_find_by_issue_keys_matches_either_end() {
+        let schema = TestSchema::create("issue_link_query_test").await;
+        let pool = schema.pool();
+        sqlx::query(
+            "INSERT INTO jira_issue_link (source_key, target_key, link_type) VALUES \
+             ('QLK-1', 'QLK-2', 'Blocks'), ('QLK-3', 'QLK-1', 'Relates'), ('QLK-4', 'QLK-5', 'Blocks')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repository = JiraIssueLinkQueryRepositoryImpl::new(pool.clone(), None);
+
+        let links = repository
+            .find_by_issue_keys(vec![JiraIssueKey::new("QLK-1")])
+            .await;
+
+        schema.drop().await;
+        let link = |source: &str, target: &str, link_type: &str| {
+            JiraIssueLinkQueryDto::new(source.into(), target.into(), link_type.into())
+        };
+        assert_eq!(
+            links.unwrap(),
+            vec![
+                link("QLK-1", "QLK-2", "Blocks"),
+                link("QLK-3", "QLK-1", "Relates"),
+            ]
+        );
+    }
+}
@@ -1,22 +1,56 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, QueryBuilder};
 
-use application::dto::query::jira::JiraIssueQueryDto;
+use application::dto::query::jira::{
+    JiraIssueListFilter, JiraIssueListSort, JiraIssueListSummary, JiraIssuePriorityCount,
+    JiraIssueQueryDto, JiraIssueTypeCount,
+};
 use application::repository::jira::JiraIssueQueryRepository;
 use domain::error::JiraError;
-use domain::value_object::jira::JiraIssueId;
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey, JiraProjectId};
 use domain::value_object::{Page, PageNumber, PageSize};
 
-use crate::database::JiraIssueRow;
+use crate::database::{
+    JiraIssuePriorityDb, JiraIssueRow, JiraIssueTypeDb, JiraStatusCategoryDb,
+    begin_read_transaction, map_query_error, push_jira_issue_list_filter,
+};
+
+/// Default maximum number of ids bound into a single `find_by_ids` query.
+const DEFAULT_FIND_BY_IDS_CHUNK_SIZE: usize = 1000;
+
+/// Columns of `jira_issue` read into a [`JiraIssueRow`] by the queries built at runtime.
+const ISSUE_COLUMNS: &str = "id, project_id, key, summary, description, description_text, \
+    issue_type, priority, reporter_account_id, reporter_display_name, watches, votes, \
+    status_category, original_estimate_seconds, remaining_estimate_seconds, \
+    time_spent_seconds, resolution, due_date, created_at, updated_at, synced_at";
 
 /// PostgreSQL implementation of JiraIssueQueryRepository using sqlx.
+///
+/// Reads run inside a transaction with `statement_timeout` applied, so a runaway
+/// query is cancelled by PostgreSQL instead of holding a pooled connection.
 pub struct JiraIssueQueryRepositoryImpl {
     pool: PgPool,
+    statement_timeout: Option<Duration>,
+    find_by_ids_chunk_size: usize,
 }
 
 impl JiraIssueQueryRepositoryImpl {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    pub fn new(pool: PgPool, statement_timeout: Option<Duration>) -> Self {
+        Self {
+            pool,
+            statement_timeout,
+            find_by_ids_chunk_size: DEFAULT_FIND_BY_IDS_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets how many ids `find_by_ids` binds into one query; larger lists are split
+    /// into several queries. Values below 1 are treated as 1.
+    pub fn with_find_by_ids_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.find_by_ids_chunk_size = chunk_size.max(1);
+        self
     }
 }
 
@@ -30,55 +64,510 @@ impl JiraIssueQueryRepository for JiraIssueQueryRepositoryImpl {
             return Ok(vec![]);
         }
 
-        let id_values: Vec<i64> = ids.iter().map(|id| id.value()).collect();
+        // Sorted and deduplicated, so the chunk results concatenate in id order
+        let mut id_values: Vec<i64> = ids.iter().map(|id| id.value()).collect();
+        id_values.sort_unstable();
+        id_values.dedup();
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let mut issues = Vec::with_capacity(id_values.len());
+        for chunk in id_values.chunks(self.find_by_ids_chunk_size) {
+            let rows = sqlx::query_as!(
+                JiraIssueRow,
+                r#"
+                SELECT id, project_id, key, summary, description, description_text, issue_type AS "issue_type: JiraIssueTypeDb", priority AS "priority: JiraIssuePriorityDb", reporter_account_id, reporter_display_name, watches, votes, status_category AS "status_category: JiraStatusCategoryDb", original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at, updated_at, synced_at AS "synced_at?"
+                FROM jira_issue
+                WHERE id = ANY($1)
+                ORDER BY id
+                "#,
+                chunk
+            )
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| map_query_error("Failed to fetch issues by IDs", e))?;
+
+            issues.extend(rows.into_iter().map(|row| row.into_dto()));
+        }
+
+        Ok(issues)
+    }
+
+    async fn find_ids_by_keys(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraError> {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let key_values: Vec<String> = keys.iter().map(|key| key.value().to_string()).collect();
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
 
-        let rows: Vec<JiraIssueRow> = sqlx::query_as(
+        let rows = sqlx::query!(
             r#"
-            SELECT id, project_id, key, summary, description, issue_type, priority, created_at, updated_at
+            SELECT id, key
             FROM jira_issue
-            WHERE id = ANY($1)
-            ORDER BY id
+            WHERE key = ANY($1)
             "#,
+            &key_values
         )
-        .bind(&id_values)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await
-        .map_err(|e| JiraError::database_error_with_cause("Failed to fetch issues by IDs", e))?;
+        .map_err(|e| map_query_error("Failed to resolve issue IDs by keys", e))?;
 
-        Ok(rows.into_iter().map(|row| row.into_dto()).collect())
+        Ok(rows
+            .into_iter()
+            .map(|row| (JiraIssueKey::new(row.key), JiraIssueId::new(row.id)))
+            .collect())
     }
 
     async fn list(
         &self,
         page_number: PageNumber,
         page_size: PageSize,
+        filter: &JiraIssueListFilter,
     ) -> Result<Page<JiraIssueQueryDto>, JiraError> {
-        let offset = (page_number.value() - 1) * page_size.value();
-        let limit = page_size.value();
+        let offset = page_number.offset(page_size);
+        let limit = page_size.value() as i64;
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
 
         // Get total count
-        let total_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM jira_issue")
-            .fetch_one(&self.pool)
+        let mut count_query = QueryBuilder::new("SELECT COUNT(*) FROM jira_issue");
+        push_jira_issue_list_filter(&mut count_query, filter);
+        let total_count: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&mut *tx)
             .await
-            .map_err(|e| JiraError::database_error_with_cause("Failed to count issues", e))?;
+            .map_err(|e| map_query_error("Failed to count issues", e))?;
 
         // Get paginated items
-        let rows: Vec<JiraIssueRow> = sqlx::query_as(
+        let mut items_query = QueryBuilder::new(format!("SELECT {ISSUE_COLUMNS} FROM jira_issue"));
+        push_jira_issue_list_filter(&mut items_query, filter);
+        items_query.push(" ORDER BY ");
+        if filter.sort == JiraIssueListSort::Watches {
+            items_query.push("watches DESC NULLS LAST, ");
+        }
+        items_query
+            .push("updated_at DESC, id LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        let rows: Vec<JiraIssueRow> = items_query
+            .build_query_as()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| map_query_error("Failed to fetch issues", e))?;
+
+        let items: Vec<JiraIssueQueryDto> = rows.into_iter().map(|row| row.into_dto()).collect();
+
+        Ok(Page::new(total_count as i32, items).with_pagination(page_number, page_size))
+    }
+
+    async fn summarize(
+        &self,
+        filter: &JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraError> {
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        // Same filter as `list`, so the counts add up to its total count
+        let mut by_type_query =
+            QueryBuilder::new("SELECT issue_type, COUNT(*) AS count FROM jira_issue");
+        push_jira_issue_list_filter(&mut by_type_query, filter);
+        by_type_query.push(" GROUP BY issue_type ORDER BY 2 DESC, issue_type");
+        let by_type: Vec<(JiraIssueTypeDb, i64)> = by_type_query
+            .build_query_as()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| map_query_error("Failed to count issues by type", e))?;
+
+        let mut by_priority_query =
+            QueryBuilder::new("SELECT priority, COUNT(*) AS count FROM jira_issue");
+        push_jira_issue_list_filter(&mut by_priority_query, filter);
+        by_priority_query.push(" GROUP BY priority ORDER BY 2 DESC, priority NULLS LAST");
+        let by_priority: Vec<(Option<JiraIssuePriorityDb>, i64)> = by_priority_query
+            .build_query_as()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| map_query_error("Failed to count issues by priority", e))?;
+
+        Ok(JiraIssueListSummary {
+            by_type: by_type
+                .into_iter()
+                .map(|(issue_type, count)| JiraIssueTypeCount {
+                    issue_type: issue_type.into_domain(),
+                    count: count as i32,
+                })
+                .collect(),
+            by_priority: by_priority
+                .into_iter()
+                .map(|(priority, count)| JiraIssuePriorityCount {
+                    priority: priority.map(JiraIssuePriorityDb::into_domain),
+                    count: count as i32,
+                })
+                .collect(),
+        })
+    }
+
+    async fn list_ids_only(
+        &self,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraIssueId>, JiraError> {
+        let offset = page_number.offset(page_size);
+        let limit = page_size.value() as i64;
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM jira_issue
+            "#
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to count issues", e))?;
+
+        let ids = sqlx::query_scalar!(
             r#"
-            SELECT id, project_id, key, summary, description, issue_type, priority, created_at, updated_at
+            SELECT id
             FROM jira_issue
-            ORDER BY updated_at DESC, id
+            ORDER BY id
             LIMIT $1 OFFSET $2
             "#,
+            limit,
+            offset
         )
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await
-        .map_err(|e| JiraError::database_error_with_cause("Failed to fetch issues", e))?;
+        .map_err(|e| map_query_error("Failed to fetch issue IDs", e))?;
 
-        let items: Vec<JiraIssueQueryDto> = rows.into_iter().map(|row| row.into_dto()).collect();
+        let items: Vec<JiraIssueId> = ids.into_iter().map(JiraIssueId::new).collect();
+
+        Ok(Page::new(total_count as i32, items).with_pagination(page_number, page_size))
+    }
+
+    async fn list_by_project(
+        &self,
+        project_id: JiraProjectId,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let rows = sqlx::query_as!(
+            JiraIssueRow,
+            r#"
+            SELECT id, project_id, key, summary, description, description_text, issue_type AS "issue_type: JiraIssueTypeDb", priority AS "priority: JiraIssuePriorityDb", reporter_account_id, reporter_display_name, watches, votes, status_category AS "status_category: JiraStatusCategoryDb", original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at, updated_at, synced_at AS "synced_at?"
+            FROM jira_issue
+            WHERE project_id = $1
+            ORDER BY id
+            "#,
+            project_id.value()
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to fetch issues by project", e))?;
+
+        Ok(rows.into_iter().map(|row| row.into_dto()).collect())
+    }
+
+    async fn list_grouped_by_project(
+        &self,
+        project_ids: Vec<JiraProjectId>,
+        per_project_limit: PageSize,
+    ) -> Result<HashMap<JiraProjectId, Vec<JiraIssueQueryDto>>, JiraError> {
+        if project_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let id_values: Vec<i64> = project_ids.iter().map(|id| id.value()).collect();
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        // Columns of the derived table lose their NOT NULL information, hence the overrides
+        let rows = sqlx::query_as!(
+            JiraIssueRow,
+            r#"
+            SELECT id AS "id!", project_id AS "project_id!", key AS "key!", summary AS "summary!", description, description_text, issue_type AS "issue_type!: JiraIssueTypeDb", priority AS "priority?: JiraIssuePriorityDb", reporter_account_id, reporter_display_name, watches, votes, status_category AS "status_category: JiraStatusCategoryDb", original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at AS "created_at!", updated_at AS "updated_at!", synced_at AS "synced_at?"
+            FROM (
+                SELECT *, ROW_NUMBER() OVER (PARTITION BY project_id ORDER BY updated_at DESC, id) AS rank
+                FROM jira_issue
+                WHERE project_id = ANY($1)
+            ) ranked
+            WHERE rank <= $2
+            ORDER BY project_id, rank
+            "#,
+            &id_values,
+            i64::from(per_project_limit.value())
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to fetch issues grouped by project", e))?;
+
+        let mut groups: HashMap<JiraProjectId, Vec<JiraIssueQueryDto>> = HashMap::new();
+        for row in rows {
+            groups
+                .entry(JiraProjectId::new(row.project_id))
+                .or_default()
+                .push(row.into_dto());
+        }
+        Ok(groups)
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<JiraIssueId>,
+        limit: PageSize,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraError> {
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let rows = sqlx::query_as!(
+            JiraIssueRow,
+            r#"
+            SELECT id, project_id, key, summary, description, description_text, issue_type AS "issue_type: JiraIssueTypeDb", priority AS "priority: JiraIssuePriorityDb", reporter_account_id, reporter_display_name, watches, votes, status_category AS "status_category: JiraStatusCategoryDb", original_estimate_seconds, remaining_estimate_seconds, time_spent_seconds, resolution, due_date, created_at, updated_at, synced_at AS "synced_at?"
+            FROM jira_issue
+            WHERE ($1::bigint IS NULL OR id > $1)
+            ORDER BY id
+            LIMIT $2
+            "#,
+            cursor.map(|id| id.value()),
+            i64::from(limit.value())
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to fetch issues after cursor", e))?;
+
+        Ok(rows.into_iter().map(|row| row.into_dto()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+    use sqlx::Executor;
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_list_ids_only_returns_page_of_ids_and_total() {
+        let schema = TestSchema::create("list_ids_only_test").await;
+        let pool = schema.pool();
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES (1, 'IDS', 'Ids');
+             INSERT INTO jira_issue (id, project_id, key, summary, issue_type, priority, created_at, updated_at)
+             SELECT id, 1, 'IDS-' || id, 'Issue', 'task', 'medium', now(), now()
+             FROM unnest(ARRAY[30, 10, 20]::bigint[]) AS id",
+        )
+        .await
+        .unwrap();
+        let repository = JiraIssueQueryRepositoryImpl::new(pool, None);
+
+        let first = repository
+            .list_ids_only(PageNumber::of(1).unwrap(), PageSize::of(2).unwrap())
+            .await
+            .unwrap();
+        let second = repository
+            .list_ids_only(PageNumber::of(2).unwrap(), PageSize::of(2).unwrap())
+            .await
+            .unwrap();
+
+        schema.drop().await;
+        assert_eq!(first.total_count, 3);
+        assert_eq!(
+            first.items,
+            vec![JiraIssueId::new(10), JiraIssueId::new(20)]
+        );
+        assert_eq!(second.items, vec![JiraIssueId::new(30)]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_list_grouped_by_project_caps_and_orders_each_group() {
+        let schema = TestSchema::create("list_grouped_test").await;
+        let pool = schema.pool();
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES (1, 'GRPA', 'Group A'), (2, 'GRPB', 'Group B');
+             INSERT INTO jira_issue (id, project_id, key, summary, issue_type, priority, created_at, updated_at)
+             SELECT n, CASE WHEN n <= 5 THEN 1 ELSE 2 END, 'GRP-' || n, 'Issue', 'task', 'medium',
+                    now(), timestamptz '2024-01-01' + n * interval '1 hour'
+             FROM generate_series(1, 7) AS n",
+        )
+        .await
+        .unwrap();
+        let repository = JiraIssueQueryRepositoryImpl::new(pool.clone(), None);
+
+        let groups = repository
+            .list_grouped_by_project(
+                vec![JiraProjectId::new(1), JiraProjectId::new(2)],
+                PageSize::of(3).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        schema.drop().await;
+        let ids = |project_id: i64| -> Vec<i64> {
+            groups[&JiraProjectId::new(project_id)]
+                .iter()
+                .map(|issue| issue.id)
+                .collect()
+        };
+        assert_eq!(groups.len(), 2);
+        assert_eq!(ids(1), vec![5, 4, 3]);
+        assert_eq!(ids(2), vec![7, 6]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_find_by_ids_splits_large_id_lists_into_chunks() {
+        let schema = TestSchema::create("find_by_ids_chunks_test").await;
+        let pool = schema.pool();
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES (1, 'CHUNK', 'Chunked');
+             INSERT INTO jira_issue (id, project_id, key, summary, issue_type, priority, created_at, updated_at)
+             SELECT n, 1, 'CHUNK-' || n, 'Issue', 'task', 'medium', now(), now()
+             FROM generate_series(1, 5000) AS n",
+        )
+        .await
+        .unwrap();
+        let repository =
+            JiraIssueQueryRepositoryImpl::new(pool.clone(), None).with_find_by_ids_chunk_size(1000);
+        // Reversed input, so the result order must come from the query rather than the input
+        let ids: Vec<JiraIssueId> = (1..=5000).rev().map(JiraIssueId::new).collect();
+
+        let issues = repository.find_by_ids(ids).await;
+
+        schema.drop().await;
+        let returned: Vec<i64> = issues.unwrap().iter().map(|issue| issue.id).collect();
+        let expected: Vec<i64> = (1..=5000).collect();
+        assert_eq!(returned, expected);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_list_overdue_returns_only_unresolved_issues_past_due() {
+        let schema = TestSchema::create("list_overdue_test").await;
+        let pool = schema.pool();
+        // 1: overdue, 2: due in the future, 3: due today, 4: resolved but past due, 5: no due date
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES (1, 'DUE', 'Due');
+             INSERT INTO jira_issue (id, project_id, key, summary, issue_type, priority, resolution, due_date, created_at, updated_at)
+             VALUES
+                 (1, 1, 'DUE-1', 'Overdue', 'task', 'medium', NULL, CURRENT_DATE - 3, now(), now()),
+                 (2, 1, 'DUE-2', 'Not yet due', 'task', 'medium', NULL, CURRENT_DATE + 3, now(), now()),
+                 (3, 1, 'DUE-3', 'Due today', 'task', 'medium', NULL, CURRENT_DATE, now(), now()),
+                 (4, 1, 'DUE-4', 'Resolved late', 'task', 'medium', 'Done', CURRENT_DATE - 3, now(), now()),
+                 (5, 1, 'DUE-5', 'No due date', 'task', 'medium', NULL, NULL, now(), now())",
+        )
+        .await
+        .unwrap();
+        let repository = JiraIssueQueryRepositoryImpl::new(pool, None);
+
+        let overdue = repository
+            .list(
+                PageNumber::of(1).unwrap(),
+                PageSize::of(10).unwrap(),
+                &JiraIssueListFilter::new(None).with_overdue(true),
+            )
+            .await
+            .unwrap();
+        let all = repository
+            .list(
+                PageNumber::of(1).unwrap(),
+                PageSize::of(10).unwrap(),
+                &JiraIssueListFilter::new(None),
+            )
+            .await
+            .unwrap();
+
+        schema.drop().await;
+        assert_eq!(overdue.total_count, 1);
+        let keys: Vec<&str> = overdue
+            .items
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["DUE-1"]);
+        assert_eq!(overdue.items[0].resolution, None);
+        assert!(overdue.items[0].due_date.is_some());
+        assert_eq!(all.total_count, 5);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_summarize_counts_only_issues_matching_the_filter() {
+        let schema = TestSchema::create("summarize_test").await;
+        let pool = schema.pool();
+        // alice reported 1-3, bob reported 4
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES (1, 'SUM', 'Summary');
+             INSERT INTO jira_issue (id, project_id, key, summary, issue_type, priority, reporter_account_id, created_at, updated_at)
+             VALUES
+                 (1, 1, 'SUM-1', 'Bug', 'bug', 'high', 'alice', now(), now()),
+                 (2, 1, 'SUM-2', 'Bug', 'bug', NULL, 'alice', now(), now()),
+                 (3, 1, 'SUM-3', 'Task', 'task', 'high', 'alice', now(), now()),
+                 (4, 1, 'SUM-4', 'Task', 'task', 'low', 'bob', now(), now())",
+        )
+        .await
+        .unwrap();
+        let repository = JiraIssueQueryRepositoryImpl::new(pool, None);
+
+        let alice = repository
+            .summarize(&JiraIssueListFilter::new(Some("alice".to_string())))
+            .await
+            .unwrap();
+        let all = repository
+            .summarize(&JiraIssueListFilter::new(None))
+            .await
+            .unwrap();
 
-        Ok(Page::new(total_count.0 as i32, items))
+        schema.drop().await;
+        assert_eq!(
+            alice.by_type,
+            vec![
+                JiraIssueTypeCount {
+                    issue_type: JiraIssueType::Bug,
+                    count: 2
+                },
+                JiraIssueTypeCount {
+                    issue_type: JiraIssueType::Task,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            alice.by_priority,
+            vec![
+                JiraIssuePriorityCount {
+                    priority: Some(JiraIssuePriority::High),
+                    count: 2
+                },
+                JiraIssuePriorityCount {
+                    priority: None,
+                    count: 1
+                },
+            ]
+        );
+        let total: i32 = all.by_type.iter().map(|count| count.count).sum();
+        assert_eq!(total, 4);
+        assert_eq!(all.by_priority.len(), 3);
     }
 }
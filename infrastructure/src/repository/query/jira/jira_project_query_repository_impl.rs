@@ -1,22 +1,31 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 use sqlx::PgPool;
 
 use application::dto::query::jira::JiraProjectQueryDto;
 use application::repository::jira::JiraProjectQueryRepository;
 use domain::error::JiraError;
-use domain::value_object::jira::JiraProjectId;
+use domain::value_object::jira::{JiraProjectId, JiraProjectKey};
 use domain::value_object::{Page, PageNumber, PageSize};
 
-use crate::database::JiraProjectRow;
+use crate::database::{JiraProjectRow, begin_read_transaction, contains_pattern, map_query_error};
 
 /// PostgreSQL implementation of JiraProjectQueryRepository using sqlx.
+///
+/// Reads run inside a transaction with `statement_timeout` applied, so a runaway
+/// query is cancelled by PostgreSQL instead of holding a pooled connection.
 pub struct JiraProjectQueryRepositoryImpl {
     pool: PgPool,
+    statement_timeout: Option<Duration>,
 }
 
 impl JiraProjectQueryRepositoryImpl {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    pub fn new(pool: PgPool, statement_timeout: Option<Duration>) -> Self {
+        Self {
+            pool,
+            statement_timeout,
+        }
     }
 }
 
@@ -32,53 +41,272 @@ impl JiraProjectQueryRepository for JiraProjectQueryRepositoryImpl {
 
         let id_values: Vec<i64> = ids.iter().map(|id| id.value()).collect();
 
-        let rows: Vec<JiraProjectRow> = sqlx::query_as(
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let rows = sqlx::query_as!(
+            JiraProjectRow,
             r#"
-            SELECT id, key, name
+            SELECT id, key, name, updated_at
             FROM jira_project
             WHERE id = ANY($1)
             ORDER BY id
             "#,
+            &id_values
         )
-        .bind(&id_values)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await
-        .map_err(|e| JiraError::database_error_with_cause("Failed to fetch projects by IDs", e))?;
+        .map_err(|e| map_query_error("Failed to fetch projects by IDs", e))?;
 
         Ok(rows.into_iter().map(|row| row.into_dto()).collect())
     }
 
+    async fn find_by_key(
+        &self,
+        key: &JiraProjectKey,
+    ) -> Result<Option<JiraProjectQueryDto>, JiraError> {
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        let row = sqlx::query_as!(
+            JiraProjectRow,
+            r#"
+            SELECT id, key, name, updated_at
+            FROM jira_project
+            WHERE key = $1
+            "#,
+            key.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to fetch project by key", e))?;
+
+        Ok(row.map(|row| row.into_dto()))
+    }
+
     async fn list(
         &self,
         page_number: PageNumber,
         page_size: PageSize,
     ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
-        let offset = (page_number.value() - 1) * page_size.value();
-        let limit = page_size.value();
+        let offset = page_number.offset(page_size);
+        let limit = page_size.value() as i64;
 
-        // Get total count
-        let total_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM jira_project")
-            .fetch_one(&self.pool)
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
             .await
-            .map_err(|e| JiraError::database_error_with_cause("Failed to count projects", e))?;
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
 
-        // Get paginated items
-        let rows: Vec<JiraProjectRow> = sqlx::query_as(
+        // Get paginated items, each carrying the total count
+        let rows = sqlx::query!(
             r#"
-            SELECT id, key, name
+            SELECT id, key, name, updated_at, COUNT(*) OVER () AS "total_count!"
             FROM jira_project
             ORDER BY key, id
             LIMIT $1 OFFSET $2
             "#,
+            limit,
+            offset
         )
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await
-        .map_err(|e| JiraError::database_error_with_cause("Failed to fetch projects", e))?;
+        .map_err(|e| map_query_error("Failed to fetch projects", e))?;
+
+        // A page past the end has no rows to carry the total, so count separately
+        let total_count = match rows.first() {
+            Some(row) => row.total_count,
+            None if offset == 0 => 0,
+            None => sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM jira_project"#)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| map_query_error("Failed to count projects", e))?,
+        };
+
+        let items: Vec<JiraProjectQueryDto> = rows
+            .into_iter()
+            .map(|row| {
+                JiraProjectRow {
+                    id: row.id,
+                    key: row.key,
+                    name: row.name,
+                    updated_at: row.updated_at,
+                }
+                .into_dto()
+            })
+            .collect();
+
+        Ok(Page::new(total_count as i32, items).with_pagination(page_number, page_size))
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page_number: PageNumber,
+        page_size: PageSize,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraError> {
+        let offset = page_number.offset(page_size);
+        let limit = page_size.value() as i64;
+        let pattern = contains_pattern(query);
+
+        let mut tx = begin_read_transaction(&self.pool, self.statement_timeout)
+            .await
+            .map_err(|e| JiraError::database_error_with_cause("Failed to begin transaction", e))?;
+
+        // Get total count
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM jira_project
+            WHERE name ILIKE $1 ESCAPE '\' OR key ILIKE $1 ESCAPE '\'
+            "#,
+            pattern
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to count projects", e))?;
+
+        // Get paginated items
+        let rows = sqlx::query_as!(
+            JiraProjectRow,
+            r#"
+            SELECT id, key, name, updated_at
+            FROM jira_project
+            WHERE name ILIKE $1 ESCAPE '\' OR key ILIKE $1 ESCAPE '\'
+            ORDER BY key, id
+            LIMIT $2 OFFSET $3
+            "#,
+            pattern,
+            limit,
+            offset
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| map_query_error("Failed to search projects", e))?;
 
         let items: Vec<JiraProjectQueryDto> = rows.into_iter().map(|row| row.into_dto()).collect();
 
-        Ok(Page::new(total_count.0 as i32, items))
+        Ok(Page::new(total_count as i32, items).with_pagination(page_number, page_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::TestSchema;
+    use sqlx::Executor;
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_list_counts_every_project_on_later_and_out_of_range_pages() {
+        let schema = TestSchema::create("list_projects_test").await;
+        let pool = schema.pool();
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name)
+             SELECT n, 'PROJ' || lpad(n::text, 2, '0'), 'Project ' || n FROM generate_series(1, 25) AS n",
+        )
+        .await
+        .unwrap();
+        let repository = JiraProjectQueryRepositoryImpl::new(pool, None);
+        let page_size = PageSize::of(10).unwrap();
+
+        let second = repository
+            .list(PageNumber::of(2).unwrap(), page_size)
+            .await
+            .unwrap();
+        let past_end = repository
+            .list(PageNumber::of(5).unwrap(), page_size)
+            .await
+            .unwrap();
+
+        schema.drop().await;
+        assert_eq!(second.total_count, 25);
+        assert_eq!(second.items.len(), 10);
+        assert_eq!(second.items[0].key, "PROJ11");
+        assert_eq!(past_end.total_count, 25);
+        assert!(past_end.items.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_list_blocked_past_statement_timeout_reports_timeout() {
+        let schema = TestSchema::create("list_projects_timeout_test").await;
+        let pool = schema.pool();
+        let mut lock = pool.begin().await.unwrap();
+        lock.execute("LOCK TABLE jira_project IN ACCESS EXCLUSIVE MODE")
+            .await
+            .unwrap();
+        let repository = JiraProjectQueryRepositoryImpl::new(pool, Some(Duration::from_millis(50)));
+
+        let result = repository
+            .list(PageNumber::of(1).unwrap(), PageSize::of(10).unwrap())
+            .await;
+
+        lock.rollback().await.unwrap();
+        schema.drop().await;
+        assert!(result.unwrap_err().is_timeout());
+    }
+
+    /// Creates a schema holding projects whose names and keys the search tests tell apart.
+    async fn search_fixture(name: &str) -> (TestSchema, JiraProjectQueryRepositoryImpl) {
+        let schema = TestSchema::create(name).await;
+        let pool = schema.pool();
+        pool.execute(
+            "INSERT INTO jira_project (id, key, name) VALUES
+                 (1, 'PAY', 'Payments'),
+                 (2, 'WEB', 'Web Storefront'),
+                 (3, 'DISC', '100% Discounts'),
+                 (4, 'SNAKE', 'snake_case tools'),
+                 (5, 'OPS', 'Operations')",
+        )
+        .await
+        .unwrap();
+        (schema, JiraProjectQueryRepositoryImpl::new(pool, None))
+    }
+
+    async fn search_keys(repository: &JiraProjectQueryRepositoryImpl, query: &str) -> Vec<String> {
+        repository
+            .search(query, PageNumber::of(1).unwrap(), PageSize::of(10).unwrap())
+            .await
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|project| project.key)
+            .collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_search_matches_name_case_insensitively() {
+        let (schema, repository) = search_fixture("search_by_name_test").await;
+
+        let keys = search_keys(&repository, "storefront").await;
+
+        schema.drop().await;
+        assert_eq!(keys, vec!["WEB"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_search_matches_key() {
+        let (schema, repository) = search_fixture("search_by_key_test").await;
+
+        let keys = search_keys(&repository, "ops").await;
+
+        schema.drop().await;
+        assert_eq!(keys, vec!["OPS"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a PostgreSQL database (DATABASE_URL)"]
+    async fn test_search_treats_wildcards_literally() {
+        let (schema, repository) = search_fixture("search_wildcards_test").await;
+
+        let percent = search_keys(&repository, "%").await;
+        let underscore = search_keys(&repository, "_").await;
+
+        schema.drop().await;
+        assert_eq!(percent, vec!["DISC"]);
+        assert_eq!(underscore, vec!["SNAKE"]);
     }
 }
@@ -1,5 +1,7 @@
+mod jira_issue_link_query_repository_impl;
 mod jira_issue_query_repository_impl;
 mod jira_project_query_repository_impl;
 
+pub use jira_issue_link_query_repository_impl::JiraIssueLinkQueryRepositoryImpl;
 pub use jira_issue_query_repository_impl::JiraIssueQueryRepositoryImpl;
 pub use jira_project_query_repository_impl::JiraProjectQueryRepositoryImpl;
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_graphql::dataloader::Loader;
+
+use application::usecase::query::jira::JiraIssueLinkFindByKeysQueryUseCase;
+use domain::value_object::jira::JiraIssueKey;
+
+use crate::api::graphql::types::JiraIssueLinkGql;
+
+/// DataLoader for batching the link lookups of Jira issues, keyed by issue key.
+/// A link between two requested issues is returned for both of them.
+pub struct JiraIssueLinkLoader {
+    usecase: Arc<dyn JiraIssueLinkFindByKeysQueryUseCase>,
+}
+
+impl JiraIssueLinkLoader {
+    pub fn new(usecase: Arc<dyn JiraIssueLinkFindByKeysQueryUseCase>) -> Self {
+        Self { usecase }
+    }
+}
+
+impl Loader<String> for JiraIssueLinkLoader {
+    type Value = Vec<JiraIssueLinkGql>;
+    type Error = String;
+
+    async fn load(&self, keys: &[String]) -> Result<HashMap<String, Self::Value>, Self::Error> {
+        let issue_keys: Vec<JiraIssueKey> = keys.iter().map(JiraIssueKey::new).collect();
+
+        let dtos = self
+            .usecase
+            .execute(issue_keys)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut map: HashMap<String, Vec<JiraIssueLinkGql>> = HashMap::new();
+        for dto in dtos {
+            let link = JiraIssueLinkGql::from(dto);
+            for key in [&link.source_key, &link.target_key] {
+                if keys.contains(key) {
+                    map.entry(key.clone()).or_default().push(link.clone());
+                }
+            }
+        }
+
+        Ok(map)
+    }
+}
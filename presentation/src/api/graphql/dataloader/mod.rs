@@ -1,5 +1,7 @@
+mod jira_issue_link_loader;
 mod jira_issue_loader;
 mod jira_project_loader;
 
+pub use jira_issue_link_loader::JiraIssueLinkLoader;
 pub use jira_issue_loader::JiraIssueLoader;
 pub use jira_project_loader::JiraProjectLoader;
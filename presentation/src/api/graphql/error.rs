@@ -0,0 +1,100 @@
+use async_graphql::{Error, ErrorExtensions};
+
+use application::error::ApplicationError;
+
+/// Extension telling clients whether retrying the failed operation may succeed.
+pub const RETRYABLE_EXTENSION: &str = "retryable";
+
+/// Extension carrying the stable code of an application error.
+pub const CODE_EXTENSION: &str = "code";
+
+/// Converts an application error into a GraphQL error with the `code` extension set.
+pub fn from_application_error(error: &impl ApplicationError) -> Error {
+    Error::new(error.to_string()).extend_with(|_, extensions| {
+        extensions.set(CODE_EXTENSION, error.code());
+    })
+}
+
+/// Converts an application error into a GraphQL error with both the `code` and the
+/// `retryable` extensions set.
+pub fn from_retryable_application_error(error: &impl ApplicationError, retryable: bool) -> Error {
+    from_application_error(error).extend_with(|_, extensions| {
+        extensions.set(RETRYABLE_EXTENSION, retryable);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::error::command::jira::JiraProjectSingleSyncError;
+    use async_graphql::Value;
+    use domain::error::JiraError;
+    use domain::value_object::jira::JiraProjectKey;
+
+    #[test]
+    fn test_sync_errors_are_retryable_only_when_the_cause_is() {
+        let hint = |error: JiraProjectSingleSyncError| {
+            from_retryable_application_error(&error, error.is_retryable())
+                .extensions
+                .and_then(|extensions| extensions.get(RETRYABLE_EXTENSION).cloned())
+        };
+
+        assert_eq!(
+            hint(JiraProjectSingleSyncError::ProjectFetchFailed(
+                JiraError::timeout("Request timed out")
+            )),
+            Some(Value::Boolean(true))
+        );
+        assert_eq!(
+            hint(JiraProjectSingleSyncError::NotFound(JiraProjectKey::new(
+                "GONE"
+            ))),
+            Some(Value::Boolean(false))
+        );
+        assert_eq!(
+            hint(JiraProjectSingleSyncError::ValidationFailed(
+                JiraError::empty_project_key()
+            )),
+            Some(Value::Boolean(false))
+        );
+    }
+
+    #[test]
+    fn test_application_errors_carry_their_code() {
+        let error = from_application_error(&JiraProjectSingleSyncError::NotFound(
+            JiraProjectKey::new("GONE"),
+        ));
+
+        assert_eq!(error.message, "Project not found in Jira: GONE");
+        assert_eq!(
+            error
+                .extensions
+                .and_then(|extensions| extensions.get(CODE_EXTENSION).cloned()),
+            Some(Value::String("JIRA_PROJECT_NOT_FOUND".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_retryable_errors_keep_their_message_and_code() {
+        let error = from_retryable_application_error(
+            &JiraProjectSingleSyncError::ProjectFetchFailed(JiraError::timeout(
+                "Request timed out",
+            )),
+            true,
+        );
+        let extensions = error.extensions.unwrap();
+
+        assert_eq!(
+            error.message,
+            "Failed to fetch project: Operation timed out: Request timed out"
+        );
+        assert_eq!(
+            extensions.get(CODE_EXTENSION).cloned(),
+            Some(Value::String("JIRA_PROJECT_FETCH_FAILED".to_string()))
+        );
+        assert_eq!(
+            extensions.get(RETRYABLE_EXTENSION).cloned(),
+            Some(Value::Boolean(true))
+        );
+    }
+}
@@ -1,8 +1,9 @@
 pub mod dataloader;
+pub mod error;
 pub mod mutation;
 pub mod query;
 mod schema;
 pub mod types;
 
 pub use mutation::JiraProjectMutation;
-pub use schema::{AppSchema, build_schema};
+pub use schema::{AppSchema, AppSchemaBuilder, SchemaConfig};
@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use async_graphql::{Context, Object, Result};
 
-use application::usecase::command::jira::{JiraProjectCreateUseCase, JiraProjectUpdateUseCase};
+use application::usecase::command::jira::{
+    JiraProjectBatchCreateUseCase, JiraProjectCreateUseCase, JiraProjectSingleSyncUseCase,
+    JiraProjectUpdateUseCase,
+};
 
-use super::super::types::{CreateJiraProjectInputGql, JiraProjectGql, UpdateJiraProjectInputGql};
+use super::super::error::{from_application_error, from_retryable_application_error};
+use super::super::types::{
+    CreateJiraProjectInputGql, CreateJiraProjectsPayloadGql, JiraProjectBatchModeGql,
+    JiraProjectGql, UpdateJiraProjectInputGql,
+};
 
 /// GraphQL mutation for Jira projects.
 #[derive(Default)]
@@ -19,11 +26,51 @@ impl JiraProjectMutation {
         ctx: &Context<'_>,
         input: CreateJiraProjectInputGql,
     ) -> Result<JiraProjectGql> {
-        let usecase = ctx.data_unchecked::<Arc<dyn JiraProjectCreateUseCase>>();
-        let project = usecase.execute(input.into()).await?;
+        let usecase = ctx.data::<Arc<dyn JiraProjectCreateUseCase>>()?;
+        let project = usecase
+            .execute(input.into())
+            .await
+            .map_err(|e| from_application_error(&e))?;
         Ok(JiraProjectGql::from(project))
     }
 
+    /// Creates the Jira project with `key`, taking its id and name from Jira.
+    ///
+    /// Fails with code `JIRA_PROJECT_NOT_FOUND` if Jira has no such project.
+    #[graphql(name = "createJiraProjectFromJira")]
+    async fn create_jira_project_from_jira(
+        &self,
+        ctx: &Context<'_>,
+        key: String,
+    ) -> Result<JiraProjectGql> {
+        let usecase = ctx.data::<Arc<dyn JiraProjectCreateUseCase>>()?;
+        let project = usecase
+            .execute_from_jira(key)
+            .await
+            .map_err(|e| from_application_error(&e))?;
+        Ok(JiraProjectGql::from(project))
+    }
+
+    /// Creates many Jira projects in one transaction.
+    ///
+    /// With `mode: PARTIAL_SUCCESS` (the default) invalid inputs are skipped and listed in
+    /// `errors`; with `ALL_OR_NOTHING` any invalid input fails the mutation and nothing
+    /// is created.
+    #[graphql(name = "createProjects")]
+    async fn create_projects(
+        &self,
+        ctx: &Context<'_>,
+        inputs: Vec<CreateJiraProjectInputGql>,
+        #[graphql(default)] mode: JiraProjectBatchModeGql,
+    ) -> Result<CreateJiraProjectsPayloadGql> {
+        let usecase = ctx.data::<Arc<dyn JiraProjectBatchCreateUseCase>>()?;
+        let result = usecase
+            .execute(inputs.into_iter().map(Into::into).collect(), mode.into())
+            .await
+            .map_err(|e| from_application_error(&e))?;
+        Ok(CreateJiraProjectsPayloadGql::from(result))
+    }
+
     /// Updates an existing Jira project.
     #[graphql(name = "updateJiraProject")]
     async fn update_jira_project(
@@ -31,8 +78,25 @@ impl JiraProjectMutation {
         ctx: &Context<'_>,
         input: UpdateJiraProjectInputGql,
     ) -> Result<JiraProjectGql> {
-        let usecase = ctx.data_unchecked::<Arc<dyn JiraProjectUpdateUseCase>>();
-        let project = usecase.execute(input.into()).await?;
+        let usecase = ctx.data::<Arc<dyn JiraProjectUpdateUseCase>>()?;
+        let project = usecase
+            .execute(input.into())
+            .await
+            .map_err(|e| from_application_error(&e))?;
+        Ok(JiraProjectGql::from(project))
+    }
+
+    /// Fetches a single project from Jira by key and upserts it.
+    ///
+    /// Errors carry a `retryable` extension telling whether trying again may succeed,
+    /// alongside the error `code`.
+    #[graphql(name = "syncProject")]
+    async fn sync_project(&self, ctx: &Context<'_>, key: String) -> Result<JiraProjectGql> {
+        let usecase = ctx.data::<Arc<dyn JiraProjectSingleSyncUseCase>>()?;
+        let project = usecase
+            .execute(key)
+            .await
+            .map_err(|e| from_retryable_application_error(&e, e.is_retryable()))?;
         Ok(JiraProjectGql::from(project))
     }
 }
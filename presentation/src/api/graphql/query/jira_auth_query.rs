@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Object, Result};
+
+use application::error::query::jira::JiraAuthCheckQueryError;
+use application::usecase::query::jira::JiraAuthCheckQueryUseCase;
+
+use crate::api::graphql::error::from_application_error;
+
+/// GraphQL Query root for Jira API access.
+#[derive(Default)]
+pub struct JiraAuthQuery;
+
+#[Object]
+impl JiraAuthQuery {
+    /// Returns whether Jira accepts the server's configured credentials.
+    /// Failures other than rejected credentials are returned as errors.
+    #[graphql(name = "checkJiraAuth")]
+    async fn check_jira_auth(&self, ctx: &Context<'_>) -> Result<bool> {
+        let usecase = ctx
+            .data_opt::<Arc<dyn JiraAuthCheckQueryUseCase>>()
+            .ok_or_else(|| async_graphql::Error::new("Jira API is not configured"))?;
+
+        match usecase.execute().await {
+            Ok(()) => Ok(true),
+            Err(JiraAuthCheckQueryError::InvalidCredentials(_)) => Ok(false),
+            Err(e) => Err(from_application_error(&e)),
+        }
+    }
+}
@@ -2,10 +2,19 @@ use std::sync::Arc;
 
 use async_graphql::dataloader::DataLoader;
 use async_graphql::{Context, ID, Object, Result};
+use chrono::{DateTime, Utc};
 
-use application::usecase::query::jira::JiraIssueListQueryUseCase;
+use application::dto::query::jira::JiraIssueListFilter;
+use application::usecase::query::jira::{
+    JiraIssueListQueryUseCase, JiraIssueResolveIdsQueryUseCase,
+};
+use domain::value_object::jira::JiraIssueKey;
 
-use crate::api::graphql::types::{JiraIssueGql, JiraIssueListGql};
+use super::PageSizeLimits;
+use crate::api::graphql::error::from_application_error;
+use crate::api::graphql::types::{
+    JiraIssueGql, JiraIssueIdMappingGql, JiraIssueListGql, JiraIssueSortGql,
+};
 
 /// DataLoader type alias for Jira issues.
 pub type JiraIssueDataLoader = DataLoader<crate::api::graphql::dataloader::JiraIssueLoader>;
@@ -33,21 +42,63 @@ impl JiraIssueQuery {
         Ok(issue)
     }
 
-    /// Fetches a paginated list of Jira issues.
-    #[graphql(name = "jiraIssues")]
+    /// Fetches a paginated list of Jira issues, optionally filtered by reporter account ID.
+    /// `sortBy: WATCHES` lists the most watched issues first. `overdue: true` keeps only
+    /// unresolved issues whose due date has passed; an issue due today is not overdue yet.
+    /// `syncedBefore` keeps only issues no sync has written since that time.
+    #[graphql(
+        name = "jiraIssues",
+        complexity = "super::page_complexity(page_size, child_complexity)"
+    )]
+    #[allow(clippy::too_many_arguments)]
     async fn jira_issues(
         &self,
         ctx: &Context<'_>,
         #[graphql(name = "pageNumber", default = 1)] page_number: i32,
-        #[graphql(name = "pageSize", default = 10)] page_size: i32,
+        #[graphql(name = "pageSize")] page_size: Option<i32>,
+        #[graphql(name = "reportedBy")] reported_by: Option<String>,
+        #[graphql(name = "sortBy", default)] sort_by: JiraIssueSortGql,
+        #[graphql(default)] overdue: bool,
+        #[graphql(name = "syncedBefore")] synced_before: Option<DateTime<Utc>>,
     ) -> Result<JiraIssueListGql> {
         let usecase = ctx.data::<Arc<dyn JiraIssueListQueryUseCase>>()?;
+        let page_size = ctx.data::<PageSizeLimits>()?.resolve(page_size)?;
 
-        let page = usecase
-            .execute(page_number, page_size)
+        let result = usecase
+            .execute(
+                page_number,
+                page_size,
+                JiraIssueListFilter::new(reported_by)
+                    .with_overdue(overdue)
+                    .with_synced_before(synced_before)
+                    .with_sort(sort_by.into()),
+            )
             .await
-            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+            .map_err(|e| from_application_error(&e))?;
+
+        Ok(JiraIssueListGql::from(result))
+    }
+
+    /// Resolves Jira issue keys to internal IDs, in request order.
+    /// Keys with no matching issue are omitted from the result.
+    #[graphql(name = "resolveIssueIds")]
+    async fn resolve_issue_ids(
+        &self,
+        ctx: &Context<'_>,
+        keys: Vec<String>,
+    ) -> Result<Vec<JiraIssueIdMappingGql>> {
+        let usecase = ctx.data::<Arc<dyn JiraIssueResolveIdsQueryUseCase>>()?;
+
+        let keys: Vec<JiraIssueKey> = keys.into_iter().map(JiraIssueKey::from).collect();
+        let mut resolved = usecase
+            .execute(keys.clone())
+            .await
+            .map_err(|e| from_application_error(&e))?;
 
-        Ok(JiraIssueListGql::from(page))
+        Ok(keys
+            .into_iter()
+            .filter_map(|key| resolved.remove_entry(&key))
+            .map(JiraIssueIdMappingGql::from)
+            .collect())
     }
 }
@@ -3,9 +3,13 @@ use std::sync::Arc;
 use async_graphql::dataloader::DataLoader;
 use async_graphql::{Context, ID, Object, Result};
 
-use application::usecase::query::jira::JiraProjectListQueryUseCase;
+use application::usecase::query::jira::{
+    JiraProjectListQueryUseCase, JiraProjectSearchQueryUseCase,
+};
 
-use crate::api::graphql::types::{JiraProjectGql, JiraProjectListGql};
+use super::PageSizeLimits;
+use crate::api::graphql::error::from_application_error;
+use crate::api::graphql::types::{JiraProjectGql, JiraProjectListFiltersGql, JiraProjectListGql};
 
 /// DataLoader type alias for Jira projects.
 pub type JiraProjectDataLoader = DataLoader<crate::api::graphql::dataloader::JiraProjectLoader>;
@@ -34,20 +38,50 @@ impl JiraProjectQuery {
     }
 
     /// Fetches a paginated list of Jira projects.
-    #[graphql(name = "jiraProjects")]
+    #[graphql(
+        name = "jiraProjects",
+        complexity = "super::page_complexity(page_size, child_complexity)"
+    )]
     async fn jira_projects(
         &self,
         ctx: &Context<'_>,
         #[graphql(name = "pageNumber", default = 1)] page_number: i32,
-        #[graphql(name = "pageSize", default = 10)] page_size: i32,
+        #[graphql(name = "pageSize")] page_size: Option<i32>,
     ) -> Result<JiraProjectListGql> {
         let usecase = ctx.data::<Arc<dyn JiraProjectListQueryUseCase>>()?;
+        let page_size = ctx.data::<PageSizeLimits>()?.resolve(page_size)?;
 
         let page = usecase
             .execute(page_number, page_size)
             .await
-            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+            .map_err(|e| from_application_error(&e))?;
 
         Ok(JiraProjectListGql::from(page))
     }
+
+    /// Searches Jira projects whose name or key contains the query (case-insensitive).
+    #[graphql(
+        name = "searchProjects",
+        complexity = "super::page_complexity(page_size, child_complexity)"
+    )]
+    async fn search_projects(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+        #[graphql(name = "pageNumber", default = 1)] page_number: i32,
+        #[graphql(name = "pageSize")] page_size: Option<i32>,
+    ) -> Result<JiraProjectListGql> {
+        let usecase = ctx.data::<Arc<dyn JiraProjectSearchQueryUseCase>>()?;
+        let page_size = ctx.data::<PageSizeLimits>()?.resolve(page_size)?;
+        let applied_filters = JiraProjectListFiltersGql {
+            query: Some(query.trim().to_string()),
+        };
+
+        let page = usecase
+            .execute(query, page_number, page_size)
+            .await
+            .map_err(|e| from_application_error(&e))?;
+
+        Ok(JiraProjectListGql::from(page).with_applied_filters(applied_filters))
+    }
 }
@@ -1,5 +1,63 @@
+mod jira_auth_query;
 mod jira_issue_query;
 mod jira_project_query;
 
+pub use jira_auth_query::JiraAuthQuery;
 pub use jira_issue_query::JiraIssueQuery;
 pub use jira_project_query::JiraProjectQuery;
+
+use async_graphql::{Error, ErrorExtensions, Result};
+
+use domain::value_object::PageSize;
+
+use super::error::CODE_EXTENSION;
+
+/// Default page size of a paginated list field when `pageSize` is omitted.
+const DEFAULT_PAGE_SIZE: i32 = 10;
+
+/// Bounds on the `pageSize` argument of paginated list fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageSizeLimits {
+    /// Page size used when `pageSize` is omitted.
+    pub default: i32,
+    /// Largest accepted `pageSize`; PageSize::MAX_VALUE caps it in any case.
+    pub max: i32,
+}
+
+impl Default for PageSizeLimits {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_PAGE_SIZE,
+            max: PageSize::MAX_VALUE,
+        }
+    }
+}
+
+impl PageSizeLimits {
+    /// Returns the page size to request, rejecting sizes above `max`. Sizes below 1 are
+    /// passed on for the use case to reject.
+    fn resolve(&self, page_size: Option<i32>) -> Result<i32> {
+        match page_size {
+            None => Ok(self.default),
+            Some(page_size) if page_size > self.max => Err(Error::new(format!(
+                "Page size must be at most {}, but was {}",
+                self.max, page_size
+            ))
+            .extend_with(|_, extensions| {
+                extensions.set(CODE_EXTENSION, "INVALID_PAGE_SIZE");
+            })),
+            Some(page_size) => Ok(page_size),
+        }
+    }
+}
+
+/// Complexity of a paginated list field: its selection counted once per item of the
+/// requested page. Sizes outside the valid range are clamped; the use case rejects them.
+/// An omitted size counts as the largest page, as the configured default is not known
+/// while validating.
+fn page_complexity(page_size: Option<i32>, child_complexity: usize) -> usize {
+    page_size
+        .unwrap_or(PageSize::MAX_VALUE)
+        .clamp(1, PageSize::MAX_VALUE) as usize
+        * child_complexity
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{EmptySubscription, Schema};
+
+use application::usecase::command::jira::{
+    JiraProjectBatchCreateUseCase, JiraProjectCreateUseCase, JiraProjectSingleSyncUseCase,
+    JiraProjectUpdateUseCase,
+};
+use application::usecase::query::jira::{
+    JiraAuthCheckQueryUseCase, JiraIssueFindByIdsQueryUseCase, JiraIssueLinkFindByKeysQueryUseCase,
+    JiraIssueListQueryUseCase, JiraIssueResolveIdsQueryUseCase, JiraProjectFindByIdsQueryUseCase,
+    JiraProjectListQueryUseCase, JiraProjectSearchQueryUseCase,
+};
+
+use super::{AppSchema, Mutation, Query, SchemaConfig};
+use crate::api::graphql::dataloader::{JiraIssueLinkLoader, JiraIssueLoader, JiraProjectLoader};
+
+/// Collects the use cases the schema serves and builds it with a [`SchemaConfig`].
+///
+/// Every use case is optional, so adding one does not touch existing callers. A field
+/// whose use case is not set returns an error when queried; `syncProject` and
+/// `checkJiraAuth` are typically left unset without Jira API access.
+#[derive(Default)]
+pub struct AppSchemaBuilder {
+    config: SchemaConfig,
+    issue_find_by_ids_usecase: Option<Arc<dyn JiraIssueFindByIdsQueryUseCase>>,
+    issue_list_usecase: Option<Arc<dyn JiraIssueListQueryUseCase>>,
+    issue_resolve_ids_usecase: Option<Arc<dyn JiraIssueResolveIdsQueryUseCase>>,
+    issue_link_find_by_keys_usecase: Option<Arc<dyn JiraIssueLinkFindByKeysQueryUseCase>>,
+    project_find_by_ids_usecase: Option<Arc<dyn JiraProjectFindByIdsQueryUseCase>>,
+    project_list_usecase: Option<Arc<dyn JiraProjectListQueryUseCase>>,
+    project_search_usecase: Option<Arc<dyn JiraProjectSearchQueryUseCase>>,
+    create_project_usecase: Option<Arc<dyn JiraProjectCreateUseCase>>,
+    batch_create_project_usecase: Option<Arc<dyn JiraProjectBatchCreateUseCase>>,
+    update_project_usecase: Option<Arc<dyn JiraProjectUpdateUseCase>>,
+    sync_project_usecase: Option<Arc<dyn JiraProjectSingleSyncUseCase>>,
+    auth_check_usecase: Option<Arc<dyn JiraAuthCheckQueryUseCase>>,
+}
+
+impl AppSchemaBuilder {
+    pub fn new(config: SchemaConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_issue_find_by_ids_usecase(
+        mut self,
+        usecase: Arc<dyn JiraIssueFindByIdsQueryUseCase>,
+    ) -> Self {
+        self.issue_find_by_ids_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_issue_list_usecase(mut self, usecase: Arc<dyn JiraIssueListQueryUseCase>) -> Self {
+        self.issue_list_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_issue_resolve_ids_usecase(
+        mut self,
+        usecase: Arc<dyn JiraIssueResolveIdsQueryUseCase>,
+    ) -> Self {
+        self.issue_resolve_ids_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_issue_link_find_by_keys_usecase(
+        mut self,
+        usecase: Arc<dyn JiraIssueLinkFindByKeysQueryUseCase>,
+    ) -> Self {
+        self.issue_link_find_by_keys_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_project_find_by_ids_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectFindByIdsQueryUseCase>,
+    ) -> Self {
+        self.project_find_by_ids_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_project_list_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectListQueryUseCase>,
+    ) -> Self {
+        self.project_list_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_project_search_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectSearchQueryUseCase>,
+    ) -> Self {
+        self.project_search_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_create_project_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectCreateUseCase>,
+    ) -> Self {
+        self.create_project_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_batch_create_project_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectBatchCreateUseCase>,
+    ) -> Self {
+        self.batch_create_project_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_update_project_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectUpdateUseCase>,
+    ) -> Self {
+        self.update_project_usecase = Some(usecase);
+        self
+    }
+
+    pub fn with_sync_project_usecase(
+        mut self,
+        usecase: Arc<dyn JiraProjectSingleSyncUseCase>,
+    ) -> Self {
+        self.sync_project_usecase = Some(usecase);
+        self
+    }
+
+    /// Ignored when [`SchemaConfig::auth_check`] is off.
+    pub fn with_auth_check_usecase(mut self, usecase: Arc<dyn JiraAuthCheckQueryUseCase>) -> Self {
+        self.auth_check_usecase = Some(usecase);
+        self
+    }
+
+    /// Builds the schema with the collected use cases, features and limits.
+    pub fn build(self) -> AppSchema {
+        let config = self.config;
+        let mut builder = Schema::build(Query::default(), Mutation::default(), EmptySubscription)
+            .data(config.page_size)
+            .limit_depth(config.max_depth)
+            .limit_complexity(config.max_complexity);
+
+        if let Some(usecase) = self.issue_find_by_ids_usecase {
+            builder = builder.data(DataLoader::new(JiraIssueLoader::new(usecase), tokio::spawn));
+        }
+        if let Some(usecase) = self.issue_link_find_by_keys_usecase {
+            builder = builder.data(DataLoader::new(
+                JiraIssueLinkLoader::new(usecase),
+                tokio::spawn,
+            ));
+        }
+        if let Some(usecase) = self.project_find_by_ids_usecase {
+            builder = builder.data(DataLoader::new(
+                JiraProjectLoader::new(usecase),
+                tokio::spawn,
+            ));
+        }
+        if let Some(usecase) = self.issue_list_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.issue_resolve_ids_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.project_list_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.project_search_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.create_project_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.batch_create_project_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.update_project_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.sync_project_usecase {
+            builder = builder.data(usecase);
+        }
+        if let Some(usecase) = self.auth_check_usecase.filter(|_| config.auth_check) {
+            builder = builder.data(usecase);
+        }
+        if !config.introspection {
+            builder = builder.disable_introspection();
+        }
+
+        builder.finish()
+    }
+}
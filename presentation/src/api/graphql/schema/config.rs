@@ -0,0 +1,84 @@
+use domain::value_object::PageSize;
+
+use crate::api::graphql::query::PageSizeLimits;
+
+/// Default deepest selection nesting a query may use. Introspection counts too; the
+/// query GraphiQL sends nests 12 levels deep.
+const DEFAULT_MAX_DEPTH: usize = 15;
+
+/// Default complexity budget of a query; see [`SchemaConfig::max_complexity`].
+const DEFAULT_MAX_COMPLEXITY: usize = 5000;
+
+/// Features and limits the schema is built with.
+#[derive(Debug, Clone)]
+pub struct SchemaConfig {
+    /// Serves introspection queries; disable to hide the schema from public clients.
+    pub introspection: bool,
+    /// Serves `checkJiraAuth` when a credential check use case is set.
+    pub auth_check: bool,
+    /// Queries nesting selections deeper than this are rejected before execution.
+    pub max_depth: usize,
+    /// Queries costing more than this are rejected before execution. Every selected
+    /// field costs 1, and a paginated list costs its selection once per requested item.
+    pub max_complexity: usize,
+    /// Default and largest `pageSize` of paginated list fields.
+    pub page_size: PageSizeLimits,
+}
+
+impl Default for SchemaConfig {
+    fn default() -> Self {
+        Self {
+            introspection: true,
+            auth_check: true,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_complexity: DEFAULT_MAX_COMPLEXITY,
+            page_size: PageSizeLimits::default(),
+        }
+    }
+}
+
+impl SchemaConfig {
+    /// Creates SchemaConfig from environment variables, falling back to defaults.
+    /// GRAPHQL_DISABLE_INTROSPECTION=true disables introspection and
+    /// GRAPHQL_DISABLE_AUTH_CHECK=true disables `checkJiraAuth`. GRAPHQL_MAX_DEPTH,
+    /// GRAPHQL_MAX_COMPLEXITY, GRAPHQL_DEFAULT_PAGE_SIZE and GRAPHQL_MAX_PAGE_SIZE set the
+    /// query limits; a default page size above the max is lowered to it.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let max_page_size = std::env::var("GRAPHQL_MAX_PAGE_SIZE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|size: &i32| (1..=PageSize::MAX_VALUE).contains(size))
+            .unwrap_or(default.page_size.max);
+        let default_page_size = std::env::var("GRAPHQL_DEFAULT_PAGE_SIZE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&size: &i32| size > 0)
+            .unwrap_or(default.page_size.default)
+            .min(max_page_size);
+        Self {
+            introspection: !std::env::var("GRAPHQL_DISABLE_INTROSPECTION")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(!default.introspection),
+            auth_check: !std::env::var("GRAPHQL_DISABLE_AUTH_CHECK")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(!default.auth_check),
+            max_depth: std::env::var("GRAPHQL_MAX_DEPTH")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&depth: &usize| depth > 0)
+                .unwrap_or(default.max_depth),
+            max_complexity: std::env::var("GRAPHQL_MAX_COMPLEXITY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&complexity: &usize| complexity > 0)
+                .unwrap_or(default.max_complexity),
+            page_size: PageSizeLimits {
+                default: default_page_size,
+                max: max_page_size,
+            },
+        }
+    }
+}
@@ -0,0 +1,25 @@
+mod builder;
+mod config;
+#[cfg(test)]
+mod test_stubs;
+#[cfg(test)]
+mod tests;
+
+use async_graphql::{EmptySubscription, MergedObject, Schema};
+
+use super::mutation::JiraProjectMutation;
+use super::query::{JiraAuthQuery, JiraIssueQuery, JiraProjectQuery};
+
+pub use builder::AppSchemaBuilder;
+pub use config::SchemaConfig;
+
+/// Combined Query root with all query resolvers.
+#[derive(MergedObject, Default)]
+pub struct Query(JiraIssueQuery, JiraProjectQuery, JiraAuthQuery);
+
+/// Combined Mutation root with all mutation resolvers.
+#[derive(MergedObject, Default)]
+pub struct Mutation(JiraProjectMutation);
+
+/// The GraphQL schema type alias.
+pub type AppSchema = Schema<Query, Mutation, EmptySubscription>;
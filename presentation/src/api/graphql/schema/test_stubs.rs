@@ -0,0 +1,235 @@
+//! Stub use cases the schema tests build the schema from.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use application::dto::query::jira::{
+    JiraIssueLinkQueryDto, JiraIssueListFilter, JiraIssueListQueryMeta, JiraIssueListQueryResult,
+    JiraIssueListSort, JiraIssueListSummary, JiraIssuePriorityCount, JiraIssueQueryDto,
+    JiraIssueTypeCount, JiraProjectQueryDto,
+};
+use application::error::query::jira::{
+    JiraAuthCheckQueryError, JiraIssueFindByIdQueryError, JiraIssueLinkFindByKeysQueryError,
+    JiraIssueListQueryError, JiraIssueResolveIdsQueryError, JiraProjectFindByIdQueryError,
+    JiraProjectListQueryError, JiraProjectSearchQueryError,
+};
+use application::usecase::command::jira::{
+    JiraProjectBatchCreateUseCaseImpl, JiraProjectCreateUseCaseImpl, JiraProjectUpdateUseCaseImpl,
+};
+use application::usecase::query::jira::{
+    JiraAuthCheckQueryUseCase, JiraIssueFindByIdsQueryUseCase, JiraIssueLinkFindByKeysQueryUseCase,
+    JiraIssueListQueryUseCase, JiraIssueResolveIdsQueryUseCase, JiraProjectFindByIdsQueryUseCase,
+    JiraProjectListQueryUseCase, JiraProjectSearchQueryUseCase,
+};
+use domain::test_support::jira::InMemoryJiraProjectRepository;
+use domain::value_object::jira::{
+    JiraIssueId, JiraIssueKey, JiraIssuePriority, JiraIssueType, JiraProjectId,
+};
+use domain::value_object::{Page, PageNumber, PageSize};
+
+use super::{AppSchema, AppSchemaBuilder, SchemaConfig};
+
+/// Stands in for every query use case; only the project list, watch-sorted issue list,
+/// issue list summary, issue links and key resolution return data. The project
+/// mutations run the real use cases over an in-memory repository.
+pub(super) struct StubUseCases;
+
+#[async_trait]
+impl JiraIssueFindByIdsQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        _ids: Vec<JiraIssueId>,
+    ) -> Result<Vec<JiraIssueQueryDto>, JiraIssueFindByIdQueryError> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl JiraIssueListQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        _page_number: i32,
+        _page_size: i32,
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListQueryResult, JiraIssueListQueryError> {
+        let issue = |id: i64| {
+            JiraIssueQueryDto::builder()
+                .id(id)
+                .key(format!("PROJ-{}", id))
+                .build()
+        };
+        let page = if filter.overdue {
+            Page::new(
+                1,
+                vec![issue(3).with_due_date(NaiveDate::from_ymd_opt(2024, 1, 31))],
+            )
+        } else if let Some(synced_before) = filter.synced_before {
+            Page::new(
+                1,
+                vec![issue(4).with_synced_at(Some(synced_before - chrono::Duration::days(1)))],
+            )
+        } else if filter.sort != JiraIssueListSort::Watches {
+            Page::empty()
+        } else {
+            Page::new(
+                2,
+                vec![
+                    issue(1)
+                        .with_watches(Some(7))
+                        .with_votes(Some(3))
+                        .with_time_tracking(Some(28_800), Some(14_400), Some(14_400)),
+                    issue(2),
+                ],
+            )
+        };
+        Ok(JiraIssueListQueryResult {
+            page,
+            meta: JiraIssueListQueryMeta::from_filter(&filter),
+        })
+    }
+
+    async fn summarize(
+        &self,
+        filter: JiraIssueListFilter,
+    ) -> Result<JiraIssueListSummary, JiraIssueListQueryError> {
+        let type_count = |issue_type, count| JiraIssueTypeCount { issue_type, count };
+        let priority_count = |priority, count| JiraIssuePriorityCount { priority, count };
+        if filter.overdue {
+            Ok(JiraIssueListSummary {
+                by_type: vec![type_count(JiraIssueType::Bug, 1)],
+                by_priority: vec![priority_count(None, 1)],
+            })
+        } else {
+            Ok(JiraIssueListSummary {
+                by_type: vec![
+                    type_count(JiraIssueType::Task, 3),
+                    type_count(JiraIssueType::Bug, 1),
+                ],
+                by_priority: vec![
+                    priority_count(Some(JiraIssuePriority::High), 2),
+                    priority_count(None, 2),
+                ],
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl JiraIssueResolveIdsQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<HashMap<JiraIssueKey, JiraIssueId>, JiraIssueResolveIdsQueryError> {
+        Ok(keys
+            .into_iter()
+            .filter(|key| key.value().starts_with("PROJ-"))
+            .map(|key| {
+                let id = key.value()["PROJ-".len()..].parse().unwrap();
+                (key, JiraIssueId::new(id))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl JiraIssueLinkFindByKeysQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        keys: Vec<JiraIssueKey>,
+    ) -> Result<Vec<JiraIssueLinkQueryDto>, JiraIssueLinkFindByKeysQueryError> {
+        let links = [
+            ("PROJ-1", "PROJ-2", "Blocks"),
+            ("OTHER-3", "PROJ-1", "Relates"),
+        ];
+        Ok(links
+            .into_iter()
+            .filter(|(source, target, _)| {
+                keys.iter()
+                    .any(|key| key.value() == *source || key.value() == *target)
+            })
+            .map(|(source, target, link_type)| {
+                JiraIssueLinkQueryDto::new(source.into(), target.into(), link_type.into())
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl JiraProjectFindByIdsQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        _ids: Vec<JiraProjectId>,
+    ) -> Result<Vec<JiraProjectQueryDto>, JiraProjectFindByIdQueryError> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl JiraProjectListQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        page_number: i32,
+        page_size: i32,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraProjectListQueryError> {
+        Ok(Page::new(
+            42,
+            vec![
+                JiraProjectQueryDto::new(1, "PROJ".to_string(), "Project".to_string())
+                    .with_updated_at("2024-03-01T12:00:00Z".parse().unwrap()),
+            ],
+        )
+        .with_pagination(
+            PageNumber::of(page_number).unwrap(),
+            PageSize::of(page_size).unwrap(),
+        ))
+    }
+}
+
+#[async_trait]
+impl JiraAuthCheckQueryUseCase for StubUseCases {
+    async fn execute(&self) -> Result<(), JiraAuthCheckQueryError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl JiraProjectSearchQueryUseCase for StubUseCases {
+    async fn execute(
+        &self,
+        _query: String,
+        _page_number: i32,
+        _page_size: i32,
+    ) -> Result<Page<JiraProjectQueryDto>, JiraProjectSearchQueryError> {
+        Ok(Page::empty())
+    }
+}
+
+pub(super) fn stub_schema() -> AppSchema {
+    stub_schema_with(SchemaConfig::default())
+}
+
+pub(super) fn stub_schema_with(config: SchemaConfig) -> AppSchema {
+    let stub = Arc::new(StubUseCases);
+    let project_repository = Arc::new(InMemoryJiraProjectRepository::new());
+    AppSchemaBuilder::new(config)
+        .with_issue_find_by_ids_usecase(stub.clone())
+        .with_issue_list_usecase(stub.clone())
+        .with_issue_resolve_ids_usecase(stub.clone())
+        .with_issue_link_find_by_keys_usecase(stub.clone())
+        .with_project_find_by_ids_usecase(stub.clone())
+        .with_project_list_usecase(stub.clone())
+        .with_project_search_usecase(stub)
+        .with_create_project_usecase(Arc::new(JiraProjectCreateUseCaseImpl::new(
+            project_repository.clone(),
+        )))
+        .with_batch_create_project_usecase(Arc::new(JiraProjectBatchCreateUseCaseImpl::new(
+            project_repository.clone(),
+        )))
+        .with_update_project_usecase(Arc::new(JiraProjectUpdateUseCaseImpl::new(
+            project_repository,
+        )))
+        .build()
+}
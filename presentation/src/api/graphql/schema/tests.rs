@@ -0,0 +1,451 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use super::test_stubs::{StubUseCases, stub_schema, stub_schema_with};
+use super::{AppSchemaBuilder, SchemaConfig};
+use crate::api::graphql::query::PageSizeLimits;
+
+#[tokio::test]
+async fn test_build_schema_from_trait_objects() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute("{ jiraProjects { totalCount items { key } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraProjects": { "totalCount": 42, "items": [{ "key": "PROJ" }] } })
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_issue_ids_omits_unknown_keys() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(r#"{ resolveIssueIds(keys: ["PROJ-2", "OTHER-1", "PROJ-1"]) { key id } }"#)
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "resolveIssueIds": [
+            { "key": "PROJ-2", "id": "2" },
+            { "key": "PROJ-1", "id": "1" }
+        ] })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_projects_expose_version_for_updates() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute("{ jiraProjects { items { key updatedAt } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraProjects": { "items": [
+            { "key": "PROJ", "updatedAt": "2024-03-01T12:00:00+00:00" }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_sorted_by_watches_exposes_aggregates() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute("{ jiraIssues(sortBy: WATCHES) { items { key watches votes } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "items": [
+            { "key": "PROJ-1", "watches": 7, "votes": 3 },
+            { "key": "PROJ-2", "watches": null, "votes": null }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_expose_time_tracking() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            "{ jiraIssues(sortBy: WATCHES) { items { key originalEstimateSeconds remainingEstimateSeconds timeSpentSeconds } } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "items": [
+            {
+                "key": "PROJ-1",
+                "originalEstimateSeconds": 28800,
+                "remainingEstimateSeconds": 14400,
+                "timeSpentSeconds": 14400
+            },
+            {
+                "key": "PROJ-2",
+                "originalEstimateSeconds": null,
+                "remainingEstimateSeconds": null,
+                "timeSpentSeconds": null
+            }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_overdue_filter_exposes_due_date() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute("{ jiraIssues(overdue: true) { totalCount items { key resolution dueDate } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "totalCount": 1, "items": [
+            { "key": "PROJ-3", "resolution": null, "dueDate": "2024-01-31" }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_summary_reflects_the_filter() {
+    let schema = stub_schema();
+    let query = |args: &str| {
+        format!(
+            "{{ jiraIssues{} {{ summary {{ byType {{ type count }} byPriority {{ priority count }} }} }} }}",
+            args
+        )
+    };
+
+    let all = schema.execute(query("")).await;
+    let overdue = schema.execute(query("(overdue: true)")).await;
+
+    assert!(all.errors.is_empty(), "{:?}", all.errors);
+    assert!(overdue.errors.is_empty(), "{:?}", overdue.errors);
+    assert_eq!(
+        all.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "summary": {
+            "byType": [{ "type": "TASK", "count": 3 }, { "type": "BUG", "count": 1 }],
+            "byPriority": [{ "priority": "HIGH", "count": 2 }, { "priority": null, "count": 2 }]
+        } } })
+    );
+    assert_eq!(
+        overdue.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "summary": {
+            "byType": [{ "type": "BUG", "count": 1 }],
+            "byPriority": [{ "priority": null, "count": 1 }]
+        } } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_meta_echoes_defaults_when_arguments_are_omitted() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            "{ jiraIssues { meta { sortBy direction filters { reportedBy overdue syncedBefore } } } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "meta": {
+            "sortBy": "UPDATED_AT",
+            "direction": "DESCENDING",
+            "filters": { "reportedBy": null, "overdue": false, "syncedBefore": null }
+        } } })
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issues_synced_before_filter_exposes_synced_at() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            r#"{ jiraIssues(syncedBefore: "2024-03-01T00:00:00Z") { totalCount items { key syncedAt } } }"#,
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "totalCount": 1, "items": [
+            { "key": "PROJ-4", "syncedAt": "2024-02-29T00:00:00+00:00" }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_create_projects_returns_every_project_when_all_inputs_are_valid() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            r#"mutation { createProjects(inputs: [
+                { id: "1", key: "ONE", name: "Project One" },
+                { id: "2", key: "TWO", name: "Project Two" }
+            ]) { projects { id key } errors { index } } }"#,
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "createProjects": {
+            "projects": [{ "id": "1", "key": "ONE" }, { "id": "2", "key": "TWO" }],
+            "errors": []
+        } })
+    );
+}
+
+#[tokio::test]
+async fn test_create_projects_reports_invalid_input_without_dropping_valid_ones() {
+    let schema = stub_schema();
+    let batch = r#"inputs: [
+        { id: "1", key: "ONE", name: "Project One" },
+        { id: "2", key: "", name: "Project Two" }
+    ]"#;
+
+    let partial = schema
+        .execute(format!(
+            "mutation {{ createProjects({batch}) {{ projects {{ key }} errors {{ index fields message }} }} }}"
+        ))
+        .await;
+    let atomic = schema
+        .execute(format!(
+            "mutation {{ createProjects({batch}, mode: ALL_OR_NOTHING) {{ projects {{ key }} }} }}"
+        ))
+        .await;
+
+    assert!(partial.errors.is_empty(), "{:?}", partial.errors);
+    assert_eq!(
+        partial.data.into_json().unwrap(),
+        json!({ "createProjects": {
+            "projects": [{ "key": "ONE" }],
+            "errors": [{
+                "index": 1,
+                "fields": ["key"],
+                "message": "input 1: key: Project key cannot be empty"
+            }]
+        } })
+    );
+    assert_eq!(
+        atomic.errors[0].message,
+        "Validation error: input 1: key: Project key cannot be empty"
+    );
+}
+
+#[tokio::test]
+async fn test_jira_issue_links_are_loaded_for_both_ends() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            "{ jiraIssues(sortBy: WATCHES) { items { key links { sourceKey targetKey linkType } } } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let blocks = json!({ "sourceKey": "PROJ-1", "targetKey": "PROJ-2", "linkType": "Blocks" });
+    let relates = json!({ "sourceKey": "OTHER-3", "targetKey": "PROJ-1", "linkType": "Relates" });
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "jiraIssues": { "items": [
+            { "key": "PROJ-1", "links": [blocks, relates] },
+            { "key": "PROJ-2", "links": [blocks] }
+        ] } })
+    );
+}
+
+#[tokio::test]
+async fn test_introspection_enabled_by_default() {
+    let schema = stub_schema();
+
+    let response = schema.execute("{ __schema { queryType { name } } }").await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "__schema": { "queryType": { "name": "Query" } } })
+    );
+}
+
+#[tokio::test]
+async fn test_introspection_rejected_when_disabled() {
+    let schema = stub_schema_with(SchemaConfig {
+        introspection: false,
+        ..SchemaConfig::default()
+    });
+
+    let response = schema.execute("{ __schema { queryType { name } } }").await;
+
+    // The introspection fields resolve to null instead of exposing the schema
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "__schema": null })
+    );
+}
+
+#[tokio::test]
+async fn test_normal_query_passes_limits() {
+    let schema = stub_schema();
+
+    let response = schema
+        .execute(
+            "{ jiraIssues(pageSize: 100, sortBy: WATCHES) { totalCount \
+               items { id key summary reporter { accountId displayName } links { sourceKey targetKey } } } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[tokio::test]
+async fn test_deeply_nested_query_is_rejected() {
+    let schema = stub_schema_with(SchemaConfig {
+        max_depth: 3,
+        ..SchemaConfig::default()
+    });
+
+    let response = schema
+        .execute("{ jiraIssues { items { reporter { accountId } } } }")
+        .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].message, "Query is nested too deep.");
+}
+
+#[tokio::test]
+async fn test_complexity_grows_with_page_size() {
+    let schema = stub_schema_with(SchemaConfig {
+        max_complexity: 100,
+        ..SchemaConfig::default()
+    });
+    let query = |page_size: i32| {
+        format!(
+            "{{ jiraProjects(pageSize: {}) {{ items {{ id key name }} }} }}",
+            page_size
+        )
+    };
+
+    let small = schema.execute(query(10)).await;
+    let large = schema.execute(query(100)).await;
+
+    assert!(small.errors.is_empty(), "{:?}", small.errors);
+    assert_eq!(large.errors.len(), 1);
+    assert_eq!(large.errors[0].message, "Query is too complex.");
+}
+
+#[tokio::test]
+async fn test_introspection_passes_default_limits() {
+    let schema = stub_schema();
+    // The type reference nesting of GraphiQL's introspection query
+    let type_ref = (0..7).fold("name".to_string(), |inner, _| {
+        format!("kind name ofType {{ {} }}", inner)
+    });
+
+    let response = schema
+        .execute(format!(
+            "{{ __schema {{ types {{ name fields {{ name type {{ {} }} }} }} }} }}",
+            type_ref
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[tokio::test]
+async fn test_builder_applies_toggled_features_and_page_size_limits() {
+    let stub = Arc::new(StubUseCases);
+    let schema = AppSchemaBuilder::new(SchemaConfig {
+        auth_check: false,
+        page_size: PageSizeLimits {
+            default: 25,
+            max: 50,
+        },
+        ..SchemaConfig::default()
+    })
+    .with_project_list_usecase(stub.clone())
+    .with_auth_check_usecase(stub)
+    .build();
+
+    let defaulted = schema.execute("{ jiraProjects { pageSize } }").await;
+    let too_large = schema
+        .execute("{ jiraProjects(pageSize: 51) { pageSize } }")
+        .await;
+    let auth_check = schema.execute("{ checkJiraAuth }").await;
+
+    assert!(defaulted.errors.is_empty(), "{:?}", defaulted.errors);
+    assert_eq!(
+        defaulted.data.into_json().unwrap(),
+        json!({ "jiraProjects": { "pageSize": 25 } })
+    );
+    assert_eq!(too_large.errors.len(), 1);
+    assert_eq!(
+        too_large.errors[0].message,
+        "Page size must be at most 50, but was 51"
+    );
+    assert_eq!(auth_check.errors.len(), 1);
+    assert_eq!(auth_check.errors[0].message, "Jira API is not configured");
+}
+
+#[tokio::test]
+async fn test_builder_serves_auth_check_when_enabled() {
+    let schema = AppSchemaBuilder::new(SchemaConfig::default())
+        .with_auth_check_usecase(Arc::new(StubUseCases))
+        .build();
+
+    let response = schema.execute("{ checkJiraAuth }").await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "checkJiraAuth": true })
+    );
+}
+
+#[tokio::test]
+async fn test_builder_reports_unset_mutation_usecase_as_error() {
+    let schema = AppSchemaBuilder::new(SchemaConfig::default()).build();
+
+    let response = schema
+        .execute(r#"mutation { createJiraProject(input: { id: "1", key: "ONE", name: "One" }) { key } }"#)
+        .await;
+    let sync = schema
+        .execute(r#"mutation { syncProject(key: "ONE") { key } }"#)
+        .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert!(
+        response.errors[0]
+            .message
+            .contains("JiraProjectCreateUseCase"),
+        "{:?}",
+        response.errors
+    );
+    assert_eq!(sync.errors.len(), 1);
+    assert!(
+        sync.errors[0]
+            .message
+            .contains("JiraProjectSingleSyncUseCase"),
+        "{:?}",
+        sync.errors
+    );
+}
@@ -1,9 +1,12 @@
 use async_graphql::Enum;
 
-use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+use application::dto::command::jira::JiraProjectBatchMode;
+use application::dto::query::jira::{JiraIssueListSort, JiraSortDirection};
+use domain::error::JiraError;
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType, JiraStatusCategory};
 
 /// GraphQL enum for Jira issue type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
 #[graphql(name = "JiraIssueType")]
 pub enum JiraIssueTypeGql {
     Epic,
@@ -13,20 +16,36 @@ pub enum JiraIssueTypeGql {
     Bug,
 }
 
-impl From<JiraIssueType> for JiraIssueTypeGql {
-    fn from(value: JiraIssueType) -> Self {
+impl TryFrom<JiraIssueType> for JiraIssueTypeGql {
+    type Error = JiraError;
+
+    fn try_from(value: JiraIssueType) -> Result<Self, JiraError> {
+        match value {
+            JiraIssueType::Epic => Ok(Self::Epic),
+            JiraIssueType::Story => Ok(Self::Story),
+            JiraIssueType::Task => Ok(Self::Task),
+            JiraIssueType::Subtask => Ok(Self::Subtask),
+            JiraIssueType::Bug => Ok(Self::Bug),
+            // A domain variant the schema has no counterpart for yet
+            other => Err(JiraError::unknown_issue_type(other.as_str())),
+        }
+    }
+}
+
+impl From<JiraIssueTypeGql> for JiraIssueType {
+    fn from(value: JiraIssueTypeGql) -> Self {
         match value {
-            JiraIssueType::Epic => Self::Epic,
-            JiraIssueType::Story => Self::Story,
-            JiraIssueType::Task => Self::Task,
-            JiraIssueType::Subtask => Self::Subtask,
-            JiraIssueType::Bug => Self::Bug,
+            JiraIssueTypeGql::Epic => Self::Epic,
+            JiraIssueTypeGql::Story => Self::Story,
+            JiraIssueTypeGql::Task => Self::Task,
+            JiraIssueTypeGql::Subtask => Self::Subtask,
+            JiraIssueTypeGql::Bug => Self::Bug,
         }
     }
 }
 
 /// GraphQL enum for Jira issue priority.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
 #[graphql(name = "JiraIssuePriority")]
 pub enum JiraIssuePriorityGql {
     Highest,
@@ -36,14 +55,183 @@ pub enum JiraIssuePriorityGql {
     Lowest,
 }
 
-impl From<JiraIssuePriority> for JiraIssuePriorityGql {
-    fn from(value: JiraIssuePriority) -> Self {
+impl TryFrom<JiraIssuePriority> for JiraIssuePriorityGql {
+    type Error = JiraError;
+
+    fn try_from(value: JiraIssuePriority) -> Result<Self, JiraError> {
+        match value {
+            JiraIssuePriority::Highest => Ok(Self::Highest),
+            JiraIssuePriority::High => Ok(Self::High),
+            JiraIssuePriority::Medium => Ok(Self::Medium),
+            JiraIssuePriority::Low => Ok(Self::Low),
+            JiraIssuePriority::Lowest => Ok(Self::Lowest),
+            // Likewise a domain variant the schema has no counterpart for yet
+            other => Err(JiraError::unknown_priority(other.as_str())),
+        }
+    }
+}
+
+impl From<JiraIssuePriorityGql> for JiraIssuePriority {
+    fn from(value: JiraIssuePriorityGql) -> Self {
+        match value {
+            JiraIssuePriorityGql::Highest => Self::Highest,
+            JiraIssuePriorityGql::High => Self::High,
+            JiraIssuePriorityGql::Medium => Self::Medium,
+            JiraIssuePriorityGql::Low => Self::Low,
+            JiraIssuePriorityGql::Lowest => Self::Lowest,
+        }
+    }
+}
+
+/// GraphQL enum for Jira status category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
+#[graphql(name = "JiraStatusCategory")]
+pub enum JiraStatusCategoryGql {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+impl From<JiraStatusCategory> for JiraStatusCategoryGql {
+    fn from(value: JiraStatusCategory) -> Self {
+        match value {
+            JiraStatusCategory::ToDo => Self::ToDo,
+            JiraStatusCategory::InProgress => Self::InProgress,
+            JiraStatusCategory::Done => Self::Done,
+        }
+    }
+}
+
+impl From<JiraStatusCategoryGql> for JiraStatusCategory {
+    fn from(value: JiraStatusCategoryGql) -> Self {
         match value {
-            JiraIssuePriority::Highest => Self::Highest,
-            JiraIssuePriority::High => Self::High,
-            JiraIssuePriority::Medium => Self::Medium,
-            JiraIssuePriority::Low => Self::Low,
-            JiraIssuePriority::Lowest => Self::Lowest,
+            JiraStatusCategoryGql::ToDo => Self::ToDo,
+            JiraStatusCategoryGql::InProgress => Self::InProgress,
+            JiraStatusCategoryGql::Done => Self::Done,
         }
     }
 }
+
+/// GraphQL enum for the ordering of issue lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Enum)]
+#[graphql(name = "JiraIssueSort")]
+pub enum JiraIssueSortGql {
+    #[default]
+    UpdatedAt,
+    Watches,
+}
+
+impl From<JiraIssueSortGql> for JiraIssueListSort {
+    fn from(value: JiraIssueSortGql) -> Self {
+        match value {
+            JiraIssueSortGql::UpdatedAt => Self::UpdatedAt,
+            JiraIssueSortGql::Watches => Self::Watches,
+        }
+    }
+}
+
+impl From<JiraIssueListSort> for JiraIssueSortGql {
+    fn from(value: JiraIssueListSort) -> Self {
+        match value {
+            JiraIssueListSort::UpdatedAt => Self::UpdatedAt,
+            JiraIssueListSort::Watches => Self::Watches,
+        }
+    }
+}
+
+/// GraphQL enum for the direction of a list ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
+#[graphql(name = "SortDirection")]
+pub enum JiraSortDirectionGql {
+    Ascending,
+    Descending,
+}
+
+impl From<JiraSortDirection> for JiraSortDirectionGql {
+    fn from(value: JiraSortDirection) -> Self {
+        match value {
+            JiraSortDirection::Ascending => Self::Ascending,
+            JiraSortDirection::Descending => Self::Descending,
+        }
+    }
+}
+
+/// GraphQL enum for how a batch of project inputs handles invalid inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Enum)]
+#[graphql(name = "JiraProjectBatchMode")]
+pub enum JiraProjectBatchModeGql {
+    /// Creates the valid inputs and reports the invalid ones.
+    #[default]
+    PartialSuccess,
+    /// Creates nothing if any input is invalid.
+    AllOrNothing,
+}
+
+impl From<JiraProjectBatchModeGql> for JiraProjectBatchMode {
+    fn from(value: JiraProjectBatchModeGql) -> Self {
+        match value {
+            JiraProjectBatchModeGql::PartialSuccess => Self::PartialSuccess,
+            JiraProjectBatchModeGql::AllOrNothing => Self::AllOrNothing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use async_graphql::resolver_utils::EnumType;
+
+    use super::*;
+
+    #[test]
+    fn test_issue_type_round_trips_to_distinct_gql_variants() {
+        let mapped: HashSet<JiraIssueTypeGql> = JiraIssueType::ALL
+            .iter()
+            .map(|&value| {
+                let gql = JiraIssueTypeGql::try_from(value).unwrap();
+                assert_eq!(JiraIssueType::from(gql), value);
+                gql
+            })
+            .collect();
+
+        assert_eq!(mapped.len(), JiraIssueType::ALL.len());
+        assert_eq!(JiraIssueTypeGql::items().len(), JiraIssueType::ALL.len());
+    }
+
+    #[test]
+    fn test_issue_priority_round_trips_to_distinct_gql_variants() {
+        let mapped: HashSet<JiraIssuePriorityGql> = JiraIssuePriority::ALL
+            .iter()
+            .map(|&value| {
+                let gql = JiraIssuePriorityGql::try_from(value).unwrap();
+                assert_eq!(JiraIssuePriority::from(gql), value);
+                gql
+            })
+            .collect();
+
+        assert_eq!(mapped.len(), JiraIssuePriority::ALL.len());
+        assert_eq!(
+            JiraIssuePriorityGql::items().len(),
+            JiraIssuePriority::ALL.len()
+        );
+    }
+
+    #[test]
+    fn test_status_category_round_trips_to_distinct_gql_variants() {
+        let mapped: HashSet<JiraStatusCategoryGql> = JiraStatusCategory::ALL
+            .iter()
+            .map(|&value| {
+                let gql = JiraStatusCategoryGql::from(value);
+                assert_eq!(JiraStatusCategory::from(gql), value);
+                gql
+            })
+            .collect();
+
+        assert_eq!(mapped.len(), JiraStatusCategory::ALL.len());
+        assert_eq!(
+            JiraStatusCategoryGql::items().len(),
+            JiraStatusCategory::ALL.len()
+        );
+    }
+}
@@ -1,9 +1,14 @@
-use async_graphql::{ID, Object};
-use chrono::{DateTime, Utc};
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, ID, Object, Result};
+use chrono::{DateTime, NaiveDate, Utc};
 
 use application::dto::query::jira::JiraIssueQueryDto;
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
 
-use super::{JiraIssuePriorityGql, JiraIssueTypeGql};
+use super::{
+    JiraIssueLinkGql, JiraIssuePriorityGql, JiraIssueTypeGql, JiraStatusCategoryGql, JiraUserGql,
+};
+use crate::api::graphql::dataloader::JiraIssueLinkLoader;
 
 /// GraphQL representation of a Jira issue.
 #[derive(Clone)]
@@ -12,10 +17,20 @@ pub struct JiraIssueGql {
     pub key: String,
     pub summary: String,
     pub description: Option<String>,
-    pub issue_type: JiraIssueTypeGql,
-    pub priority: JiraIssuePriorityGql,
+    pub issue_type: JiraIssueType,
+    pub priority: Option<JiraIssuePriority>,
+    pub reporter: Option<JiraUserGql>,
+    pub watches: Option<i32>,
+    pub votes: Option<i32>,
+    pub status_category: Option<JiraStatusCategoryGql>,
+    pub original_estimate_seconds: Option<i64>,
+    pub remaining_estimate_seconds: Option<i64>,
+    pub time_spent_seconds: Option<i64>,
+    pub resolution: Option<String>,
+    pub due_date: Option<NaiveDate>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub synced_at: Option<DateTime<Utc>>,
 }
 
 #[Object(name = "JiraIssue")]
@@ -36,13 +51,76 @@ impl JiraIssueGql {
         self.description.as_deref()
     }
 
+    /// Fails for a type the schema cannot represent yet.
     #[graphql(name = "issueType")]
-    async fn issue_type(&self) -> JiraIssueTypeGql {
-        self.issue_type
+    async fn issue_type(&self) -> Result<JiraIssueTypeGql> {
+        Ok(self.issue_type.try_into()?)
     }
 
-    async fn priority(&self) -> JiraIssuePriorityGql {
-        self.priority
+    /// Priority of the issue; null when it has none. Fails for a priority the schema
+    /// cannot represent yet.
+    async fn priority(&self) -> Result<Option<JiraIssuePriorityGql>> {
+        Ok(self.priority.map(TryInto::try_into).transpose()?)
+    }
+
+    async fn reporter(&self) -> Option<&JiraUserGql> {
+        self.reporter.as_ref()
+    }
+
+    /// Number of users watching the issue.
+    async fn watches(&self) -> Option<i32> {
+        self.watches
+    }
+
+    /// Number of votes on the issue.
+    async fn votes(&self) -> Option<i32> {
+        self.votes
+    }
+
+    /// Category of the issue's current status.
+    #[graphql(name = "statusCategory")]
+    async fn status_category(&self) -> Option<JiraStatusCategoryGql> {
+        self.status_category
+    }
+
+    /// Originally estimated time in seconds, if time tracking is used.
+    #[graphql(name = "originalEstimateSeconds")]
+    async fn original_estimate_seconds(&self) -> Option<i64> {
+        self.original_estimate_seconds
+    }
+
+    /// Estimated time left in seconds, if time tracking is used.
+    #[graphql(name = "remainingEstimateSeconds")]
+    async fn remaining_estimate_seconds(&self) -> Option<i64> {
+        self.remaining_estimate_seconds
+    }
+
+    /// Time logged in seconds, if time tracking is used.
+    #[graphql(name = "timeSpentSeconds")]
+    async fn time_spent_seconds(&self) -> Option<i64> {
+        self.time_spent_seconds
+    }
+
+    /// Resolution name such as "Done"; null while the issue is unresolved.
+    async fn resolution(&self) -> Option<&str> {
+        self.resolution.as_deref()
+    }
+
+    #[graphql(name = "dueDate")]
+    async fn due_date(&self) -> Option<NaiveDate> {
+        self.due_date
+    }
+
+    /// Links to other issues in either direction, loaded in one batch per request.
+    async fn links(&self, ctx: &Context<'_>) -> Result<Vec<JiraIssueLinkGql>> {
+        let loader = ctx.data::<DataLoader<JiraIssueLinkLoader>>()?;
+
+        let links = loader
+            .load_one(self.key.clone())
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+
+        Ok(links.unwrap_or_default())
     }
 
     #[graphql(name = "createdAt")]
@@ -54,6 +132,12 @@ impl JiraIssueGql {
     async fn updated_at(&self) -> DateTime<Utc> {
         self.updated_at
     }
+
+    /// When a sync last wrote the issue, regardless of whether it changed in Jira.
+    #[graphql(name = "syncedAt")]
+    async fn synced_at(&self) -> Option<DateTime<Utc>> {
+        self.synced_at
+    }
 }
 
 impl From<JiraIssueQueryDto> for JiraIssueGql {
@@ -63,10 +147,20 @@ impl From<JiraIssueQueryDto> for JiraIssueGql {
             key: dto.key,
             summary: dto.summary,
             description: dto.description,
-            issue_type: dto.issue_type.into(),
-            priority: dto.priority.into(),
+            issue_type: dto.issue_type,
+            priority: dto.priority,
+            reporter: dto.reporter.map(JiraUserGql::from),
+            watches: dto.watches,
+            votes: dto.votes,
+            status_category: dto.status_category.map(JiraStatusCategoryGql::from),
+            original_estimate_seconds: dto.original_estimate_seconds,
+            remaining_estimate_seconds: dto.remaining_estimate_seconds,
+            time_spent_seconds: dto.time_spent_seconds,
+            resolution: dto.resolution,
+            due_date: dto.due_date,
             created_at: dto.created_at,
             updated_at: dto.updated_at,
+            synced_at: dto.synced_at,
         }
     }
 }
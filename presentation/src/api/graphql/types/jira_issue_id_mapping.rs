@@ -0,0 +1,30 @@
+use async_graphql::{ID, Object};
+
+use domain::value_object::jira::{JiraIssueId, JiraIssueKey};
+
+/// GraphQL representation of a resolved Jira issue key.
+#[derive(Clone)]
+pub struct JiraIssueIdMappingGql {
+    pub key: String,
+    pub id: i64,
+}
+
+#[Object(name = "JiraIssueIdMapping")]
+impl JiraIssueIdMappingGql {
+    async fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn id(&self) -> ID {
+        ID(self.id.to_string())
+    }
+}
+
+impl From<(JiraIssueKey, JiraIssueId)> for JiraIssueIdMappingGql {
+    fn from((key, id): (JiraIssueKey, JiraIssueId)) -> Self {
+        Self {
+            key: key.value().to_string(),
+            id: id.value(),
+        }
+    }
+}
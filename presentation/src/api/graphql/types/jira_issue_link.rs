@@ -0,0 +1,41 @@
+use async_graphql::Object;
+
+use application::dto::query::jira::JiraIssueLinkQueryDto;
+
+/// GraphQL representation of a link between two Jira issues.
+/// The link reads from `sourceKey` to `targetKey`, e.g. "PROJ-1 blocks PROJ-2".
+#[derive(Clone)]
+pub struct JiraIssueLinkGql {
+    pub source_key: String,
+    pub target_key: String,
+    pub link_type: String,
+}
+
+#[Object(name = "JiraIssueLink")]
+impl JiraIssueLinkGql {
+    #[graphql(name = "sourceKey")]
+    async fn source_key(&self) -> &str {
+        &self.source_key
+    }
+
+    #[graphql(name = "targetKey")]
+    async fn target_key(&self) -> &str {
+        &self.target_key
+    }
+
+    /// Name of the Jira link type, e.g. "Blocks" or "Relates".
+    #[graphql(name = "linkType")]
+    async fn link_type(&self) -> &str {
+        &self.link_type
+    }
+}
+
+impl From<JiraIssueLinkQueryDto> for JiraIssueLinkGql {
+    fn from(dto: JiraIssueLinkQueryDto) -> Self {
+        Self {
+            source_key: dto.source_key,
+            target_key: dto.target_key,
+            link_type: dto.link_type,
+        }
+    }
+}
@@ -1,14 +1,184 @@
-use async_graphql::Object;
+use std::sync::Arc;
 
-use application::dto::query::jira::JiraIssueQueryDto;
-use domain::value_object::Page;
+use async_graphql::{Context, Object, Result};
+use chrono::{DateTime, Utc};
 
-use super::JiraIssueGql;
+use application::dto::query::jira::{
+    JiraIssueListFilter, JiraIssueListQueryMeta, JiraIssueListQueryResult, JiraIssueListSummary,
+    JiraIssuePriorityCount, JiraIssueTypeCount,
+};
+use application::usecase::query::jira::JiraIssueListQueryUseCase;
+use domain::value_object::jira::{JiraIssuePriority, JiraIssueType};
+
+use super::{
+    JiraIssueGql, JiraIssuePriorityGql, JiraIssueSortGql, JiraIssueTypeGql, JiraSortDirectionGql,
+};
+use crate::api::graphql::error::from_application_error;
+
+/// Filters that were applied to produce an issue list, echoed back to the client.
+pub struct JiraIssueListFiltersGql {
+    pub reported_by: Option<String>,
+    pub overdue: bool,
+    pub synced_before: Option<DateTime<Utc>>,
+}
+
+#[Object(name = "JiraIssueListFilters")]
+impl JiraIssueListFiltersGql {
+    #[graphql(name = "reportedBy")]
+    async fn reported_by(&self) -> Option<&str> {
+        self.reported_by.as_deref()
+    }
+
+    async fn overdue(&self) -> bool {
+        self.overdue
+    }
+
+    #[graphql(name = "syncedBefore")]
+    async fn synced_before(&self) -> Option<DateTime<Utc>> {
+        self.synced_before
+    }
+}
+
+impl From<JiraIssueListFilter> for JiraIssueListFiltersGql {
+    fn from(filter: JiraIssueListFilter) -> Self {
+        Self {
+            reported_by: filter.reported_by,
+            overdue: filter.overdue,
+            synced_before: filter.synced_before,
+        }
+    }
+}
+
+/// The effective ordering and filters of an issue list, after defaults.
+pub struct JiraIssueListMetaGql {
+    pub sort_by: JiraIssueSortGql,
+    pub direction: JiraSortDirectionGql,
+    pub filters: JiraIssueListFiltersGql,
+}
+
+#[Object(name = "JiraIssueListMeta")]
+impl JiraIssueListMetaGql {
+    #[graphql(name = "sortBy")]
+    async fn sort_by(&self) -> JiraIssueSortGql {
+        self.sort_by
+    }
+
+    async fn direction(&self) -> JiraSortDirectionGql {
+        self.direction
+    }
+
+    async fn filters(&self) -> &JiraIssueListFiltersGql {
+        &self.filters
+    }
+}
+
+impl From<JiraIssueListQueryMeta> for JiraIssueListMetaGql {
+    fn from(meta: JiraIssueListQueryMeta) -> Self {
+        Self {
+            sort_by: meta.sort_by.into(),
+            direction: meta.direction.into(),
+            filters: meta.filters.into(),
+        }
+    }
+}
+
+/// Number of listed issues of one type.
+pub struct JiraIssueTypeCountGql {
+    pub issue_type: JiraIssueType,
+    pub count: i32,
+}
+
+#[Object(name = "JiraIssueTypeCount")]
+impl JiraIssueTypeCountGql {
+    /// Fails for a type the schema cannot represent yet.
+    #[graphql(name = "type")]
+    async fn issue_type(&self) -> Result<JiraIssueTypeGql> {
+        Ok(self.issue_type.try_into()?)
+    }
+
+    async fn count(&self) -> i32 {
+        self.count
+    }
+}
+
+impl From<JiraIssueTypeCount> for JiraIssueTypeCountGql {
+    fn from(count: JiraIssueTypeCount) -> Self {
+        Self {
+            issue_type: count.issue_type,
+            count: count.count,
+        }
+    }
+}
+
+/// Number of listed issues of one priority.
+pub struct JiraIssuePriorityCountGql {
+    pub priority: Option<JiraIssuePriority>,
+    pub count: i32,
+}
+
+#[Object(name = "JiraIssuePriorityCount")]
+impl JiraIssuePriorityCountGql {
+    /// Null counts the issues without a priority. Fails for a priority the schema
+    /// cannot represent yet.
+    async fn priority(&self) -> Result<Option<JiraIssuePriorityGql>> {
+        Ok(self.priority.map(TryInto::try_into).transpose()?)
+    }
+
+    async fn count(&self) -> i32 {
+        self.count
+    }
+}
+
+impl From<JiraIssuePriorityCount> for JiraIssuePriorityCountGql {
+    fn from(count: JiraIssuePriorityCount) -> Self {
+        Self {
+            priority: count.priority,
+            count: count.count,
+        }
+    }
+}
+
+/// Issue counts of a filtered list across all of its pages, most frequent first.
+pub struct JiraIssueListSummaryGql {
+    pub by_type: Vec<JiraIssueTypeCountGql>,
+    pub by_priority: Vec<JiraIssuePriorityCountGql>,
+}
+
+#[Object(name = "JiraIssueListSummary")]
+impl JiraIssueListSummaryGql {
+    #[graphql(name = "byType")]
+    async fn by_type(&self) -> &[JiraIssueTypeCountGql] {
+        &self.by_type
+    }
+
+    #[graphql(name = "byPriority")]
+    async fn by_priority(&self) -> &[JiraIssuePriorityCountGql] {
+        &self.by_priority
+    }
+}
+
+impl From<JiraIssueListSummary> for JiraIssueListSummaryGql {
+    fn from(summary: JiraIssueListSummary) -> Self {
+        Self {
+            by_type: summary.by_type.into_iter().map(Into::into).collect(),
+            by_priority: summary.by_priority.into_iter().map(Into::into).collect(),
+        }
+    }
+}
 
 /// GraphQL representation of a paginated list of Jira issues.
 pub struct JiraIssueListGql {
     pub items: Vec<JiraIssueGql>,
     pub total_count: i32,
+    pub page_number: i32,
+    pub page_size: i32,
+    pub total_pages: i32,
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+    pub page_out_of_range: bool,
+    pub meta: JiraIssueListMetaGql,
+    /// The filter the list was produced with, reused to summarize the whole list.
+    pub filter: JiraIssueListFilter,
 }
 
 #[Object(name = "JiraIssueList")]
@@ -21,13 +191,71 @@ impl JiraIssueListGql {
     async fn total_count(&self) -> i32 {
         self.total_count
     }
+
+    #[graphql(name = "pageNumber")]
+    async fn page_number(&self) -> i32 {
+        self.page_number
+    }
+
+    #[graphql(name = "pageSize")]
+    async fn page_size(&self) -> i32 {
+        self.page_size
+    }
+
+    #[graphql(name = "totalPages")]
+    async fn total_pages(&self) -> i32 {
+        self.total_pages
+    }
+
+    #[graphql(name = "hasNextPage")]
+    async fn has_next_page(&self) -> bool {
+        self.has_next_page
+    }
+
+    #[graphql(name = "hasPreviousPage")]
+    async fn has_previous_page(&self) -> bool {
+        self.has_previous_page
+    }
+
+    /// True when the requested page is past the last page, as opposed to an empty result.
+    #[graphql(name = "pageOutOfRange")]
+    async fn page_out_of_range(&self) -> bool {
+        self.page_out_of_range
+    }
+
+    /// The sort and filters the list was produced with, including defaults.
+    async fn meta(&self) -> &JiraIssueListMetaGql {
+        &self.meta
+    }
+
+    /// Counts of the issues on every page of the list by type and by priority. Only
+    /// queried when selected.
+    async fn summary(&self, ctx: &Context<'_>) -> Result<JiraIssueListSummaryGql> {
+        let usecase = ctx.data::<Arc<dyn JiraIssueListQueryUseCase>>()?;
+
+        let summary = usecase
+            .summarize(self.filter.clone())
+            .await
+            .map_err(|e| from_application_error(&e))?;
+
+        Ok(JiraIssueListSummaryGql::from(summary))
+    }
 }
 
-impl From<Page<JiraIssueQueryDto>> for JiraIssueListGql {
-    fn from(page: Page<JiraIssueQueryDto>) -> Self {
+impl From<JiraIssueListQueryResult> for JiraIssueListGql {
+    fn from(result: JiraIssueListQueryResult) -> Self {
+        let page = result.page;
         Self {
-            items: page.items.into_iter().map(JiraIssueGql::from).collect(),
+            total_pages: page.total_pages(),
+            has_next_page: page.has_next_page(),
+            has_previous_page: page.has_previous_page(),
+            page_out_of_range: page.is_out_of_range(),
+            page_number: page.page_number,
+            page_size: page.page_size,
             total_count: page.total_count,
+            items: page.into_iter().map(JiraIssueGql::from).collect(),
+            filter: result.meta.filters.clone(),
+            meta: result.meta.into(),
         }
     }
 }
@@ -1,4 +1,5 @@
 use async_graphql::{ID, Object};
+use chrono::{DateTime, Utc};
 
 use application::dto::query::jira::JiraProjectQueryDto;
 use domain::entity::jira::JiraProject;
@@ -9,6 +10,7 @@ pub struct JiraProjectGql {
     pub id: i64,
     pub key: String,
     pub name: String,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 #[Object(name = "JiraProject")]
@@ -24,6 +26,12 @@ impl JiraProjectGql {
     async fn name(&self) -> &str {
         &self.name
     }
+
+    /// When the project was last changed; pass it as `expectedUpdatedAt` when updating
+    /// the project to reject the update if someone else changed it in between.
+    async fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
 }
 
 impl From<JiraProject> for JiraProjectGql {
@@ -32,6 +40,7 @@ impl From<JiraProject> for JiraProjectGql {
             id: project.id.value(),
             key: project.key.value().to_string(),
             name: project.name.value().to_string(),
+            updated_at: project.updated_at,
         }
     }
 }
@@ -42,6 +51,7 @@ impl From<JiraProjectQueryDto> for JiraProjectGql {
             id: dto.id,
             key: dto.key,
             name: dto.name,
+            updated_at: dto.updated_at,
         }
     }
 }
@@ -0,0 +1,81 @@
+use async_graphql::Object;
+
+use application::dto::command::jira::JiraProjectBatchCreateResult;
+use application::error::command::jira::JiraProjectInputRejection;
+
+use super::JiraProjectGql;
+
+/// GraphQL representation of a batch input that failed validation.
+#[derive(Clone)]
+pub struct JiraProjectInputErrorGql {
+    pub index: i32,
+    pub fields: Vec<String>,
+    pub message: String,
+}
+
+#[Object(name = "JiraProjectInputError")]
+impl JiraProjectInputErrorGql {
+    /// Position of the input in the batch, starting at 0.
+    async fn index(&self) -> i32 {
+        self.index
+    }
+
+    /// Names of the invalid fields.
+    async fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    async fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<JiraProjectInputRejection> for JiraProjectInputErrorGql {
+    fn from(rejection: JiraProjectInputRejection) -> Self {
+        Self {
+            index: rejection.index as i32,
+            fields: rejection
+                .errors
+                .iter()
+                .map(|error| error.field.to_string())
+                .collect(),
+            message: rejection.to_string(),
+        }
+    }
+}
+
+/// GraphQL representation of the outcome of a batch project creation.
+pub struct CreateJiraProjectsPayloadGql {
+    pub projects: Vec<JiraProjectGql>,
+    pub errors: Vec<JiraProjectInputErrorGql>,
+}
+
+#[Object(name = "CreateJiraProjectsPayload")]
+impl CreateJiraProjectsPayloadGql {
+    /// Projects created, in input order.
+    async fn projects(&self) -> &[JiraProjectGql] {
+        &self.projects
+    }
+
+    /// Inputs that failed validation and were skipped.
+    async fn errors(&self) -> &[JiraProjectInputErrorGql] {
+        &self.errors
+    }
+}
+
+impl From<JiraProjectBatchCreateResult> for CreateJiraProjectsPayloadGql {
+    fn from(result: JiraProjectBatchCreateResult) -> Self {
+        Self {
+            projects: result
+                .created
+                .into_iter()
+                .map(JiraProjectGql::from)
+                .collect(),
+            errors: result
+                .rejected
+                .into_iter()
+                .map(JiraProjectInputErrorGql::from)
+                .collect(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use async_graphql::{ID, InputObject};
+use chrono::{DateTime, Utc};
 
 use application::dto::command::jira::{CreateJiraProjectDto, UpdateJiraProjectDto};
 
@@ -24,16 +25,19 @@ impl From<CreateJiraProjectInputGql> for CreateJiraProjectDto {
     }
 }
 
-/// Input for updating a Jira project.
+/// Input for updating a Jira project. Omitted fields keep their current value.
 #[derive(InputObject)]
 #[graphql(name = "UpdateJiraProjectInput")]
 pub struct UpdateJiraProjectInputGql {
     /// The project ID.
     pub id: ID,
-    /// The project key (e.g., "PROJ").
-    pub key: String,
-    /// The project name.
-    pub name: String,
+    /// The new project key (e.g., "PROJ"), if it changes.
+    pub key: Option<String>,
+    /// The new project name, if it changes.
+    pub name: Option<String>,
+    /// The `updatedAt` last read for the project. When given, the update fails with
+    /// `JIRA_PROJECT_CONFLICT` if the project has changed since.
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 impl From<UpdateJiraProjectInputGql> for UpdateJiraProjectDto {
@@ -42,6 +46,7 @@ impl From<UpdateJiraProjectInputGql> for UpdateJiraProjectDto {
             id: input.id.to_string(),
             key: input.key,
             name: input.name,
+            expected_updated_at: input.expected_updated_at,
         }
     }
 }
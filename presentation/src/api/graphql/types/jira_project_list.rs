@@ -5,10 +5,41 @@ use domain::value_object::Page;
 
 use super::JiraProjectGql;
 
+/// Filters that were applied to produce a project list, echoed back to the client.
+#[derive(Clone, Default)]
+pub struct JiraProjectListFiltersGql {
+    pub query: Option<String>,
+}
+
+#[Object(name = "JiraProjectListFilters")]
+impl JiraProjectListFiltersGql {
+    /// The search text matched against project name or key, if any.
+    async fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+}
+
 /// GraphQL representation of a paginated list of Jira projects.
 pub struct JiraProjectListGql {
     pub total_count: i32,
     pub items: Vec<JiraProjectGql>,
+    pub page_number: i32,
+    pub page_size: i32,
+    pub total_pages: i32,
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+    pub page_out_of_range: bool,
+    pub applied_filters: JiraProjectListFiltersGql,
+}
+
+impl JiraProjectListGql {
+    /// Attaches the filters that produced this list.
+    pub fn with_applied_filters(self, applied_filters: JiraProjectListFiltersGql) -> Self {
+        Self {
+            applied_filters,
+            ..self
+        }
+    }
 }
 
 #[Object(name = "JiraProjectList")]
@@ -21,13 +52,184 @@ impl JiraProjectListGql {
     async fn items(&self) -> &[JiraProjectGql] {
         &self.items
     }
+
+    #[graphql(name = "pageNumber")]
+    async fn page_number(&self) -> i32 {
+        self.page_number
+    }
+
+    #[graphql(name = "pageSize")]
+    async fn page_size(&self) -> i32 {
+        self.page_size
+    }
+
+    #[graphql(name = "totalPages")]
+    async fn total_pages(&self) -> i32 {
+        self.total_pages
+    }
+
+    #[graphql(name = "hasNextPage")]
+    async fn has_next_page(&self) -> bool {
+        self.has_next_page
+    }
+
+    #[graphql(name = "hasPreviousPage")]
+    async fn has_previous_page(&self) -> bool {
+        self.has_previous_page
+    }
+
+    /// True when the requested page is past the last page, as opposed to an empty result.
+    #[graphql(name = "pageOutOfRange")]
+    async fn page_out_of_range(&self) -> bool {
+        self.page_out_of_range
+    }
+
+    #[graphql(name = "appliedFilters")]
+    async fn applied_filters(&self) -> &JiraProjectListFiltersGql {
+        &self.applied_filters
+    }
 }
 
 impl From<Page<JiraProjectQueryDto>> for JiraProjectListGql {
     fn from(page: Page<JiraProjectQueryDto>) -> Self {
         Self {
+            total_pages: page.total_pages(),
+            has_next_page: page.has_next_page(),
+            has_previous_page: page.has_previous_page(),
+            page_out_of_range: page.is_out_of_range(),
+            page_number: page.page_number,
+            page_size: page.page_size,
             total_count: page.total_count,
-            items: page.items.into_iter().map(JiraProjectGql::from).collect(),
+            items: page.into_iter().map(JiraProjectGql::from).collect(),
+            applied_filters: JiraProjectListFiltersGql::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+    use domain::value_object::{PageNumber, PageSize};
+    use serde_json::json;
+
+    struct TestQuery {
+        page: Page<JiraProjectQueryDto>,
+    }
+
+    #[Object]
+    impl TestQuery {
+        async fn projects(&self) -> JiraProjectListGql {
+            JiraProjectListGql::from(self.page.clone())
+        }
+
+        async fn search(&self) -> JiraProjectListGql {
+            JiraProjectListGql::from(self.page.clone()).with_applied_filters(
+                JiraProjectListFiltersGql {
+                    query: Some("core".to_string()),
+                },
+            )
+        }
+    }
+
+    fn create_page(
+        total_count: i32,
+        ids: std::ops::RangeInclusive<i64>,
+        page_number: i32,
+        page_size: i32,
+    ) -> Page<JiraProjectQueryDto> {
+        let items = ids
+            .map(|id| {
+                JiraProjectQueryDto::new(id, format!("PROJ{}", id), format!("Project {}", id))
+            })
+            .collect();
+        Page::new(total_count, items).with_pagination(
+            PageNumber::of(page_number).unwrap(),
+            PageSize::of(page_size).unwrap(),
+        )
+    }
+
+    async fn query_navigation(page: Page<JiraProjectQueryDto>) -> serde_json::Value {
+        let schema = Schema::new(TestQuery { page }, EmptyMutation, EmptySubscription);
+        let response = schema
+            .execute("{ projects { pageNumber pageSize totalPages hasNextPage hasPreviousPage pageOutOfRange } }")
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()["projects"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_middle_page_has_next_and_previous() {
+        let result = query_navigation(create_page(25, 11..=20, 2, 10)).await;
+
+        assert_eq!(
+            result,
+            json!({
+                "pageNumber": 2,
+                "pageSize": 10,
+                "totalPages": 3,
+                "hasNextPage": true,
+                "hasPreviousPage": true,
+                "pageOutOfRange": false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_page_has_no_next() {
+        let result = query_navigation(create_page(25, 21..=25, 3, 10)).await;
+
+        assert_eq!(result["totalPages"], 3);
+        assert_eq!(result["hasNextPage"], false);
+        assert_eq!(result["hasPreviousPage"], true);
+        assert_eq!(result["pageOutOfRange"], false);
+    }
+
+    #[tokio::test]
+    async fn test_page_beyond_end_is_out_of_range() {
+        let page = Page::new(25, vec![])
+            .with_pagination(PageNumber::of(999).unwrap(), PageSize::of(10).unwrap());
+
+        let result = query_navigation(page).await;
+
+        assert_eq!(result["totalPages"], 3);
+        assert_eq!(result["hasNextPage"], false);
+        assert_eq!(result["pageOutOfRange"], true);
+    }
+
+    #[tokio::test]
+    async fn test_single_page_has_no_navigation() {
+        let result = query_navigation(create_page(5, 1..=5, 1, 10)).await;
+
+        assert_eq!(result["totalPages"], 1);
+        assert_eq!(result["hasNextPage"], false);
+        assert_eq!(result["hasPreviousPage"], false);
+    }
+
+    #[tokio::test]
+    async fn test_applied_filters_are_echoed() {
+        let schema = Schema::new(
+            TestQuery {
+                page: Page::empty(),
+            },
+            EmptyMutation,
+            EmptySubscription,
+        );
+
+        let response = schema
+            .execute(
+                "{ projects { totalCount appliedFilters { query } } \
+                   search { totalCount appliedFilters { query } } }",
+            )
+            .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            json!({
+                "projects": { "totalCount": 0, "appliedFilters": { "query": null } },
+                "search": { "totalCount": 0, "appliedFilters": { "query": "core" } },
+            })
+        );
+    }
+}
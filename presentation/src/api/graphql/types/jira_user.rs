@@ -0,0 +1,32 @@
+use async_graphql::Object;
+
+use domain::value_object::jira::JiraUser;
+
+/// GraphQL representation of a Jira user.
+#[derive(Clone)]
+pub struct JiraUserGql {
+    pub account_id: String,
+    pub display_name: String,
+}
+
+#[Object(name = "JiraUser")]
+impl JiraUserGql {
+    #[graphql(name = "accountId")]
+    async fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    #[graphql(name = "displayName")]
+    async fn display_name(&self) -> &str {
+        &self.display_name
+    }
+}
+
+impl From<JiraUser> for JiraUserGql {
+    fn from(user: JiraUser) -> Self {
+        Self {
+            account_id: user.account_id().to_string(),
+            display_name: user.display_name().to_string(),
+        }
+    }
+}
@@ -1,13 +1,27 @@
 mod jira_enums;
 mod jira_issue;
+mod jira_issue_id_mapping;
+mod jira_issue_link;
 mod jira_issue_list;
 mod jira_project;
+mod jira_project_batch;
 mod jira_project_input;
 mod jira_project_list;
+mod jira_user;
 
-pub use jira_enums::{JiraIssuePriorityGql, JiraIssueTypeGql};
+pub use jira_enums::{
+    JiraIssuePriorityGql, JiraIssueSortGql, JiraIssueTypeGql, JiraProjectBatchModeGql,
+    JiraSortDirectionGql, JiraStatusCategoryGql,
+};
 pub use jira_issue::JiraIssueGql;
-pub use jira_issue_list::JiraIssueListGql;
+pub use jira_issue_id_mapping::JiraIssueIdMappingGql;
+pub use jira_issue_link::JiraIssueLinkGql;
+pub use jira_issue_list::{
+    JiraIssueListFiltersGql, JiraIssueListGql, JiraIssueListMetaGql, JiraIssueListSummaryGql,
+    JiraIssuePriorityCountGql, JiraIssueTypeCountGql,
+};
 pub use jira_project::JiraProjectGql;
+pub use jira_project_batch::{CreateJiraProjectsPayloadGql, JiraProjectInputErrorGql};
 pub use jira_project_input::{CreateJiraProjectInputGql, UpdateJiraProjectInputGql};
-pub use jira_project_list::JiraProjectListGql;
+pub use jira_project_list::{JiraProjectListFiltersGql, JiraProjectListGql};
+pub use jira_user::JiraUserGql;
@@ -1 +1,4 @@
 pub mod graphql;
+pub mod rest;
+pub mod server_limits;
+pub mod sync_scheduler;
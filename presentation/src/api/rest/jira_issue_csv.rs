@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt};
+use tracing::error;
+
+use application::dto::query::jira::JiraIssueQueryDto;
+use application::error::ApplicationError;
+use application::error::query::jira::JiraIssueExportQueryError;
+use application::usecase::query::jira::JiraIssueExportQueryUseCase;
+
+/// Number of issues read from the database per keyset batch.
+const EXPORT_BATCH_SIZE: i32 = 500;
+
+const CSV_HEADER: &str = "id,key,summary,description,issue_type,priority,status_category,reporter_account_id,reporter_display_name,watches,votes,created_at,updated_at,resolution,due_date,original_estimate_seconds,remaining_estimate_seconds,time_spent_seconds,synced_at\n";
+
+/// Builds the router serving `GET /api/issues.csv`.
+pub fn jira_issue_csv_router(usecase: Arc<dyn JiraIssueExportQueryUseCase>) -> Router {
+    Router::new()
+        .route("/api/issues.csv", get(export_issues_csv))
+        .with_state(usecase)
+}
+
+/// Streams every issue as CSV, one chunk per export batch.
+///
+/// Only one batch is held in memory at a time. The status and headers are sent
+/// before the first batch is read, so a failure midway aborts the response body
+/// instead of returning an error status.
+async fn export_issues_csv(
+    State(usecase): State<Arc<dyn JiraIssueExportQueryUseCase>>,
+) -> Response {
+    // The export stream borrows the use case, so a task owning it feeds the body.
+    // The bounded channel keeps the task from reading ahead of a slow client.
+    let (mut sender, receiver) = mpsc::channel::<Result<String, JiraIssueExportQueryError>>(1);
+    tokio::spawn(async move {
+        if sender.send(Ok(CSV_HEADER.to_string())).await.is_err() {
+            return;
+        }
+        let mut batches = usecase.execute(EXPORT_BATCH_SIZE);
+        while let Some(batch) = batches.next().await {
+            let chunk = batch.map(|issues| issues.iter().map(csv_row).collect::<String>());
+            if let Err(e) = &chunk {
+                error!("CSV export failed: {} ({})", e, e.code());
+            }
+            if sender.send(chunk).await.is_err() {
+                // The client disconnected
+                return;
+            }
+        }
+    });
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"issues.csv\"",
+            ),
+        ],
+        Body::from_stream(receiver),
+    )
+        .into_response()
+}
+
+fn csv_row(issue: &JiraIssueQueryDto) -> String {
+    let fields = [
+        issue.id.to_string(),
+        escape(&issue.key),
+        escape(&issue.summary),
+        escape(issue.description.as_deref().unwrap_or_default()),
+        issue.issue_type.as_str().to_string(),
+        issue
+            .priority
+            .map(|priority| priority.as_str().to_string())
+            .unwrap_or_default(),
+        issue
+            .status_category
+            .map(|category| category.as_str().to_string())
+            .unwrap_or_default(),
+        escape(
+            issue
+                .reporter
+                .as_ref()
+                .map(|reporter| reporter.account_id())
+                .unwrap_or_default(),
+        ),
+        escape(
+            issue
+                .reporter
+                .as_ref()
+                .map(|reporter| reporter.display_name())
+                .unwrap_or_default(),
+        ),
+        issue.watches.map(|n| n.to_string()).unwrap_or_default(),
+        issue.votes.map(|n| n.to_string()).unwrap_or_default(),
+        issue.created_at.to_rfc3339(),
+        issue.updated_at.to_rfc3339(),
+        escape(issue.resolution.as_deref().unwrap_or_default()),
+        issue
+            .due_date
+            .map(|date| date.to_string())
+            .unwrap_or_default(),
+        issue
+            .original_estimate_seconds
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+        issue
+            .remaining_estimate_seconds
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+        issue
+            .time_spent_seconds
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+        issue
+            .synced_at
+            .map(|at| at.to_rfc3339())
+            .unwrap_or_default(),
+    ];
+    let mut row = fields.join(",");
+    row.push('\n');
+    row
+}
+
+/// Quotes a field per RFC 4180 when it contains a delimiter, quote or line break.
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{Request, StatusCode};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use domain::error::JiraError;
+    use domain::value_object::jira::{JiraIssuePriority, JiraUser};
+    use futures::stream::{self, BoxStream};
+    use tower::ServiceExt;
+
+    /// Streams the given batches, optionally followed by an error.
+    struct StubExportUseCase {
+        batches: Vec<Vec<JiraIssueQueryDto>>,
+        fail_after: bool,
+    }
+
+    impl JiraIssueExportQueryUseCase for StubExportUseCase {
+        fn execute(
+            &self,
+            _batch_size: i32,
+        ) -> BoxStream<'_, Result<Vec<JiraIssueQueryDto>, JiraIssueExportQueryError>> {
+            let mut items: Vec<_> = self.batches.iter().cloned().map(Ok).collect();
+            if self.fail_after {
+                items.push(Err(JiraIssueExportQueryError::IssueFetchFailed(
+                    JiraError::database_error("Connection lost"),
+                )));
+            }
+            Box::pin(stream::iter(items))
+        }
+    }
+
+    fn issue(id: i64, summary: &str) -> JiraIssueQueryDto {
+        let at = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        JiraIssueQueryDto::builder()
+            .id(id)
+            .key(format!("PROJ-{}", id))
+            .summary(summary)
+            .priority(JiraIssuePriority::Medium)
+            .reporter(Some(JiraUser::new("acc-1", "Jane")))
+            .created_at(at)
+            .updated_at(at)
+            .build()
+    }
+
+    async fn get_csv(usecase: StubExportUseCase) -> Response {
+        jira_issue_csv_router(Arc::new(usecase))
+            .oneshot(Request::get("/api/issues.csv").body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_streams_header_and_rows() {
+        let response = get_csv(StubExportUseCase {
+            batches: vec![
+                vec![
+                    issue(1, "First, with \"quotes\"")
+                        .with_resolution(Some("Won't Do".to_string()))
+                        .with_due_date(NaiveDate::from_ymd_opt(2024, 2, 1))
+                        .with_time_tracking(Some(7200), Some(3600), Some(1800))
+                        .with_synced_at(Some(Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap())),
+                ],
+                vec![issue(2, "Second")],
+            ],
+            fail_after: false,
+        })
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"issues.csv\""
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lines: Vec<&str> = std::str::from_utf8(&body).unwrap().lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER.trim_end());
+        assert!(lines[0].ends_with(
+            "updated_at,resolution,due_date,original_estimate_seconds,remaining_estimate_seconds,time_spent_seconds,synced_at"
+        ));
+        assert_eq!(
+            lines[1],
+            "1,PROJ-1,\"First, with \"\"quotes\"\"\",,Task,Medium,,acc-1,Jane,,,2024-01-02T03:04:05+00:00,2024-01-02T03:04:05+00:00,Won't Do,2024-02-01,7200,3600,1800,2024-01-03T00:00:00+00:00"
+        );
+        assert!(lines[2].starts_with("2,PROJ-2,Second,"));
+        assert!(lines[2].ends_with("2024-01-02T03:04:05+00:00,,,,,,"));
+        assert_eq!(lines[2].split(',').count(), lines[0].split(',').count());
+    }
+
+    #[tokio::test]
+    async fn test_export_aborts_body_when_batch_fails() {
+        let response = get_csv(StubExportUseCase {
+            batches: vec![vec![issue(1, "First")]],
+            fail_after: true,
+        })
+        .await;
+
+        let result = axum::body::to_bytes(response.into_body(), usize::MAX).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_escape_quotes_only_when_needed() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,3 @@
+mod jira_issue_csv;
+
+pub use jira_issue_csv::jira_issue_csv_router;
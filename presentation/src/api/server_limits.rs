@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use axum::{BoxError, Router, error_handling::HandleErrorLayer, http::StatusCode};
+use tower::ServiceBuilder;
+use tower::limit::GlobalConcurrencyLimitLayer;
+use tower::timeout::error::Elapsed;
+use tracing::warn;
+
+/// Default time a request may take before it is cancelled.
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Default number of requests handled at once.
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 256;
+
+/// Load protection applied to every route of the server.
+///
+/// Requests beyond `max_concurrent_requests` are rejected immediately instead of
+/// queueing, and requests running longer than `request_timeout` are cancelled.
+/// Both answer `503 Service Unavailable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerLimits {
+    pub request_timeout: Duration,
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for ServerLimits {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        }
+    }
+}
+
+impl ServerLimits {
+    /// Creates ServerLimits from environment variables, falling back to defaults
+    /// (SERVER_REQUEST_TIMEOUT_SECS, SERVER_MAX_CONCURRENT_REQUESTS).
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            request_timeout: std::env::var("SERVER_REQUEST_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&secs: &u64| secs > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(default.request_timeout),
+            max_concurrent_requests: std::env::var("SERVER_MAX_CONCURRENT_REQUESTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&max: &usize| max > 0)
+                .unwrap_or(default.max_concurrent_requests),
+        }
+    }
+
+    /// Wraps every route of `router` with the shedding, concurrency and timeout layers.
+    ///
+    /// `Router::layer` wraps each route separately, so the concurrency limit shares one
+    /// semaphore across all of them to cap the server rather than each route.
+    pub fn apply(&self, router: Router) -> Router {
+        router.layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(handle_overload))
+                .load_shed()
+                .layer(GlobalConcurrencyLimitLayer::new(
+                    self.max_concurrent_requests,
+                ))
+                .timeout(self.request_timeout),
+        )
+    }
+}
+
+async fn handle_overload(error: BoxError) -> (StatusCode, &'static str) {
+    if error.is::<Elapsed>() {
+        warn!("Request timed out");
+        (StatusCode::SERVICE_UNAVAILABLE, "Request timed out")
+    } else {
+        warn!("Request rejected: {}", error);
+        (StatusCode::SERVICE_UNAVAILABLE, "Server is overloaded")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get};
+    use tower::ServiceExt;
+
+    fn slow_router(limits: &ServerLimits) -> Router {
+        limits.apply(
+            Router::new()
+                .route(
+                    "/slow",
+                    get(|| async {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        "done"
+                    }),
+                )
+                .route("/fast", get(|| async { "done" })),
+        )
+    }
+
+    fn request() -> Request<Body> {
+        Request::get("/slow").body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_request_times_out_with_503() {
+        let limits = ServerLimits {
+            request_timeout: Duration::from_secs(5),
+            ..ServerLimits::default()
+        };
+
+        let response = slow_router(&limits).oneshot(request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_request_within_timeout_succeeds() {
+        let limits = ServerLimits {
+            request_timeout: Duration::from_secs(120),
+            ..ServerLimits::default()
+        };
+
+        let response = slow_router(&limits).oneshot(request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_excess_request_is_shed_with_503() {
+        let limits = ServerLimits {
+            request_timeout: Duration::from_secs(120),
+            max_concurrent_requests: 1,
+        };
+        let router = slow_router(&limits);
+        let in_flight = tokio::spawn(router.clone().oneshot(request()));
+        tokio::task::yield_now().await;
+
+        let response = router.oneshot(request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(in_flight.await.unwrap().unwrap().status(), StatusCode::OK);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_limit_is_shared_across_routes() {
+        let limits = ServerLimits {
+            request_timeout: Duration::from_secs(120),
+            max_concurrent_requests: 1,
+        };
+        let router = slow_router(&limits);
+        let in_flight = tokio::spawn(router.clone().oneshot(request()));
+        tokio::task::yield_now().await;
+
+        let response = router
+            .clone()
+            .oneshot(Request::get("/fast").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(in_flight.await.unwrap().unwrap().status(), StatusCode::OK);
+        let response = router
+            .oneshot(Request::get("/fast").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
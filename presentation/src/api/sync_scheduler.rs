@@ -0,0 +1,258 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+use application::dto::command::jira::{JiraIssueSyncParams, JiraIssueSyncStatus};
+use application::usecase::command::jira::JiraIssueSyncUseCase;
+
+/// Default time between two scheduled syncs.
+const DEFAULT_INTERVAL_SECS: u64 = 900;
+
+/// Default look-back window of the first scheduled sync.
+const DEFAULT_INITIAL_LOOKBACK_HOURS: i64 = 24;
+
+/// Settings of the in-server issue sync scheduler. Disabled by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncSchedulerConfig {
+    pub enabled: bool,
+    pub interval: Duration,
+    /// How far back the first run looks; later runs continue from the last completed run.
+    pub initial_lookback: chrono::Duration,
+}
+
+impl Default for SyncSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: Duration::from_secs(DEFAULT_INTERVAL_SECS),
+            initial_lookback: chrono::Duration::hours(DEFAULT_INITIAL_LOOKBACK_HOURS),
+        }
+    }
+}
+
+impl SyncSchedulerConfig {
+    /// Creates SyncSchedulerConfig from environment variables, falling back to defaults
+    /// (SYNC_SCHEDULER_ENABLED, SYNC_SCHEDULER_INTERVAL_SECS, SYNC_SCHEDULER_LOOKBACK_HOURS).
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            enabled: std::env::var("SYNC_SCHEDULER_ENABLED")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.enabled),
+            interval: std::env::var("SYNC_SCHEDULER_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&secs: &u64| secs > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(default.interval),
+            initial_lookback: std::env::var("SYNC_SCHEDULER_LOOKBACK_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|&hours: &i64| hours > 0)
+                .map(chrono::Duration::hours)
+                .unwrap_or(default.initial_lookback),
+        }
+    }
+}
+
+/// Background task running the incremental issue sync at a fixed interval.
+///
+/// Runs never overlap: ticks that fall due while a sync is still running are skipped.
+/// [`shutdown`](Self::shutdown) stops the schedule and cancels a running sync between
+/// batches, so no batch is left half-applied.
+pub struct SyncScheduler {
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl SyncScheduler {
+    /// Starts the scheduler; the first sync runs immediately.
+    pub fn spawn<U>(usecase: Arc<U>, config: &SyncSchedulerConfig) -> Self
+    where
+        U: JiraIssueSyncUseCase + ?Sized + 'static,
+    {
+        let (shutdown, shutdown_rx) = watch::channel(false);
+        let task = tokio::spawn(run_schedule(
+            usecase,
+            config.interval,
+            config.initial_lookback,
+            shutdown_rx,
+        ));
+        Self { shutdown, task }
+    }
+
+    /// Stops the scheduler and waits for a running sync to wind down.
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(true);
+        if let Err(e) = self.task.await {
+            error!("Sync scheduler task failed: {}", e);
+        }
+    }
+}
+
+async fn run_schedule<U>(
+    usecase: Arc<U>,
+    interval: Duration,
+    initial_lookback: chrono::Duration,
+    mut shutdown: watch::Receiver<bool>,
+) where
+    U: JiraIssueSyncUseCase + ?Sized,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut since = Utc::now() - initial_lookback;
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.wait_for(|&stop| stop) => break,
+        }
+
+        let started_at = Utc::now();
+        let mut cancel_rx = shutdown.clone();
+        let cancel = Box::pin(async move {
+            let _ = cancel_rx.wait_for(|&stop| stop).await;
+        });
+
+        match usecase
+            .execute_with_params(JiraIssueSyncParams::new(since), cancel)
+            .await
+        {
+            Ok(JiraIssueSyncStatus::Completed(report)) => {
+                info!(
+                    "Scheduled Jira issue sync completed: {} issues, {} pages",
+                    report.synced, report.pages_fetched
+                );
+                since = started_at;
+            }
+            Ok(JiraIssueSyncStatus::Cancelled(report)) => {
+                warn!(
+                    "Scheduled Jira issue sync cancelled after {} issues",
+                    report.synced
+                );
+                break;
+            }
+            // The next run retries the same window
+            Err(e) => error!("Scheduled Jira issue sync failed: {}", e),
+        }
+    }
+
+    info!("Sync scheduler stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::dto::command::jira::JiraIssueSyncReport;
+    use application::error::command::jira::JiraIssueSyncError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use futures::future::{self, BoxFuture, Either};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts runs; each run takes `run_time` unless cancelled first.
+    struct MockSyncUseCase {
+        run_time: Duration,
+        runs: AtomicUsize,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    impl MockSyncUseCase {
+        fn new(run_time: Duration) -> Self {
+            Self {
+                run_time,
+                runs: AtomicUsize::new(0),
+                running: AtomicUsize::new(0),
+                max_running: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl JiraIssueSyncUseCase for MockSyncUseCase {
+        async fn execute(
+            &self,
+            _since: DateTime<Utc>,
+        ) -> Result<JiraIssueSyncReport, JiraIssueSyncError> {
+            unreachable!("the scheduler runs cancellable syncs")
+        }
+
+        async fn execute_with_params(
+            &self,
+            _params: JiraIssueSyncParams,
+            cancel: BoxFuture<'static, ()>,
+        ) -> Result<JiraIssueSyncStatus, JiraIssueSyncError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+
+            let work = Box::pin(tokio::time::sleep(self.run_time));
+            let status = match future::select(work, cancel).await {
+                Either::Left(_) => JiraIssueSyncStatus::Completed(JiraIssueSyncReport::default()),
+                Either::Right(_) => JiraIssueSyncStatus::Cancelled(JiraIssueSyncReport::default()),
+            };
+
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(status)
+        }
+    }
+
+    fn config(interval_secs: u64) -> SyncSchedulerConfig {
+        SyncSchedulerConfig {
+            enabled: true,
+            interval: Duration::from_secs(interval_secs),
+            ..SyncSchedulerConfig::default()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scheduler_fires_at_interval_and_stops_on_shutdown() {
+        let usecase = Arc::new(MockSyncUseCase::new(Duration::from_secs(1)));
+        let scheduler = SyncScheduler::spawn(usecase.clone(), &config(10));
+
+        // Runs at 0s, 10s and 20s
+        tokio::time::sleep(Duration::from_secs(25)).await;
+        scheduler.shutdown().await;
+        tokio::time::sleep(Duration::from_secs(60)).await;
+
+        assert_eq!(usecase.runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scheduler_skips_ticks_while_a_run_is_in_progress() {
+        let usecase = Arc::new(MockSyncUseCase::new(Duration::from_secs(25)));
+        let scheduler = SyncScheduler::spawn(usecase.clone(), &config(10));
+
+        // Runs at 0s and 30s; the ticks at 10s and 20s fall inside the first run
+        tokio::time::sleep(Duration::from_secs(45)).await;
+        scheduler.shutdown().await;
+
+        assert_eq!(usecase.runs.load(Ordering::SeqCst), 2);
+        assert_eq!(usecase.max_running.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shutdown_cancels_running_sync() {
+        let usecase = Arc::new(MockSyncUseCase::new(Duration::from_secs(3600)));
+        let scheduler = SyncScheduler::spawn(usecase.clone(), &config(10));
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let started = tokio::time::Instant::now();
+        scheduler.shutdown().await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(usecase.running.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_scheduler_is_disabled_by_default() {
+        assert!(!SyncSchedulerConfig::default().enabled);
+    }
+}
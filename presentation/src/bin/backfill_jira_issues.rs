@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use clap::Parser;
+use tracing::info;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use application::usecase::command::jira::JiraIssueSyncUseCaseImpl;
+use infrastructure::adapter::jira::{
+    JiraApiConfig, JiraIssueAdapterImpl, JiraRateLimiter, JiraSyncLockAdapterImpl,
+};
+use infrastructure::config::DatabaseConfig;
+use infrastructure::database::JiraDescriptionStorage;
+use infrastructure::repository::command::jira::{
+    JiraIssueRepositoryImpl, JiraProjectRepositoryImpl,
+};
+use presentation::cli::{BackfillJiraIssuesArgs, LogFormat, run_backfill_jira_issues};
+
+/// CLI tool for backfilling the full history of Jira issues from the Jira API.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(flatten)]
+    backfill: BackfillJiraIssuesArgs,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load environment variables from .env file
+    dotenvy::dotenv().ok();
+
+    // Initialize tracing
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
+        .init();
+
+    let args = Args::parse();
+
+    // Initialize database connection
+    let db_config =
+        DatabaseConfig::from_env().map_err(|e| format!("Failed to load database config: {}", e))?;
+
+    let pool = db_config.create_pool().await?;
+    info!("Database connection pool created");
+
+    // Run migrations
+    sqlx::migrate!("../infrastructure/migrations")
+        .run(&pool)
+        .await?;
+    info!("Database migrations completed");
+
+    // Initialize Jira API client
+    let jira_config =
+        JiraApiConfig::from_env().map_err(|e| format!("Failed to load Jira API config: {}", e))?;
+
+    // Initialize repositories and adapters
+    let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+        jira_config.client_tuning.requests_per_second,
+    ));
+    let project_repository = Arc::new(JiraProjectRepositoryImpl::new(pool.clone()));
+    let issue_repository = Arc::new(
+        JiraIssueRepositoryImpl::new(pool.clone())
+            .with_description_storage(JiraDescriptionStorage::from_env()),
+    );
+    let jira_issue_port = Arc::new(JiraIssueAdapterImpl::new(jira_config, rate_limiter));
+
+    // Initialize use case
+    let sync_usecase = Arc::new(
+        JiraIssueSyncUseCaseImpl::new(project_repository, issue_repository, jira_issue_port)
+            .with_project_key_chunk_size(args.backfill.project_chunk_size)
+            .with_sync_lock(Arc::new(JiraSyncLockAdapterImpl::new(pool.clone()))),
+    );
+
+    // Run backfill
+    run_backfill_jira_issues(sync_usecase, &args.backfill).await?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use application::usecase::query::jira::JiraAuthCheckQueryUseCaseImpl;
+use infrastructure::adapter::jira::{JiraApiConfig, JiraAuthAdapterImpl, JiraRateLimiter};
+use presentation::cli::{LogFormat, run_check_jira_auth};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load environment variables from .env file
+    dotenvy::dotenv().ok();
+
+    // Initialize tracing
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
+        .init();
+
+    // Initialize Jira API client
+    let jira_config =
+        JiraApiConfig::from_env().map_err(|e| format!("Failed to load Jira API config: {}", e))?;
+
+    // Initialize adapter
+    let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+        jira_config.client_tuning.requests_per_second,
+    ));
+    let jira_auth_port = Arc::new(JiraAuthAdapterImpl::new(jira_config, rate_limiter));
+
+    // Initialize use case
+    let check_usecase = Arc::new(JiraAuthCheckQueryUseCaseImpl::new(jira_auth_port));
+
+    // Run check
+    run_check_jira_auth(check_usecase).await?;
+
+    Ok(())
+}
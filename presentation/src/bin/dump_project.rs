@@ -0,0 +1,73 @@
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::sync::Arc;
+
+use clap::Parser;
+use tracing::info;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use application::usecase::query::jira::JiraProjectDumpQueryUseCaseImpl;
+use infrastructure::config::DatabaseConfig;
+use infrastructure::repository::query::jira::{
+    JiraIssueQueryRepositoryImpl, JiraProjectQueryRepositoryImpl,
+};
+use presentation::cli::{DumpProjectArgs, LogFormat, run_dump_project};
+
+/// CLI tool for dumping a stored Jira project and its issues to JSON, e.g. for support cases.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(flatten)]
+    dump: DumpProjectArgs,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load environment variables from .env file
+    dotenvy::dotenv().ok();
+
+    // Initialize tracing; logs go to stderr so the dump can be piped from stdout
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(LogFormat::from_env().fmt_layer(io::stderr))
+        .init();
+
+    let args = Args::parse();
+
+    // Initialize database connection
+    let db_config =
+        DatabaseConfig::from_env().map_err(|e| format!("Failed to load database config: {}", e))?;
+
+    let pool = db_config.create_pool().await?;
+    info!("Database connection pool created");
+
+    // Initialize repositories
+    let statement_timeout = db_config.statement_timeout();
+    let project_query_repository = Arc::new(JiraProjectQueryRepositoryImpl::new(
+        pool.clone(),
+        statement_timeout,
+    ));
+    let issue_query_repository =
+        Arc::new(JiraIssueQueryRepositoryImpl::new(pool, statement_timeout));
+
+    // Initialize use case
+    let dump_usecase = Arc::new(JiraProjectDumpQueryUseCaseImpl::new(
+        project_query_repository,
+        issue_query_repository,
+    ));
+
+    // Run dump
+    match &args.dump.output {
+        Some(path) => {
+            let writer = BufWriter::new(File::create(path)?);
+            run_dump_project(dump_usecase, &args.dump.key, writer).await?;
+        }
+        None => {
+            run_dump_project(dump_usecase, &args.dump.key, io::stdout().lock()).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -11,22 +11,37 @@ use axum::{
 };
 use clap::Parser;
 use tower_http::cors::{Any, CorsLayer};
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use application::usecase::command::jira::{
-    JiraProjectCreateUseCaseImpl, JiraProjectUpdateUseCaseImpl,
+    JiraIssueSyncUseCaseImpl, JiraProjectBatchCreateUseCaseImpl, JiraProjectCreateUseCaseImpl,
+    JiraProjectSingleSyncUseCase, JiraProjectSingleSyncUseCaseImpl, JiraProjectUpdateUseCaseImpl,
 };
 use application::usecase::query::jira::{
-    JiraIssueFindByIdsQueryUseCaseImpl, JiraIssueListQueryUseCaseImpl,
+    JiraAuthCheckQueryUseCase, JiraAuthCheckQueryUseCaseImpl, JiraIssueExportQueryUseCaseImpl,
+    JiraIssueFindByIdsQueryUseCaseImpl, JiraIssueLinkFindByKeysQueryUseCaseImpl,
+    JiraIssueListQueryUseCaseImpl, JiraIssueResolveIdsQueryUseCaseImpl,
     JiraProjectFindByIdsQueryUseCaseImpl, JiraProjectListQueryUseCaseImpl,
+    JiraProjectSearchQueryUseCaseImpl,
+};
+use infrastructure::adapter::jira::{
+    JiraApiConfig, JiraAuthAdapterImpl, JiraIssueAdapterImpl, JiraProjectAdapterImpl,
+    JiraRateLimiter, JiraSyncLockAdapterImpl,
 };
 use infrastructure::config::DatabaseConfig;
-use infrastructure::repository::command::jira::JiraProjectRepositoryImpl;
+use infrastructure::database::JiraDescriptionStorage;
+use infrastructure::repository::command::jira::{
+    JiraIssueRepositoryImpl, JiraProjectRepositoryImpl,
+};
 use infrastructure::repository::query::jira::{
-    JiraIssueQueryRepositoryImpl, JiraProjectQueryRepositoryImpl,
+    JiraIssueLinkQueryRepositoryImpl, JiraIssueQueryRepositoryImpl, JiraProjectQueryRepositoryImpl,
 };
-use presentation::api::graphql::{AppSchema, build_schema};
+use presentation::api::graphql::{AppSchema, AppSchemaBuilder, SchemaConfig};
+use presentation::api::rest::jira_issue_csv_router;
+use presentation::api::server_limits::ServerLimits;
+use presentation::api::sync_scheduler::{SyncScheduler, SyncSchedulerConfig};
+use presentation::cli::{LogFormat, MigrationArgs, run_migrations};
 
 /// GraphQL server for Jira issue management.
 #[derive(Parser, Debug)]
@@ -39,6 +54,9 @@ struct Args {
     /// Port to listen on
     #[arg(short, long, default_value = "8080")]
     port: u16,
+
+    #[command(flatten)]
+    migrations: MigrationArgs,
 }
 
 #[tokio::main]
@@ -51,7 +69,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with(tracing_subscriber::EnvFilter::new(
             std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
         ))
-        .with(tracing_subscriber::fmt::layer())
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
         .init();
 
     let args = Args::parse();
@@ -63,44 +81,154 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let pool = db_config.create_pool().await?;
     info!("Database connection pool created");
 
+    // Open the pool's connections up front so the first requests do not pay for them
+    let warmed = db_config.warm_up_pool(&pool).await?;
+    info!(
+        "Database connection pool ready with {} warm connections",
+        warmed
+    );
+
     // Run migrations
-    sqlx::migrate!("../infrastructure/migrations")
-        .run(&pool)
-        .await?;
-    info!("Database migrations completed");
+    run_migrations(&args.migrations, || async {
+        sqlx::migrate!("../infrastructure/migrations")
+            .run(&pool)
+            .await
+    })
+    .await?;
 
     // Initialize repositories
-    let issue_query_repository = Arc::new(JiraIssueQueryRepositoryImpl::new(pool.clone()));
-    let project_query_repository = Arc::new(JiraProjectQueryRepositoryImpl::new(pool.clone()));
+    let statement_timeout = db_config.statement_timeout();
+    let issue_query_repository = Arc::new(JiraIssueQueryRepositoryImpl::new(
+        pool.clone(),
+        statement_timeout,
+    ));
+    let issue_link_query_repository = Arc::new(JiraIssueLinkQueryRepositoryImpl::new(
+        pool.clone(),
+        statement_timeout,
+    ));
+    let project_query_repository = Arc::new(JiraProjectQueryRepositoryImpl::new(
+        pool.clone(),
+        statement_timeout,
+    ));
     let project_command_repository = Arc::new(JiraProjectRepositoryImpl::new(pool.clone()));
 
     // Initialize use cases
     let issue_find_by_ids_usecase = Arc::new(JiraIssueFindByIdsQueryUseCaseImpl::new(
         issue_query_repository.clone(),
     ));
-    let issue_list_usecase = Arc::new(JiraIssueListQueryUseCaseImpl::new(issue_query_repository));
+    let issue_list_usecase = Arc::new(JiraIssueListQueryUseCaseImpl::new(
+        issue_query_repository.clone(),
+    ));
+    let issue_resolve_ids_usecase = Arc::new(JiraIssueResolveIdsQueryUseCaseImpl::new(
+        issue_query_repository.clone(),
+    ));
+    let issue_export_usecase =
+        Arc::new(JiraIssueExportQueryUseCaseImpl::new(issue_query_repository));
+    let issue_link_find_by_keys_usecase = Arc::new(JiraIssueLinkFindByKeysQueryUseCaseImpl::new(
+        issue_link_query_repository,
+    ));
     let project_find_by_ids_usecase = Arc::new(JiraProjectFindByIdsQueryUseCaseImpl::new(
         project_query_repository.clone(),
     ));
     let project_list_usecase = Arc::new(JiraProjectListQueryUseCaseImpl::new(
+        project_query_repository.clone(),
+    ));
+    let project_search_usecase = Arc::new(JiraProjectSearchQueryUseCaseImpl::new(
         project_query_repository,
     ));
-    let create_project_usecase = Arc::new(JiraProjectCreateUseCaseImpl::new(
+    let mut create_project_usecase =
+        JiraProjectCreateUseCaseImpl::new(project_command_repository.clone());
+    let batch_create_project_usecase = Arc::new(JiraProjectBatchCreateUseCaseImpl::new(
         project_command_repository.clone(),
     ));
     let update_project_usecase = Arc::new(JiraProjectUpdateUseCaseImpl::new(
-        project_command_repository,
+        project_command_repository.clone(),
     ));
 
+    // Single-project sync, the credential check and the sync scheduler require Jira API
+    // access; all are optional
+    let scheduler_config = SyncSchedulerConfig::from_env();
+    let mut sync_project_usecase: Option<Arc<dyn JiraProjectSingleSyncUseCase>> = None;
+    let mut auth_check_usecase: Option<Arc<dyn JiraAuthCheckQueryUseCase>> = None;
+    let mut sync_scheduler: Option<SyncScheduler> = None;
+    match JiraApiConfig::from_env() {
+        Ok(jira_config) => {
+            let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+                jira_config.client_tuning.requests_per_second,
+            ));
+            let jira_auth_port = Arc::new(JiraAuthAdapterImpl::new(
+                jira_config.clone(),
+                rate_limiter.clone(),
+            ));
+            if scheduler_config.enabled {
+                let jira_issue_port = Arc::new(JiraIssueAdapterImpl::new(
+                    jira_config.clone(),
+                    rate_limiter.clone(),
+                ));
+                let sync_issues_usecase = Arc::new(
+                    JiraIssueSyncUseCaseImpl::new(
+                        project_command_repository.clone(),
+                        Arc::new(
+                            JiraIssueRepositoryImpl::new(pool.clone())
+                                .with_description_storage(JiraDescriptionStorage::from_env()),
+                        ),
+                        jira_issue_port,
+                    )
+                    .with_sync_lock(Arc::new(JiraSyncLockAdapterImpl::new(pool.clone()))),
+                );
+                info!(
+                    "Sync scheduler enabled, syncing issues every {:?}",
+                    scheduler_config.interval
+                );
+                sync_scheduler = Some(SyncScheduler::spawn(sync_issues_usecase, &scheduler_config));
+            }
+            let jira_project_port =
+                Arc::new(JiraProjectAdapterImpl::new(jira_config, rate_limiter));
+            create_project_usecase =
+                create_project_usecase.with_jira_project_port(jira_project_port.clone());
+            sync_project_usecase = Some(Arc::new(JiraProjectSingleSyncUseCaseImpl::new(
+                jira_project_port,
+                project_command_repository,
+            )));
+            auth_check_usecase = Some(Arc::new(JiraAuthCheckQueryUseCaseImpl::new(jira_auth_port)));
+        }
+        Err(e) => {
+            warn!(
+                "Jira API not configured, syncProject, createJiraProjectFromJira and checkJiraAuth are disabled: {}",
+                e
+            );
+            if scheduler_config.enabled {
+                warn!("Sync scheduler enabled but Jira API not configured; not starting it");
+            }
+        }
+    }
+
     // Build GraphQL schema
-    let schema = build_schema(
-        issue_find_by_ids_usecase,
-        issue_list_usecase,
-        project_find_by_ids_usecase,
-        project_list_usecase,
-        create_project_usecase,
-        update_project_usecase,
-    );
+    let schema_config = SchemaConfig::from_env();
+    if !schema_config.introspection {
+        info!("GraphQL introspection disabled");
+    }
+    if !schema_config.auth_check {
+        info!("GraphQL checkJiraAuth disabled");
+    }
+    let mut schema_builder = AppSchemaBuilder::new(schema_config)
+        .with_issue_find_by_ids_usecase(issue_find_by_ids_usecase)
+        .with_issue_list_usecase(issue_list_usecase)
+        .with_issue_resolve_ids_usecase(issue_resolve_ids_usecase)
+        .with_issue_link_find_by_keys_usecase(issue_link_find_by_keys_usecase)
+        .with_project_find_by_ids_usecase(project_find_by_ids_usecase)
+        .with_project_list_usecase(project_list_usecase)
+        .with_project_search_usecase(project_search_usecase)
+        .with_create_project_usecase(Arc::new(create_project_usecase))
+        .with_batch_create_project_usecase(batch_create_project_usecase)
+        .with_update_project_usecase(update_project_usecase);
+    if let Some(sync_project_usecase) = sync_project_usecase {
+        schema_builder = schema_builder.with_sync_project_usecase(sync_project_usecase);
+    }
+    if let Some(auth_check_usecase) = auth_check_usecase {
+        schema_builder = schema_builder.with_auth_check_usecase(auth_check_usecase);
+    }
+    let schema = schema_builder.build();
 
     // Configure CORS
     let cors = CorsLayer::new()
@@ -109,10 +237,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .allow_headers(Any);
 
     // Build router
-    let app = Router::new()
-        .route("/", get(graphiql))
-        .route("/graphql", post(graphql_handler))
-        .with_state(schema)
+    let limits = ServerLimits::from_env();
+    info!(
+        "Request timeout {:?}, at most {} concurrent requests",
+        limits.request_timeout, limits.max_concurrent_requests
+    );
+    let app = limits
+        .apply(
+            Router::new()
+                .route("/", get(graphiql))
+                .route("/graphql", post(graphql_handler))
+                .with_state(schema)
+                .merge(jira_issue_csv_router(issue_export_usecase)),
+        )
         .layer(cors);
 
     let addr = format!("{}:{}", args.host, args.port);
@@ -120,11 +257,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("GraphiQL IDE available at http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    if let Some(scheduler) = sync_scheduler {
+        scheduler.shutdown().await;
+    }
 
     Ok(())
 }
 
+/// Resolves on Ctrl-C, starting a graceful shutdown.
+async fn shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+    info!("Shutdown signal received");
+}
+
 async fn graphiql() -> impl IntoResponse {
     Html(GraphiQLSource::build().endpoint("/graphql").finish())
 }
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use clap::Parser;
+use tracing::info;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use application::usecase::command::jira::JiraIssueChangelogSyncUseCaseImpl;
+use domain::value_object::jira::JiraIssueKey;
+use infrastructure::adapter::jira::{
+    JiraApiConfig, JiraIssueAdapterImpl, JiraRateLimiter, JiraSyncLockAdapterImpl,
+};
+use infrastructure::config::DatabaseConfig;
+use infrastructure::repository::command::jira::JiraChangelogRepositoryImpl;
+use presentation::cli::{LogFormat, parse_issue_key, run_sync_jira_changelog};
+
+/// CLI tool for syncing the change history of Jira issues from the Jira API.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Keys of the issues whose changelog is synced, e.g. PROJ-1.
+    #[arg(required = true, value_parser = parse_issue_key)]
+    issue_keys: Vec<JiraIssueKey>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load environment variables from .env file
+    dotenvy::dotenv().ok();
+
+    // Initialize tracing
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
+        .init();
+
+    let args = Args::parse();
+
+    // Initialize database connection
+    let db_config =
+        DatabaseConfig::from_env().map_err(|e| format!("Failed to load database config: {}", e))?;
+
+    let pool = db_config.create_pool().await?;
+    info!("Database connection pool created");
+
+    // Run migrations
+    sqlx::migrate!("../infrastructure/migrations")
+        .run(&pool)
+        .await?;
+    info!("Database migrations completed");
+
+    // Initialize Jira API client
+    let jira_config =
+        JiraApiConfig::from_env().map_err(|e| format!("Failed to load Jira API config: {}", e))?;
+
+    // Initialize repository and adapter
+    let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+        jira_config.client_tuning.requests_per_second,
+    ));
+    let changelog_repository = Arc::new(JiraChangelogRepositoryImpl::new(pool.clone()));
+    let jira_issue_port = Arc::new(JiraIssueAdapterImpl::new(jira_config, rate_limiter));
+
+    // Initialize use case
+    let sync_usecase = Arc::new(
+        JiraIssueChangelogSyncUseCaseImpl::new(jira_issue_port, changelog_repository)
+            .with_sync_lock(Arc::new(JiraSyncLockAdapterImpl::new(pool.clone()))),
+    );
+
+    // Run sync
+    run_sync_jira_changelog(sync_usecase, args.issue_keys).await?;
+
+    Ok(())
+}
@@ -5,12 +5,15 @@ use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use application::usecase::command::jira::JiraIssueSyncUseCaseImpl;
-use infrastructure::adapter::jira::{JiraApiConfig, JiraIssueAdapterImpl};
+use infrastructure::adapter::jira::{
+    JiraApiConfig, JiraIssueAdapterImpl, JiraRateLimiter, JiraSyncLockAdapterImpl,
+};
 use infrastructure::config::DatabaseConfig;
+use infrastructure::database::JiraDescriptionStorage;
 use infrastructure::repository::command::jira::{
-    JiraIssueRepositoryImpl, JiraProjectRepositoryImpl,
+    JiraIssueLinkRepositoryImpl, JiraIssueRepositoryImpl, JiraProjectRepositoryImpl,
 };
-use presentation::cli::{SyncJiraIssuesArgs, run_sync_jira_issues};
+use presentation::cli::{LogFormat, SyncJiraIssuesArgs, run_sync_jira_issues};
 
 /// CLI tool for syncing Jira issues from the Jira API.
 #[derive(Parser, Debug)]
@@ -30,7 +33,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with(tracing_subscriber::EnvFilter::new(
             std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
         ))
-        .with(tracing_subscriber::fmt::layer())
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
         .init();
 
     let args = Args::parse();
@@ -53,16 +56,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         JiraApiConfig::from_env().map_err(|e| format!("Failed to load Jira API config: {}", e))?;
 
     // Initialize repositories and adapters
+    let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+        jira_config.client_tuning.requests_per_second,
+    ));
     let project_repository = Arc::new(JiraProjectRepositoryImpl::new(pool.clone()));
-    let issue_repository = Arc::new(JiraIssueRepositoryImpl::new(pool.clone()));
-    let jira_issue_port = Arc::new(JiraIssueAdapterImpl::new(jira_config));
+    let issue_repository = Arc::new(
+        JiraIssueRepositoryImpl::new(pool.clone())
+            .with_description_storage(JiraDescriptionStorage::from_env()),
+    );
+    let jira_issue_port = Arc::new(JiraIssueAdapterImpl::new(jira_config, rate_limiter));
 
     // Initialize use case
-    let sync_usecase = Arc::new(JiraIssueSyncUseCaseImpl::new(
-        project_repository,
-        issue_repository,
-        jira_issue_port,
-    ));
+    let mut sync_usecase =
+        JiraIssueSyncUseCaseImpl::new(project_repository, issue_repository, jira_issue_port)
+            .with_project_key_chunk_size(args.sync.project_chunk_size)
+            .with_sync_lock(Arc::new(JiraSyncLockAdapterImpl::new(pool.clone())));
+    if args.sync.with_links {
+        sync_usecase =
+            sync_usecase.with_issue_links(Arc::new(JiraIssueLinkRepositoryImpl::new(pool.clone())));
+    }
+    if let Some(max) = args.sync.max_in_flight_issues {
+        sync_usecase = sync_usecase.with_max_in_flight_issues(max);
+    }
+    let sync_usecase = Arc::new(sync_usecase);
 
     // Run sync
     run_sync_jira_issues(sync_usecase, &args.sync).await?;
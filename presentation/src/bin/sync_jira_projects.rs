@@ -1,13 +1,25 @@
 use std::sync::Arc;
 
+use clap::Parser;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use application::usecase::command::jira::JiraProjectSyncUseCaseImpl;
-use infrastructure::adapter::jira::{JiraApiConfig, JiraProjectAdapterImpl};
+use infrastructure::adapter::jira::{
+    JiraApiConfig, JiraProjectAdapterImpl, JiraRateLimiter, JiraSyncLockAdapterImpl,
+};
 use infrastructure::config::DatabaseConfig;
 use infrastructure::repository::command::jira::JiraProjectRepositoryImpl;
-use presentation::cli::run_sync_jira_projects;
+use presentation::cli::{LogFormat, run_sync_jira_projects};
+
+/// CLI tool for syncing Jira projects from the Jira API.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Fail if the Jira API returns no projects instead of syncing zero.
+    #[arg(long)]
+    strict: bool,
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -19,9 +31,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with(tracing_subscriber::EnvFilter::new(
             std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
         ))
-        .with(tracing_subscriber::fmt::layer())
+        .with(LogFormat::from_env().fmt_layer(std::io::stdout))
         .init();
 
+    let args = Args::parse();
+
     // Initialize database connection
     let db_config =
         DatabaseConfig::from_env().map_err(|e| format!("Failed to load database config: {}", e))?;
@@ -40,14 +54,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         JiraApiConfig::from_env().map_err(|e| format!("Failed to load Jira API config: {}", e))?;
 
     // Initialize repository and adapter
+    let rate_limiter = Arc::new(JiraRateLimiter::per_second(
+        jira_config.client_tuning.requests_per_second,
+    ));
     let project_repository = Arc::new(JiraProjectRepositoryImpl::new(pool.clone()));
-    let jira_project_port = Arc::new(JiraProjectAdapterImpl::new(jira_config));
+    let jira_project_port = Arc::new(JiraProjectAdapterImpl::new(jira_config, rate_limiter));
 
     // Initialize use case
-    let sync_usecase = Arc::new(JiraProjectSyncUseCaseImpl::new(
-        jira_project_port,
-        project_repository,
-    ));
+    let sync_usecase = Arc::new(
+        JiraProjectSyncUseCaseImpl::new(jira_project_port, project_repository)
+            .with_strict(args.strict)
+            .with_sync_lock(Arc::new(JiraSyncLockAdapterImpl::new(pool.clone()))),
+    );
 
     // Run sync
     run_sync_jira_projects(sync_usecase).await?;
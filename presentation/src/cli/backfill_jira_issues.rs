@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use clap::Args;
+use futures::FutureExt;
+use tracing::{error, info, warn};
+
+use application::dto::command::jira::{
+    JiraIssueSyncParams, JiraIssueSyncReport, JiraIssueSyncStatus,
+};
+use application::usecase::command::jira::{DEFAULT_PROJECT_KEY_CHUNK_SIZE, JiraIssueSyncUseCase};
+
+use super::sync_report::format_sync_report;
+
+/// CLI arguments for the backfill-issues command.
+#[derive(Debug, Args)]
+pub struct BackfillJiraIssuesArgs {
+    /// Earliest update date to load (YYYY-MM-DD). Defaults to the Unix epoch, i.e. everything.
+    #[arg(long)]
+    pub from: Option<NaiveDate>,
+
+    /// Length in days of the windows the history is loaded in, oldest first.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(i64).range(1..))]
+    pub window: i64,
+
+    /// Number of project keys combined into one JQL query.
+    #[arg(long, default_value_t = DEFAULT_PROJECT_KEY_CHUNK_SIZE)]
+    pub project_chunk_size: usize,
+}
+
+/// Returns the lower bound for the backfill: `--from` at midnight UTC, or the Unix epoch.
+pub fn backfill_floor(args: &BackfillJiraIssuesArgs) -> DateTime<Utc> {
+    args.from
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+        .unwrap_or(DateTime::UNIX_EPOCH)
+}
+
+/// Returns the sync params of the backfill: everything since the floor, in `--window` days.
+pub fn backfill_params(args: &BackfillJiraIssuesArgs) -> JiraIssueSyncParams {
+    JiraIssueSyncParams::new(backfill_floor(args)).with_window(Duration::days(args.window))
+}
+
+/// Runs a full historical backfill of Jira issues.
+///
+/// Unlike the incremental sync this ignores the look-back window and loads every issue
+/// updated since the floor. The range is synced one window at a time, oldest first, and
+/// the progress is logged after each window.
+pub async fn run_backfill_jira_issues<U: JiraIssueSyncUseCase>(
+    usecase: Arc<U>,
+    args: &BackfillJiraIssuesArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let params = backfill_params(args);
+    let windows = params.windows(Utc::now());
+
+    info!("Starting Jira issue backfill job...");
+    info!(
+        "Loading all issues updated since {} in {} windows of {} days",
+        params.since,
+        windows.len(),
+        args.window
+    );
+
+    // Stop between batches on Ctrl-C so no batch is left half-applied
+    let cancel = async {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+    .boxed()
+    .shared();
+
+    let mut total = JiraIssueSyncReport::default();
+    for (index, (since, until)) in windows.iter().copied().enumerate() {
+        let window_params = JiraIssueSyncParams {
+            since,
+            until,
+            window: None,
+            ..params.clone()
+        };
+        let end = until.map_or_else(|| "now".to_string(), |until| until.to_rfc3339());
+
+        match usecase
+            .execute_with_params(window_params, cancel.clone().boxed())
+            .await
+        {
+            Ok(JiraIssueSyncStatus::Completed(report)) => {
+                total.merge(&report);
+                info!(
+                    "Window {}/{} ({} to {}): {}",
+                    index + 1,
+                    windows.len(),
+                    since.to_rfc3339(),
+                    end,
+                    format_sync_report(&report)
+                );
+            }
+            Ok(JiraIssueSyncStatus::Cancelled(report)) => {
+                total.merge(&report);
+                warn!("Jira issue backfill cancelled in window {}", index + 1);
+                info!("Before cancellation: {}", format_sync_report(&total));
+                return Ok(());
+            }
+            Err(e) => {
+                error!("Jira issue backfill failed in window {}: {}", index + 1, e);
+                info!("Before the failure: {}", format_sync_report(&total));
+                return Err(Box::new(e));
+            }
+        }
+    }
+
+    info!("Jira issue backfill completed successfully!");
+    info!("{}", format_sync_report(&total));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backfill_floor_defaults_to_epoch() {
+        let args = BackfillJiraIssuesArgs {
+            from: None,
+            window: 30,
+            project_chunk_size: DEFAULT_PROJECT_KEY_CHUNK_SIZE,
+        };
+
+        assert_eq!(backfill_floor(&args), DateTime::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn test_backfill_floor_uses_from_date() {
+        let args = BackfillJiraIssuesArgs {
+            from: NaiveDate::from_ymd_opt(2020, 1, 15),
+            window: 30,
+            project_chunk_size: DEFAULT_PROJECT_KEY_CHUNK_SIZE,
+        };
+
+        assert_eq!(
+            backfill_floor(&args).to_rfc3339(),
+            "2020-01-15T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_backfill_params_split_history_into_windows() {
+        let args = BackfillJiraIssuesArgs {
+            from: NaiveDate::from_ymd_opt(2024, 1, 1),
+            window: 7,
+            project_chunk_size: DEFAULT_PROJECT_KEY_CHUNK_SIZE,
+        };
+        let now = "2024-01-20T00:00:00Z".parse().unwrap();
+
+        let params = backfill_params(&args);
+        let windows = params.windows(now);
+
+        assert_eq!(params.window, Some(Duration::days(7)));
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].0, backfill_floor(&args));
+        assert_eq!(windows[2].1, None);
+    }
+}
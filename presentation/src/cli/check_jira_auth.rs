@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use application::usecase::query::jira::JiraAuthCheckQueryUseCase;
+
+/// Checks that the configured Jira credentials are accepted.
+pub async fn run_check_jira_auth<U: JiraAuthCheckQueryUseCase>(
+    usecase: Arc<U>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Checking Jira credentials...");
+
+    match usecase.execute().await {
+        Ok(()) => {
+            info!("Jira credentials are valid");
+            Ok(())
+        }
+        Err(e) => {
+            error!("Jira credential check failed: {}", e);
+            Err(Box::new(e))
+        }
+    }
+}
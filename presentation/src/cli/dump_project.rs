@@ -0,0 +1,147 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use clap::Args;
+use tracing::{error, info};
+
+use application::usecase::query::jira::JiraProjectDumpQueryUseCase;
+
+/// CLI arguments for the dump-project command.
+#[derive(Debug, Args)]
+pub struct DumpProjectArgs {
+    /// Key of the project to dump.
+    pub key: String,
+
+    /// File to write the JSON document to. Defaults to standard output.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Writes the project with the given key and all of its stored issues to `writer`
+/// as one pretty-printed JSON document.
+pub async fn run_dump_project<U: JiraProjectDumpQueryUseCase, W: Write>(
+    usecase: Arc<U>,
+    key: &str,
+    mut writer: W,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Dumping Jira project {}...", key);
+
+    let dump = match usecase.execute(key.to_string()).await {
+        Ok(dump) => dump,
+        Err(e) => {
+            error!("Jira project dump failed: {}", e);
+            return Err(Box::new(e));
+        }
+    };
+    let issue_count = dump.issues.len();
+
+    serde_json::to_writer_pretty(&mut writer, &dump)?;
+    writeln!(writer)?;
+    writer.flush()?;
+
+    info!("Dumped project {} with {} issues", key, issue_count);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::dto::query::jira::{
+        JiraIssueQueryDto, JiraProjectDumpDto, JiraProjectQueryDto,
+    };
+    use application::error::query::jira::JiraProjectDumpQueryError;
+    use async_trait::async_trait;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use domain::value_object::jira::{JiraIssuePriority, JiraIssueType, JiraUser};
+    use serde_json::json;
+
+    /// Serves a single seeded project.
+    struct StubDumpUseCase;
+
+    #[async_trait]
+    impl JiraProjectDumpQueryUseCase for StubDumpUseCase {
+        async fn execute(
+            &self,
+            key: String,
+        ) -> Result<JiraProjectDumpDto, JiraProjectDumpQueryError> {
+            if key != "PROJ" {
+                return Err(JiraProjectDumpQueryError::ProjectNotFound(key));
+            }
+            let at = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+            let issue = |id: i64| {
+                JiraIssueQueryDto::builder()
+                    .id(id)
+                    .key(format!("PROJ-{}", id))
+                    .summary(format!("Issue {}", id))
+                    .issue_type(JiraIssueType::Bug)
+                    .priority(JiraIssuePriority::High)
+                    .reporter(Some(JiraUser::new("acc-1", "Jane")))
+                    .created_at(at)
+                    .updated_at(at)
+                    .build()
+            };
+            Ok(JiraProjectDumpDto {
+                project: JiraProjectQueryDto::new(10, "PROJ".to_string(), "Project".to_string()),
+                issues: vec![
+                    issue(1),
+                    issue(2)
+                        .with_watches(Some(4))
+                        .with_time_tracking(Some(28800), Some(14400), Some(14400))
+                        .with_resolution(Some("Done".to_string()))
+                        .with_due_date(NaiveDate::from_ymd_opt(2024, 1, 31))
+                        .with_synced_at(Some(at)),
+                ],
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dump_writes_project_and_issues_as_json() {
+        let mut output = Vec::new();
+
+        run_dump_project(Arc::new(StubDumpUseCase), "PROJ", &mut output)
+            .await
+            .unwrap();
+
+        let document: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            document["project"],
+            json!({ "id": 10, "key": "PROJ", "name": "Project", "updated_at": null })
+        );
+        assert_eq!(document["issues"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            document["issues"][1],
+            json!({
+                "id": 2,
+                "key": "PROJ-2",
+                "summary": "Issue 2",
+                "description": null,
+                "issue_type": "Bug",
+                "priority": "High",
+                "status_category": null,
+                "reporter": { "account_id": "acc-1", "display_name": "Jane" },
+                "watches": 4,
+                "votes": null,
+                "original_estimate_seconds": 28800,
+                "remaining_estimate_seconds": 14400,
+                "time_spent_seconds": 14400,
+                "resolution": "Done",
+                "due_date": "2024-01-31",
+                "created_at": "2024-01-02T03:04:05Z",
+                "updated_at": "2024-01-02T03:04:05Z",
+                "synced_at": "2024-01-02T03:04:05Z",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dump_of_unknown_project_writes_nothing() {
+        let mut output = Vec::new();
+
+        let result = run_dump_project(Arc::new(StubDumpUseCase), "OTHER", &mut output).await;
+
+        assert!(result.is_err());
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+use tracing::Subscriber;
+use tracing_subscriber::Layer;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Output format of the log lines written by the binaries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, with span and event fields as JSON fields.
+    Json,
+}
+
+impl LogFormat {
+    /// Reads LOG_FORMAT (`json` or `text`), falling back to text when unset or unknown.
+    pub fn from_env() -> Self {
+        std::env::var("LOG_FORMAT")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    /// Parses a format name, ignoring case.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Builds the formatting layer for this format, writing to `writer`.
+    pub fn fmt_layer<S, W>(self, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        let layer = tracing_subscriber::fmt::layer().with_writer(writer);
+        match self {
+            Self::Text => layer.boxed(),
+            Self::Json => layer.json().boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing::info;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Collects everything written by the layer.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn log_line(format: LogFormat) -> String {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber =
+            tracing_subscriber::registry().with(format.fmt_layer(move || writer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            info!(project = "PROJ", "Synced issues");
+        });
+
+        String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(LogFormat::parse("json"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse(" JSON "), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse("text"), Some(LogFormat::Text));
+        assert_eq!(LogFormat::parse("xml"), None);
+    }
+
+    #[test]
+    fn test_json_format_writes_structured_fields() {
+        let line = log_line(LogFormat::Json);
+
+        let json: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(json["level"], "INFO");
+        assert_eq!(json["fields"]["message"], "Synced issues");
+        assert_eq!(json["fields"]["project"], "PROJ");
+    }
+
+    #[test]
+    fn test_text_format_writes_plain_lines() {
+        let line = log_line(LogFormat::Text);
+
+        assert!(serde_json::from_str::<serde_json::Value>(line.trim()).is_err());
+        assert!(line.contains("Synced issues"), "{}", line);
+        assert!(line.contains("PROJ"), "{}", line);
+    }
+}
@@ -0,0 +1,101 @@
+use std::future::Future;
+
+use clap::Args;
+use sqlx::migrate::MigrateError;
+use tracing::info;
+
+/// CLI arguments controlling the migration step run on startup.
+#[derive(Debug, Args)]
+pub struct MigrationArgs {
+    /// Do not apply database migrations on startup, for deployments that apply them
+    /// separately or connect without DDL permissions.
+    #[arg(long, env = "SKIP_MIGRATIONS")]
+    pub skip_migrations: bool,
+}
+
+/// Applies pending migrations through `migrate`, unless `--skip-migrations` was given.
+///
+/// Migrations already recorded as applied are skipped, so running this on every start
+/// is safe. A failure leaves the migrations applied before it in place.
+pub async fn run_migrations<F, Fut>(
+    args: &MigrationArgs,
+    migrate: F,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<(), MigrateError>>,
+{
+    if args.skip_migrations {
+        info!("Skipping database migrations (--skip-migrations)");
+        return Ok(());
+    }
+
+    migrate().await.map_err(|e| {
+        format!(
+            "Database migrations failed: {}. Migrations applied before the failure are kept; \
+             fix the cause and restart, or apply migrations separately and start with \
+             --skip-migrations",
+            e
+        )
+    })?;
+    info!("Database migrations completed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[derive(Debug, Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        migrations: MigrationArgs,
+    }
+
+    #[test]
+    fn test_skip_migrations_flag_parsing() {
+        let args = TestArgs::try_parse_from(["server"]).unwrap();
+        assert!(!args.migrations.skip_migrations);
+
+        let args = TestArgs::try_parse_from(["server", "--skip-migrations"]).unwrap();
+        assert!(args.migrations.skip_migrations);
+    }
+
+    #[tokio::test]
+    async fn test_skip_migrations_does_not_call_migration_step() {
+        let called = AtomicBool::new(false);
+        let args = MigrationArgs {
+            skip_migrations: true,
+        };
+
+        run_migrations(&args, || async {
+            called.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert!(!called.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_migration_failure_names_the_way_out() {
+        let args = MigrationArgs {
+            skip_migrations: false,
+        };
+
+        let err = run_migrations(&args, || async { Err(MigrateError::VersionMissing(3)) })
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(
+            message.starts_with("Database migrations failed:"),
+            "{}",
+            message
+        );
+        assert!(message.contains("--skip-migrations"), "{}", message);
+    }
+}
@@ -1,5 +1,18 @@
+mod backfill_jira_issues;
+mod check_jira_auth;
+mod dump_project;
+mod log_format;
+mod migrations;
+mod sync_jira_changelog;
 mod sync_jira_issues;
 mod sync_jira_projects;
+mod sync_report;
 
+pub use backfill_jira_issues::{BackfillJiraIssuesArgs, backfill_floor, run_backfill_jira_issues};
+pub use check_jira_auth::run_check_jira_auth;
+pub use dump_project::{DumpProjectArgs, run_dump_project};
+pub use log_format::LogFormat;
+pub use migrations::{MigrationArgs, run_migrations};
+pub use sync_jira_changelog::{parse_issue_key, run_sync_jira_changelog};
 pub use sync_jira_issues::{SyncJiraIssuesArgs, run_sync_jira_issues};
 pub use sync_jira_projects::run_sync_jira_projects;
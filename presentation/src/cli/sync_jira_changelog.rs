@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use application::usecase::command::jira::JiraIssueChangelogSyncUseCase;
+use domain::value_object::jira::JiraIssueKey;
+
+/// Parses an issue key argument, rejecting keys the domain considers malformed.
+pub fn parse_issue_key(value: &str) -> Result<JiraIssueKey, String> {
+    JiraIssueKey::of(value.trim()).map_err(|e| e.to_string())
+}
+
+/// Runs the Jira issue changelog sync job for the issues with `issue_keys`.
+pub async fn run_sync_jira_changelog<U: JiraIssueChangelogSyncUseCase>(
+    usecase: Arc<U>,
+    issue_keys: Vec<JiraIssueKey>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!(
+        "Starting Jira issue changelog sync job for {} issues...",
+        issue_keys.len()
+    );
+
+    match usecase.execute(issue_keys).await {
+        Ok(stats) => {
+            info!("Jira issue changelog sync completed successfully!");
+            info!(
+                "Issues: {}, changelog entries fetched: {}, newly stored: {}",
+                stats.issues, stats.fetched, stats.stored
+            );
+            Ok(())
+        }
+        Err(e) => {
+            error!("Jira issue changelog sync failed: {}", e);
+            Err(Box::new(e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_key_accepts_issue_keys() {
+        assert_eq!(
+            parse_issue_key(" PROJ-12 "),
+            Ok(JiraIssueKey::new("PROJ-12"))
+        );
+    }
+
+    #[test]
+    fn test_parse_issue_key_rejects_paths() {
+        assert!(parse_issue_key("../myself").is_err());
+        assert!(parse_issue_key("PROJ-1/changelog").is_err());
+    }
+}
@@ -2,9 +2,15 @@ use std::sync::Arc;
 
 use chrono::{Duration, Utc};
 use clap::Args;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use application::usecase::command::jira::JiraIssueSyncUseCase;
+use application::dto::command::jira::{
+    JiraIssueSyncParams, JiraIssueSyncReport, JiraIssueSyncStatus,
+};
+use application::usecase::command::jira::{DEFAULT_PROJECT_KEY_CHUNK_SIZE, JiraIssueSyncUseCase};
+use domain::value_object::jira::JiraProjectKey;
+
+use super::sync_report::format_sync_report;
 
 /// CLI arguments for the sync-issues command.
 #[derive(Debug, Args)]
@@ -12,6 +18,42 @@ pub struct SyncJiraIssuesArgs {
     /// Number of days to look back for updated issues.
     #[arg(short, long, default_value = "90")]
     pub days: i64,
+
+    /// Report per-project issue counts without persisting anything.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Number of project keys combined into one JQL query.
+    #[arg(long, default_value_t = DEFAULT_PROJECT_KEY_CHUNK_SIZE)]
+    pub project_chunk_size: usize,
+
+    /// Keep fetching pages while earlier batches are persisted, holding at most this
+    /// many issues in memory. Without it each page is persisted before the next fetch.
+    #[arg(long)]
+    pub max_in_flight_issues: Option<usize>,
+
+    /// Also store the links between issues ("blocks", "relates to") of every synced issue.
+    #[arg(long)]
+    pub with_links: bool,
+
+    /// Comma-separated project keys to sync instead of every project in the database.
+    #[arg(long, value_delimiter = ',', value_parser = parse_project_key)]
+    pub projects: Vec<JiraProjectKey>,
+}
+
+/// Parses a single `--projects` entry, rejecting keys the domain considers invalid.
+fn parse_project_key(value: &str) -> Result<JiraProjectKey, String> {
+    JiraProjectKey::of(value.trim()).map_err(|e| e.to_string())
+}
+
+/// Returns the sync params of the run: the look-back window, `--projects` and `--dry-run`.
+pub fn sync_params(args: &SyncJiraIssuesArgs) -> JiraIssueSyncParams {
+    let mut params =
+        JiraIssueSyncParams::new(Utc::now() - Duration::days(args.days)).with_dry_run(args.dry_run);
+    if !args.projects.is_empty() {
+        params = params.with_project_keys(args.projects.clone());
+    }
+    params
 }
 
 /// Runs the Jira issue sync job.
@@ -21,13 +63,34 @@ pub async fn run_sync_jira_issues<U: JiraIssueSyncUseCase>(
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting Jira issue sync job...");
     info!("Looking back {} days for updated issues", args.days);
+    if !args.projects.is_empty() {
+        let keys: Vec<&str> = args.projects.iter().map(JiraProjectKey::value).collect();
+        info!("Limiting sync to projects: {}", keys.join(", "));
+    }
+    if args.dry_run {
+        info!("Dry run: no issues will be persisted");
+    }
 
-    let since = Utc::now() - Duration::days(args.days);
+    // Stop between batches on Ctrl-C so no batch is left half-applied
+    let cancel = Box::pin(async {
+        let _ = tokio::signal::ctrl_c().await;
+    });
 
-    match usecase.execute(since).await {
-        Ok(count) => {
+    match usecase.execute_with_params(sync_params(args), cancel).await {
+        Ok(JiraIssueSyncStatus::Completed(report)) => {
             info!("Jira issue sync completed successfully!");
-            info!("Total issues synced: {}", count);
+            info!("{}", format_sync_report(&report));
+            if args.dry_run {
+                log_project_counts(&report);
+            }
+            Ok(())
+        }
+        Ok(JiraIssueSyncStatus::Cancelled(report)) => {
+            warn!("Jira issue sync cancelled");
+            info!("Before cancellation: {}", format_sync_report(&report));
+            if args.dry_run {
+                log_project_counts(&report);
+            }
             Ok(())
         }
         Err(e) => {
@@ -36,3 +99,81 @@ pub async fn run_sync_jira_issues<U: JiraIssueSyncUseCase>(
         }
     }
 }
+
+/// Logs how many issues each project would contribute in a dry run.
+fn log_project_counts(report: &JiraIssueSyncReport) {
+    for (project_key, count) in &report.per_project {
+        info!("  {}: {} issues", project_key, count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct TestArgs {
+        #[command(flatten)]
+        sync: SyncJiraIssuesArgs,
+    }
+
+    #[test]
+    fn test_projects_defaults_to_empty() {
+        let args = TestArgs::try_parse_from(["sync-jira-issues"]).unwrap();
+
+        assert!(args.sync.projects.is_empty());
+    }
+
+    #[test]
+    fn test_projects_parses_comma_separated_keys() {
+        let args =
+            TestArgs::try_parse_from(["sync-jira-issues", "--projects", "PROJ1, PROJ2"]).unwrap();
+
+        assert_eq!(
+            args.sync.projects,
+            vec![JiraProjectKey::new("PROJ1"), JiraProjectKey::new("PROJ2")]
+        );
+    }
+
+    #[test]
+    fn test_sync_params_carry_projects_and_dry_run() {
+        let args = TestArgs::try_parse_from([
+            "sync-jira-issues",
+            "--days",
+            "7",
+            "--dry-run",
+            "--projects",
+            "PROJ1",
+        ])
+        .unwrap();
+
+        let params = sync_params(&args.sync);
+
+        assert!(params.dry_run);
+        assert_eq!(
+            params.project_keys,
+            Some(vec![JiraProjectKey::new("PROJ1")])
+        );
+        assert!(params.since <= Utc::now() - Duration::days(7));
+    }
+
+    #[test]
+    fn test_sync_params_sync_every_project_by_default() {
+        let args = TestArgs::try_parse_from(["sync-jira-issues"]).unwrap();
+
+        let params = sync_params(&args.sync);
+
+        assert!(!params.dry_run);
+        assert_eq!(params.project_keys, None);
+    }
+
+    #[test]
+    fn test_projects_rejects_empty_key() {
+        let result = TestArgs::try_parse_from(["sync-jira-issues", "--projects", "PROJ1,,PROJ2"]);
+
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(err.to_string().contains("Project key cannot be empty"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use application::usecase::command::jira::JiraProjectSyncUseCase;
 
@@ -11,9 +11,21 @@ pub async fn run_sync_jira_projects<U: JiraProjectSyncUseCase>(
     info!("Starting Jira project sync job...");
 
     match usecase.execute().await {
-        Ok(count) => {
+        Ok(stats) => {
+            for conflict in &stats.id_conflicts {
+                let dropped: Vec<&str> = conflict.dropped.iter().map(|key| key.value()).collect();
+                warn!(
+                    "Jira returned several projects with id {}: kept {}, skipped {}",
+                    conflict.id,
+                    conflict.kept,
+                    dropped.join(", ")
+                );
+            }
             info!("Jira project sync completed successfully!");
-            info!("Total projects synced: {}", count);
+            info!("Total projects synced: {}", stats.synced);
+            if !stats.id_conflicts.is_empty() {
+                info!("Project id conflicts: {}", stats.id_conflicts.len());
+            }
             Ok(())
         }
         Err(e) => {
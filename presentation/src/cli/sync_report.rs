@@ -0,0 +1,55 @@
+use application::dto::command::jira::JiraIssueSyncReport;
+
+/// Formats a sync report as a one-line summary for the job log.
+pub(crate) fn format_sync_report(report: &JiraIssueSyncReport) -> String {
+    let watermark = report
+        .watermark
+        .map(|watermark| watermark.to_rfc3339())
+        .unwrap_or_else(|| "none".to_string());
+    format!(
+        "{} issues synced, {} skipped, {} rejected, {} API pages fetched, watermark {}, took {:.2}s",
+        report.synced,
+        report.skipped,
+        report.rejected,
+        report.pages_fetched,
+        watermark,
+        report.duration.as_secs_f64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_sync_report() {
+        let report = JiraIssueSyncReport {
+            synced: 120,
+            skipped: 3,
+            rejected: 1,
+            pages_fetched: 2,
+            watermark: Some(Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap()),
+            duration: Duration::from_millis(4250),
+            per_project: vec![],
+        };
+
+        assert_eq!(
+            format_sync_report(&report),
+            "120 issues synced, 3 skipped, 1 rejected, 2 API pages fetched, \
+             watermark 2024-03-01T12:30:00+00:00, took 4.25s"
+        );
+    }
+
+    #[test]
+    fn test_format_sync_report_without_watermark() {
+        let report = JiraIssueSyncReport::default();
+
+        assert_eq!(
+            format_sync_report(&report),
+            "0 issues synced, 0 skipped, 0 rejected, 0 API pages fetched, watermark none, \
+             took 0.00s"
+        );
+    }
+}